@@ -0,0 +1,260 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Populates the specification cache from a single archive, for environments without network
+//! access
+
+use crate::Error;
+use anyhow::anyhow;
+use std::{
+    ffi::OsStr,
+    fs::File,
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+/// The directory archive entries may optionally be nested under
+const CACHE_PREFIX: &str = "specs";
+
+type OnEntry<'a> = dyn FnMut(&Path, &mut dyn Read) -> Result<(), Error> + 'a;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+impl Kind {
+    fn from_path(path: &Path) -> Result<Self, Error> {
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if name.ends_with(".tar") {
+            Ok(Self::Tar)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Ok(Self::TarGz)
+        } else if name.ends_with(".zip") {
+            Ok(Self::Zip)
+        } else {
+            Err(anyhow!(
+                "unsupported spec archive {:?}; expected a .tar, .tar.gz, .tgz or .zip file",
+                path
+            ))
+        }
+    }
+}
+
+/// Extracts the specifications contained in `archive` into the `cache` directory
+///
+/// Entries must follow the cache layout (`<host>/<path>.txt`) and may optionally be nested
+/// under a `specs/` directory. Files which already exist in the cache are left untouched.
+///
+/// Returns the number of files which were written to the cache.
+pub fn populate(archive: &Path, cache: &Path) -> Result<usize, Error> {
+    let kind = Kind::from_path(archive)?;
+    let file = File::open(archive)
+        .map_err(|err| anyhow!("could not open spec archive {:?}: {}", archive, err))?;
+
+    let mut populated = 0;
+    let mut on_entry = |name: &Path, contents: &mut dyn Read| -> Result<(), Error> {
+        let path = cache.join(cache_path(name)?);
+
+        if path.exists() {
+            return Ok(());
+        }
+
+        let mut buffer = vec![];
+        contents.read_to_end(&mut buffer)?;
+
+        std::fs::create_dir_all(path.parent().unwrap())?;
+        std::fs::write(&path, buffer)?;
+        populated += 1;
+
+        Ok(())
+    };
+
+    match kind {
+        Kind::Tar => tar_entries(file, &mut on_entry)?,
+        Kind::TarGz => tar_entries(flate2::read::GzDecoder::new(file), &mut on_entry)?,
+        Kind::Zip => zip_entries(file, &mut on_entry)?,
+    }
+
+    Ok(populated)
+}
+
+fn tar_entries<R: Read>(reader: R, on_entry: &mut OnEntry) -> Result<(), Error> {
+    let mut archive = tar::Archive::new(reader);
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_type = entry.header().entry_type();
+
+        if entry_type.is_dir() {
+            continue;
+        }
+
+        let name = entry.path()?.into_owned();
+
+        if !entry_type.is_file() {
+            return Err(anyhow!(
+                "spec archive entry {:?} is not a regular file",
+                name
+            ));
+        }
+
+        on_entry(&name, &mut entry)?;
+    }
+
+    Ok(())
+}
+
+fn zip_entries<R: Read + io::Seek>(reader: R, on_entry: &mut OnEntry) -> Result<(), Error> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+
+        if entry.is_dir() {
+            continue;
+        }
+
+        let name = PathBuf::from(entry.name());
+        on_entry(&name, &mut entry)?;
+    }
+
+    Ok(())
+}
+
+/// Maps an archive entry to its path relative to the cache, verifying it matches the layout
+/// used by `TargetPath::local`
+fn cache_path(name: &Path) -> Result<PathBuf, Error> {
+    let mut components = vec![];
+
+    for component in name.components() {
+        match component {
+            Component::Normal(component) => components.push(component),
+            Component::CurDir => continue,
+            _ => {
+                return Err(anyhow!(
+                    "spec archive entry {:?} must be a relative path within the archive",
+                    name
+                ))
+            }
+        }
+    }
+
+    if components.first() == Some(&OsStr::new(CACHE_PREFIX)) {
+        components.remove(0);
+    }
+
+    let is_text = components.last().map_or(false, |file| {
+        Path::new(file).extension() == Some(OsStr::new("txt"))
+    });
+
+    if components.len() < 2 || !is_text {
+        return Err(anyhow!(
+            "spec archive entry {:?} does not match the cache layout `<host>/<path>.txt`",
+            name
+        ));
+    }
+
+    Ok(components.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_path_test() {
+        let valid = [
+            (
+                "www.rfc-editor.org/rfc/rfc9000.txt",
+                "www.rfc-editor.org/rfc/rfc9000.txt",
+            ),
+            (
+                "./www.rfc-editor.org/rfc/rfc9000.txt",
+                "www.rfc-editor.org/rfc/rfc9000.txt",
+            ),
+            (
+                "specs/www.rfc-editor.org/rfc/rfc9000.txt",
+                "www.rfc-editor.org/rfc/rfc9000.txt",
+            ),
+            (
+                "datatracker.ietf.org/doc/draft.txt",
+                "datatracker.ietf.org/doc/draft.txt",
+            ),
+        ];
+
+        for (name, expected) in valid {
+            assert_eq!(
+                cache_path(Path::new(name)).unwrap(),
+                Path::new(expected),
+                "{}",
+                name
+            );
+        }
+
+        let invalid = [
+            "rfc9000.txt",
+            "specs/rfc9000.txt",
+            "www.rfc-editor.org/rfc/rfc9000.html",
+            "../www.rfc-editor.org/rfc/rfc9000.txt",
+            "/www.rfc-editor.org/rfc/rfc9000.txt",
+        ];
+
+        for name in invalid {
+            assert!(cache_path(Path::new(name)).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn populate_test() {
+        let dir = std::env::temp_dir().join(format!("duvet-archive-{}", std::process::id()));
+        let cache = dir.join("specs");
+        std::fs::create_dir_all(&cache).unwrap();
+
+        let contents = b"Network Working Group\n";
+        let name = "specs/www.rfc-editor.org/rfc/rfc9000.txt";
+
+        let tar_path = dir.join("specs.tar");
+        let mut builder = tar::Builder::new(File::create(&tar_path).unwrap());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, &contents[..])
+            .unwrap();
+        builder.into_inner().unwrap();
+
+        let zip_path = dir.join("specs.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&zip_path).unwrap());
+        writer
+            .start_file(
+                "www.rfc-editor.org/rfc/rfc9001.txt",
+                zip::write::FileOptions::default(),
+            )
+            .unwrap();
+        io::Write::write_all(&mut writer, contents).unwrap();
+        writer.finish().unwrap();
+
+        assert_eq!(populate(&tar_path, &cache).unwrap(), 1);
+        assert_eq!(populate(&zip_path, &cache).unwrap(), 1);
+
+        // existing files are not overwritten
+        assert_eq!(populate(&tar_path, &cache).unwrap(), 0);
+
+        for rfc in ["rfc9000", "rfc9001"] {
+            let path = cache.join(format!("www.rfc-editor.org/rfc/{}.txt", rfc));
+            assert_eq!(std::fs::read(path).unwrap(), contents);
+        }
+
+        assert!(populate(&dir.join("specs.rar"), &cache).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
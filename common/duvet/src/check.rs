@@ -0,0 +1,173 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    annotation::{Annotation, AnnotationSet},
+    project::Project,
+    target::{TargetFilter, TargetSet},
+    Error,
+};
+use anyhow::anyhow;
+use core::fmt;
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap};
+use structopt::StructOpt;
+
+/// Verifies every annotation resolves to a section of a specification, without matching quotes
+#[derive(Debug, StructOpt)]
+pub struct Check {
+    #[structopt(flatten)]
+    project: Project,
+
+    /// Only checks annotations with targets whose path or URL matches the glob pattern or
+    /// contains the substring. May be specified multiple times.
+    #[structopt(long = "target-filter")]
+    target_filters: Vec<TargetFilter>,
+}
+
+#[derive(Debug)]
+enum CheckError<'a> {
+    UnresolvedTarget {
+        annotation: &'a Annotation,
+        error: String,
+    },
+    UnloadableTarget {
+        annotation: &'a Annotation,
+        error: &'a str,
+    },
+    MissingSection {
+        annotation: &'a Annotation,
+    },
+}
+
+impl<'a> fmt::Display for CheckError<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnresolvedTarget { annotation, error } => write!(
+                f,
+                "{}#{}:{} - target {:?} could not be resolved: {}",
+                annotation.source.display(),
+                annotation.anno_line,
+                annotation.anno_column,
+                annotation.target_path(),
+                error,
+            ),
+            Self::UnloadableTarget { annotation, error } => write!(
+                f,
+                "{}#{}:{} - target {:?} could not be loaded: {}",
+                annotation.source.display(),
+                annotation.anno_line,
+                annotation.anno_column,
+                annotation.target_path(),
+                error,
+            ),
+            Self::MissingSection { annotation } => write!(
+                f,
+                "{}#{}:{} - section {:?} not found in {:?}",
+                annotation.source.display(),
+                annotation.anno_line,
+                annotation.anno_column,
+                annotation.target_section().unwrap_or("-"),
+                annotation.target_path(),
+            ),
+        }
+    }
+}
+
+impl Check {
+    pub fn exec(&self) -> Result<(), Error> {
+        self.project.load_spec_archive()?;
+
+        let project_sources = self.project.sources()?;
+
+        let annotations: AnnotationSet = project_sources
+            .par_iter()
+            .flat_map(|source| {
+                // TODO gracefully handle error
+                source
+                    .annotations()
+                    .unwrap_or_else(|_| panic!("could not extract annotations from {:?}", source))
+            })
+            .collect();
+
+        let mut errors = BTreeSet::new();
+        let mut resolved = vec![];
+
+        // resolve each annotation individually so every unresolvable target is reported, rather
+        // than only the first one
+        for annotation in annotations.iter() {
+            match annotation.target() {
+                Ok(target) => {
+                    if self.target_filters.is_empty()
+                        || self
+                            .target_filters
+                            .iter()
+                            .any(|filter| filter.matches(&target))
+                    {
+                        resolved.push((annotation, target));
+                    }
+                }
+                Err(error) => {
+                    let error = error.to_string();
+                    errors.insert(CheckError::UnresolvedTarget { annotation, error }.to_string());
+                }
+            }
+        }
+
+        let targets: TargetSet = resolved.iter().map(|(_, target)| target.clone()).collect();
+
+        let contents: HashMap<_, _> = targets
+            .par_iter()
+            .map(|target| {
+                let contents = target.path.load().map_err(|err| err.to_string());
+                (target, contents)
+            })
+            .collect();
+
+        let specifications: HashMap<_, _> = contents
+            .par_iter()
+            .map(|(target, contents)| {
+                let spec = match contents {
+                    Ok(contents) => target.format.parse(contents).map_err(|err| err.to_string()),
+                    Err(err) => Err(err.clone()),
+                };
+                (*target, spec)
+            })
+            .collect();
+
+        for (annotation, target) in &resolved {
+            let spec = specifications
+                .get(target)
+                .expect("all targets should be loaded");
+
+            let error = match spec {
+                Ok(spec) => match annotation.target_section() {
+                    Some(section) if !spec.sections.contains_key(section) => {
+                        CheckError::MissingSection { annotation }
+                    }
+                    // annotations without a section reference the entire specification
+                    _ => continue,
+                },
+                Err(error) => CheckError::UnloadableTarget {
+                    annotation,
+                    error: error.as_str(),
+                },
+            };
+
+            errors.insert(error.to_string());
+        }
+
+        if !errors.is_empty() {
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+
+            return Err(anyhow!(
+                "{} annotations could not be resolved",
+                errors.len()
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -5,8 +5,9 @@
 
 use crate::{
     annotation::AnnotationLevel,
+    archive,
     specification::{Format, Section},
-    target::TargetPath,
+    target::{self, TargetPath},
     Error,
 };
 use core::ops::Deref;
@@ -53,11 +54,20 @@ pub struct Extract {
     #[structopt(short, long, default_value = ".")]
     out: PathBuf,
 
+    /// Archive (.tar, .tar.gz, .tgz or .zip) used to populate the spec cache instead of
+    /// downloading the target
+    #[structopt(long = "spec-archive")]
+    spec_archive: Option<PathBuf>,
+
     target: TargetPath,
 }
 
 impl Extract {
     pub fn exec(&self) -> Result<(), Error> {
+        if let Some(spec_archive) = &self.spec_archive {
+            archive::populate(spec_archive, &target::cache_dir())?;
+        }
+
         let contents = self.target.load()?;
         let spec = self.format.parse(&contents)?;
 
@@ -4,6 +4,8 @@
 use structopt::StructOpt;
 
 mod annotation;
+mod archive;
+mod check;
 mod extract;
 mod parser;
 mod pattern;
@@ -25,6 +27,7 @@ fn main() {
 
 #[derive(Debug, StructOpt)]
 enum Arguments {
+    Check(check::Check),
     Extract(extract::Extract),
     Report(report::Report),
 }
@@ -32,6 +35,7 @@ enum Arguments {
 impl Arguments {
     pub fn exec(&self) -> Result<(), Error> {
         match self {
+            Self::Check(args) => args.exec(),
             Self::Extract(args) => args.exec(),
             Self::Report(args) => args.exec(),
         }
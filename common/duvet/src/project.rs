@@ -1,9 +1,9 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{pattern::Pattern, source::SourceFile, Error};
+use crate::{archive, pattern::Pattern, source::SourceFile, target, Error};
 use glob::glob;
-use std::collections::HashSet;
+use std::{collections::HashSet, path::PathBuf};
 use structopt::StructOpt;
 
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Hash, StructOpt)]
@@ -55,9 +55,23 @@ pub struct Project {
     /// Glob patterns for spec files
     #[structopt(long = "spec-pattern")]
     spec_patterns: Vec<String>,
+
+    /// Archive (.tar, .tar.gz, .tgz or .zip) used to populate the spec cache instead of
+    /// downloading specs
+    #[structopt(long = "spec-archive")]
+    spec_archive: Option<PathBuf>,
 }
 
 impl Project {
+    /// Populates the spec cache from the `--spec-archive`, if one was provided
+    pub fn load_spec_archive(&self) -> Result<(), Error> {
+        if let Some(spec_archive) = &self.spec_archive {
+            archive::populate(spec_archive, &target::cache_dir())?;
+        }
+
+        Ok(())
+    }
+
     pub fn sources(&self) -> Result<HashSet<SourceFile>, Error> {
         let mut sources = HashSet::new();
 
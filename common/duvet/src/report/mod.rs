@@ -5,7 +5,7 @@ use crate::{
     annotation::{Annotation, AnnotationLevel, AnnotationSet, AnnotationSetExt},
     project::Project,
     specification::Specification,
-    target::Target,
+    target::{Target, TargetFilter},
     Error,
 };
 use anyhow::anyhow;
@@ -54,6 +54,11 @@ pub struct Report {
 
     #[structopt(long)]
     issue_link: Option<String>,
+
+    /// Only reports on targets whose path or URL matches the glob pattern or contains the
+    /// substring. May be specified multiple times.
+    #[structopt(long = "target-filter")]
+    target_filters: Vec<TargetFilter>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord)]
@@ -98,6 +103,8 @@ impl<'a> fmt::Display for ReportError<'a> {
 
 impl Report {
     pub fn exec(&self) -> Result<(), Error> {
+        self.project.load_spec_archive()?;
+
         let project_sources = self.project.sources()?;
 
         let annotations: AnnotationSet = project_sources
@@ -110,7 +117,15 @@ impl Report {
             })
             .collect();
 
-        let targets = annotations.targets()?;
+        let mut targets = annotations.targets()?;
+
+        if !self.target_filters.is_empty() {
+            targets.retain(|target| {
+                self.target_filters
+                    .iter()
+                    .any(|filter| filter.matches(target))
+            });
+        }
 
         let contents: HashMap<_, _> = targets
             .par_iter()
@@ -132,6 +147,7 @@ impl Report {
 
         let results: Vec<_> = reference_map
             .par_iter()
+            .filter(|((target, _), _)| targets.contains(target))
             .flat_map(|((target, section_id), annotations)| {
                 let spec = specifications.get(&target).expect("spec already checked");
 
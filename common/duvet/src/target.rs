@@ -27,6 +27,36 @@ impl Target {
     }
 }
 
+/// Selects targets by matching their path or URL against a glob pattern or substring
+#[derive(Clone, Debug)]
+pub enum TargetFilter {
+    Glob(glob::Pattern),
+    Substring(String),
+}
+
+impl TargetFilter {
+    pub fn matches(&self, target: &Target) -> bool {
+        let path = target.path.to_string();
+        match self {
+            Self::Glob(pattern) => pattern.matches(&path),
+            Self::Substring(value) => path.contains(value.as_str()),
+        }
+    }
+}
+
+impl FromStr for TargetFilter {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // only treat the filter as a glob if it contains pattern characters
+        if s.contains(|c| matches!(c, '*' | '?' | '[')) {
+            return Ok(Self::Glob(glob::Pattern::new(s)?));
+        }
+
+        Ok(Self::Substring(s.to_owned()))
+    }
+}
+
 impl FromStr for Target {
     type Err = Error;
 
@@ -38,6 +68,13 @@ impl FromStr for Target {
     }
 }
 
+/// Returns the directory in which targets referenced by URL are cached
+pub fn cache_dir() -> PathBuf {
+    let mut path = std::env::current_dir().unwrap();
+    path.push("specs");
+    path
+}
+
 #[derive(Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash)]
 pub enum TargetPath {
     Url(Url),
@@ -106,8 +143,7 @@ impl TargetPath {
     pub fn local(&self) -> PathBuf {
         match self {
             Self::Url(url) => {
-                let mut path = std::env::current_dir().unwrap();
-                path.push("specs");
+                let mut path = cache_dir();
                 path.push(url.host_str().expect("url should have host"));
                 path.extend(url.path_segments().expect("url should have path"));
                 path.set_extension("txt");
@@ -147,3 +183,28 @@ impl FromStr for TargetPath {
         Ok(Self::Path(path))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_test() {
+        let rfc: Target = "https://www.rfc-editor.org/rfc/rfc9000.txt"
+            .parse()
+            .unwrap();
+        let local: Target = "specs/draft-ietf-quic-tls.txt".parse().unwrap();
+
+        let filter: TargetFilter = "rfc9000".parse().unwrap();
+        assert!(filter.matches(&rfc));
+        assert!(!filter.matches(&local));
+
+        let filter: TargetFilter = "specs/*.txt".parse().unwrap();
+        assert!(!filter.matches(&rfc));
+        assert!(filter.matches(&local));
+
+        let filter: TargetFilter = "https://*".parse().unwrap();
+        assert!(filter.matches(&rfc));
+        assert!(!filter.matches(&local));
+    }
+}
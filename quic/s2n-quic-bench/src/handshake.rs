@@ -0,0 +1,125 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use criterion::{measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
+use s2n_quic::{client::Connect, provider::tls::offload, Client, Server};
+use s2n_quic_core::crypto::tls::testing::certificates::{CERT_PEM, KEY_PEM};
+use std::net::SocketAddr;
+use tokio::runtime::Runtime;
+
+/// The number of client endpoints which open the connections
+///
+/// Each endpoint performs its TLS operations inline on its own IO task, so the handshakes are
+/// spread across several clients to keep the client side from capping the server's throughput.
+const CLIENTS: usize = 8;
+
+pub fn benchmarks(c: &mut Criterion) {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    // the endpoints spawn their IO tasks on the runtime
+    let _guard = runtime.enter();
+
+    let clients: Vec<_> = (0..CLIENTS)
+        .map(|_| {
+            Client::builder()
+                .with_tls(CERT_PEM)
+                .unwrap()
+                .with_io("127.0.0.1:0")
+                .unwrap()
+                .start()
+                .unwrap()
+        })
+        .collect();
+
+    let inline = Server::builder()
+        .with_tls((CERT_PEM, KEY_PEM))
+        .unwrap()
+        .with_io("127.0.0.1:0")
+        .unwrap()
+        .start()
+        .unwrap();
+    let inline = spawn_server(&runtime, inline);
+
+    let offload = |threads| {
+        let tls = offload::Provider::new((CERT_PEM, KEY_PEM)).with_threads(threads);
+        let server = Server::builder()
+            .with_tls(tls)
+            .unwrap()
+            .with_io("127.0.0.1:0")
+            .unwrap()
+            .start()
+            .unwrap();
+        spawn_server(&runtime, server)
+    };
+    let servers = [
+        ("inline", inline),
+        ("offload_1_thread", offload(1)),
+        ("offload_4_threads", offload(4)),
+    ];
+
+    let mut group = c.benchmark_group("handshake");
+    // the number of handshakes which are in progress at the same time
+    for concurrency in [1, 16, 64] {
+        group.throughput(Throughput::Elements(concurrency));
+        for (name, server_addr) in servers {
+            handshakes(
+                &mut group,
+                &runtime,
+                &clients,
+                name,
+                concurrency,
+                server_addr,
+            );
+        }
+    }
+    group.finish();
+}
+
+/// Measures the time it takes to complete `concurrency` handshakes with the server
+///
+/// The handshakes are spread across the `clients` in turn.
+fn handshakes(
+    group: &mut BenchmarkGroup<WallTime>,
+    runtime: &Runtime,
+    clients: &[Client],
+    name: &str,
+    concurrency: u64,
+    server_addr: SocketAddr,
+) {
+    group.bench_with_input(
+        BenchmarkId::new(name, concurrency),
+        &concurrency,
+        |b, concurrency| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    let handshakes: Vec<_> = clients
+                        .iter()
+                        .cycle()
+                        .take(*concurrency as usize)
+                        .map(|client| {
+                            let client = client.clone();
+                            tokio::spawn(async move {
+                                let connect =
+                                    Connect::new(server_addr).with_server_name("localhost");
+                                client.connect(connect).await.unwrap()
+                            })
+                        })
+                        .collect();
+
+                    for handshake in handshakes {
+                        handshake.await.unwrap();
+                    }
+                })
+            });
+        },
+    );
+}
+
+/// Accepts and drops the server's connections on the runtime and returns the server's address
+fn spawn_server(runtime: &Runtime, mut server: Server) -> SocketAddr {
+    let server_addr = server.local_addr().unwrap();
+    runtime.spawn(async move { while server.accept().await.is_some() {} });
+    server_addr
+}
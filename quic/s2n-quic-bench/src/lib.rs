@@ -5,12 +5,14 @@ use criterion::Criterion;
 
 mod crypto;
 mod frame;
+mod handshake;
 mod packet;
 mod varint;
 
 pub fn benchmarks(c: &mut Criterion) {
     crypto::benchmarks(c);
     frame::benchmarks(c);
+    handshake::benchmarks(c);
     packet::benchmarks(c);
     varint::benchmarks(c);
 }
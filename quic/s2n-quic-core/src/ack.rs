@@ -5,4 +5,4 @@ pub mod set;
 pub mod settings;
 
 pub use set::Set;
-pub use settings::Settings;
+pub use settings::{Priority, Settings};
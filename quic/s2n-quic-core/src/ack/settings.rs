@@ -16,6 +16,9 @@ const RECOMMENDED_ELICITATION_INTERVAL: u8 = 4;
 /// The recommended number of packet number ranges that an endpoint should store
 const RECOMMENDED_RANGES_LIMIT: u8 = 10;
 
+/// The recommended number of ack-eliciting packets received before an ACK is sent immediately
+pub const RECOMMENDED_PACKET_TOLERANCE: u8 = 10;
+
 /// Settings for ACK frames
 #[derive(Clone, Copy, Debug)]
 pub struct Settings {
@@ -41,6 +44,41 @@ pub struct Settings {
     pub ack_ranges_limit: u8,
 }
 
+/// Controls whether ACK frames or ack-eliciting data is written first into a packet
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Priority {
+    /// ACK frames are written before any other frames
+    ///
+    /// This keeps the peer's loss detection and congestion controller responsive, since pending
+    /// ACKs are never held back by data.
+    Ack,
+
+    /// Ack-eliciting frames are written first and ACK frames fill the remaining space
+    ///
+    /// When the packet is filled with data, the pending ACK is deferred to a later packet. Once the
+    /// congestion window is exhausted, the ACK is sent on its own in an ACK-only packet, which is
+    /// not subject to congestion control.
+    Data,
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Self::Ack
+    }
+}
+
+impl Priority {
+    #[inline]
+    pub fn is_ack(self) -> bool {
+        matches!(self, Self::Ack)
+    }
+
+    #[inline]
+    pub fn is_data(self) -> bool {
+        matches!(self, Self::Data)
+    }
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self::RECOMMENDED
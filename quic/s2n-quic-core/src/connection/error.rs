@@ -74,6 +74,18 @@ pub enum Error {
         source: &'static panic::Location<'static>,
     },
 
+    /// The connection was closed because the deadline set by the application was reached
+    #[non_exhaustive]
+    DeadlineExceeded {
+        source: &'static panic::Location<'static>,
+    },
+
+    /// The connection was closed because the cancellation set by the application completed
+    #[non_exhaustive]
+    Cancelled {
+        source: &'static panic::Location<'static>,
+    },
+
     /// The connection should be closed immediately without notifying the peer
     #[non_exhaustive]
     ImmediateClose {
@@ -87,6 +99,20 @@ pub enum Error {
         source: &'static panic::Location<'static>,
     },
 
+    /// The connection attempt was abandoned because the server does not support the QUIC
+    /// version selected by the client
+    #[non_exhaustive]
+    VersionNegotiation {
+        source: &'static panic::Location<'static>,
+    },
+
+    /// The handshake did not complete because the server kept responding with Retry packets
+    /// after the client had already processed one
+    #[non_exhaustive]
+    RetryLoop {
+        source: &'static panic::Location<'static>,
+    },
+
     /// The connection was closed due to an unspecified reason
     #[non_exhaustive]
     Unspecified {
@@ -145,6 +171,14 @@ impl fmt::Display for Error {
                 "The connection was closed because the handshake took longer than the max handshake \
                 duration of {:?}", max_handshake_duration
             ),
+            Self::DeadlineExceeded { .. } => write!(
+                f,
+                "The connection was closed because the connection's deadline was reached"
+            ),
+            Self::Cancelled { .. } => write!(
+                f,
+                "The connection was closed because the connection was cancelled by the application"
+            ),
             Self::ImmediateClose { reason, .. } => write!(
                 f,
                 "The connection was closed due to: {}", reason
@@ -152,6 +186,16 @@ impl fmt::Display for Error {
             Self::EndpointClosing { .. } => {
                 write!(f, "The connection attempt was rejected because the endpoint is closing")
             }
+            Self::VersionNegotiation { .. } => write!(
+                f,
+                "The connection attempt was abandoned because the server does not support the \
+                selected QUIC version"
+            ),
+            Self::RetryLoop { .. } => write!(
+                f,
+                "The handshake did not complete because the server kept responding with Retry \
+                packets"
+            ),
             Self::Unspecified { .. } => {
                 write!(f, "The connection was closed due to an unspecified reason")
             }
@@ -171,8 +215,12 @@ impl Error {
             Error::NoValidPath { source } => source,
             Error::StreamIdExhausted { source } => source,
             Error::MaxHandshakeDurationExceeded { source, .. } => source,
+            Error::DeadlineExceeded { source } => source,
+            Error::Cancelled { source } => source,
             Error::ImmediateClose { source, .. } => source,
             Error::EndpointClosing { source } => source,
+            Error::VersionNegotiation { source } => source,
+            Error::RetryLoop { source } => source,
             Error::Unspecified { source } => source,
         }
     }
@@ -257,6 +305,22 @@ impl Error {
         }
     }
 
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn deadline_exceeded() -> Error {
+        let source = panic::Location::caller();
+        Error::DeadlineExceeded { source }
+    }
+
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn cancelled() -> Error {
+        let source = panic::Location::caller();
+        Error::Cancelled { source }
+    }
+
     #[inline]
     #[track_caller]
     #[doc(hidden)]
@@ -277,6 +341,22 @@ impl Error {
         Error::EndpointClosing { source }
     }
 
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn version_negotiation() -> Error {
+        let source = panic::Location::caller();
+        Error::VersionNegotiation { source }
+    }
+
+    #[inline]
+    #[track_caller]
+    #[doc(hidden)]
+    pub fn retry_loop() -> Error {
+        let source = panic::Location::caller();
+        Error::RetryLoop { source }
+    }
+
     #[inline]
     #[track_caller]
     #[doc(hidden)]
@@ -286,6 +366,87 @@ impl Error {
     }
 }
 
+impl Error {
+    /// Returns the category of the failure, for errors which occur while establishing a
+    /// connection
+    ///
+    /// The categories group the errors which call for the same reaction from a client.
+    /// See [`ConnectError::is_retryable`].
+    pub fn connect_error(&self) -> ConnectError {
+        match self {
+            Self::IdleTimerExpired { .. } | Self::MaxHandshakeDurationExceeded { .. } => {
+                ConnectError::Timeout
+            }
+            Self::VersionNegotiation { .. } => ConnectError::VersionNegotiation,
+            Self::RetryLoop { .. } => ConnectError::RetryLoop,
+            Self::Transport { code, .. } if *code == transport::Error::CONNECTION_REFUSED.code => {
+                ConnectError::ConnectionRefused
+            }
+            Self::Transport { code, .. } => {
+                let code = code.as_u64();
+                if (0x100..=0x1ff).contains(&code) {
+                    ConnectError::TlsAlert { code: code as u8 }
+                } else {
+                    ConnectError::Other
+                }
+            }
+            _ => ConnectError::Other,
+        }
+    }
+}
+
+/// The category of a failed connection attempt
+///
+/// Returned by [`Error::connect_error`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConnectError {
+    /// The handshake did not complete before the idle timeout or max handshake duration expired
+    ///
+    /// This usually means the server is unreachable or packets were lost.
+    Timeout,
+
+    /// The server does not support the QUIC version selected by the client
+    VersionNegotiation,
+
+    /// The TLS handshake failed with the given alert
+    TlsAlert { code: u8 },
+
+    /// The server refused the connection with a CONNECTION_REFUSED error
+    ConnectionRefused,
+
+    /// The server kept responding with Retry packets and never started the handshake
+    RetryLoop,
+
+    /// The connection attempt failed for any other reason
+    Other,
+}
+
+impl ConnectError {
+    /// Returns `true` if the connection attempt may succeed when it is retried
+    ///
+    /// Timeouts can be caused by transient network conditions and servers refuse connections
+    /// while they are overloaded, so the attempt may succeed at a later time. The other
+    /// failures are caused by a mismatch in configuration between the client and the server and
+    /// will keep failing until it is resolved.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout | Self::ConnectionRefused)
+    }
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Timeout => write!(f, "the connection attempt timed out"),
+            Self::VersionNegotiation => write!(f, "version negotiation failed"),
+            Self::TlsAlert { code } => write!(f, "the TLS handshake failed with alert {}", code),
+            Self::ConnectionRefused => write!(f, "the server refused the connection"),
+            Self::RetryLoop => write!(f, "the server kept responding with Retry packets"),
+            Self::Other => write!(f, "the connection attempt failed"),
+        }
+    }
+}
+
 /// Returns a CONNECTION_CLOSE frame for the given connection Error, if any
 ///
 /// The first item will be a close frame for an early (initial, handshake) packet.
@@ -357,8 +518,32 @@ pub fn as_frame<'a, F: connection::close::Formatter>(
             Some((early, one_rtt))
         }
         Error::MaxHandshakeDurationExceeded { .. } => None,
+        // The deadline is set by the application so the peer is notified that the connection was
+        // closed without an error
+        Error::DeadlineExceeded { .. } => {
+            let error = transport::Error::NO_ERROR.with_reason("connection deadline reached");
+
+            let early = formatter.format_early_transport_error(context, error);
+            let one_rtt = formatter.format_transport_error(context, error);
+
+            Some((early, one_rtt))
+        }
+        // Cancellation is also initiated by the application so the connection is closed without
+        // an error
+        Error::Cancelled { .. } => {
+            let error = transport::Error::NO_ERROR.with_reason("connection cancelled");
+
+            let early = formatter.format_early_transport_error(context, error);
+            let one_rtt = formatter.format_transport_error(context, error);
+
+            Some((early, one_rtt))
+        }
         Error::ImmediateClose { .. } => None,
         Error::EndpointClosing { .. } => None,
+        // The server doesn't have any connection state to close after sending a Version
+        // Negotiation packet
+        Error::VersionNegotiation { .. } => None,
+        Error::RetryLoop { .. } => None,
         Error::Unspecified { .. } => {
             let error =
                 transport::Error::INTERNAL_ERROR.with_reason("an unspecified error occurred");
@@ -442,8 +627,12 @@ impl From<Error> for std::io::ErrorKind {
             Error::NoValidPath { .. } => ErrorKind::Other,
             Error::StreamIdExhausted { .. } => ErrorKind::Other,
             Error::MaxHandshakeDurationExceeded { .. } => ErrorKind::TimedOut,
+            Error::DeadlineExceeded { .. } => ErrorKind::TimedOut,
+            Error::Cancelled { .. } => ErrorKind::Other,
             Error::ImmediateClose { .. } => ErrorKind::Other,
             Error::EndpointClosing { .. } => ErrorKind::Other,
+            Error::VersionNegotiation { .. } => ErrorKind::Other,
+            Error::RetryLoop { .. } => ErrorKind::Other,
             Error::Unspecified { .. } => ErrorKind::Other,
         }
     }
@@ -484,3 +673,48 @@ impl From<CryptoError> for ProcessingError {
         ProcessingError::CryptoError(inner_error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_error_test() {
+        assert_eq!(
+            Error::idle_timer_expired().connect_error(),
+            ConnectError::Timeout
+        );
+        assert_eq!(
+            Error::max_handshake_duration_exceeded(Duration::from_secs(10)).connect_error(),
+            ConnectError::Timeout
+        );
+        assert_eq!(
+            Error::version_negotiation().connect_error(),
+            ConnectError::VersionNegotiation
+        );
+        assert_eq!(Error::retry_loop().connect_error(), ConnectError::RetryLoop);
+        assert_eq!(
+            Error::from(transport::Error::CONNECTION_REFUSED).connect_error(),
+            ConnectError::ConnectionRefused
+        );
+        assert_eq!(
+            Error::from(CryptoError::HANDSHAKE_FAILURE).connect_error(),
+            ConnectError::TlsAlert {
+                code: CryptoError::HANDSHAKE_FAILURE.code
+            }
+        );
+        assert_eq!(
+            Error::from(transport::Error::PROTOCOL_VIOLATION).connect_error(),
+            ConnectError::Other
+        );
+        assert_eq!(
+            Error::stateless_reset().connect_error(),
+            ConnectError::Other
+        );
+
+        assert!(ConnectError::Timeout.is_retryable());
+        assert!(ConnectError::ConnectionRefused.is_retryable());
+        assert!(!ConnectError::VersionNegotiation.is_retryable());
+        assert!(!ConnectError::TlsAlert { code: 40 }.is_retryable());
+    }
+}
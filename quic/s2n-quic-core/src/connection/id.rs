@@ -273,6 +273,9 @@ impl core::fmt::Display for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 impl From<Error> for transport::Error {
     #[inline]
     fn from(error: Error) -> Self {
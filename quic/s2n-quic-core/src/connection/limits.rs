@@ -3,8 +3,11 @@
 
 use crate::{
     ack,
+    connection::Tags,
     event::{api::SocketAddress, IntoEvent},
-    inet, stream,
+    inet,
+    recovery::K_GRANULARITY,
+    stream,
     transport::parameters::{
         AckDelayExponent, ActiveConnectionIdLimit, InitialFlowControlLimits, InitialMaxData,
         InitialMaxStreamDataBidiLocal, InitialMaxStreamDataBidiRemote, InitialMaxStreamDataUni,
@@ -13,6 +16,7 @@ use crate::{
     },
 };
 use core::{convert::TryInto, time::Duration};
+use s2n_codec::decoder_invariant;
 
 pub use crate::transport::parameters::ValidationError;
 
@@ -28,6 +32,26 @@ const MAX_HANDSHAKE_DURATION_DEFAULT: Duration = Duration::from_secs(10);
 //# middleboxes from losing state for UDP flows [GATEWAY].
 const MAX_KEEP_ALIVE_PERIOD_DEFAULT: Duration = Duration::from_secs(30);
 
+/// A datagram can carry at most one packet from each of the Initial, Handshake, and
+/// ApplicationData packet number spaces, so this value doesn't limit coalescing
+const MAX_COALESCED_PACKETS_DEFAULT: u8 = 3;
+
+//= https://www.rfc-editor.org/rfc/rfc9000#section-5.1.1
+//# An endpoint that initiates migration and requires non-zero-length
+//# connection IDs SHOULD ensure that the pool of connection IDs
+//# available to its peer allows the peer to use a new connection ID on
+//# migration, as the peer will be unable to respond if the pool is
+//# exhausted.
+/// Keeps enough connection IDs at the peer for it to probe a new path while migrating
+///
+/// Increasing this value allows peers to probe more paths simultaneously at the expense of
+/// additional state to maintain.
+#[doc(hidden)]
+pub const ACTIVE_CONNECTION_ID_TARGET_DEFAULT: u8 = 3;
+
+/// Bounds the per-connection state required to track issued connection IDs
+const MAX_ACTIVE_CONNECTION_ID_TARGET: u8 = 8;
+
 #[non_exhaustive]
 #[derive(Debug)]
 pub struct ConnectionInfo<'a> {
@@ -56,14 +80,24 @@ pub struct Limits {
     pub(crate) max_open_remote_bidirectional_streams: InitialMaxStreamsBidi,
     pub(crate) max_open_remote_unidirectional_streams: InitialMaxStreamsUni,
     pub(crate) max_ack_delay: MaxAckDelay,
+    pub(crate) peer_max_ack_delay_limit: Option<MaxAckDelay>,
     pub(crate) ack_delay_exponent: AckDelayExponent,
     pub(crate) max_active_connection_ids: ActiveConnectionIdLimit,
+    pub(crate) active_connection_id_target: u8,
     pub(crate) ack_elicitation_interval: u8,
+    pub(crate) ack_elicitation: bool,
     pub(crate) ack_ranges_limit: u8,
+    pub(crate) ack_packet_tolerance: u8,
+    pub(crate) ack_priority: ack::Priority,
     pub(crate) max_send_buffer_size: stream::limits::MaxSendBufferSize,
+    pub(crate) max_remote_open_stream_rate: stream::limits::MaxRemoteOpenRate,
+    pub(crate) stream_starvation_threshold: Option<Duration>,
     pub(crate) max_handshake_duration: Duration,
     pub(crate) max_keep_alive_period: Duration,
+    pub(crate) rtt_probing_interval: Option<Duration>,
+    pub(crate) timer_granularity: Duration,
     pub(crate) max_datagram_frame_size: MaxDatagramFrameSize,
+    pub(crate) max_coalesced_packets: u8,
 }
 
 impl Default for Limits {
@@ -73,7 +107,8 @@ impl Default for Limits {
 }
 
 macro_rules! setter {
-    ($name:ident, $field:ident, $inner:ty) => {
+    ($(#[doc = $doc:expr])* $name:ident, $field:ident, $inner:ty) => {
+        $(#[doc = $doc])*
         pub fn $name(mut self, value: $inner) -> Result<Self, ValidationError> {
             self.$field = value.try_into()?;
             Ok(self)
@@ -94,14 +129,24 @@ impl Limits {
             max_open_remote_bidirectional_streams: InitialMaxStreamsBidi::RECOMMENDED,
             max_open_remote_unidirectional_streams: InitialMaxStreamsUni::RECOMMENDED,
             max_ack_delay: MaxAckDelay::RECOMMENDED,
+            peer_max_ack_delay_limit: None,
             ack_delay_exponent: AckDelayExponent::RECOMMENDED,
             max_active_connection_ids: ActiveConnectionIdLimit::RECOMMENDED,
+            active_connection_id_target: ACTIVE_CONNECTION_ID_TARGET_DEFAULT,
             ack_elicitation_interval: ack::Settings::RECOMMENDED.ack_elicitation_interval,
+            ack_elicitation: true,
             ack_ranges_limit: ack::Settings::RECOMMENDED.ack_ranges_limit,
+            ack_packet_tolerance: ack::settings::RECOMMENDED_PACKET_TOLERANCE,
+            ack_priority: ack::Priority::Ack,
             max_send_buffer_size: stream::Limits::RECOMMENDED.max_send_buffer_size,
+            max_remote_open_stream_rate: stream::Limits::RECOMMENDED.max_remote_open_rate,
+            stream_starvation_threshold: stream::Limits::RECOMMENDED.starvation_threshold,
             max_handshake_duration: MAX_HANDSHAKE_DURATION_DEFAULT,
             max_keep_alive_period: MAX_KEEP_ALIVE_PERIOD_DEFAULT,
+            rtt_probing_interval: None,
+            timer_granularity: K_GRANULARITY,
             max_datagram_frame_size: MaxDatagramFrameSize::DEFAULT,
+            max_coalesced_packets: MAX_COALESCED_PACKETS_DEFAULT,
         }
     }
 
@@ -169,14 +214,148 @@ impl Limits {
         u64
     );
     setter!(with_max_ack_delay, max_ack_delay, Duration);
+
+    /// Sets the maximum `max_ack_delay` the endpoint accepts from the peer for loss detection
+    ///
+    /// A peer declaring a larger `max_ack_delay` than this value has its declared value clamped
+    /// when computing the probe timeout and adjusting RTT samples. This prevents a peer from
+    /// inflating the RTT estimate by declaring an unrealistically large value. The delay the
+    /// endpoint uses for its own acknowledgements is configured with `with_max_ack_delay`.
+    ///
+    /// The declared value is reported in the `on_transport_parameters_received` event and the
+    /// effective value in the `on_recovery_metrics` event.
+    pub fn with_peer_max_ack_delay_limit(
+        mut self,
+        value: Duration,
+    ) -> Result<Self, ValidationError> {
+        self.peer_max_ack_delay_limit = Some(value.try_into()?);
+        Ok(self)
+    }
     setter!(
         with_max_active_connection_ids,
         max_active_connection_ids,
         u64
     );
+
+    /// Sets the number of usable connection IDs the endpoint maintains at the peer
+    ///
+    /// The endpoint issues new connection IDs in NEW_CONNECTION_ID frames until the peer has this
+    /// many, replacing each connection ID as soon as it is retired. Each spare connection ID
+    /// allows the peer to migrate to a new path, or probe one, without running out of
+    /// connection IDs, which is useful for mobile peers that migrate often. Fewer spare IDs save
+    /// bandwidth and per-connection state. The number of connection IDs issued is also bounded
+    /// by the `active_connection_id_limit` of the peer. The value must be between 1 and 8 and
+    /// defaults to 3.
+    ///
+    /// Each connection ID is reported with the `on_local_connection_id_issued` event when it is
+    /// issued and the `on_local_connection_id_retired` event when it is retired.
+    pub fn with_active_connection_id_target(mut self, value: u8) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            (1..=MAX_ACTIVE_CONNECTION_ID_TARGET).contains(&value),
+            "active_connection_id_target must be between 1 and 8"
+        );
+        self.active_connection_id_target = value;
+        Ok(self)
+    }
     setter!(with_ack_elicitation_interval, ack_elicitation_interval, u8);
-    setter!(with_max_ack_ranges, ack_ranges_limit, u8);
+
+    /// Sets whether a PING is sent to elicit an acknowledgement for ACK-only packets
+    ///
+    /// Packets which only contain ACK frames are not acknowledged by the peer, so an endpoint
+    /// which is mostly receiving data stops producing RTT samples and its loss detection is based
+    /// on a stale estimate. When enabled, which is the default, a PING is added to every ACK-only
+    /// packet after `ack_elicitation_interval` packets have been sent without one. Disabling this
+    /// saves a few bytes per ACK at the cost of RTT freshness.
+    ///
+    /// Each RTT sample is reported with the `on_rtt_sample` event.
+    pub fn with_ack_elicitation(mut self, enabled: bool) -> Result<Self, ValidationError> {
+        self.ack_elicitation = enabled;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of ACK ranges included in each ACK frame
+    ///
+    /// Each disjoint range of received packet numbers occupies an ACK range. On paths with heavy
+    /// loss or reordering, the number of gaps can grow to the point that ACK frames take up a
+    /// significant part of each packet. Capping the number of ranges bounds the size of every ACK
+    /// frame, at the cost of precision: once the limit is reached the lowest ranges are no longer
+    /// reported to the peer, which may then retransmit packets that were actually received.
+    ///
+    /// The number of ranges in each transmitted ACK frame is reported in the `ack_range_count`
+    /// field of the `on_frame_sent` event.
+    pub fn with_max_ack_ranges(mut self, value: u8) -> Result<Self, ValidationError> {
+        decoder_invariant!(value > 0, "max_ack_ranges must be greater than zero");
+        self.ack_ranges_limit = value;
+        Ok(self)
+    }
+
+    /// Sets the number of ack-eliciting packets received before an ACK frame is sent immediately
+    ///
+    /// Until this many packets have been received, the endpoint waits up to `max_ack_delay`
+    /// before acknowledging them, unless another condition requires an immediate ACK.
+    /// Higher values reduce the number of ACK frames sent, at the cost of slower feedback to
+    /// the peer's loss recovery and congestion controller.
+    ///
+    /// The reason each ACK frame was sent is reported with the `on_ack_sent` event.
+    pub fn with_ack_packet_tolerance(mut self, value: u8) -> Result<Self, ValidationError> {
+        decoder_invariant!(value > 0, "ack_packet_tolerance must be greater than zero");
+        self.ack_packet_tolerance = value;
+        Ok(self)
+    }
+
+    /// Sets whether ACK frames or ack-eliciting data is written first when both are pending
+    ///
+    /// The default is [`Priority::Ack`](ack::Priority::Ack), which writes pending ACK frames
+    /// before any data. This keeps the peer's loss detection and congestion controller responsive
+    /// and is the right choice for most connections.
+    ///
+    /// [`Priority::Data`](ack::Priority::Data) writes data first and only includes an ACK
+    /// frame if there is space left in the packet. When the congestion window is what limits the
+    /// connection, this leaves more of the window for data, improving goodput for bulk transfers
+    /// on constrained links. The deferred ACKs are sent once the window is exhausted, which slows
+    /// the peer's recovery from loss and should be avoided when the peer is also sending data
+    /// that is sensitive to latency.
+    pub fn with_ack_priority(mut self, value: ack::Priority) -> Result<Self, ValidationError> {
+        self.ack_priority = value;
+        Ok(self)
+    }
+
     setter!(with_max_send_buffer_size, max_send_buffer_size, u32);
+
+    setter!(
+        /// Sets the maximum number of streams of each type the peer may open per second
+        ///
+        /// Once the peer exceeds this rate, the endpoint delays granting additional
+        /// stream credit via MAX_STREAMS frames until the current one-second window expires.
+        /// The rate must be greater than zero.
+        with_max_remote_open_stream_rate,
+        max_remote_open_stream_rate,
+        u32
+    );
+
+    /// Reports streams which wait longer than `threshold` to transmit buffered data
+    ///
+    /// A stream waits from the time data is queued, the stream is unblocked by flow control, or
+    /// the stream last transmitted, while it still has data to send which isn't blocked by flow
+    /// control. Once a stream has waited `threshold` without transmitting, the `on_stream_starved`
+    /// event is published with the duration of the wait, even if the stream scheduler never gets to
+    /// the stream. This can be used to verify that the stream scheduler shares the connection
+    /// fairly among streams.
+    ///
+    /// Streams also wait while the connection is congestion limited, so `threshold` should be
+    /// several round trips long. Detection is disabled by default.
+    pub fn with_stream_starvation_threshold(
+        mut self,
+        threshold: Duration,
+    ) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            threshold > Duration::ZERO,
+            "stream_starvation_threshold must be greater than zero"
+        );
+        self.stream_starvation_threshold = Some(threshold);
+        Ok(self)
+    }
+
     setter!(
         with_max_handshake_duration,
         max_handshake_duration,
@@ -184,6 +363,57 @@ impl Limits {
     );
     setter!(with_max_keep_alive_period, max_keep_alive_period, Duration);
 
+    /// Sends a PING to the peer at the given `interval` to continuously sample the RTT
+    ///
+    /// Each sample is reported with the `on_rtt_sample` event. Probes are skipped while the
+    /// connection is limited by its congestion window.
+    pub fn with_rtt_probing(mut self, interval: Duration) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            !interval.is_zero(),
+            "rtt_probing_interval must be greater than zero"
+        );
+        self.rtt_probing_interval = Some(interval);
+        Ok(self)
+    }
+
+    /// Sets the local timer granularity (`kGranularity`) used for loss detection
+    ///
+    /// The granularity is the lower bound of the variance term in the probe timeout (PTO) period
+    /// and of the time threshold used to declare packets lost, which keeps timers from expiring
+    /// before the acknowledgement for a packet could have been processed. The RECOMMENDED value
+    /// of 1ms is used by default. On platforms with low-resolution timers, setting this to the
+    /// timer resolution prevents spurious probe timeouts and retransmissions, at the cost of
+    /// slower recovery from losses on paths with short round trip times.
+    ///
+    /// The effective value is reported in the `on_recovery_metrics` event.
+    pub fn with_timer_granularity(mut self, value: Duration) -> Result<Self, ValidationError> {
+        decoder_invariant!(
+            value > Duration::ZERO,
+            "timer_granularity must be greater than zero"
+        );
+        self.timer_granularity = value;
+        Ok(self)
+    }
+
+    /// Sets the maximum number of QUIC packets coalesced into a single UDP datagram
+    ///
+    /// By default, packets from each packet number space are coalesced into a datagram whenever
+    /// they fit, as recommended by the specification, which reduces the number of datagrams
+    /// sent during the handshake. Some middleboxes fail to forward datagrams containing more than
+    /// one packet; setting this to `1` disables coalescing entirely, at the cost of sending
+    /// additional, padded datagrams while the handshake is in progress.
+    ///
+    /// When the limit prevents a CONNECTION_CLOSE frame from being sent in every packet number
+    /// space, the packets with the highest level of protection are preferred.
+    ///
+    /// The number of packets in each transmitted datagram is reported in the `packet_count`
+    /// field of the `on_datagram_sent` event.
+    pub fn with_max_coalesced_packets(mut self, value: u8) -> Result<Self, ValidationError> {
+        decoder_invariant!(value > 0, "max_coalesced_packets must be greater than zero");
+        self.max_coalesced_packets = value;
+        Ok(self)
+    }
+
     // internal APIs
 
     #[doc(hidden)]
@@ -202,6 +432,30 @@ impl Limits {
         }
     }
 
+    #[doc(hidden)]
+    pub const fn ack_elicitation(&self) -> bool {
+        self.ack_elicitation
+    }
+
+    #[doc(hidden)]
+    pub const fn ack_packet_tolerance(&self) -> u8 {
+        self.ack_packet_tolerance
+    }
+
+    #[doc(hidden)]
+    pub const fn ack_priority(&self) -> ack::Priority {
+        self.ack_priority
+    }
+
+    /// Returns the `max_ack_delay` used for loss detection, given the value declared by the peer
+    #[doc(hidden)]
+    pub fn peer_max_ack_delay(&self, declared: MaxAckDelay) -> MaxAckDelay {
+        match self.peer_max_ack_delay_limit {
+            Some(limit) => declared.min(limit),
+            None => declared,
+        }
+    }
+
     #[doc(hidden)]
     pub const fn initial_flow_control_limits(&self) -> InitialFlowControlLimits {
         InitialFlowControlLimits {
@@ -231,6 +485,8 @@ impl Limits {
             max_send_buffer_size: self.max_send_buffer_size,
             max_open_local_unidirectional_streams: self.max_open_local_unidirectional_streams,
             max_open_local_bidirectional_streams: self.max_open_local_bidirectional_streams,
+            max_remote_open_rate: self.max_remote_open_stream_rate,
+            starvation_threshold: self.stream_starvation_threshold,
         }
     }
 
@@ -248,11 +504,42 @@ impl Limits {
     pub fn max_keep_alive_period(&self) -> Duration {
         self.max_keep_alive_period
     }
+
+    #[doc(hidden)]
+    pub fn rtt_probing_interval(&self) -> Option<Duration> {
+        self.rtt_probing_interval
+    }
+
+    #[doc(hidden)]
+    pub fn timer_granularity(&self) -> Duration {
+        self.timer_granularity
+    }
+
+    #[doc(hidden)]
+    pub fn active_connection_id_target(&self) -> u8 {
+        self.active_connection_id_target
+    }
+
+    #[doc(hidden)]
+    pub fn max_coalesced_packets(&self) -> usize {
+        self.max_coalesced_packets as usize
+    }
 }
 
 /// Creates limits for a given connection
 pub trait Limiter: 'static + Send {
     fn on_connection(&mut self, info: &ConnectionInfo) -> Limits;
+
+    /// Returns the tags attached to a new connection
+    ///
+    /// Tags are included in the `ConnectionMeta` of every event for the connection. Client
+    /// connections use the tags set with `Connect::with_tags` instead, if any were set.
+    ///
+    /// By default, no tags are attached.
+    fn on_connection_tags(&mut self, info: &ConnectionInfo) -> Tags {
+        let _ = info;
+        Tags::new()
+    }
 }
 
 /// Implement Limiter for a Limits struct
@@ -0,0 +1,258 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transport::parameters::ValidationError;
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::fmt;
+use s2n_codec::{decoder_invariant, DecoderError};
+
+// The limits are defined as macros so the error messages can be built from the same literals
+macro_rules! max_tags {
+    () => {
+        4
+    };
+}
+
+macro_rules! max_value_len {
+    () => {
+        32
+    };
+}
+
+/// The maximum number of tags which can be attached to a connection
+pub const MAX_TAGS: usize = max_tags!();
+
+/// The maximum length of a tag value, in bytes
+pub const MAX_VALUE_LEN: usize = max_value_len!();
+
+/// A small, bounded set of key/value tags attached to a connection
+///
+/// Tags are set when the connection is created and are included in the
+/// [`ConnectionMeta`](crate::event::api::ConnectionMeta) of every connection event, which allows
+/// subscribers to label metrics and events with application-defined dimensions, such as a
+/// tenant or region.
+///
+/// Keys are static strings. Values are copied into the set so they can be created at runtime,
+/// but are limited to [`MAX_VALUE_LEN`] bytes. At most [`MAX_TAGS`] tags can be set.
+///
+/// The entries are shared between clones so carrying the tags in every event only copies a
+/// pointer. An empty set doesn't allocate.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Tags {
+    #[cfg(feature = "alloc")]
+    entries: Option<Arc<Entries>>,
+    #[cfg(not(feature = "alloc"))]
+    entries: Entries,
+}
+
+type Entries = [Option<Entry>; MAX_TAGS];
+
+const EMPTY: Entries = [None; MAX_TAGS];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Entry {
+    key: &'static str,
+    value: [u8; MAX_VALUE_LEN],
+    len: u8,
+}
+
+impl Entry {
+    #[inline]
+    fn value(&self) -> &str {
+        let value = core::str::from_utf8(&self.value[..self.len as usize]);
+        debug_assert!(value.is_ok(), "tag values are copied from a str");
+        value.unwrap_or_default()
+    }
+}
+
+impl Tags {
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            entries: None,
+            #[cfg(not(feature = "alloc"))]
+            entries: EMPTY,
+        }
+    }
+
+    /// Sets the tag `key` to `value`, replacing any previous value for `key`
+    pub fn insert(&mut self, key: &'static str, value: &str) -> Result<(), ValidationError> {
+        decoder_invariant!(
+            value.len() <= MAX_VALUE_LEN,
+            concat!("tag values must not exceed ", max_value_len!(), " bytes")
+        );
+
+        let entries = self.entries();
+        let slot = entries
+            .iter()
+            .position(|entry| matches!(entry, Some(entry) if entry.key == key))
+            .or_else(|| entries.iter().position(Option::is_none))
+            .ok_or(DecoderError::InvariantViolation(concat!(
+                "connections can have at most ",
+                max_tags!(),
+                " tags"
+            )))?;
+
+        let mut entry = Entry {
+            key,
+            value: [0; MAX_VALUE_LEN],
+            len: value.len() as u8,
+        };
+        entry.value[..value.len()].copy_from_slice(value.as_bytes());
+        self.entries_mut()[slot] = Some(entry);
+
+        Ok(())
+    }
+
+    /// Sets the tag `key` to `value`, replacing any previous value for `key`
+    pub fn with(mut self, key: &'static str, value: &str) -> Result<Self, ValidationError> {
+        self.insert(key, value)?;
+        Ok(self)
+    }
+
+    /// Sets all of the tags in `other`, replacing the values of any keys already set
+    pub fn extend(&mut self, other: &Self) -> Result<(), ValidationError> {
+        for (key, value) in other.iter() {
+            self.insert(key, value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the value of the tag `key`, if set
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the tags in the order they were first set
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.entries()
+            .iter()
+            .flatten()
+            .map(|entry| (entry.key, entry.value()))
+    }
+
+    /// Returns the number of tags which are set
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries().iter().flatten().count()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries()[0].is_none()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn entries(&self) -> &Entries {
+        self.entries.as_deref().unwrap_or(&EMPTY)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn entries_mut(&mut self) -> &mut Entries {
+        Arc::make_mut(self.entries.get_or_insert_with(|| Arc::new(EMPTY)))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[inline]
+    fn entries(&self) -> &Entries {
+        &self.entries
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[inline]
+    fn entries_mut(&mut self) -> &mut Entries {
+        &mut self.entries
+    }
+}
+
+impl fmt::Debug for Tags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_test() {
+        let mut tags = Tags::new();
+        assert!(tags.is_empty());
+
+        tags.insert("tenant", "a").unwrap();
+        tags.insert("region", "us-west-2").unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags.get("tenant"), Some("a"));
+        assert_eq!(tags.get("region"), Some("us-west-2"));
+        assert_eq!(tags.get("app"), None);
+
+        // existing keys are replaced in place
+        tags.insert("tenant", "b").unwrap();
+        assert_eq!(tags.len(), 2);
+        assert_eq!(
+            tags.iter().collect::<Vec<_>>(),
+            [("tenant", "b"), ("region", "us-west-2")]
+        );
+    }
+
+    #[test]
+    fn bounds_test() {
+        let mut tags = Tags::new();
+
+        assert!(tags.insert("key", &"a".repeat(MAX_VALUE_LEN + 1)).is_err());
+        tags.insert("key", &"a".repeat(MAX_VALUE_LEN)).unwrap();
+
+        for key in ["a", "b", "c"] {
+            tags.insert(key, "value").unwrap();
+        }
+        assert_eq!(tags.len(), MAX_TAGS);
+        assert!(tags.insert("d", "value").is_err());
+
+        // replacing an existing key is still allowed when full
+        tags.insert("a", "other").unwrap();
+        assert_eq!(tags.get("a"), Some("other"));
+    }
+
+    #[test]
+    fn error_message_test() {
+        let mut tags = Tags::new();
+
+        let error = tags
+            .insert("key", &"a".repeat(MAX_VALUE_LEN + 1))
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("tag values must not exceed {} bytes", MAX_VALUE_LEN)
+        );
+
+        for key in ["a", "b", "c", "d"] {
+            tags.insert(key, "value").unwrap();
+        }
+        let error = tags.insert("e", "value").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("connections can have at most {} tags", MAX_TAGS)
+        );
+    }
+
+    #[test]
+    fn shared_test() {
+        // the tags only take up a single pointer
+        assert_eq!(core::mem::size_of::<Tags>(), core::mem::size_of::<usize>());
+
+        let tags = Tags::new().with("tenant", "a").unwrap();
+        let mut other = tags.clone();
+        assert_eq!(tags, other);
+
+        // updating a clone doesn't change the original
+        other.insert("tenant", "b").unwrap();
+        assert_eq!(tags.get("tenant"), Some("a"));
+        assert_eq!(other.get("tenant"), Some("b"));
+    }
+}
@@ -59,6 +59,12 @@ pub trait Context<Crypto: CryptoSuite> {
         application_protocol: Bytes,
     ) -> Result<(), transport::Error>;
 
+    /// Called when the key exchange group for the handshake has been selected
+    fn on_key_exchange_group(&mut self, group: NamedGroup) -> Result<(), transport::Error>;
+
+    /// Called when the signature scheme used to authenticate the handshake has been selected
+    fn on_signature_scheme(&mut self, scheme: SignatureScheme) -> Result<(), transport::Error>;
+
     //= https://www.rfc-editor.org/rfc/rfc9001#section-4.1.1
     //# The TLS handshake is considered complete when the
     //# TLS stack has reported that the handshake is complete.  This happens
@@ -112,6 +118,46 @@ pub trait Endpoint: 'static + Sized + Send {
 
     /// The maximum length of a tag for any algorithm that may be negotiated
     fn max_tag_length(&self) -> usize;
+
+    /// Controls how handshake data is fragmented across CRYPTO frames
+    ///
+    /// This is only overridden by the `testing::fragmentation` TLS wrapper in order to
+    /// reproduce interoperability issues with peers which mishandle fragmented CRYPTO data.
+    #[cfg(any(test, feature = "testing"))]
+    #[doc(hidden)]
+    #[inline]
+    fn crypto_fragmentation(&self) -> Option<testing::fragmentation::Mode> {
+        None
+    }
+}
+
+/// The limits applied to each CRYPTO frame written by the transport
+///
+/// By default, CRYPTO frames are only limited by the space available in the packet. Other limits
+/// can only be configured with the `testing::fragmentation` TLS wrapper.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FragmentLimits {
+    /// The maximum amount of handshake data written to each CRYPTO frame
+    pub max_len: Option<usize>,
+    /// Each packet carries at most one CRYPTO frame
+    pub single_frame: bool,
+}
+
+impl FragmentLimits {
+    /// Returns the limits configured for the given endpoint
+    #[inline]
+    pub fn new<E: Endpoint>(endpoint: &E) -> Self {
+        #[cfg(any(test, feature = "testing"))]
+        {
+            if let Some(mode) = endpoint.crypto_fragmentation() {
+                return mode.into();
+            }
+        }
+
+        let _ = endpoint;
+        Self::default()
+    }
 }
 
 pub trait Session: CryptoSuite + Sized + Send + Debug {
@@ -148,6 +194,42 @@ impl crate::event::IntoEvent<crate::event::api::CipherSuite> for CipherSuite {
     }
 }
 
+/// The signature scheme selected to authenticate the handshake
+///
+/// See https://www.rfc-editor.org/rfc/rfc8446#section-4.2.3
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(non_camel_case_types)]
+pub enum SignatureScheme {
+    RSA_PKCS1_SHA256,
+    RSA_PKCS1_SHA384,
+    RSA_PKCS1_SHA512,
+    ECDSA_SECP256R1_SHA256,
+    ECDSA_SECP384R1_SHA384,
+    ECDSA_SECP521R1_SHA512,
+    RSA_PSS_RSAE_SHA256,
+    RSA_PSS_RSAE_SHA384,
+    RSA_PSS_RSAE_SHA512,
+    ED25519,
+    ED448,
+    RSA_PSS_PSS_SHA256,
+    RSA_PSS_PSS_SHA384,
+    RSA_PSS_PSS_SHA512,
+    Unknown,
+}
+
+/// The group selected for the key exchange in the handshake
+///
+/// See https://www.rfc-editor.org/rfc/rfc8446#section-4.2.7
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NamedGroup {
+    SECP256R1,
+    SECP384R1,
+    SECP521R1,
+    X25519,
+    X448,
+    Unknown,
+}
+
 macro_rules! handshake_type {
     ($($variant:ident($value:literal)),* $(,)?) => {
         #[derive(Debug, PartialEq, Eq, AsBytes, Unaligned)]
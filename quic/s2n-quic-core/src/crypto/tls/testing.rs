@@ -20,6 +20,9 @@ use futures_test::task::new_count_waker;
 use s2n_codec::EncoderValue;
 use std::{collections::VecDeque, fmt::Debug};
 
+pub mod fragmentation;
+pub mod header_protection;
+
 pub mod certificates {
     macro_rules! pem {
         ($name:ident, $path:expr) => {
@@ -40,6 +43,8 @@ pub mod certificates {
     der!(CERT_DER, "cert.der");
     pem!(UNTRUSTED_CERT_PEM, "untrusted_cert.pem");
     pem!(UNTRUSTED_KEY_PEM, "untrusted_key.pem");
+    pem!(RSA_CERT_PEM, "rsa_cert.pem");
+    pem!(RSA_KEY_PEM, "rsa_key.pem");
 }
 
 #[derive(Debug)]
@@ -327,6 +332,8 @@ pub struct Context<C: CryptoSuite, State: Debug> {
     pub handshake_complete: bool,
     pub server_name: Option<Bytes>,
     pub application_protocol: Option<Bytes>,
+    pub key_exchange_group: Option<tls::NamedGroup>,
+    pub signature_scheme: Option<tls::SignatureScheme>,
     pub transport_parameters: Option<Bytes>,
     endpoint: endpoint::Type,
     pub state: State,
@@ -343,6 +350,8 @@ impl<C: CryptoSuite, State: Debug> fmt::Debug for Context<C, State> {
             .field("handshake_complete", &self.handshake_complete)
             .field("sni", &self.server_name)
             .field("application_protocol", &self.application_protocol)
+            .field("key_exchange_group", &self.key_exchange_group)
+            .field("signature_scheme", &self.signature_scheme)
             .field("transport_parameters", &self.transport_parameters)
             .field("endpoint", &self.endpoint)
             .finish()
@@ -360,6 +369,8 @@ impl<C: CryptoSuite, State: Debug> Context<C, State> {
             handshake_complete: false,
             server_name: None,
             application_protocol: None,
+            key_exchange_group: None,
+            signature_scheme: None,
             transport_parameters: None,
             endpoint,
             state,
@@ -388,6 +399,18 @@ impl<C: CryptoSuite, State: Debug> Context<C, State> {
             "application_protocol is not consistent between endpoints"
         );
 
+        // not all TLS implementations are able to report these values so only compare them if
+        // both endpoints did
+        if let (Some(a), Some(b)) = (self.key_exchange_group, other.key_exchange_group) {
+            assert_eq!(
+                a, b,
+                "key_exchange_group is not consistent between endpoints"
+            );
+        }
+        if let (Some(a), Some(b)) = (self.signature_scheme, other.signature_scheme) {
+            assert_eq!(a, b, "signature_scheme is not consistent between endpoints");
+        }
+
         assert_eq!(
             self.zero_rtt_crypto.is_some(),
             other.zero_rtt_crypto.is_some(),
@@ -619,6 +642,21 @@ impl<C: CryptoSuite, State: Debug> tls::Context<C> for Context<C, State> {
         Ok(())
     }
 
+    fn on_key_exchange_group(&mut self, group: tls::NamedGroup) -> Result<(), transport::Error> {
+        self.log("key exchange group");
+        self.key_exchange_group = Some(group);
+        Ok(())
+    }
+
+    fn on_signature_scheme(
+        &mut self,
+        scheme: tls::SignatureScheme,
+    ) -> Result<(), transport::Error> {
+        self.log("signature scheme");
+        self.signature_scheme = Some(scheme);
+        Ok(())
+    }
+
     fn on_handshake_complete(&mut self) -> Result<(), transport::Error> {
         assert!(
             !self.handshake_complete,
@@ -0,0 +1,85 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wraps a TLS endpoint to control how handshake data is fragmented across CRYPTO frames
+//!
+//! Some peers mishandle TLS messages which are split across several CRYPTO frames or packets.
+//! Limiting the size of each fragment, or the number of fragments in each packet, makes it
+//! possible to reproduce these failures in tests.
+
+use crate::{application::ServerName, crypto::tls};
+use s2n_codec::EncoderValue;
+
+/// Controls how handshake data is split into CRYPTO frames
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// Each CRYPTO frame carries at most the given number of bytes
+    ///
+    /// Each packet carries at most one new CRYPTO frame, so every fragment is sent in its own
+    /// packet.
+    MaxLen(usize),
+    /// Each packet carries at most one CRYPTO frame
+    ///
+    /// Retransmitted and new handshake data are never combined into the same packet.
+    SingleFrame,
+}
+
+impl From<Mode> for tls::FragmentLimits {
+    #[inline]
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::MaxLen(max_len) => Self {
+                max_len: Some(max_len),
+                single_frame: false,
+            },
+            Mode::SingleFrame => Self {
+                max_len: None,
+                single_frame: true,
+            },
+        }
+    }
+}
+
+/// A TLS endpoint which controls how handshake data is split into CRYPTO frames
+#[derive(Debug)]
+pub struct Endpoint<E> {
+    inner: E,
+    mode: Mode,
+}
+
+impl<E> Endpoint<E> {
+    /// Wraps `inner` so handshake data is split into CRYPTO frames according to `mode`
+    pub fn new(inner: E, mode: Mode) -> Self {
+        assert_ne!(mode, Mode::MaxLen(0), "fragments must contain data");
+
+        Self { inner, mode }
+    }
+}
+
+impl<E: tls::Endpoint> tls::Endpoint for Endpoint<E> {
+    type Session = E::Session;
+
+    fn new_server_session<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+    ) -> Self::Session {
+        self.inner.new_server_session(transport_parameters)
+    }
+
+    fn new_client_session<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+        server_name: ServerName,
+    ) -> Self::Session {
+        self.inner
+            .new_client_session(transport_parameters, server_name)
+    }
+
+    fn max_tag_length(&self) -> usize {
+        self.inner.max_tag_length()
+    }
+
+    fn crypto_fragmentation(&self) -> Option<Mode> {
+        Some(self.mode)
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wraps a TLS endpoint so header protection can be observed or perturbed in tests
+//!
+//! The header protection keys produced by the wrapped session are passed through an
+//! [`Interceptor`], which is notified each time a mask is derived and may modify it before it is
+//! applied to or removed from a packet. Initial header protection keys are derived from the
+//! connection ID rather than the TLS session, so they are not intercepted.
+
+use crate::{
+    application::ServerName,
+    crypto::{
+        tls, CryptoSuite, HandshakeHeaderKey, HeaderKey as CryptoHeaderKey, HeaderProtectionMask,
+        OneRttHeaderKey, ZeroRttHeaderKey,
+    },
+    transport,
+};
+use alloc::sync::Arc;
+use bytes::Bytes;
+use core::{
+    fmt,
+    task::{Poll, Waker},
+};
+use s2n_codec::EncoderValue;
+
+/// The key space which derived a header protection mask
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeySpace {
+    Handshake,
+    ZeroRtt,
+    OneRtt,
+}
+
+/// Observes and optionally modifies header protection masks
+pub trait Interceptor: 'static + Send + Sync {
+    /// Called after a mask is derived for protecting an outgoing packet
+    #[inline]
+    fn on_sealing_mask(&self, space: KeySpace, mask: &mut HeaderProtectionMask) {
+        let _ = space;
+        let _ = mask;
+    }
+
+    /// Called after a mask is derived for removing protection from an incoming packet
+    #[inline]
+    fn on_opening_mask(&self, space: KeySpace, mask: &mut HeaderProtectionMask) {
+        let _ = space;
+        let _ = mask;
+    }
+}
+
+impl<I: Interceptor> Interceptor for Arc<I> {
+    #[inline]
+    fn on_sealing_mask(&self, space: KeySpace, mask: &mut HeaderProtectionMask) {
+        (**self).on_sealing_mask(space, mask)
+    }
+
+    #[inline]
+    fn on_opening_mask(&self, space: KeySpace, mask: &mut HeaderProtectionMask) {
+        (**self).on_opening_mask(space, mask)
+    }
+}
+
+/// A TLS endpoint which passes the header protection keys of `E` through an [`Interceptor`]
+pub struct Endpoint<E, I> {
+    inner: E,
+    interceptor: Arc<I>,
+}
+
+impl<E, I> Endpoint<E, I> {
+    pub fn new(inner: E, interceptor: I) -> Self {
+        Self {
+            inner,
+            interceptor: Arc::new(interceptor),
+        }
+    }
+}
+
+impl<E: tls::Endpoint, I: Interceptor> tls::Endpoint for Endpoint<E, I> {
+    type Session = Session<E::Session, I>;
+
+    fn new_server_session<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+    ) -> Self::Session {
+        Session {
+            inner: self.inner.new_server_session(transport_parameters),
+            interceptor: self.interceptor.clone(),
+        }
+    }
+
+    fn new_client_session<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+        server_name: ServerName,
+    ) -> Self::Session {
+        Session {
+            inner: self
+                .inner
+                .new_client_session(transport_parameters, server_name),
+            interceptor: self.interceptor.clone(),
+        }
+    }
+
+    fn max_tag_length(&self) -> usize {
+        self.inner.max_tag_length()
+    }
+
+    fn crypto_fragmentation(&self) -> Option<super::fragmentation::Mode> {
+        self.inner.crypto_fragmentation()
+    }
+}
+
+pub struct Session<S, I> {
+    inner: S,
+    interceptor: Arc<I>,
+}
+
+impl<S: fmt::Debug, I> fmt::Debug for Session<S, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<S: tls::Session, I: Interceptor> tls::Session for Session<S, I> {
+    fn poll<C: tls::Context<Self>>(
+        &mut self,
+        context: &mut C,
+    ) -> Poll<Result<(), transport::Error>> {
+        let mut context = Context {
+            inner: context,
+            interceptor: &self.interceptor,
+        };
+        self.inner.poll(&mut context)
+    }
+}
+
+impl<S: CryptoSuite, I: Interceptor> CryptoSuite for Session<S, I> {
+    type HandshakeKey = S::HandshakeKey;
+    type HandshakeHeaderKey = HeaderKey<S::HandshakeHeaderKey, I>;
+    type InitialKey = S::InitialKey;
+    type InitialHeaderKey = S::InitialHeaderKey;
+    type ZeroRttKey = S::ZeroRttKey;
+    type ZeroRttHeaderKey = HeaderKey<S::ZeroRttHeaderKey, I>;
+    type OneRttKey = S::OneRttKey;
+    type OneRttHeaderKey = HeaderKey<S::OneRttHeaderKey, I>;
+    type RetryKey = S::RetryKey;
+}
+
+/// A header protection key which notifies an [`Interceptor`] of each derived mask
+pub struct HeaderKey<K, I> {
+    inner: K,
+    space: KeySpace,
+    interceptor: Arc<I>,
+}
+
+impl<K: CryptoHeaderKey, I: Interceptor> CryptoHeaderKey for HeaderKey<K, I> {
+    #[inline]
+    fn opening_header_protection_mask(&self, ciphertext_sample: &[u8]) -> HeaderProtectionMask {
+        let mut mask = self.inner.opening_header_protection_mask(ciphertext_sample);
+        self.interceptor.on_opening_mask(self.space, &mut mask);
+        mask
+    }
+
+    #[inline]
+    fn opening_sample_len(&self) -> usize {
+        self.inner.opening_sample_len()
+    }
+
+    #[inline]
+    fn sealing_header_protection_mask(&self, ciphertext_sample: &[u8]) -> HeaderProtectionMask {
+        let mut mask = self.inner.sealing_header_protection_mask(ciphertext_sample);
+        self.interceptor.on_sealing_mask(self.space, &mut mask);
+        mask
+    }
+
+    #[inline]
+    fn sealing_sample_len(&self) -> usize {
+        self.inner.sealing_sample_len()
+    }
+}
+
+impl<K: HandshakeHeaderKey, I: Interceptor> HandshakeHeaderKey for HeaderKey<K, I> {}
+impl<K: ZeroRttHeaderKey, I: Interceptor> ZeroRttHeaderKey for HeaderKey<K, I> {}
+impl<K: OneRttHeaderKey, I: Interceptor> OneRttHeaderKey for HeaderKey<K, I> {}
+
+/// Adapts the context of the wrapping session to the context expected by the inner session
+struct Context<'a, C, I> {
+    inner: &'a mut C,
+    interceptor: &'a Arc<I>,
+}
+
+impl<'a, C, I> Context<'a, C, I> {
+    #[inline]
+    fn header_key<K>(&self, inner: K, space: KeySpace) -> HeaderKey<K, I> {
+        HeaderKey {
+            inner,
+            space,
+            interceptor: self.interceptor.clone(),
+        }
+    }
+}
+
+impl<'a, S, I, C> tls::Context<S> for Context<'a, C, I>
+where
+    S: CryptoSuite,
+    I: Interceptor,
+    C: tls::Context<Session<S, I>>,
+{
+    fn on_handshake_keys(
+        &mut self,
+        key: S::HandshakeKey,
+        header_key: S::HandshakeHeaderKey,
+    ) -> Result<(), transport::Error> {
+        let header_key = self.header_key(header_key, KeySpace::Handshake);
+        self.inner.on_handshake_keys(key, header_key)
+    }
+
+    fn on_zero_rtt_keys(
+        &mut self,
+        key: S::ZeroRttKey,
+        header_key: S::ZeroRttHeaderKey,
+        application_parameters: tls::ApplicationParameters,
+    ) -> Result<(), transport::Error> {
+        let header_key = self.header_key(header_key, KeySpace::ZeroRtt);
+        self.inner
+            .on_zero_rtt_keys(key, header_key, application_parameters)
+    }
+
+    fn on_one_rtt_keys(
+        &mut self,
+        key: S::OneRttKey,
+        header_key: S::OneRttHeaderKey,
+        application_parameters: tls::ApplicationParameters,
+    ) -> Result<(), transport::Error> {
+        let header_key = self.header_key(header_key, KeySpace::OneRtt);
+        self.inner
+            .on_one_rtt_keys(key, header_key, application_parameters)
+    }
+
+    fn on_server_name(&mut self, server_name: ServerName) -> Result<(), transport::Error> {
+        self.inner.on_server_name(server_name)
+    }
+
+    fn on_application_protocol(
+        &mut self,
+        application_protocol: Bytes,
+    ) -> Result<(), transport::Error> {
+        self.inner.on_application_protocol(application_protocol)
+    }
+
+    fn on_key_exchange_group(&mut self, group: tls::NamedGroup) -> Result<(), transport::Error> {
+        self.inner.on_key_exchange_group(group)
+    }
+
+    fn on_signature_scheme(
+        &mut self,
+        scheme: tls::SignatureScheme,
+    ) -> Result<(), transport::Error> {
+        self.inner.on_signature_scheme(scheme)
+    }
+
+    fn on_handshake_complete(&mut self) -> Result<(), transport::Error> {
+        self.inner.on_handshake_complete()
+    }
+
+    fn receive_initial(&mut self, max_len: Option<usize>) -> Option<Bytes> {
+        self.inner.receive_initial(max_len)
+    }
+
+    fn receive_handshake(&mut self, max_len: Option<usize>) -> Option<Bytes> {
+        self.inner.receive_handshake(max_len)
+    }
+
+    fn receive_application(&mut self, max_len: Option<usize>) -> Option<Bytes> {
+        self.inner.receive_application(max_len)
+    }
+
+    fn can_send_initial(&self) -> bool {
+        self.inner.can_send_initial()
+    }
+
+    fn send_initial(&mut self, transmission: Bytes) {
+        self.inner.send_initial(transmission)
+    }
+
+    fn can_send_handshake(&self) -> bool {
+        self.inner.can_send_handshake()
+    }
+
+    fn send_handshake(&mut self, transmission: Bytes) {
+        self.inner.send_handshake(transmission)
+    }
+
+    fn can_send_application(&self) -> bool {
+        self.inner.can_send_application()
+    }
+
+    fn send_application(&mut self, transmission: Bytes) {
+        self.inner.send_application(transmission)
+    }
+
+    fn waker(&self) -> &Waker {
+        self.inner.waker()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::key::testing;
+
+    struct FlipPacketNumber;
+
+    impl Interceptor for FlipPacketNumber {
+        fn on_opening_mask(&self, space: KeySpace, mask: &mut HeaderProtectionMask) {
+            assert_eq!(space, KeySpace::OneRtt);
+            mask[1] ^= 0xff;
+        }
+    }
+
+    #[test]
+    fn perturb_opening_mask_test() {
+        let key = HeaderKey {
+            inner: testing::HeaderKey::new(),
+            space: KeySpace::OneRtt,
+            interceptor: Arc::new(FlipPacketNumber),
+        };
+
+        assert_eq!(key.sealing_header_protection_mask(&[]), [0; 5]);
+        assert_eq!(key.opening_header_protection_mask(&[]), [0, 0xff, 0, 0, 0]);
+    }
+}
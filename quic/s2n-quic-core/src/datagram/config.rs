@@ -0,0 +1,46 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+/// The datagram support negotiated with the peer during the handshake
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Config {
+    /// The maximum payload size of a datagram which can be sent to the peer
+    ///
+    /// This is `0` if the peer did not advertise support for receiving datagrams.
+    pub max_send_payload_size: u64,
+    /// The maximum size of a DATAGRAM frame, including the frame type and length fields,
+    /// which the local endpoint accepts from the peer
+    ///
+    /// This is the `max_datagram_frame_size` transport parameter advertised to the peer
+    /// and is `0` if the local endpoint did not advertise support for receiving datagrams.
+    pub max_receive_frame_size: u64,
+}
+
+impl Config {
+    #[doc(hidden)]
+    pub fn new(max_send_payload_size: u64, max_receive_frame_size: u64) -> Self {
+        Self {
+            max_send_payload_size,
+            max_receive_frame_size,
+        }
+    }
+
+    /// Returns `true` if the peer accepts datagrams
+    #[inline]
+    pub fn can_send(&self) -> bool {
+        self.max_send_payload_size > 0
+    }
+
+    /// Returns `true` if the local endpoint accepts datagrams
+    #[inline]
+    pub fn can_receive(&self) -> bool {
+        self.max_receive_frame_size > 0
+    }
+
+    /// Returns `true` if datagrams can be exchanged in either direction
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.can_send() || self.can_receive()
+    }
+}
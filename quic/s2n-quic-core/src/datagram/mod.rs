@@ -1,9 +1,11 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod config;
 #[cfg(feature = "alloc")]
 pub mod default;
 pub mod disabled;
 pub mod traits;
+pub use config::Config;
 pub use disabled::*;
 pub use traits::*;
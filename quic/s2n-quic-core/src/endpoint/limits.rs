@@ -10,7 +10,7 @@ use crate::{
 /// use information from the ConnectionAttempt object to determine how the library should handle
 /// the connection attempt
 #[non_exhaustive]
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Outcome {
     /// Allow the connection to continue
     ///
@@ -59,6 +59,33 @@ impl Outcome {
     }
 }
 
+/// Limits the number of handshakes an endpoint starts each second
+///
+/// Use `HandshakeRateLimit::new()` to construct this type
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HandshakeRateLimit {
+    /// The maximum number of handshakes to start each second
+    pub handshakes_per_sec: usize,
+
+    /// How connection attempts in excess of the limit are handled
+    ///
+    /// This is typically [`Outcome::retry()`], which defers the attempt until the peer has
+    /// validated its address, or [`Outcome::drop()`], which refuses it.
+    pub outcome: Outcome,
+}
+
+impl HandshakeRateLimit {
+    /// Limits the endpoint to `handshakes_per_sec` handshakes each second, handling the attempts
+    /// in excess of the limit with `outcome`
+    pub fn new(handshakes_per_sec: usize, outcome: Outcome) -> Self {
+        Self {
+            handshakes_per_sec,
+            outcome,
+        }
+    }
+}
+
 /// A ConnectionAttempt holds information about the state of endpoint receiving a connect, along
 /// with information about the connection. This can be used to make decisions about the Outcome of
 /// an attempted connection
@@ -71,6 +98,9 @@ pub struct ConnectionAttempt<'a> {
     /// Number of open connections
     pub connection_count: usize,
 
+    /// Number of bytes currently buffered by open connections
+    pub memory_usage: u64,
+
     /// The unverified address of the connecting peer
     /// This address comes from the datagram
     pub remote_address: SocketAddress<'a>,
@@ -88,10 +118,17 @@ impl<'a> ConnectionAttempt<'a> {
         Self {
             inflight_handshakes,
             connection_count,
+            memory_usage: 0,
             remote_address: remote_address.into_event(),
             timestamp,
         }
     }
+
+    #[doc(hidden)]
+    pub fn with_memory_usage(mut self, memory_usage: u64) -> Self {
+        self.memory_usage = memory_usage;
+        self
+    }
 }
 
 pub trait Limiter: 'static + Send {
@@ -119,4 +156,68 @@ pub trait Limiter: 'static + Send {
     /// }
     /// ```
     fn on_connection_attempt(&mut self, info: &ConnectionAttempt) -> Outcome;
+
+    /// Returns the maximum number of bytes the open connections may buffer
+    ///
+    /// Each connection is charged the data it has received on its streams which has not yet been
+    /// consumed by the application. The charges are updated each time the endpoint interacts
+    /// with the connection, so the budget is shared by the connections according to what they
+    /// actually buffer rather than what they are allowed to buffer.
+    ///
+    /// Once the sum of the charges reaches the limit, the endpoint is under memory pressure:
+    ///
+    /// * Connection attempts are dropped without consulting [`Limiter::on_connection_attempt`]
+    ///   and an `EndpointMemoryPressure` event is emitted for each refused attempt.
+    /// * Existing connections stop extending their connection-level receive window as the
+    ///   application consumes data. Peers can still use the window which was already
+    ///   advertised, which bounds how much the usage can grow past the limit.
+    /// * Existing connections delay their ACKs until `max_ack_delay` expires instead of
+    ///   acknowledging every `packet_tolerance` packets, which slows down the peers' senders.
+    ///
+    /// The pressure is released once the usage drops below three quarters of the limit, at
+    /// which point the connections advertise the window they held back and resume acknowledging
+    /// packets as usual.
+    ///
+    /// By default, the endpoint memory usage is unlimited.
+    fn memory_limit(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the limit on the number of handshakes the endpoint starts each second
+    ///
+    /// The endpoint counts the connection attempts it allows in fixed one-second windows. Once the
+    /// limit is reached, the remaining attempts in the window are handled with the configured
+    /// [`HandshakeRateLimit::outcome`] without consulting [`Limiter::on_connection_attempt`], and
+    /// an `EndpointHandshakeRateLimited` event is emitted the first time this happens in each
+    /// window. The limit is checked after the memory limit, so attempts refused because of memory
+    /// pressure are not counted.
+    ///
+    /// Initial packets carrying a valid Retry token were already deferred once and are not
+    /// counted or limited. As a result, handling excess attempts with [`Outcome::retry()`] only
+    /// admits peers which can prove ownership of their address, at the cost of an extra round
+    /// trip, while [`Outcome::drop()`] strictly bounds the handshake rate.
+    ///
+    /// By default, the handshake rate is unlimited.
+    fn handshake_rate_limit(&self) -> Option<HandshakeRateLimit> {
+        None
+    }
+
+    /// Returns the number of connections the endpoint expects to have open at the same time
+    ///
+    /// The endpoint uses this value when it is created to reserve room in its connection id
+    /// tables and wakeup queues, so that connections up to this count can be opened without
+    /// rehashing or reallocating those structures while the endpoint is under load. The value is
+    /// only a hint: the endpoint can still open more connections, at the cost of growing the
+    /// tables as before.
+    ///
+    /// The reserved memory is allocated up front and held for the lifetime of the endpoint,
+    /// whether or not the connections are ever opened. It grows linearly with the count: each
+    /// expected connection reserves one entry in each of the connection id tables, which are
+    /// rounded up to the next power of two, and one slot in each wakeup queue. Overestimating the
+    /// count wastes that memory; underestimating it only delays the allocations.
+    ///
+    /// By default, no capacity is reserved.
+    fn expected_connections(&self) -> usize {
+        0
+    }
 }
@@ -56,6 +56,7 @@ ident_into_event!(
     Duration,
     bool,
     connection::Error,
+    connection::Tags,
     endpoint::Location,
 );
 borrowed_into_event!([u8; 4], [u8; 16], [u8], [u32], [&'a [u8]]);
@@ -103,6 +104,7 @@ impl Timestamp {
     /// #     endpoint_type: endpoint::Type::Server,
     /// #     id: 0,
     /// #     timestamp: unsafe { Timestamp::from_duration(Duration::from_secs(1) )},
+    /// #     tags: Default::default(),
     /// # }.into_event();
     /// let event_time = start_time + meta.timestamp.duration_since_start();
     /// ```
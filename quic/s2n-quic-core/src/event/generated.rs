@@ -16,6 +16,8 @@ pub mod api {
         pub endpoint_type: EndpointType,
         pub id: u64,
         pub timestamp: crate::event::Timestamp,
+        #[doc = " The application-defined tags attached to the connection"]
+        pub tags: crate::connection::Tags,
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
@@ -83,6 +85,36 @@ pub mod api {
         #[doc = " received with the CE codepoint."]
         pub ce_count: u64,
     }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A summary of the frame types contained in a packet"]
+    pub struct FrameSummary {
+        #[doc = " Set if the packet contained an ACK frame"]
+        pub ack: bool,
+        #[doc = " Set if the packet contained a PING frame"]
+        pub ping: bool,
+        #[doc = " Set if the packet contained a CRYPTO frame"]
+        pub crypto: bool,
+        #[doc = " Set if the packet contained a STREAM frame"]
+        pub stream: bool,
+        #[doc = " Set if the packet contained a RESET_STREAM or STOP_SENDING frame"]
+        pub stream_control: bool,
+        #[doc = " Set if the packet contained a frame which updates or reports a flow control limit"]
+        #[doc = ""]
+        #[doc = " These are the MAX_DATA, MAX_STREAM_DATA, MAX_STREAMS, DATA_BLOCKED, STREAM_DATA_BLOCKED and"]
+        #[doc = " STREAMS_BLOCKED frames."]
+        pub flow_control: bool,
+        #[doc = " Set if the packet contained a DATAGRAM frame"]
+        pub datagram: bool,
+        #[doc = " Set if the packet contained any other frame, not including PADDING frames"]
+        pub other: bool,
+        #[doc = " The number of frames in the packet, not including PADDING frames"]
+        pub frame_count: usize,
+        #[doc = " The lowest and highest ids of the streams with STREAM frames in the packet"]
+        #[doc = ""]
+        #[doc = " Stream ids above `u32::MAX` are reported as `u32::MAX`."]
+        pub stream_ids: Option<RangeInclusive<u64>>,
+    }
     #[non_exhaustive]
     #[derive(Clone)]
     pub enum SocketAddress<'a> {
@@ -464,6 +496,63 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The reason a local connection ID was retired"]
+    pub enum LocalConnectionIdRetirementCause {
+        #[non_exhaustive]
+        #[doc = " The peer retired the connection ID with a RETIRE_CONNECTION_ID frame"]
+        Peer {},
+        #[non_exhaustive]
+        #[doc = " The connection ID reached the end of its lifetime"]
+        Expired {},
+        #[non_exhaustive]
+        #[doc = " The connection ID used during the handshake was retired once the handshake was confirmed"]
+        HandshakeConfirmed {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The reason an ACK frame was sent"]
+    pub enum AckSentReason {
+        #[non_exhaustive]
+        #[doc = " The number of packets received since the last ACK frame reached the packet tolerance"]
+        PacketTolerance {},
+        #[non_exhaustive]
+        #[doc = " The `max_ack_delay` timer expired"]
+        Timer {},
+        #[non_exhaustive]
+        #[doc = " A packet was received out of order or after a gap in packet numbers"]
+        Reordering {},
+        #[non_exhaustive]
+        #[doc = " A packet was received with an ECN Congestion Experienced marking"]
+        CongestionExperienced {},
+        #[non_exhaustive]
+        #[doc = " A PATH_CHALLENGE frame was received on the active path"]
+        PathChallenge {},
+        #[non_exhaustive]
+        #[doc = " A packet which contained a previous ACK frame was declared lost"]
+        Loss {},
+        #[non_exhaustive]
+        #[doc = " The ACK frame was bundled with other frames that were being transmitted"]
+        Piggyback {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The reason a packet was sent"]
+    pub enum PacketSentReason {
+        #[non_exhaustive]
+        #[doc = " The packet was sent as part of normal transmission"]
+        Normal {},
+        #[non_exhaustive]
+        #[doc = " The packet was sent as a probe after the probe timeout (PTO) expired"]
+        PtoProbe {},
+        #[non_exhaustive]
+        #[doc = " The packet only carried data which was already sent in a previous packet"]
+        Retransmit {},
+        #[non_exhaustive]
+        #[doc = " The packet was not ack-eliciting, for example because it only contained an ACK frame"]
+        AckOnly {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " The reason the slow start congestion controller state has been exited"]
     pub enum SlowStartExitCause {
         #[non_exhaustive]
@@ -499,6 +588,55 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The reason PADDING frames were added to a datagram"]
+    pub enum PaddingReason {
+        #[non_exhaustive]
+        #[doc = " An ack-eliciting Initial packet was padded so the datagram reaches the minimum size of"]
+        #[doc = " 1200 bytes"]
+        InitialMinimumSize {},
+        #[non_exhaustive]
+        #[doc = " An ack-eliciting Initial packet was coalesced with later packets and the last of those was"]
+        #[doc = " padded so the datagram reaches the minimum size of 1200 bytes"]
+        CoalescedMinimumSize {},
+        #[non_exhaustive]
+        #[doc = " The datagram carried a PATH_CHALLENGE or PATH_RESPONSE frame and was padded to validate"]
+        #[doc = " the path MTU"]
+        PathValidation {},
+        #[non_exhaustive]
+        #[doc = " A packet was padded so it is indistinguishable from a stateless reset, or to fill space at"]
+        #[doc = " the end of the datagram which was too small for another packet"]
+        MinimumPacketSize {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The scope of a flow control window"]
+    pub enum FlowControlScope {
+        #[non_exhaustive]
+        #[doc = " The window applies to the data sent on all streams of the connection"]
+        Connection {},
+        #[non_exhaustive]
+        #[doc = " The window applies to the data sent on a single stream"]
+        Stream { id: u64 },
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The direction of data which is limited by a flow control window"]
+    pub enum FlowControlDirection {
+        #[non_exhaustive]
+        #[doc = " The window limits the data which the peer is allowed to send"]
+        #[doc = ""]
+        #[doc = " The window is updated once the local endpoint transmits a MAX_DATA or MAX_STREAM_DATA frame"]
+        #[doc = " with a higher limit."]
+        Receive {},
+        #[non_exhaustive]
+        #[doc = " The window limits the data which the local endpoint is allowed to send"]
+        #[doc = ""]
+        #[doc = " The window is updated once a MAX_DATA or MAX_STREAM_DATA frame with a higher limit is"]
+        #[doc = " received from the peer."]
+        Send {},
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Application level protocol"]
     pub struct ApplicationProtocolInformation<'a> {
         pub chosen_application_protocol: &'a [u8],
@@ -520,6 +658,7 @@ pub mod api {
     #[doc = " Packet was sent by a connection"]
     pub struct PacketSent {
         pub packet_header: PacketHeader,
+        pub sent_reason: PacketSentReason,
     }
     impl Event for PacketSent {
         const NAME: &'static str = "transport:packet_sent";
@@ -583,6 +722,8 @@ pub mod api {
         pub path: Path<'a>,
         pub bytes_lost: u16,
         pub is_mtu_probe: bool,
+        #[doc = " The frames which were contained in the lost packet"]
+        pub frames: FrameSummary,
     }
     impl<'a> Event for PacketLost<'a> {
         const NAME: &'static str = "recovery:packet_lost";
@@ -597,6 +738,8 @@ pub mod api {
         pub latest_rtt: Duration,
         pub rtt_variance: Duration,
         pub max_ack_delay: Duration,
+        #[doc = " The local timer granularity used as the lower bound of the PTO period"]
+        pub timer_granularity: Duration,
         pub pto_count: u32,
         pub congestion_window: u32,
         pub bytes_in_flight: u32,
@@ -607,6 +750,19 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A new RTT sample was taken for the path"]
+    pub struct RttSample<'a> {
+        pub path: Path<'a>,
+        pub latest_rtt: Duration,
+        pub min_rtt: Duration,
+        pub smoothed_rtt: Duration,
+        pub rtt_variance: Duration,
+    }
+    impl<'a> Event for RttSample<'a> {
+        const NAME: &'static str = "recovery:rtt_sample";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Congestion (ECN or packet loss) has occurred"]
     pub struct Congestion<'a> {
         pub path: Path<'a>,
@@ -657,12 +813,57 @@ pub mod api {
         pub packet_header: PacketHeader,
         pub path: Path<'a>,
         pub ack_range: RangeInclusive<u64>,
+        #[doc = " The largest packet number acknowledged by the ACK frame containing the range"]
+        pub largest_acknowledged: u64,
+        #[doc = " The ACK delay reported by the peer in the ACK frame containing the range"]
+        pub ack_delay: Duration,
     }
     impl<'a> Event for AckRangeReceived<'a> {
         const NAME: &'static str = "recovery:ack_range_received";
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " ACK frame was received which did not acknowledge any new packets"]
+    #[doc = ""]
+    #[doc = " All of the packets in the frame were either already acknowledged, declared lost, or never"]
+    #[doc = " sent. This is expected occasionally due to reordering and retransmitted ACK frames, but a"]
+    #[doc = " high rate may indicate a misbehaving peer or replayed ACK frames."]
+    pub struct DuplicateAckReceived<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        #[doc = " The largest packet number acknowledged by the ACK frame"]
+        pub largest_acknowledged: u64,
+    }
+    impl<'a> Event for DuplicateAckReceived<'a> {
+        const NAME: &'static str = "recovery:duplicate_ack_received";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " ACK frame was sent"]
+    pub struct AckSent {
+        pub packet_header: PacketHeader,
+        pub path_id: u64,
+        pub reason: AckSentReason,
+    }
+    impl Event for AckSent {
+        const NAME: &'static str = "recovery:ack_sent";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " Ack-eliciting packet was received and processed by the ACK manager"]
+    pub struct AckElicitingPacketReceived<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        #[doc = " The reason the packet caused an ACK frame to be sent immediately"]
+        #[doc = ""]
+        #[doc = " `None` indicates the ACK frame was delayed by up to `max_ack_delay`."]
+        pub immediate_ack_reason: Option<AckSentReason>,
+    }
+    impl<'a> Event for AckElicitingPacketReceived<'a> {
+        const NAME: &'static str = "recovery:ack_eliciting_packet_received";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Packet was dropped with the given reason"]
     pub struct PacketDropped<'a> {
         pub reason: PacketDropReason<'a>,
@@ -738,6 +939,17 @@ pub mod api {
         #[doc = ""]
         #[doc = " See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details."]
         pub gso_offset: usize,
+        #[doc = " The number of bytes of PADDING frames in the datagram"]
+        pub padding_len: u16,
+        #[doc = " The reason the datagram was padded, if it contains any PADDING frames"]
+        pub padding_reason: Option<PaddingReason>,
+        #[doc = " The number of QUIC packets coalesced into the datagram"]
+        pub packet_count: u8,
+        #[doc = " The length of the Initial packet in the datagram, if it contains one"]
+        #[doc = ""]
+        #[doc = " Datagrams carrying Initial packets are expanded to at least 1200 bytes, either by padding"]
+        #[doc = " the Initial packet itself or by padding the last packet coalesced after it."]
+        pub initial_packet_len: Option<u16>,
     }
     impl Event for DatagramSent {
         const NAME: &'static str = "transport:datagram_sent";
@@ -776,6 +988,32 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " A local connection ID was issued to the peer in a NEW_CONNECTION_ID frame"]
+    #[doc = ""]
+    #[doc = " This is only published the first time the connection ID is transmitted, not when a lost"]
+    #[doc = " NEW_CONNECTION_ID frame is retransmitted."]
+    pub struct LocalConnectionIdIssued<'a> {
+        pub connection_id: ConnectionId<'a>,
+        pub sequence_number: u64,
+        #[doc = " The sequence number below which the peer was requested to retire connection IDs"]
+        pub retire_prior_to: u64,
+    }
+    impl<'a> Event for LocalConnectionIdIssued<'a> {
+        const NAME: &'static str = "connectivity:local_connection_id_issued";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A local connection ID was retired"]
+    pub struct LocalConnectionIdRetired<'a> {
+        pub connection_id: ConnectionId<'a>,
+        pub sequence_number: u64,
+        pub cause: LocalConnectionIdRetirementCause,
+    }
+    impl<'a> Event for LocalConnectionIdRetired<'a> {
+        const NAME: &'static str = "connectivity:local_connection_id_retired";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     pub struct EcnStateChanged<'a> {
         pub path: Path<'a>,
         pub state: EcnState,
@@ -874,6 +1112,125 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The peer opened streams faster than the configured rate and MAX_STREAMS credit was delayed"]
+    pub struct StreamOpenRateLimited {
+        pub stream_type: StreamType,
+        #[doc = " The number of streams that were withheld from the peer during the rate window"]
+        pub deferred_streams: u64,
+    }
+    impl Event for StreamOpenRateLimited {
+        const NAME: &'static str = "transport:stream_open_rate_limited";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A stream has waited at least the configured starvation threshold to transmit its data"]
+    #[doc = ""]
+    #[doc = " The event is published once per wait, either when the threshold is reached or when the stream"]
+    #[doc = " transmits, whichever happens first."]
+    pub struct StreamStarved {
+        pub id: u64,
+        #[doc = " The amount of time the stream waited with data to send"]
+        pub duration: Duration,
+    }
+    impl Event for StreamStarved {
+        const NAME: &'static str = "transport:stream_starved";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A stream was closed and its state was released"]
+    pub struct StreamClosed {
+        pub id: u64,
+        #[doc = " The number of bytes of stream data which were retransmitted"]
+        pub retransmitted_bytes: u64,
+    }
+    impl Event for StreamClosed {
+        const NAME: &'static str = "transport:stream_closed";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A connection or stream flow control window was increased"]
+    pub struct FlowControlWindowChanged {
+        pub scope: FlowControlScope,
+        pub direction: FlowControlDirection,
+        #[doc = " The previous maximum offset of data allowed by the window"]
+        pub old_window: u64,
+        #[doc = " The new maximum offset of data allowed by the window"]
+        pub new_window: u64,
+    }
+    impl Event for FlowControlWindowChanged {
+        const NAME: &'static str = "transport:flow_control_window_changed";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The connection has no open streams and no data in flight"]
+    #[doc = ""]
+    #[doc = " This is only emitted once the handshake has completed."]
+    pub struct ConnectionIdle {}
+    impl Event for ConnectionIdle {
+        const NAME: &'static str = "connectivity:connection_idle";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The connection has resumed activity after previously becoming idle"]
+    pub struct ConnectionActive {}
+    impl Event for ConnectionActive {
+        const NAME: &'static str = "connectivity:connection_active";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The application updated the send rate limit of the connection"]
+    pub struct SendRateLimitUpdated {
+        #[doc = " The maximum number of bytes sent per second, or `None` if the limit was removed"]
+        pub bytes_per_sec: Option<u64>,
+    }
+    impl Event for SendRateLimitUpdated {
+        const NAME: &'static str = "recovery:send_rate_limit_updated";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The connection has become limited by the congestion window"]
+    #[doc = ""]
+    #[doc = " A connection is congestion limited while it has data to send on the active path, but the"]
+    #[doc = " congestion window is full. `CongestionLimitEnded` is published once the connection is able"]
+    #[doc = " to send again, or no longer has data to send."]
+    pub struct CongestionLimited {
+        pub path_id: u64,
+        #[doc = " The congestion window when the connection became congestion limited"]
+        pub congestion_window: u32,
+    }
+    impl Event for CongestionLimited {
+        const NAME: &'static str = "recovery:congestion_limited";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " The connection is no longer limited by the congestion window"]
+    pub struct CongestionLimitEnded {
+        pub path_id: u64,
+        #[doc = " The amount of time the connection was congestion limited"]
+        pub duration: Duration,
+        #[doc = " The congestion window when the connection became congestion limited"]
+        pub congestion_window: u32,
+    }
+    impl Event for CongestionLimitEnded {
+        const NAME: &'static str = "recovery:congestion_limit_ended";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " CRYPTO data was retransmitted in an Initial or Handshake packet"]
+    #[doc = ""]
+    #[doc = " This is emitted separately from the retransmission of stream data so delays in connection"]
+    #[doc = " establishment caused by loss of handshake packets can be identified."]
+    pub struct CryptoRetransmitted {
+        pub packet_header: PacketHeader,
+        pub path_id: u64,
+        #[doc = " The number of CRYPTO bytes which were retransmitted in the packet"]
+        pub bytes: u64,
+    }
+    impl Event for CryptoRetransmitted {
+        const NAME: &'static str = "recovery:crypto_retransmitted";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " QUIC version"]
     pub struct VersionInformation<'a> {
         pub server_versions: &'a [u32],
@@ -946,6 +1303,35 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " The endpoint handshake rate limit was reached"]
+    #[doc = ""]
+    #[doc = " This is emitted once for each one-second window in which the limit is reached. All further"]
+    #[doc = " connection attempts in the window are deferred with a Retry packet or refused."]
+    pub struct EndpointHandshakeRateLimited {
+        #[doc = " The configured maximum number of handshakes per second"]
+        pub handshakes_per_sec: usize,
+        #[doc = " Whether the attempts are deferred with a Retry packet rather than refused"]
+        pub retry: bool,
+    }
+    impl Event for EndpointHandshakeRateLimited {
+        const NAME: &'static str = "connectivity:endpoint_handshake_rate_limited";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
+    #[doc = " A connection attempt was refused because the endpoint is under memory pressure"]
+    pub struct EndpointMemoryPressure {
+        #[doc = " The number of bytes buffered by open connections"]
+        pub memory_usage: u64,
+        #[doc = " The configured endpoint memory limit"]
+        pub memory_limit: u64,
+        #[doc = " The number of open connections"]
+        pub connection_count: usize,
+    }
+    impl Event for EndpointMemoryPressure {
+        const NAME: &'static str = "connectivity:endpoint_memory_pressure";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Emitted when the platform sends at least one packet"]
     pub struct PlatformTx {
         #[doc = " The number of packets sent"]
@@ -986,6 +1372,23 @@ pub mod api {
     }
     #[derive(Clone, Debug)]
     #[non_exhaustive]
+    #[doc = " Emitted when the operating system reports datagrams which were dropped because the socket"]
+    #[doc = " receive buffer was full"]
+    #[doc = ""]
+    #[doc = " These drops occur before the datagrams reach the endpoint and are distinct from loss on the"]
+    #[doc = " network. Frequent overflows indicate the receive buffer size should be increased or more"]
+    #[doc = " sockets should be used to receive."]
+    pub struct IoRecvOverflow {
+        #[doc = " The number of datagrams dropped since the previous event"]
+        pub count: u64,
+        #[doc = " The total number of datagrams dropped since the socket was opened"]
+        pub total_count: u64,
+    }
+    impl Event for IoRecvOverflow {
+        const NAME: &'static str = "platform:io_recv_overflow";
+    }
+    #[derive(Clone, Debug)]
+    #[non_exhaustive]
     #[doc = " Emitted when a platform feature is configured"]
     pub struct PlatformFeatureConfigured {
         pub configuration: PlatformFeatureConfiguration,
@@ -1485,7 +1888,7 @@ pub mod tracing {
                 api::EndpointType::Client {} => self.client.id(),
                 api::EndpointType::Server {} => self.server.id(),
             };
-            tracing :: span ! (target : "s2n_quic" , parent : parent , tracing :: Level :: DEBUG , "conn" , id = meta . id)
+            tracing :: span ! (target : "s2n_quic" , parent : parent , tracing :: Level :: DEBUG , "conn" , id = meta . id , tags = tracing :: field :: debug (& meta . tags))
         }
         #[inline]
         fn on_application_protocol_information(
@@ -1519,8 +1922,11 @@ pub mod tracing {
             event: &api::PacketSent,
         ) {
             let id = context.id();
-            let api::PacketSent { packet_header } = event;
-            tracing :: event ! (target : "packet_sent" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header));
+            let api::PacketSent {
+                packet_header,
+                sent_reason,
+            } = event;
+            tracing :: event ! (target : "packet_sent" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , sent_reason = tracing :: field :: debug (sent_reason));
         }
         #[inline]
         fn on_packet_received(
@@ -1598,8 +2004,9 @@ pub mod tracing {
                 path,
                 bytes_lost,
                 is_mtu_probe,
+                frames,
             } = event;
-            tracing :: event ! (target : "packet_lost" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path = tracing :: field :: debug (path) , bytes_lost = tracing :: field :: debug (bytes_lost) , is_mtu_probe = tracing :: field :: debug (is_mtu_probe));
+            tracing :: event ! (target : "packet_lost" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path = tracing :: field :: debug (path) , bytes_lost = tracing :: field :: debug (bytes_lost) , is_mtu_probe = tracing :: field :: debug (is_mtu_probe) , frames = tracing :: field :: debug (frames));
         }
         #[inline]
         fn on_recovery_metrics(
@@ -1616,12 +2023,30 @@ pub mod tracing {
                 latest_rtt,
                 rtt_variance,
                 max_ack_delay,
+                timer_granularity,
                 pto_count,
                 congestion_window,
                 bytes_in_flight,
                 congestion_limited,
             } = event;
-            tracing :: event ! (target : "recovery_metrics" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , min_rtt = tracing :: field :: debug (min_rtt) , smoothed_rtt = tracing :: field :: debug (smoothed_rtt) , latest_rtt = tracing :: field :: debug (latest_rtt) , rtt_variance = tracing :: field :: debug (rtt_variance) , max_ack_delay = tracing :: field :: debug (max_ack_delay) , pto_count = tracing :: field :: debug (pto_count) , congestion_window = tracing :: field :: debug (congestion_window) , bytes_in_flight = tracing :: field :: debug (bytes_in_flight) , congestion_limited = tracing :: field :: debug (congestion_limited));
+            tracing :: event ! (target : "recovery_metrics" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , min_rtt = tracing :: field :: debug (min_rtt) , smoothed_rtt = tracing :: field :: debug (smoothed_rtt) , latest_rtt = tracing :: field :: debug (latest_rtt) , rtt_variance = tracing :: field :: debug (rtt_variance) , max_ack_delay = tracing :: field :: debug (max_ack_delay) , timer_granularity = tracing :: field :: debug (timer_granularity) , pto_count = tracing :: field :: debug (pto_count) , congestion_window = tracing :: field :: debug (congestion_window) , bytes_in_flight = tracing :: field :: debug (bytes_in_flight) , congestion_limited = tracing :: field :: debug (congestion_limited));
+        }
+        #[inline]
+        fn on_rtt_sample(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::RttSample,
+        ) {
+            let id = context.id();
+            let api::RttSample {
+                path,
+                latest_rtt,
+                min_rtt,
+                smoothed_rtt,
+                rtt_variance,
+            } = event;
+            tracing :: event ! (target : "rtt_sample" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , latest_rtt = tracing :: field :: debug (latest_rtt) , min_rtt = tracing :: field :: debug (min_rtt) , smoothed_rtt = tracing :: field :: debug (smoothed_rtt) , rtt_variance = tracing :: field :: debug (rtt_variance));
         }
         #[inline]
         fn on_congestion(
@@ -1674,8 +2099,55 @@ pub mod tracing {
                 packet_header,
                 path,
                 ack_range,
+                largest_acknowledged,
+                ack_delay,
+            } = event;
+            tracing :: event ! (target : "ack_range_received" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path = tracing :: field :: debug (path) , ack_range = tracing :: field :: debug (ack_range) , largest_acknowledged = tracing :: field :: debug (largest_acknowledged) , ack_delay = tracing :: field :: debug (ack_delay));
+        }
+        #[inline]
+        fn on_duplicate_ack_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::DuplicateAckReceived,
+        ) {
+            let id = context.id();
+            let api::DuplicateAckReceived {
+                packet_header,
+                path,
+                largest_acknowledged,
+            } = event;
+            tracing :: event ! (target : "duplicate_ack_received" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path = tracing :: field :: debug (path) , largest_acknowledged = tracing :: field :: debug (largest_acknowledged));
+        }
+        #[inline]
+        fn on_ack_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::AckSent,
+        ) {
+            let id = context.id();
+            let api::AckSent {
+                packet_header,
+                path_id,
+                reason,
+            } = event;
+            tracing :: event ! (target : "ack_sent" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path_id = tracing :: field :: debug (path_id) , reason = tracing :: field :: debug (reason));
+        }
+        #[inline]
+        fn on_ack_eliciting_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::AckElicitingPacketReceived,
+        ) {
+            let id = context.id();
+            let api::AckElicitingPacketReceived {
+                packet_header,
+                path,
+                immediate_ack_reason,
             } = event;
-            tracing :: event ! (target : "ack_range_received" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path = tracing :: field :: debug (path) , ack_range = tracing :: field :: debug (ack_range));
+            tracing :: event ! (target : "ack_eliciting_packet_received" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path = tracing :: field :: debug (path) , immediate_ack_reason = tracing :: field :: debug (immediate_ack_reason));
         }
         #[inline]
         fn on_packet_dropped(
@@ -1771,8 +2243,15 @@ pub mod tracing {
             event: &api::DatagramSent,
         ) {
             let id = context.id();
-            let api::DatagramSent { len, gso_offset } = event;
-            tracing :: event ! (target : "datagram_sent" , parent : id , tracing :: Level :: DEBUG , len = tracing :: field :: debug (len) , gso_offset = tracing :: field :: debug (gso_offset));
+            let api::DatagramSent {
+                len,
+                gso_offset,
+                padding_len,
+                padding_reason,
+                packet_count,
+                initial_packet_len,
+            } = event;
+            tracing :: event ! (target : "datagram_sent" , parent : id , tracing :: Level :: DEBUG , len = tracing :: field :: debug (len) , gso_offset = tracing :: field :: debug (gso_offset) , padding_len = tracing :: field :: debug (padding_len) , padding_reason = tracing :: field :: debug (padding_reason) , packet_count = tracing :: field :: debug (packet_count) , initial_packet_len = tracing :: field :: debug (initial_packet_len));
         }
         #[inline]
         fn on_datagram_received(
@@ -1813,6 +2292,36 @@ pub mod tracing {
             tracing :: event ! (target : "connection_id_updated" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , cid_consumer = tracing :: field :: debug (cid_consumer) , previous = tracing :: field :: debug (previous) , current = tracing :: field :: debug (current));
         }
         #[inline]
+        fn on_local_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::LocalConnectionIdIssued,
+        ) {
+            let id = context.id();
+            let api::LocalConnectionIdIssued {
+                connection_id,
+                sequence_number,
+                retire_prior_to,
+            } = event;
+            tracing :: event ! (target : "local_connection_id_issued" , parent : id , tracing :: Level :: DEBUG , connection_id = tracing :: field :: debug (connection_id) , sequence_number = tracing :: field :: debug (sequence_number) , retire_prior_to = tracing :: field :: debug (retire_prior_to));
+        }
+        #[inline]
+        fn on_local_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::LocalConnectionIdRetired,
+        ) {
+            let id = context.id();
+            let api::LocalConnectionIdRetired {
+                connection_id,
+                sequence_number,
+                cause,
+            } = event;
+            tracing :: event ! (target : "local_connection_id_retired" , parent : id , tracing :: Level :: DEBUG , connection_id = tracing :: field :: debug (connection_id) , sequence_number = tracing :: field :: debug (sequence_number) , cause = tracing :: field :: debug (cause));
+        }
+        #[inline]
         fn on_ecn_state_changed(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -1946,46 +2455,178 @@ pub mod tracing {
             tracing :: event ! (target : "slow_start_exited" , parent : id , tracing :: Level :: DEBUG , path = tracing :: field :: debug (path) , cause = tracing :: field :: debug (cause) , congestion_window = tracing :: field :: debug (congestion_window));
         }
         #[inline]
-        fn on_version_information(
+        fn on_stream_open_rate_limited(
             &mut self,
-            meta: &api::EndpointMeta,
-            event: &api::VersionInformation,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::StreamOpenRateLimited,
         ) {
-            let parent = match meta.endpoint_type {
-                api::EndpointType::Client {} => self.client.id(),
-                api::EndpointType::Server {} => self.server.id(),
-            };
-            let api::VersionInformation {
-                server_versions,
-                client_versions,
-                chosen_version,
+            let id = context.id();
+            let api::StreamOpenRateLimited {
+                stream_type,
+                deferred_streams,
             } = event;
-            tracing :: event ! (target : "version_information" , parent : parent , tracing :: Level :: DEBUG , server_versions = tracing :: field :: debug (server_versions) , client_versions = tracing :: field :: debug (client_versions) , chosen_version = tracing :: field :: debug (chosen_version));
+            tracing :: event ! (target : "stream_open_rate_limited" , parent : id , tracing :: Level :: DEBUG , stream_type = tracing :: field :: debug (stream_type) , deferred_streams = tracing :: field :: debug (deferred_streams));
         }
         #[inline]
-        fn on_endpoint_packet_sent(
+        fn on_stream_starved(
             &mut self,
-            meta: &api::EndpointMeta,
-            event: &api::EndpointPacketSent,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::StreamStarved,
         ) {
-            let parent = match meta.endpoint_type {
-                api::EndpointType::Client {} => self.client.id(),
-                api::EndpointType::Server {} => self.server.id(),
-            };
-            let api::EndpointPacketSent { packet_header } = event;
-            tracing :: event ! (target : "endpoint_packet_sent" , parent : parent , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header));
+            let id = context.id();
+            let api::StreamStarved { id, duration } = event;
+            tracing :: event ! (target : "stream_starved" , parent : id , tracing :: Level :: DEBUG , id = tracing :: field :: debug (id) , duration = tracing :: field :: debug (duration));
         }
         #[inline]
-        fn on_endpoint_packet_received(
+        fn on_stream_closed(
             &mut self,
-            meta: &api::EndpointMeta,
-            event: &api::EndpointPacketReceived,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::StreamClosed,
         ) {
-            let parent = match meta.endpoint_type {
-                api::EndpointType::Client {} => self.client.id(),
-                api::EndpointType::Server {} => self.server.id(),
-            };
-            let api::EndpointPacketReceived { packet_header } = event;
+            let id = context.id();
+            let api::StreamClosed {
+                id,
+                retransmitted_bytes,
+            } = event;
+            tracing :: event ! (target : "stream_closed" , parent : id , tracing :: Level :: DEBUG , id = tracing :: field :: debug (id) , retransmitted_bytes = tracing :: field :: debug (retransmitted_bytes));
+        }
+        #[inline]
+        fn on_flow_control_window_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::FlowControlWindowChanged,
+        ) {
+            let id = context.id();
+            let api::FlowControlWindowChanged {
+                scope,
+                direction,
+                old_window,
+                new_window,
+            } = event;
+            tracing :: event ! (target : "flow_control_window_changed" , parent : id , tracing :: Level :: DEBUG , scope = tracing :: field :: debug (scope) , direction = tracing :: field :: debug (direction) , old_window = tracing :: field :: debug (old_window) , new_window = tracing :: field :: debug (new_window));
+        }
+        #[inline]
+        fn on_connection_idle(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdle,
+        ) {
+            let id = context.id();
+            let api::ConnectionIdle {} = event;
+            tracing :: event ! (target : "connection_idle" , parent : id , tracing :: Level :: DEBUG , );
+        }
+        #[inline]
+        fn on_connection_active(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::ConnectionActive,
+        ) {
+            let id = context.id();
+            let api::ConnectionActive {} = event;
+            tracing :: event ! (target : "connection_active" , parent : id , tracing :: Level :: DEBUG , );
+        }
+        #[inline]
+        fn on_send_rate_limit_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::SendRateLimitUpdated,
+        ) {
+            let id = context.id();
+            let api::SendRateLimitUpdated { bytes_per_sec } = event;
+            tracing :: event ! (target : "send_rate_limit_updated" , parent : id , tracing :: Level :: DEBUG , bytes_per_sec = tracing :: field :: debug (bytes_per_sec));
+        }
+        #[inline]
+        fn on_congestion_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::CongestionLimited,
+        ) {
+            let id = context.id();
+            let api::CongestionLimited {
+                path_id,
+                congestion_window,
+            } = event;
+            tracing :: event ! (target : "congestion_limited" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , congestion_window = tracing :: field :: debug (congestion_window));
+        }
+        #[inline]
+        fn on_congestion_limit_ended(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::CongestionLimitEnded,
+        ) {
+            let id = context.id();
+            let api::CongestionLimitEnded {
+                path_id,
+                duration,
+                congestion_window,
+            } = event;
+            tracing :: event ! (target : "congestion_limit_ended" , parent : id , tracing :: Level :: DEBUG , path_id = tracing :: field :: debug (path_id) , duration = tracing :: field :: debug (duration) , congestion_window = tracing :: field :: debug (congestion_window));
+        }
+        #[inline]
+        fn on_crypto_retransmitted(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            _meta: &api::ConnectionMeta,
+            event: &api::CryptoRetransmitted,
+        ) {
+            let id = context.id();
+            let api::CryptoRetransmitted {
+                packet_header,
+                path_id,
+                bytes,
+            } = event;
+            tracing :: event ! (target : "crypto_retransmitted" , parent : id , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header) , path_id = tracing :: field :: debug (path_id) , bytes = tracing :: field :: debug (bytes));
+        }
+        #[inline]
+        fn on_version_information(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::VersionInformation,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::VersionInformation {
+                server_versions,
+                client_versions,
+                chosen_version,
+            } = event;
+            tracing :: event ! (target : "version_information" , parent : parent , tracing :: Level :: DEBUG , server_versions = tracing :: field :: debug (server_versions) , client_versions = tracing :: field :: debug (client_versions) , chosen_version = tracing :: field :: debug (chosen_version));
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketSent,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::EndpointPacketSent { packet_header } = event;
+            tracing :: event ! (target : "endpoint_packet_sent" , parent : parent , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header));
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketReceived,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::EndpointPacketReceived { packet_header } = event;
             tracing :: event ! (target : "endpoint_packet_received" , parent : parent , tracing :: Level :: DEBUG , packet_header = tracing :: field :: debug (packet_header));
         }
         #[inline]
@@ -2041,6 +2682,39 @@ pub mod tracing {
             tracing :: event ! (target : "endpoint_connection_attempt_failed" , parent : parent , tracing :: Level :: DEBUG , error = tracing :: field :: debug (error));
         }
         #[inline]
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointHandshakeRateLimited,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::EndpointHandshakeRateLimited {
+                handshakes_per_sec,
+                retry,
+            } = event;
+            tracing :: event ! (target : "endpoint_handshake_rate_limited" , parent : parent , tracing :: Level :: DEBUG , handshakes_per_sec = tracing :: field :: debug (handshakes_per_sec) , retry = tracing :: field :: debug (retry));
+        }
+        #[inline]
+        fn on_endpoint_memory_pressure(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointMemoryPressure,
+        ) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::EndpointMemoryPressure {
+                memory_usage,
+                memory_limit,
+                connection_count,
+            } = event;
+            tracing :: event ! (target : "endpoint_memory_pressure" , parent : parent , tracing :: Level :: DEBUG , memory_usage = tracing :: field :: debug (memory_usage) , memory_limit = tracing :: field :: debug (memory_limit) , connection_count = tracing :: field :: debug (connection_count));
+        }
+        #[inline]
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             let parent = match meta.endpoint_type {
                 api::EndpointType::Client {} => self.client.id(),
@@ -2077,6 +2751,15 @@ pub mod tracing {
             tracing :: event ! (target : "platform_rx_error" , parent : parent , tracing :: Level :: DEBUG , errno = tracing :: field :: debug (errno));
         }
         #[inline]
+        fn on_io_recv_overflow(&mut self, meta: &api::EndpointMeta, event: &api::IoRecvOverflow) {
+            let parent = match meta.endpoint_type {
+                api::EndpointType::Client {} => self.client.id(),
+                api::EndpointType::Server {} => self.server.id(),
+            };
+            let api::IoRecvOverflow { count, total_count } = event;
+            tracing :: event ! (target : "io_recv_overflow" , parent : parent , tracing :: Level :: DEBUG , count = tracing :: field :: debug (count) , total_count = tracing :: field :: debug (total_count));
+        }
+        #[inline]
         fn on_platform_feature_configured(
             &mut self,
             meta: &api::EndpointMeta,
@@ -2125,6 +2808,924 @@ pub mod tracing {
         }
     }
 }
+#[cfg(feature = "std")]
+pub mod timing {
+    #![doc = r" This module contains a subscriber which measures the time spent in each callback"]
+    #![doc = r" of another subscriber"]
+    use super::{api, query, supervisor, Event, Meta};
+    use core::time::Duration;
+    use std::time::Instant;
+    #[doc = r" Records the time spent in each callback of a timed [`Subscriber`]"]
+    pub trait Recorder: 'static + Send {
+        #[doc = r" Called after each callback with the name of the callback and the time spent in it"]
+        fn on_callback(&mut self, name: &'static str, elapsed: Duration);
+    }
+    impl<F: 'static + Send + FnMut(&'static str, Duration)> Recorder for F {
+        #[inline]
+        fn on_callback(&mut self, name: &'static str, elapsed: Duration) {
+            (self)(name, elapsed)
+        }
+    }
+    #[doc = r" Wraps a subscriber and measures the time spent in each of its callbacks"]
+    #[derive(Clone, Debug)]
+    pub struct Subscriber<S, R> {
+        subscriber: S,
+        recorder: R,
+    }
+    impl<S, R> Subscriber<S, R> {
+        #[doc = r" Creates a subscriber which reports the time spent in each callback of `subscriber`"]
+        #[doc = r" to `recorder`"]
+        pub fn new(subscriber: S, recorder: R) -> Self {
+            Self {
+                subscriber,
+                recorder,
+            }
+        }
+        #[doc = r" Returns a reference to the recorder"]
+        pub fn recorder(&self) -> &R {
+            &self.recorder
+        }
+    }
+    impl<S: super::Subscriber, R: Recorder> super::Subscriber for Subscriber<S, R> {
+        type ConnectionContext = S::ConnectionContext;
+        #[inline]
+        fn create_connection_context(
+            &mut self,
+            meta: &api::ConnectionMeta,
+            info: &api::ConnectionInfo,
+        ) -> Self::ConnectionContext {
+            let start = Instant::now();
+            let context = self.subscriber.create_connection_context(meta, info);
+            self.recorder
+                .on_callback("create_connection_context", start.elapsed());
+            context
+        }
+        #[inline]
+        fn supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> Option<Duration> {
+            let start = Instant::now();
+            let timeout = self
+                .subscriber
+                .supervisor_timeout(conn_context, meta, context);
+            self.recorder
+                .on_callback("supervisor_timeout", start.elapsed());
+            timeout
+        }
+        #[inline]
+        fn on_supervisor_timeout(
+            &mut self,
+            conn_context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            context: &supervisor::Context,
+        ) -> supervisor::Outcome {
+            let start = Instant::now();
+            let outcome = self
+                .subscriber
+                .on_supervisor_timeout(conn_context, meta, context);
+            self.recorder
+                .on_callback("on_supervisor_timeout", start.elapsed());
+            outcome
+        }
+        #[inline]
+        fn on_application_protocol_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ApplicationProtocolInformation,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_application_protocol_information(context, meta, event);
+            self.recorder
+                .on_callback("on_application_protocol_information", start.elapsed());
+        }
+        #[inline]
+        fn on_server_name_information(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ServerNameInformation,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_server_name_information(context, meta, event);
+            self.recorder
+                .on_callback("on_server_name_information", start.elapsed());
+        }
+        #[inline]
+        fn on_packet_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketSent,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_packet_sent(context, meta, event);
+            self.recorder.on_callback("on_packet_sent", start.elapsed());
+        }
+        #[inline]
+        fn on_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_packet_received(context, meta, event);
+            self.recorder
+                .on_callback("on_packet_received", start.elapsed());
+        }
+        #[inline]
+        fn on_active_path_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ActivePathUpdated,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_active_path_updated(context, meta, event);
+            self.recorder
+                .on_callback("on_active_path_updated", start.elapsed());
+        }
+        #[inline]
+        fn on_path_created(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathCreated,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_path_created(context, meta, event);
+            self.recorder
+                .on_callback("on_path_created", start.elapsed());
+        }
+        #[inline]
+        fn on_frame_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FrameSent,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_frame_sent(context, meta, event);
+            self.recorder.on_callback("on_frame_sent", start.elapsed());
+        }
+        #[inline]
+        fn on_frame_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FrameReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_frame_received(context, meta, event);
+            self.recorder
+                .on_callback("on_frame_received", start.elapsed());
+        }
+        #[inline]
+        fn on_packet_lost(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketLost,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_packet_lost(context, meta, event);
+            self.recorder.on_callback("on_packet_lost", start.elapsed());
+        }
+        #[inline]
+        fn on_recovery_metrics(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RecoveryMetrics,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_recovery_metrics(context, meta, event);
+            self.recorder
+                .on_callback("on_recovery_metrics", start.elapsed());
+        }
+        #[inline]
+        fn on_rtt_sample(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RttSample,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_rtt_sample(context, meta, event);
+            self.recorder.on_callback("on_rtt_sample", start.elapsed());
+        }
+        #[inline]
+        fn on_congestion(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::Congestion,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_congestion(context, meta, event);
+            self.recorder.on_callback("on_congestion", start.elapsed());
+        }
+        #[inline]
+        #[allow(deprecated)]
+        fn on_ack_processed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckProcessed,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_ack_processed(context, meta, event);
+            self.recorder
+                .on_callback("on_ack_processed", start.elapsed());
+        }
+        #[inline]
+        fn on_rx_ack_range_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RxAckRangeDropped,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_rx_ack_range_dropped(context, meta, event);
+            self.recorder
+                .on_callback("on_rx_ack_range_dropped", start.elapsed());
+        }
+        #[inline]
+        fn on_ack_range_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckRangeReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_ack_range_received(context, meta, event);
+            self.recorder
+                .on_callback("on_ack_range_received", start.elapsed());
+        }
+        #[inline]
+        fn on_duplicate_ack_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DuplicateAckReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_duplicate_ack_received(context, meta, event);
+            self.recorder
+                .on_callback("on_duplicate_ack_received", start.elapsed());
+        }
+        #[inline]
+        fn on_ack_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckSent,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_ack_sent(context, meta, event);
+            self.recorder.on_callback("on_ack_sent", start.elapsed());
+        }
+        #[inline]
+        fn on_ack_eliciting_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckElicitingPacketReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_ack_eliciting_packet_received(context, meta, event);
+            self.recorder
+                .on_callback("on_ack_eliciting_packet_received", start.elapsed());
+        }
+        #[inline]
+        fn on_packet_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PacketDropped,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_packet_dropped(context, meta, event);
+            self.recorder
+                .on_callback("on_packet_dropped", start.elapsed());
+        }
+        #[inline]
+        fn on_key_update(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeyUpdate,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_key_update(context, meta, event);
+            self.recorder.on_callback("on_key_update", start.elapsed());
+        }
+        #[inline]
+        fn on_key_space_discarded(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeySpaceDiscarded,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_key_space_discarded(context, meta, event);
+            self.recorder
+                .on_callback("on_key_space_discarded", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_started(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionStarted,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_connection_started(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_started", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionClosed,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_connection_closed(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_closed", start.elapsed());
+        }
+        #[inline]
+        fn on_duplicate_packet(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DuplicatePacket,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_duplicate_packet(context, meta, event);
+            self.recorder
+                .on_callback("on_duplicate_packet", start.elapsed());
+        }
+        #[inline]
+        fn on_transport_parameters_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TransportParametersReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_transport_parameters_received(context, meta, event);
+            self.recorder
+                .on_callback("on_transport_parameters_received", start.elapsed());
+        }
+        #[inline]
+        fn on_datagram_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramSent,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_datagram_sent(context, meta, event);
+            self.recorder
+                .on_callback("on_datagram_sent", start.elapsed());
+        }
+        #[inline]
+        fn on_datagram_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_datagram_received(context, meta, event);
+            self.recorder
+                .on_callback("on_datagram_received", start.elapsed());
+        }
+        #[inline]
+        fn on_datagram_dropped(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DatagramDropped,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_datagram_dropped(context, meta, event);
+            self.recorder
+                .on_callback("on_datagram_dropped", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_id_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdUpdated,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_connection_id_updated(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_id_updated", start.elapsed());
+        }
+        #[inline]
+        fn on_local_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::LocalConnectionIdIssued,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_local_connection_id_issued(context, meta, event);
+            self.recorder
+                .on_callback("on_local_connection_id_issued", start.elapsed());
+        }
+        #[inline]
+        fn on_local_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::LocalConnectionIdRetired,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_local_connection_id_retired(context, meta, event);
+            self.recorder
+                .on_callback("on_local_connection_id_retired", start.elapsed());
+        }
+        #[inline]
+        fn on_ecn_state_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::EcnStateChanged,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_ecn_state_changed(context, meta, event);
+            self.recorder
+                .on_callback("on_ecn_state_changed", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_migration_denied(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionMigrationDenied,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_connection_migration_denied(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_migration_denied", start.elapsed());
+        }
+        #[inline]
+        fn on_handshake_status_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::HandshakeStatusUpdated,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_handshake_status_updated(context, meta, event);
+            self.recorder
+                .on_callback("on_handshake_status_updated", start.elapsed());
+        }
+        #[inline]
+        fn on_path_challenge_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::PathChallengeUpdated,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_path_challenge_updated(context, meta, event);
+            self.recorder
+                .on_callback("on_path_challenge_updated", start.elapsed());
+        }
+        #[inline]
+        fn on_tls_client_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TlsClientHello,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_tls_client_hello(context, meta, event);
+            self.recorder
+                .on_callback("on_tls_client_hello", start.elapsed());
+        }
+        #[inline]
+        fn on_tls_server_hello(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TlsServerHello,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_tls_server_hello(context, meta, event);
+            self.recorder
+                .on_callback("on_tls_server_hello", start.elapsed());
+        }
+        #[inline]
+        fn on_rx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RxStreamProgress,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_rx_stream_progress(context, meta, event);
+            self.recorder
+                .on_callback("on_rx_stream_progress", start.elapsed());
+        }
+        #[inline]
+        fn on_tx_stream_progress(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::TxStreamProgress,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_tx_stream_progress(context, meta, event);
+            self.recorder
+                .on_callback("on_tx_stream_progress", start.elapsed());
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::KeepAliveTimerExpired,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_keep_alive_timer_expired(context, meta, event);
+            self.recorder
+                .on_callback("on_keep_alive_timer_expired", start.elapsed());
+        }
+        #[inline]
+        fn on_mtu_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::MtuUpdated,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_mtu_updated(context, meta, event);
+            self.recorder.on_callback("on_mtu_updated", start.elapsed());
+        }
+        #[inline]
+        fn on_slow_start_exited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::SlowStartExited,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_slow_start_exited(context, meta, event);
+            self.recorder
+                .on_callback("on_slow_start_exited", start.elapsed());
+        }
+        #[inline]
+        fn on_stream_open_rate_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamOpenRateLimited,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_stream_open_rate_limited(context, meta, event);
+            self.recorder
+                .on_callback("on_stream_open_rate_limited", start.elapsed());
+        }
+        #[inline]
+        fn on_stream_starved(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamStarved,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_stream_starved(context, meta, event);
+            self.recorder
+                .on_callback("on_stream_starved", start.elapsed());
+        }
+        #[inline]
+        fn on_stream_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamClosed,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_stream_closed(context, meta, event);
+            self.recorder
+                .on_callback("on_stream_closed", start.elapsed());
+        }
+        #[inline]
+        fn on_flow_control_window_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FlowControlWindowChanged,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_flow_control_window_changed(context, meta, event);
+            self.recorder
+                .on_callback("on_flow_control_window_changed", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_idle(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdle,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_connection_idle(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_idle", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_active(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionActive,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_connection_active(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_active", start.elapsed());
+        }
+        #[inline]
+        fn on_send_rate_limit_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::SendRateLimitUpdated,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_send_rate_limit_updated(context, meta, event);
+            self.recorder
+                .on_callback("on_send_rate_limit_updated", start.elapsed());
+        }
+        #[inline]
+        fn on_congestion_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CongestionLimited,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_congestion_limited(context, meta, event);
+            self.recorder
+                .on_callback("on_congestion_limited", start.elapsed());
+        }
+        #[inline]
+        fn on_congestion_limit_ended(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CongestionLimitEnded,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_congestion_limit_ended(context, meta, event);
+            self.recorder
+                .on_callback("on_congestion_limit_ended", start.elapsed());
+        }
+        #[inline]
+        fn on_crypto_retransmitted(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CryptoRetransmitted,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_crypto_retransmitted(context, meta, event);
+            self.recorder
+                .on_callback("on_crypto_retransmitted", start.elapsed());
+        }
+        #[inline]
+        fn on_version_information(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::VersionInformation,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_version_information(meta, event);
+            self.recorder
+                .on_callback("on_version_information", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_packet_sent(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketSent,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_endpoint_packet_sent(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_packet_sent", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_packet_received(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointPacketReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_endpoint_packet_received(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_packet_received", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_datagram_sent(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointDatagramSent,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_endpoint_datagram_sent(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_datagram_sent", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_datagram_received(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointDatagramReceived,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_endpoint_datagram_received(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_datagram_received", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_datagram_dropped(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointDatagramDropped,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_endpoint_datagram_dropped(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_datagram_dropped", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_connection_attempt_failed(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointConnectionAttemptFailed,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_endpoint_connection_attempt_failed(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_connection_attempt_failed", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointHandshakeRateLimited,
+        ) {
+            let start = Instant::now();
+            self.subscriber
+                .on_endpoint_handshake_rate_limited(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_handshake_rate_limited", start.elapsed());
+        }
+        #[inline]
+        fn on_endpoint_memory_pressure(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointMemoryPressure,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_endpoint_memory_pressure(meta, event);
+            self.recorder
+                .on_callback("on_endpoint_memory_pressure", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
+            let start = Instant::now();
+            self.subscriber.on_platform_tx(meta, event);
+            self.recorder.on_callback("on_platform_tx", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_tx_error(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTxError) {
+            let start = Instant::now();
+            self.subscriber.on_platform_tx_error(meta, event);
+            self.recorder
+                .on_callback("on_platform_tx_error", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_rx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformRx) {
+            let start = Instant::now();
+            self.subscriber.on_platform_rx(meta, event);
+            self.recorder.on_callback("on_platform_rx", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_rx_error(&mut self, meta: &api::EndpointMeta, event: &api::PlatformRxError) {
+            let start = Instant::now();
+            self.subscriber.on_platform_rx_error(meta, event);
+            self.recorder
+                .on_callback("on_platform_rx_error", start.elapsed());
+        }
+        #[inline]
+        fn on_io_recv_overflow(&mut self, meta: &api::EndpointMeta, event: &api::IoRecvOverflow) {
+            let start = Instant::now();
+            self.subscriber.on_io_recv_overflow(meta, event);
+            self.recorder
+                .on_callback("on_io_recv_overflow", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_feature_configured(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformFeatureConfigured,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_platform_feature_configured(meta, event);
+            self.recorder
+                .on_callback("on_platform_feature_configured", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_event_loop_wakeup(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformEventLoopWakeup,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_platform_event_loop_wakeup(meta, event);
+            self.recorder
+                .on_callback("on_platform_event_loop_wakeup", start.elapsed());
+        }
+        #[inline]
+        fn on_platform_event_loop_sleep(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::PlatformEventLoopSleep,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_platform_event_loop_sleep(meta, event);
+            self.recorder
+                .on_callback("on_platform_event_loop_sleep", start.elapsed());
+        }
+        #[inline]
+        fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+            let start = Instant::now();
+            self.subscriber.on_event(meta, event);
+            self.recorder.on_callback("on_event", start.elapsed());
+        }
+        #[inline]
+        fn on_connection_event<E: Event>(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &E,
+        ) {
+            let start = Instant::now();
+            self.subscriber.on_connection_event(context, meta, event);
+            self.recorder
+                .on_callback("on_connection_event", start.elapsed());
+        }
+        #[inline]
+        fn query(
+            context: &Self::ConnectionContext,
+            query: &mut dyn query::Query,
+        ) -> query::ControlFlow {
+            S::query(context, query)
+        }
+        #[inline]
+        fn query_mut(
+            context: &mut Self::ConnectionContext,
+            query: &mut dyn query::QueryMut,
+        ) -> query::ControlFlow {
+            S::query_mut(context, query)
+        }
+    }
+}
 pub mod builder {
     use super::*;
     #[derive(Clone, Debug)]
@@ -2132,6 +3733,8 @@ pub mod builder {
         pub endpoint_type: crate::endpoint::Type,
         pub id: u64,
         pub timestamp: crate::time::Timestamp,
+        #[doc = " The application-defined tags attached to the connection"]
+        pub tags: crate::connection::Tags,
     }
     impl IntoEvent<api::ConnectionMeta> for ConnectionMeta {
         #[inline]
@@ -2140,11 +3743,13 @@ pub mod builder {
                 endpoint_type,
                 id,
                 timestamp,
+                tags,
             } = self;
             api::ConnectionMeta {
                 endpoint_type: endpoint_type.into_event(),
                 id: id.into_event(),
                 timestamp: timestamp.into_event(),
+                tags: tags.into_event(),
             }
         }
     }
@@ -2333,6 +3938,64 @@ pub mod builder {
             }
         }
     }
+    #[derive(Clone, Debug)]
+    #[doc = " A summary of the frame types contained in a packet"]
+    pub struct FrameSummary {
+        #[doc = " Set if the packet contained an ACK frame"]
+        pub ack: bool,
+        #[doc = " Set if the packet contained a PING frame"]
+        pub ping: bool,
+        #[doc = " Set if the packet contained a CRYPTO frame"]
+        pub crypto: bool,
+        #[doc = " Set if the packet contained a STREAM frame"]
+        pub stream: bool,
+        #[doc = " Set if the packet contained a RESET_STREAM or STOP_SENDING frame"]
+        pub stream_control: bool,
+        #[doc = " Set if the packet contained a frame which updates or reports a flow control limit"]
+        #[doc = ""]
+        #[doc = " These are the MAX_DATA, MAX_STREAM_DATA, MAX_STREAMS, DATA_BLOCKED, STREAM_DATA_BLOCKED and"]
+        #[doc = " STREAMS_BLOCKED frames."]
+        pub flow_control: bool,
+        #[doc = " Set if the packet contained a DATAGRAM frame"]
+        pub datagram: bool,
+        #[doc = " Set if the packet contained any other frame, not including PADDING frames"]
+        pub other: bool,
+        #[doc = " The number of frames in the packet, not including PADDING frames"]
+        pub frame_count: usize,
+        #[doc = " The lowest and highest ids of the streams with STREAM frames in the packet"]
+        #[doc = ""]
+        #[doc = " Stream ids above `u32::MAX` are reported as `u32::MAX`."]
+        pub stream_ids: Option<RangeInclusive<u64>>,
+    }
+    impl IntoEvent<api::FrameSummary> for FrameSummary {
+        #[inline]
+        fn into_event(self) -> api::FrameSummary {
+            let FrameSummary {
+                ack,
+                ping,
+                crypto,
+                stream,
+                stream_control,
+                flow_control,
+                datagram,
+                other,
+                frame_count,
+                stream_ids,
+            } = self;
+            api::FrameSummary {
+                ack: ack.into_event(),
+                ping: ping.into_event(),
+                crypto: crypto.into_event(),
+                stream: stream.into_event(),
+                stream_control: stream_control.into_event(),
+                flow_control: flow_control.into_event(),
+                datagram: datagram.into_event(),
+                other: other.into_event(),
+                frame_count: frame_count.into_event(),
+                stream_ids: stream_ids.into_event(),
+            }
+        }
+    }
     #[derive(Copy, Clone, Debug)]
     pub enum SocketAddress<'a> {
         IpV4 { ip: &'a [u8; 4], port: u16 },
@@ -2984,6 +4647,84 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " The reason a local connection ID was retired"]
+    pub enum LocalConnectionIdRetirementCause {
+        #[doc = " The peer retired the connection ID with a RETIRE_CONNECTION_ID frame"]
+        Peer,
+        #[doc = " The connection ID reached the end of its lifetime"]
+        Expired,
+        #[doc = " The connection ID used during the handshake was retired once the handshake was confirmed"]
+        HandshakeConfirmed,
+    }
+    impl IntoEvent<api::LocalConnectionIdRetirementCause> for LocalConnectionIdRetirementCause {
+        #[inline]
+        fn into_event(self) -> api::LocalConnectionIdRetirementCause {
+            use api::LocalConnectionIdRetirementCause::*;
+            match self {
+                Self::Peer => Peer {},
+                Self::Expired => Expired {},
+                Self::HandshakeConfirmed => HandshakeConfirmed {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The reason an ACK frame was sent"]
+    pub enum AckSentReason {
+        #[doc = " The number of packets received since the last ACK frame reached the packet tolerance"]
+        PacketTolerance,
+        #[doc = " The `max_ack_delay` timer expired"]
+        Timer,
+        #[doc = " A packet was received out of order or after a gap in packet numbers"]
+        Reordering,
+        #[doc = " A packet was received with an ECN Congestion Experienced marking"]
+        CongestionExperienced,
+        #[doc = " A PATH_CHALLENGE frame was received on the active path"]
+        PathChallenge,
+        #[doc = " A packet which contained a previous ACK frame was declared lost"]
+        Loss,
+        #[doc = " The ACK frame was bundled with other frames that were being transmitted"]
+        Piggyback,
+    }
+    impl IntoEvent<api::AckSentReason> for AckSentReason {
+        #[inline]
+        fn into_event(self) -> api::AckSentReason {
+            use api::AckSentReason::*;
+            match self {
+                Self::PacketTolerance => PacketTolerance {},
+                Self::Timer => Timer {},
+                Self::Reordering => Reordering {},
+                Self::CongestionExperienced => CongestionExperienced {},
+                Self::PathChallenge => PathChallenge {},
+                Self::Loss => Loss {},
+                Self::Piggyback => Piggyback {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The reason a packet was sent"]
+    pub enum PacketSentReason {
+        #[doc = " The packet was sent as part of normal transmission"]
+        Normal,
+        #[doc = " The packet was sent as a probe after the probe timeout (PTO) expired"]
+        PtoProbe,
+        #[doc = " The packet only carried data which was already sent in a previous packet"]
+        Retransmit,
+        #[doc = " The packet was not ack-eliciting, for example because it only contained an ACK frame"]
+        AckOnly,
+    }
+    impl IntoEvent<api::PacketSentReason> for PacketSentReason {
+        #[inline]
+        fn into_event(self) -> api::PacketSentReason {
+            use api::PacketSentReason::*;
+            match self {
+                Self::Normal => Normal {},
+                Self::PtoProbe => PtoProbe {},
+                Self::Retransmit => Retransmit {},
+                Self::AckOnly => AckOnly {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " The reason the slow start congestion controller state has been exited"]
     pub enum SlowStartExitCause {
         #[doc = " A packet was determined lost"]
@@ -3033,6 +4774,78 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " The reason PADDING frames were added to a datagram"]
+    pub enum PaddingReason {
+        #[doc = " An ack-eliciting Initial packet was padded so the datagram reaches the minimum size of"]
+        #[doc = " 1200 bytes"]
+        InitialMinimumSize,
+        #[doc = " An ack-eliciting Initial packet was coalesced with later packets and the last of those was"]
+        #[doc = " padded so the datagram reaches the minimum size of 1200 bytes"]
+        CoalescedMinimumSize,
+        #[doc = " The datagram carried a PATH_CHALLENGE or PATH_RESPONSE frame and was padded to validate"]
+        #[doc = " the path MTU"]
+        PathValidation,
+        #[doc = " A packet was padded so it is indistinguishable from a stateless reset, or to fill space at"]
+        #[doc = " the end of the datagram which was too small for another packet"]
+        MinimumPacketSize,
+    }
+    impl IntoEvent<api::PaddingReason> for PaddingReason {
+        #[inline]
+        fn into_event(self) -> api::PaddingReason {
+            use api::PaddingReason::*;
+            match self {
+                Self::InitialMinimumSize => InitialMinimumSize {},
+                Self::CoalescedMinimumSize => CoalescedMinimumSize {},
+                Self::PathValidation => PathValidation {},
+                Self::MinimumPacketSize => MinimumPacketSize {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The scope of a flow control window"]
+    pub enum FlowControlScope {
+        #[doc = " The window applies to the data sent on all streams of the connection"]
+        Connection,
+        #[doc = " The window applies to the data sent on a single stream"]
+        Stream { id: u64 },
+    }
+    impl IntoEvent<api::FlowControlScope> for FlowControlScope {
+        #[inline]
+        fn into_event(self) -> api::FlowControlScope {
+            use api::FlowControlScope::*;
+            match self {
+                Self::Connection => Connection {},
+                Self::Stream { id } => Stream {
+                    id: id.into_event(),
+                },
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The direction of data which is limited by a flow control window"]
+    pub enum FlowControlDirection {
+        #[doc = " The window limits the data which the peer is allowed to send"]
+        #[doc = ""]
+        #[doc = " The window is updated once the local endpoint transmits a MAX_DATA or MAX_STREAM_DATA frame"]
+        #[doc = " with a higher limit."]
+        Receive,
+        #[doc = " The window limits the data which the local endpoint is allowed to send"]
+        #[doc = ""]
+        #[doc = " The window is updated once a MAX_DATA or MAX_STREAM_DATA frame with a higher limit is"]
+        #[doc = " received from the peer."]
+        Send,
+    }
+    impl IntoEvent<api::FlowControlDirection> for FlowControlDirection {
+        #[inline]
+        fn into_event(self) -> api::FlowControlDirection {
+            use api::FlowControlDirection::*;
+            match self {
+                Self::Receive => Receive {},
+                Self::Send => Send {},
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Application level protocol"]
     pub struct ApplicationProtocolInformation<'a> {
         pub chosen_application_protocol: &'a [u8],
@@ -3066,13 +4879,18 @@ pub mod builder {
     #[doc = " Packet was sent by a connection"]
     pub struct PacketSent {
         pub packet_header: PacketHeader,
+        pub sent_reason: PacketSentReason,
     }
     impl IntoEvent<api::PacketSent> for PacketSent {
         #[inline]
         fn into_event(self) -> api::PacketSent {
-            let PacketSent { packet_header } = self;
+            let PacketSent {
+                packet_header,
+                sent_reason,
+            } = self;
             api::PacketSent {
                 packet_header: packet_header.into_event(),
+                sent_reason: sent_reason.into_event(),
             }
         }
     }
@@ -3173,6 +4991,8 @@ pub mod builder {
         pub path: Path<'a>,
         pub bytes_lost: u16,
         pub is_mtu_probe: bool,
+        #[doc = " The frames which were contained in the lost packet"]
+        pub frames: FrameSummary,
     }
     impl<'a> IntoEvent<api::PacketLost<'a>> for PacketLost<'a> {
         #[inline]
@@ -3182,12 +5002,14 @@ pub mod builder {
                 path,
                 bytes_lost,
                 is_mtu_probe,
+                frames,
             } = self;
             api::PacketLost {
                 packet_header: packet_header.into_event(),
                 path: path.into_event(),
                 bytes_lost: bytes_lost.into_event(),
                 is_mtu_probe: is_mtu_probe.into_event(),
+                frames: frames.into_event(),
             }
         }
     }
@@ -3200,6 +5022,8 @@ pub mod builder {
         pub latest_rtt: Duration,
         pub rtt_variance: Duration,
         pub max_ack_delay: Duration,
+        #[doc = " The local timer granularity used as the lower bound of the PTO period"]
+        pub timer_granularity: Duration,
         pub pto_count: u32,
         pub congestion_window: u32,
         pub bytes_in_flight: u32,
@@ -3215,6 +5039,7 @@ pub mod builder {
                 latest_rtt,
                 rtt_variance,
                 max_ack_delay,
+                timer_granularity,
                 pto_count,
                 congestion_window,
                 bytes_in_flight,
@@ -3227,6 +5052,7 @@ pub mod builder {
                 latest_rtt: latest_rtt.into_event(),
                 rtt_variance: rtt_variance.into_event(),
                 max_ack_delay: max_ack_delay.into_event(),
+                timer_granularity: timer_granularity.into_event(),
                 pto_count: pto_count.into_event(),
                 congestion_window: congestion_window.into_event(),
                 bytes_in_flight: bytes_in_flight.into_event(),
@@ -3235,6 +5061,34 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " A new RTT sample was taken for the path"]
+    pub struct RttSample<'a> {
+        pub path: Path<'a>,
+        pub latest_rtt: Duration,
+        pub min_rtt: Duration,
+        pub smoothed_rtt: Duration,
+        pub rtt_variance: Duration,
+    }
+    impl<'a> IntoEvent<api::RttSample<'a>> for RttSample<'a> {
+        #[inline]
+        fn into_event(self) -> api::RttSample<'a> {
+            let RttSample {
+                path,
+                latest_rtt,
+                min_rtt,
+                smoothed_rtt,
+                rtt_variance,
+            } = self;
+            api::RttSample {
+                path: path.into_event(),
+                latest_rtt: latest_rtt.into_event(),
+                min_rtt: min_rtt.into_event(),
+                smoothed_rtt: smoothed_rtt.into_event(),
+                rtt_variance: rtt_variance.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Congestion (ECN or packet loss) has occurred"]
     pub struct Congestion<'a> {
         pub path: Path<'a>,
@@ -3308,6 +5162,10 @@ pub mod builder {
         pub packet_header: PacketHeader,
         pub path: Path<'a>,
         pub ack_range: RangeInclusive<u64>,
+        #[doc = " The largest packet number acknowledged by the ACK frame containing the range"]
+        pub largest_acknowledged: u64,
+        #[doc = " The ACK delay reported by the peer in the ACK frame containing the range"]
+        pub ack_delay: Duration,
     }
     impl<'a> IntoEvent<api::AckRangeReceived<'a>> for AckRangeReceived<'a> {
         #[inline]
@@ -3316,11 +5174,89 @@ pub mod builder {
                 packet_header,
                 path,
                 ack_range,
+                largest_acknowledged,
+                ack_delay,
             } = self;
             api::AckRangeReceived {
                 packet_header: packet_header.into_event(),
                 path: path.into_event(),
                 ack_range: ack_range.into_event(),
+                largest_acknowledged: largest_acknowledged.into_event(),
+                ack_delay: ack_delay.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " ACK frame was received which did not acknowledge any new packets"]
+    #[doc = ""]
+    #[doc = " All of the packets in the frame were either already acknowledged, declared lost, or never"]
+    #[doc = " sent. This is expected occasionally due to reordering and retransmitted ACK frames, but a"]
+    #[doc = " high rate may indicate a misbehaving peer or replayed ACK frames."]
+    pub struct DuplicateAckReceived<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        #[doc = " The largest packet number acknowledged by the ACK frame"]
+        pub largest_acknowledged: u64,
+    }
+    impl<'a> IntoEvent<api::DuplicateAckReceived<'a>> for DuplicateAckReceived<'a> {
+        #[inline]
+        fn into_event(self) -> api::DuplicateAckReceived<'a> {
+            let DuplicateAckReceived {
+                packet_header,
+                path,
+                largest_acknowledged,
+            } = self;
+            api::DuplicateAckReceived {
+                packet_header: packet_header.into_event(),
+                path: path.into_event(),
+                largest_acknowledged: largest_acknowledged.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " ACK frame was sent"]
+    pub struct AckSent {
+        pub packet_header: PacketHeader,
+        pub path_id: u64,
+        pub reason: AckSentReason,
+    }
+    impl IntoEvent<api::AckSent> for AckSent {
+        #[inline]
+        fn into_event(self) -> api::AckSent {
+            let AckSent {
+                packet_header,
+                path_id,
+                reason,
+            } = self;
+            api::AckSent {
+                packet_header: packet_header.into_event(),
+                path_id: path_id.into_event(),
+                reason: reason.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " Ack-eliciting packet was received and processed by the ACK manager"]
+    pub struct AckElicitingPacketReceived<'a> {
+        pub packet_header: PacketHeader,
+        pub path: Path<'a>,
+        #[doc = " The reason the packet caused an ACK frame to be sent immediately"]
+        #[doc = ""]
+        #[doc = " `None` indicates the ACK frame was delayed by up to `max_ack_delay`."]
+        pub immediate_ack_reason: Option<AckSentReason>,
+    }
+    impl<'a> IntoEvent<api::AckElicitingPacketReceived<'a>> for AckElicitingPacketReceived<'a> {
+        #[inline]
+        fn into_event(self) -> api::AckElicitingPacketReceived<'a> {
+            let AckElicitingPacketReceived {
+                packet_header,
+                path,
+                immediate_ack_reason,
+            } = self;
+            api::AckElicitingPacketReceived {
+                packet_header: packet_header.into_event(),
+                path: path.into_event(),
+                immediate_ack_reason: immediate_ack_reason.into_event(),
             }
         }
     }
@@ -3447,14 +5383,36 @@ pub mod builder {
         #[doc = ""]
         #[doc = " See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details."]
         pub gso_offset: usize,
+        #[doc = " The number of bytes of PADDING frames in the datagram"]
+        pub padding_len: u16,
+        #[doc = " The reason the datagram was padded, if it contains any PADDING frames"]
+        pub padding_reason: Option<PaddingReason>,
+        #[doc = " The number of QUIC packets coalesced into the datagram"]
+        pub packet_count: u8,
+        #[doc = " The length of the Initial packet in the datagram, if it contains one"]
+        #[doc = ""]
+        #[doc = " Datagrams carrying Initial packets are expanded to at least 1200 bytes, either by padding"]
+        #[doc = " the Initial packet itself or by padding the last packet coalesced after it."]
+        pub initial_packet_len: Option<u16>,
     }
     impl IntoEvent<api::DatagramSent> for DatagramSent {
         #[inline]
         fn into_event(self) -> api::DatagramSent {
-            let DatagramSent { len, gso_offset } = self;
+            let DatagramSent {
+                len,
+                gso_offset,
+                padding_len,
+                padding_reason,
+                packet_count,
+                initial_packet_len,
+            } = self;
             api::DatagramSent {
                 len: len.into_event(),
                 gso_offset: gso_offset.into_event(),
+                padding_len: padding_len.into_event(),
+                padding_reason: padding_reason.into_event(),
+                packet_count: packet_count.into_event(),
+                initial_packet_len: initial_packet_len.into_event(),
             }
         }
     }
@@ -3515,6 +5473,54 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " A local connection ID was issued to the peer in a NEW_CONNECTION_ID frame"]
+    #[doc = ""]
+    #[doc = " This is only published the first time the connection ID is transmitted, not when a lost"]
+    #[doc = " NEW_CONNECTION_ID frame is retransmitted."]
+    pub struct LocalConnectionIdIssued<'a> {
+        pub connection_id: ConnectionId<'a>,
+        pub sequence_number: u64,
+        #[doc = " The sequence number below which the peer was requested to retire connection IDs"]
+        pub retire_prior_to: u64,
+    }
+    impl<'a> IntoEvent<api::LocalConnectionIdIssued<'a>> for LocalConnectionIdIssued<'a> {
+        #[inline]
+        fn into_event(self) -> api::LocalConnectionIdIssued<'a> {
+            let LocalConnectionIdIssued {
+                connection_id,
+                sequence_number,
+                retire_prior_to,
+            } = self;
+            api::LocalConnectionIdIssued {
+                connection_id: connection_id.into_event(),
+                sequence_number: sequence_number.into_event(),
+                retire_prior_to: retire_prior_to.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A local connection ID was retired"]
+    pub struct LocalConnectionIdRetired<'a> {
+        pub connection_id: ConnectionId<'a>,
+        pub sequence_number: u64,
+        pub cause: LocalConnectionIdRetirementCause,
+    }
+    impl<'a> IntoEvent<api::LocalConnectionIdRetired<'a>> for LocalConnectionIdRetired<'a> {
+        #[inline]
+        fn into_event(self) -> api::LocalConnectionIdRetired<'a> {
+            let LocalConnectionIdRetired {
+                connection_id,
+                sequence_number,
+                cause,
+            } = self;
+            api::LocalConnectionIdRetired {
+                connection_id: connection_id.into_event(),
+                sequence_number: sequence_number.into_event(),
+                cause: cause.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     pub struct EcnStateChanged<'a> {
         pub path: Path<'a>,
         pub state: EcnState,
@@ -3617,76 +5623,274 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
-    pub struct TxStreamProgress {
-        pub bytes: usize,
-    }
-    impl IntoEvent<api::TxStreamProgress> for TxStreamProgress {
+    pub struct TxStreamProgress {
+        pub bytes: usize,
+    }
+    impl IntoEvent<api::TxStreamProgress> for TxStreamProgress {
+        #[inline]
+        fn into_event(self) -> api::TxStreamProgress {
+            let TxStreamProgress { bytes } = self;
+            api::TxStreamProgress {
+                bytes: bytes.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    pub struct KeepAliveTimerExpired {
+        pub timeout: Duration,
+    }
+    impl IntoEvent<api::KeepAliveTimerExpired> for KeepAliveTimerExpired {
+        #[inline]
+        fn into_event(self) -> api::KeepAliveTimerExpired {
+            let KeepAliveTimerExpired { timeout } = self;
+            api::KeepAliveTimerExpired {
+                timeout: timeout.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The maximum transmission unit (MTU) for the path has changed"]
+    pub struct MtuUpdated {
+        pub path_id: u64,
+        pub mtu: u16,
+        pub cause: MtuUpdatedCause,
+    }
+    impl IntoEvent<api::MtuUpdated> for MtuUpdated {
+        #[inline]
+        fn into_event(self) -> api::MtuUpdated {
+            let MtuUpdated {
+                path_id,
+                mtu,
+                cause,
+            } = self;
+            api::MtuUpdated {
+                path_id: path_id.into_event(),
+                mtu: mtu.into_event(),
+                cause: cause.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The slow start congestion controller state has been exited"]
+    pub struct SlowStartExited<'a> {
+        pub path: Path<'a>,
+        pub cause: SlowStartExitCause,
+        pub congestion_window: u32,
+    }
+    impl<'a> IntoEvent<api::SlowStartExited<'a>> for SlowStartExited<'a> {
+        #[inline]
+        fn into_event(self) -> api::SlowStartExited<'a> {
+            let SlowStartExited {
+                path,
+                cause,
+                congestion_window,
+            } = self;
+            api::SlowStartExited {
+                path: path.into_event(),
+                cause: cause.into_event(),
+                congestion_window: congestion_window.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The peer opened streams faster than the configured rate and MAX_STREAMS credit was delayed"]
+    pub struct StreamOpenRateLimited {
+        pub stream_type: StreamType,
+        #[doc = " The number of streams that were withheld from the peer during the rate window"]
+        pub deferred_streams: u64,
+    }
+    impl IntoEvent<api::StreamOpenRateLimited> for StreamOpenRateLimited {
+        #[inline]
+        fn into_event(self) -> api::StreamOpenRateLimited {
+            let StreamOpenRateLimited {
+                stream_type,
+                deferred_streams,
+            } = self;
+            api::StreamOpenRateLimited {
+                stream_type: stream_type.into_event(),
+                deferred_streams: deferred_streams.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A stream has waited at least the configured starvation threshold to transmit its data"]
+    #[doc = ""]
+    #[doc = " The event is published once per wait, either when the threshold is reached or when the stream"]
+    #[doc = " transmits, whichever happens first."]
+    pub struct StreamStarved {
+        pub id: u64,
+        #[doc = " The amount of time the stream waited with data to send"]
+        pub duration: Duration,
+    }
+    impl IntoEvent<api::StreamStarved> for StreamStarved {
+        #[inline]
+        fn into_event(self) -> api::StreamStarved {
+            let StreamStarved { id, duration } = self;
+            api::StreamStarved {
+                id: id.into_event(),
+                duration: duration.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A stream was closed and its state was released"]
+    pub struct StreamClosed {
+        pub id: u64,
+        #[doc = " The number of bytes of stream data which were retransmitted"]
+        pub retransmitted_bytes: u64,
+    }
+    impl IntoEvent<api::StreamClosed> for StreamClosed {
+        #[inline]
+        fn into_event(self) -> api::StreamClosed {
+            let StreamClosed {
+                id,
+                retransmitted_bytes,
+            } = self;
+            api::StreamClosed {
+                id: id.into_event(),
+                retransmitted_bytes: retransmitted_bytes.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A connection or stream flow control window was increased"]
+    pub struct FlowControlWindowChanged {
+        pub scope: FlowControlScope,
+        pub direction: FlowControlDirection,
+        #[doc = " The previous maximum offset of data allowed by the window"]
+        pub old_window: u64,
+        #[doc = " The new maximum offset of data allowed by the window"]
+        pub new_window: u64,
+    }
+    impl IntoEvent<api::FlowControlWindowChanged> for FlowControlWindowChanged {
+        #[inline]
+        fn into_event(self) -> api::FlowControlWindowChanged {
+            let FlowControlWindowChanged {
+                scope,
+                direction,
+                old_window,
+                new_window,
+            } = self;
+            api::FlowControlWindowChanged {
+                scope: scope.into_event(),
+                direction: direction.into_event(),
+                old_window: old_window.into_event(),
+                new_window: new_window.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The connection has no open streams and no data in flight"]
+    #[doc = ""]
+    #[doc = " This is only emitted once the handshake has completed."]
+    pub struct ConnectionIdle {}
+    impl IntoEvent<api::ConnectionIdle> for ConnectionIdle {
+        #[inline]
+        fn into_event(self) -> api::ConnectionIdle {
+            let ConnectionIdle {} = self;
+            api::ConnectionIdle {}
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " The connection has resumed activity after previously becoming idle"]
+    pub struct ConnectionActive {}
+    impl IntoEvent<api::ConnectionActive> for ConnectionActive {
         #[inline]
-        fn into_event(self) -> api::TxStreamProgress {
-            let TxStreamProgress { bytes } = self;
-            api::TxStreamProgress {
-                bytes: bytes.into_event(),
-            }
+        fn into_event(self) -> api::ConnectionActive {
+            let ConnectionActive {} = self;
+            api::ConnectionActive {}
         }
     }
     #[derive(Clone, Debug)]
-    pub struct KeepAliveTimerExpired {
-        pub timeout: Duration,
+    #[doc = " The application updated the send rate limit of the connection"]
+    pub struct SendRateLimitUpdated {
+        #[doc = " The maximum number of bytes sent per second, or `None` if the limit was removed"]
+        pub bytes_per_sec: Option<u64>,
     }
-    impl IntoEvent<api::KeepAliveTimerExpired> for KeepAliveTimerExpired {
+    impl IntoEvent<api::SendRateLimitUpdated> for SendRateLimitUpdated {
         #[inline]
-        fn into_event(self) -> api::KeepAliveTimerExpired {
-            let KeepAliveTimerExpired { timeout } = self;
-            api::KeepAliveTimerExpired {
-                timeout: timeout.into_event(),
+        fn into_event(self) -> api::SendRateLimitUpdated {
+            let SendRateLimitUpdated { bytes_per_sec } = self;
+            api::SendRateLimitUpdated {
+                bytes_per_sec: bytes_per_sec.into_event(),
             }
         }
     }
     #[derive(Clone, Debug)]
-    #[doc = " The maximum transmission unit (MTU) for the path has changed"]
-    pub struct MtuUpdated {
+    #[doc = " The connection has become limited by the congestion window"]
+    #[doc = ""]
+    #[doc = " A connection is congestion limited while it has data to send on the active path, but the"]
+    #[doc = " congestion window is full. `CongestionLimitEnded` is published once the connection is able"]
+    #[doc = " to send again, or no longer has data to send."]
+    pub struct CongestionLimited {
         pub path_id: u64,
-        pub mtu: u16,
-        pub cause: MtuUpdatedCause,
+        #[doc = " The congestion window when the connection became congestion limited"]
+        pub congestion_window: u32,
     }
-    impl IntoEvent<api::MtuUpdated> for MtuUpdated {
+    impl IntoEvent<api::CongestionLimited> for CongestionLimited {
         #[inline]
-        fn into_event(self) -> api::MtuUpdated {
-            let MtuUpdated {
+        fn into_event(self) -> api::CongestionLimited {
+            let CongestionLimited {
                 path_id,
-                mtu,
-                cause,
+                congestion_window,
             } = self;
-            api::MtuUpdated {
+            api::CongestionLimited {
                 path_id: path_id.into_event(),
-                mtu: mtu.into_event(),
-                cause: cause.into_event(),
+                congestion_window: congestion_window.into_event(),
             }
         }
     }
     #[derive(Clone, Debug)]
-    #[doc = " The slow start congestion controller state has been exited"]
-    pub struct SlowStartExited<'a> {
-        pub path: Path<'a>,
-        pub cause: SlowStartExitCause,
+    #[doc = " The connection is no longer limited by the congestion window"]
+    pub struct CongestionLimitEnded {
+        pub path_id: u64,
+        #[doc = " The amount of time the connection was congestion limited"]
+        pub duration: Duration,
+        #[doc = " The congestion window when the connection became congestion limited"]
         pub congestion_window: u32,
     }
-    impl<'a> IntoEvent<api::SlowStartExited<'a>> for SlowStartExited<'a> {
+    impl IntoEvent<api::CongestionLimitEnded> for CongestionLimitEnded {
         #[inline]
-        fn into_event(self) -> api::SlowStartExited<'a> {
-            let SlowStartExited {
-                path,
-                cause,
+        fn into_event(self) -> api::CongestionLimitEnded {
+            let CongestionLimitEnded {
+                path_id,
+                duration,
                 congestion_window,
             } = self;
-            api::SlowStartExited {
-                path: path.into_event(),
-                cause: cause.into_event(),
+            api::CongestionLimitEnded {
+                path_id: path_id.into_event(),
+                duration: duration.into_event(),
                 congestion_window: congestion_window.into_event(),
             }
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " CRYPTO data was retransmitted in an Initial or Handshake packet"]
+    #[doc = ""]
+    #[doc = " This is emitted separately from the retransmission of stream data so delays in connection"]
+    #[doc = " establishment caused by loss of handshake packets can be identified."]
+    pub struct CryptoRetransmitted {
+        pub packet_header: PacketHeader,
+        pub path_id: u64,
+        #[doc = " The number of CRYPTO bytes which were retransmitted in the packet"]
+        pub bytes: u64,
+    }
+    impl IntoEvent<api::CryptoRetransmitted> for CryptoRetransmitted {
+        #[inline]
+        fn into_event(self) -> api::CryptoRetransmitted {
+            let CryptoRetransmitted {
+                packet_header,
+                path_id,
+                bytes,
+            } = self;
+            api::CryptoRetransmitted {
+                packet_header: packet_header.into_event(),
+                path_id: path_id.into_event(),
+                bytes: bytes.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " QUIC version"]
     pub struct VersionInformation<'a> {
         pub server_versions: &'a [u32],
@@ -3802,6 +6006,55 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " The endpoint handshake rate limit was reached"]
+    #[doc = ""]
+    #[doc = " This is emitted once for each one-second window in which the limit is reached. All further"]
+    #[doc = " connection attempts in the window are deferred with a Retry packet or refused."]
+    pub struct EndpointHandshakeRateLimited {
+        #[doc = " The configured maximum number of handshakes per second"]
+        pub handshakes_per_sec: usize,
+        #[doc = " Whether the attempts are deferred with a Retry packet rather than refused"]
+        pub retry: bool,
+    }
+    impl IntoEvent<api::EndpointHandshakeRateLimited> for EndpointHandshakeRateLimited {
+        #[inline]
+        fn into_event(self) -> api::EndpointHandshakeRateLimited {
+            let EndpointHandshakeRateLimited {
+                handshakes_per_sec,
+                retry,
+            } = self;
+            api::EndpointHandshakeRateLimited {
+                handshakes_per_sec: handshakes_per_sec.into_event(),
+                retry: retry.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
+    #[doc = " A connection attempt was refused because the endpoint is under memory pressure"]
+    pub struct EndpointMemoryPressure {
+        #[doc = " The number of bytes buffered by open connections"]
+        pub memory_usage: u64,
+        #[doc = " The configured endpoint memory limit"]
+        pub memory_limit: u64,
+        #[doc = " The number of open connections"]
+        pub connection_count: usize,
+    }
+    impl IntoEvent<api::EndpointMemoryPressure> for EndpointMemoryPressure {
+        #[inline]
+        fn into_event(self) -> api::EndpointMemoryPressure {
+            let EndpointMemoryPressure {
+                memory_usage,
+                memory_limit,
+                connection_count,
+            } = self;
+            api::EndpointMemoryPressure {
+                memory_usage: memory_usage.into_event(),
+                memory_limit: memory_limit.into_event(),
+                connection_count: connection_count.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Emitted when the platform sends at least one packet"]
     pub struct PlatformTx {
         #[doc = " The number of packets sent"]
@@ -3862,6 +6115,29 @@ pub mod builder {
         }
     }
     #[derive(Clone, Debug)]
+    #[doc = " Emitted when the operating system reports datagrams which were dropped because the socket"]
+    #[doc = " receive buffer was full"]
+    #[doc = ""]
+    #[doc = " These drops occur before the datagrams reach the endpoint and are distinct from loss on the"]
+    #[doc = " network. Frequent overflows indicate the receive buffer size should be increased or more"]
+    #[doc = " sockets should be used to receive."]
+    pub struct IoRecvOverflow {
+        #[doc = " The number of datagrams dropped since the previous event"]
+        pub count: u64,
+        #[doc = " The total number of datagrams dropped since the socket was opened"]
+        pub total_count: u64,
+    }
+    impl IntoEvent<api::IoRecvOverflow> for IoRecvOverflow {
+        #[inline]
+        fn into_event(self) -> api::IoRecvOverflow {
+            let IoRecvOverflow { count, total_count } = self;
+            api::IoRecvOverflow {
+                count: count.into_event(),
+                total_count: total_count.into_event(),
+            }
+        }
+    }
+    #[derive(Clone, Debug)]
     #[doc = " Emitted when a platform feature is configured"]
     pub struct PlatformFeatureConfigured {
         pub configuration: PlatformFeatureConfiguration,
@@ -4246,6 +6522,18 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `RttSample` event is triggered"]
+        #[inline]
+        fn on_rtt_sample(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RttSample,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `Congestion` event is triggered"]
         #[inline]
         fn on_congestion(
@@ -4296,6 +6584,42 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `DuplicateAckReceived` event is triggered"]
+        #[inline]
+        fn on_duplicate_ack_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicateAckReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `AckSent` event is triggered"]
+        #[inline]
+        fn on_ack_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckSent,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `AckElicitingPacketReceived` event is triggered"]
+        #[inline]
+        fn on_ack_eliciting_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckElicitingPacketReceived,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `PacketDropped` event is triggered"]
         #[inline]
         fn on_packet_dropped(
@@ -4428,6 +6752,30 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `LocalConnectionIdIssued` event is triggered"]
+        #[inline]
+        fn on_local_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &LocalConnectionIdIssued,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `LocalConnectionIdRetired` event is triggered"]
+        #[inline]
+        fn on_local_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &LocalConnectionIdRetired,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `EcnStateChanged` event is triggered"]
         #[inline]
         fn on_ecn_state_changed(
@@ -4560,6 +6908,126 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `StreamOpenRateLimited` event is triggered"]
+        #[inline]
+        fn on_stream_open_rate_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &StreamOpenRateLimited,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `StreamStarved` event is triggered"]
+        #[inline]
+        fn on_stream_starved(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &StreamStarved,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `StreamClosed` event is triggered"]
+        #[inline]
+        fn on_stream_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &StreamClosed,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `FlowControlWindowChanged` event is triggered"]
+        #[inline]
+        fn on_flow_control_window_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlWindowChanged,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionIdle` event is triggered"]
+        #[inline]
+        fn on_connection_idle(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdle,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `ConnectionActive` event is triggered"]
+        #[inline]
+        fn on_connection_active(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionActive,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `SendRateLimitUpdated` event is triggered"]
+        #[inline]
+        fn on_send_rate_limit_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SendRateLimitUpdated,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `CongestionLimited` event is triggered"]
+        #[inline]
+        fn on_congestion_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionLimited,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `CongestionLimitEnded` event is triggered"]
+        #[inline]
+        fn on_congestion_limit_ended(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionLimitEnded,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `CryptoRetransmitted` event is triggered"]
+        #[inline]
+        fn on_crypto_retransmitted(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CryptoRetransmitted,
+        ) {
+            let _ = context;
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `VersionInformation` event is triggered"]
         #[inline]
         fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
@@ -4618,6 +7086,26 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `EndpointHandshakeRateLimited` event is triggered"]
+        #[inline]
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointHandshakeRateLimited,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
+        #[doc = "Called when the `EndpointMemoryPressure` event is triggered"]
+        #[inline]
+        fn on_endpoint_memory_pressure(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointMemoryPressure,
+        ) {
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `PlatformTx` event is triggered"]
         #[inline]
         fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
@@ -4642,6 +7130,12 @@ mod traits {
             let _ = meta;
             let _ = event;
         }
+        #[doc = "Called when the `IoRecvOverflow` event is triggered"]
+        #[inline]
+        fn on_io_recv_overflow(&mut self, meta: &EndpointMeta, event: &IoRecvOverflow) {
+            let _ = meta;
+            let _ = event;
+        }
         #[doc = "Called when the `PlatformFeatureConfigured` event is triggered"]
         #[inline]
         fn on_platform_feature_configured(
@@ -4861,14 +7355,24 @@ mod traits {
             (self.1).on_packet_lost(&mut context.1, meta, event);
         }
         #[inline]
-        fn on_recovery_metrics(
+        fn on_recovery_metrics(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &RecoveryMetrics,
+        ) {
+            (self.0).on_recovery_metrics(&mut context.0, meta, event);
+            (self.1).on_recovery_metrics(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_rtt_sample(
             &mut self,
             context: &mut Self::ConnectionContext,
             meta: &ConnectionMeta,
-            event: &RecoveryMetrics,
+            event: &RttSample,
         ) {
-            (self.0).on_recovery_metrics(&mut context.0, meta, event);
-            (self.1).on_recovery_metrics(&mut context.1, meta, event);
+            (self.0).on_rtt_sample(&mut context.0, meta, event);
+            (self.1).on_rtt_sample(&mut context.1, meta, event);
         }
         #[inline]
         fn on_congestion(
@@ -4912,6 +7416,36 @@ mod traits {
             (self.1).on_ack_range_received(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_duplicate_ack_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &DuplicateAckReceived,
+        ) {
+            (self.0).on_duplicate_ack_received(&mut context.0, meta, event);
+            (self.1).on_duplicate_ack_received(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_ack_sent(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckSent,
+        ) {
+            (self.0).on_ack_sent(&mut context.0, meta, event);
+            (self.1).on_ack_sent(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_ack_eliciting_packet_received(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &AckElicitingPacketReceived,
+        ) {
+            (self.0).on_ack_eliciting_packet_received(&mut context.0, meta, event);
+            (self.1).on_ack_eliciting_packet_received(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_packet_dropped(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -5022,6 +7556,26 @@ mod traits {
             (self.1).on_connection_id_updated(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_local_connection_id_issued(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &LocalConnectionIdIssued,
+        ) {
+            (self.0).on_local_connection_id_issued(&mut context.0, meta, event);
+            (self.1).on_local_connection_id_issued(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_local_connection_id_retired(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &LocalConnectionIdRetired,
+        ) {
+            (self.0).on_local_connection_id_retired(&mut context.0, meta, event);
+            (self.1).on_local_connection_id_retired(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_ecn_state_changed(
             &mut self,
             context: &mut Self::ConnectionContext,
@@ -5132,6 +7686,106 @@ mod traits {
             (self.1).on_slow_start_exited(&mut context.1, meta, event);
         }
         #[inline]
+        fn on_stream_open_rate_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &StreamOpenRateLimited,
+        ) {
+            (self.0).on_stream_open_rate_limited(&mut context.0, meta, event);
+            (self.1).on_stream_open_rate_limited(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_stream_starved(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &StreamStarved,
+        ) {
+            (self.0).on_stream_starved(&mut context.0, meta, event);
+            (self.1).on_stream_starved(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_stream_closed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &StreamClosed,
+        ) {
+            (self.0).on_stream_closed(&mut context.0, meta, event);
+            (self.1).on_stream_closed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_flow_control_window_changed(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &FlowControlWindowChanged,
+        ) {
+            (self.0).on_flow_control_window_changed(&mut context.0, meta, event);
+            (self.1).on_flow_control_window_changed(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_idle(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionIdle,
+        ) {
+            (self.0).on_connection_idle(&mut context.0, meta, event);
+            (self.1).on_connection_idle(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_connection_active(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &ConnectionActive,
+        ) {
+            (self.0).on_connection_active(&mut context.0, meta, event);
+            (self.1).on_connection_active(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_send_rate_limit_updated(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &SendRateLimitUpdated,
+        ) {
+            (self.0).on_send_rate_limit_updated(&mut context.0, meta, event);
+            (self.1).on_send_rate_limit_updated(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_congestion_limited(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionLimited,
+        ) {
+            (self.0).on_congestion_limited(&mut context.0, meta, event);
+            (self.1).on_congestion_limited(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_congestion_limit_ended(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CongestionLimitEnded,
+        ) {
+            (self.0).on_congestion_limit_ended(&mut context.0, meta, event);
+            (self.1).on_congestion_limit_ended(&mut context.1, meta, event);
+        }
+        #[inline]
+        fn on_crypto_retransmitted(
+            &mut self,
+            context: &mut Self::ConnectionContext,
+            meta: &ConnectionMeta,
+            event: &CryptoRetransmitted,
+        ) {
+            (self.0).on_crypto_retransmitted(&mut context.0, meta, event);
+            (self.1).on_crypto_retransmitted(&mut context.1, meta, event);
+        }
+        #[inline]
         fn on_version_information(&mut self, meta: &EndpointMeta, event: &VersionInformation) {
             (self.0).on_version_information(meta, event);
             (self.1).on_version_information(meta, event);
@@ -5183,6 +7837,24 @@ mod traits {
             (self.1).on_endpoint_connection_attempt_failed(meta, event);
         }
         #[inline]
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointHandshakeRateLimited,
+        ) {
+            (self.0).on_endpoint_handshake_rate_limited(meta, event);
+            (self.1).on_endpoint_handshake_rate_limited(meta, event);
+        }
+        #[inline]
+        fn on_endpoint_memory_pressure(
+            &mut self,
+            meta: &EndpointMeta,
+            event: &EndpointMemoryPressure,
+        ) {
+            (self.0).on_endpoint_memory_pressure(meta, event);
+            (self.1).on_endpoint_memory_pressure(meta, event);
+        }
+        #[inline]
         fn on_platform_tx(&mut self, meta: &EndpointMeta, event: &PlatformTx) {
             (self.0).on_platform_tx(meta, event);
             (self.1).on_platform_tx(meta, event);
@@ -5203,6 +7875,11 @@ mod traits {
             (self.1).on_platform_rx_error(meta, event);
         }
         #[inline]
+        fn on_io_recv_overflow(&mut self, meta: &EndpointMeta, event: &IoRecvOverflow) {
+            (self.0).on_io_recv_overflow(meta, event);
+            (self.1).on_io_recv_overflow(meta, event);
+        }
+        #[inline]
         fn on_platform_feature_configured(
             &mut self,
             meta: &EndpointMeta,
@@ -5283,6 +7960,13 @@ mod traits {
             &mut self,
             event: builder::EndpointConnectionAttemptFailed,
         );
+        #[doc = "Publishes a `EndpointHandshakeRateLimited` event to the publisher's subscriber"]
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            event: builder::EndpointHandshakeRateLimited,
+        );
+        #[doc = "Publishes a `EndpointMemoryPressure` event to the publisher's subscriber"]
+        fn on_endpoint_memory_pressure(&mut self, event: builder::EndpointMemoryPressure);
         #[doc = "Publishes a `PlatformTx` event to the publisher's subscriber"]
         fn on_platform_tx(&mut self, event: builder::PlatformTx);
         #[doc = "Publishes a `PlatformTxError` event to the publisher's subscriber"]
@@ -5291,6 +7975,8 @@ mod traits {
         fn on_platform_rx(&mut self, event: builder::PlatformRx);
         #[doc = "Publishes a `PlatformRxError` event to the publisher's subscriber"]
         fn on_platform_rx_error(&mut self, event: builder::PlatformRxError);
+        #[doc = "Publishes a `IoRecvOverflow` event to the publisher's subscriber"]
+        fn on_io_recv_overflow(&mut self, event: builder::IoRecvOverflow);
         #[doc = "Publishes a `PlatformFeatureConfigured` event to the publisher's subscriber"]
         fn on_platform_feature_configured(&mut self, event: builder::PlatformFeatureConfigured);
         #[doc = "Publishes a `PlatformEventLoopWakeup` event to the publisher's subscriber"]
@@ -5379,6 +8065,23 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            event: builder::EndpointHandshakeRateLimited,
+        ) {
+            let event = event.into_event();
+            self.subscriber
+                .on_endpoint_handshake_rate_limited(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_endpoint_memory_pressure(&mut self, event: builder::EndpointMemoryPressure) {
+            let event = event.into_event();
+            self.subscriber
+                .on_endpoint_memory_pressure(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_platform_tx(&mut self, event: builder::PlatformTx) {
             let event = event.into_event();
             self.subscriber.on_platform_tx(&self.meta, &event);
@@ -5403,6 +8106,12 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_io_recv_overflow(&mut self, event: builder::IoRecvOverflow) {
+            let event = event.into_event();
+            self.subscriber.on_io_recv_overflow(&self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_platform_feature_configured(&mut self, event: builder::PlatformFeatureConfigured) {
             let event = event.into_event();
             self.subscriber
@@ -5452,6 +8161,8 @@ mod traits {
         fn on_packet_lost(&mut self, event: builder::PacketLost);
         #[doc = "Publishes a `RecoveryMetrics` event to the publisher's subscriber"]
         fn on_recovery_metrics(&mut self, event: builder::RecoveryMetrics);
+        #[doc = "Publishes a `RttSample` event to the publisher's subscriber"]
+        fn on_rtt_sample(&mut self, event: builder::RttSample);
         #[doc = "Publishes a `Congestion` event to the publisher's subscriber"]
         fn on_congestion(&mut self, event: builder::Congestion);
         #[doc = "Publishes a `AckProcessed` event to the publisher's subscriber"]
@@ -5460,6 +8171,12 @@ mod traits {
         fn on_rx_ack_range_dropped(&mut self, event: builder::RxAckRangeDropped);
         #[doc = "Publishes a `AckRangeReceived` event to the publisher's subscriber"]
         fn on_ack_range_received(&mut self, event: builder::AckRangeReceived);
+        #[doc = "Publishes a `DuplicateAckReceived` event to the publisher's subscriber"]
+        fn on_duplicate_ack_received(&mut self, event: builder::DuplicateAckReceived);
+        #[doc = "Publishes a `AckSent` event to the publisher's subscriber"]
+        fn on_ack_sent(&mut self, event: builder::AckSent);
+        #[doc = "Publishes a `AckElicitingPacketReceived` event to the publisher's subscriber"]
+        fn on_ack_eliciting_packet_received(&mut self, event: builder::AckElicitingPacketReceived);
         #[doc = "Publishes a `PacketDropped` event to the publisher's subscriber"]
         fn on_packet_dropped(&mut self, event: builder::PacketDropped);
         #[doc = "Publishes a `KeyUpdate` event to the publisher's subscriber"]
@@ -5482,6 +8199,10 @@ mod traits {
         fn on_datagram_dropped(&mut self, event: builder::DatagramDropped);
         #[doc = "Publishes a `ConnectionIdUpdated` event to the publisher's subscriber"]
         fn on_connection_id_updated(&mut self, event: builder::ConnectionIdUpdated);
+        #[doc = "Publishes a `LocalConnectionIdIssued` event to the publisher's subscriber"]
+        fn on_local_connection_id_issued(&mut self, event: builder::LocalConnectionIdIssued);
+        #[doc = "Publishes a `LocalConnectionIdRetired` event to the publisher's subscriber"]
+        fn on_local_connection_id_retired(&mut self, event: builder::LocalConnectionIdRetired);
         #[doc = "Publishes a `EcnStateChanged` event to the publisher's subscriber"]
         fn on_ecn_state_changed(&mut self, event: builder::EcnStateChanged);
         #[doc = "Publishes a `ConnectionMigrationDenied` event to the publisher's subscriber"]
@@ -5504,6 +8225,26 @@ mod traits {
         fn on_mtu_updated(&mut self, event: builder::MtuUpdated);
         #[doc = "Publishes a `SlowStartExited` event to the publisher's subscriber"]
         fn on_slow_start_exited(&mut self, event: builder::SlowStartExited);
+        #[doc = "Publishes a `StreamOpenRateLimited` event to the publisher's subscriber"]
+        fn on_stream_open_rate_limited(&mut self, event: builder::StreamOpenRateLimited);
+        #[doc = "Publishes a `StreamStarved` event to the publisher's subscriber"]
+        fn on_stream_starved(&mut self, event: builder::StreamStarved);
+        #[doc = "Publishes a `StreamClosed` event to the publisher's subscriber"]
+        fn on_stream_closed(&mut self, event: builder::StreamClosed);
+        #[doc = "Publishes a `FlowControlWindowChanged` event to the publisher's subscriber"]
+        fn on_flow_control_window_changed(&mut self, event: builder::FlowControlWindowChanged);
+        #[doc = "Publishes a `ConnectionIdle` event to the publisher's subscriber"]
+        fn on_connection_idle(&mut self, event: builder::ConnectionIdle);
+        #[doc = "Publishes a `ConnectionActive` event to the publisher's subscriber"]
+        fn on_connection_active(&mut self, event: builder::ConnectionActive);
+        #[doc = "Publishes a `SendRateLimitUpdated` event to the publisher's subscriber"]
+        fn on_send_rate_limit_updated(&mut self, event: builder::SendRateLimitUpdated);
+        #[doc = "Publishes a `CongestionLimited` event to the publisher's subscriber"]
+        fn on_congestion_limited(&mut self, event: builder::CongestionLimited);
+        #[doc = "Publishes a `CongestionLimitEnded` event to the publisher's subscriber"]
+        fn on_congestion_limit_ended(&mut self, event: builder::CongestionLimitEnded);
+        #[doc = "Publishes a `CryptoRetransmitted` event to the publisher's subscriber"]
+        fn on_crypto_retransmitted(&mut self, event: builder::CryptoRetransmitted);
         #[doc = r" Returns the QUIC version negotiated for the current connection, if any"]
         fn quic_version(&self) -> u32;
         #[doc = r" Returns the [`Subject`] for the current publisher"]
@@ -5634,6 +8375,15 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_rtt_sample(&mut self, event: builder::RttSample) {
+            let event = event.into_event();
+            self.subscriber
+                .on_rtt_sample(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_congestion(&mut self, event: builder::Congestion) {
             let event = event.into_event();
             self.subscriber
@@ -5671,6 +8421,33 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
+        fn on_duplicate_ack_received(&mut self, event: builder::DuplicateAckReceived) {
+            let event = event.into_event();
+            self.subscriber
+                .on_duplicate_ack_received(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_ack_sent(&mut self, event: builder::AckSent) {
+            let event = event.into_event();
+            self.subscriber
+                .on_ack_sent(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_ack_eliciting_packet_received(&mut self, event: builder::AckElicitingPacketReceived) {
+            let event = event.into_event();
+            self.subscriber
+                .on_ack_eliciting_packet_received(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
         fn on_packet_dropped(&mut self, event: builder::PacketDropped) {
             let event = event.into_event();
             self.subscriber
@@ -5773,100 +8550,208 @@ mod traits {
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_ecn_state_changed(&mut self, event: builder::EcnStateChanged) {
+        fn on_local_connection_id_issued(&mut self, event: builder::LocalConnectionIdIssued) {
+            let event = event.into_event();
+            self.subscriber
+                .on_local_connection_id_issued(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_local_connection_id_retired(&mut self, event: builder::LocalConnectionIdRetired) {
+            let event = event.into_event();
+            self.subscriber
+                .on_local_connection_id_retired(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_ecn_state_changed(&mut self, event: builder::EcnStateChanged) {
+            let event = event.into_event();
+            self.subscriber
+                .on_ecn_state_changed(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_connection_migration_denied(&mut self, event: builder::ConnectionMigrationDenied) {
+            let event = event.into_event();
+            self.subscriber
+                .on_connection_migration_denied(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_handshake_status_updated(&mut self, event: builder::HandshakeStatusUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_handshake_status_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_path_challenge_updated(&mut self, event: builder::PathChallengeUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_path_challenge_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_tls_client_hello(&mut self, event: builder::TlsClientHello) {
+            let event = event.into_event();
+            self.subscriber
+                .on_tls_client_hello(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_tls_server_hello(&mut self, event: builder::TlsServerHello) {
+            let event = event.into_event();
+            self.subscriber
+                .on_tls_server_hello(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_rx_stream_progress(&mut self, event: builder::RxStreamProgress) {
+            let event = event.into_event();
+            self.subscriber
+                .on_rx_stream_progress(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_tx_stream_progress(&mut self, event: builder::TxStreamProgress) {
+            let event = event.into_event();
+            self.subscriber
+                .on_tx_stream_progress(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_keep_alive_timer_expired(&mut self, event: builder::KeepAliveTimerExpired) {
+            let event = event.into_event();
+            self.subscriber
+                .on_keep_alive_timer_expired(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_mtu_updated(&mut self, event: builder::MtuUpdated) {
+            let event = event.into_event();
+            self.subscriber
+                .on_mtu_updated(self.context, &self.meta, &event);
+            self.subscriber
+                .on_connection_event(self.context, &self.meta, &event);
+            self.subscriber.on_event(&self.meta, &event);
+        }
+        #[inline]
+        fn on_slow_start_exited(&mut self, event: builder::SlowStartExited) {
             let event = event.into_event();
             self.subscriber
-                .on_ecn_state_changed(self.context, &self.meta, &event);
+                .on_slow_start_exited(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_connection_migration_denied(&mut self, event: builder::ConnectionMigrationDenied) {
+        fn on_stream_open_rate_limited(&mut self, event: builder::StreamOpenRateLimited) {
             let event = event.into_event();
             self.subscriber
-                .on_connection_migration_denied(self.context, &self.meta, &event);
+                .on_stream_open_rate_limited(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_handshake_status_updated(&mut self, event: builder::HandshakeStatusUpdated) {
+        fn on_stream_starved(&mut self, event: builder::StreamStarved) {
             let event = event.into_event();
             self.subscriber
-                .on_handshake_status_updated(self.context, &self.meta, &event);
+                .on_stream_starved(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_path_challenge_updated(&mut self, event: builder::PathChallengeUpdated) {
+        fn on_stream_closed(&mut self, event: builder::StreamClosed) {
             let event = event.into_event();
             self.subscriber
-                .on_path_challenge_updated(self.context, &self.meta, &event);
+                .on_stream_closed(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_tls_client_hello(&mut self, event: builder::TlsClientHello) {
+        fn on_flow_control_window_changed(&mut self, event: builder::FlowControlWindowChanged) {
             let event = event.into_event();
             self.subscriber
-                .on_tls_client_hello(self.context, &self.meta, &event);
+                .on_flow_control_window_changed(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_tls_server_hello(&mut self, event: builder::TlsServerHello) {
+        fn on_connection_idle(&mut self, event: builder::ConnectionIdle) {
             let event = event.into_event();
             self.subscriber
-                .on_tls_server_hello(self.context, &self.meta, &event);
+                .on_connection_idle(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_rx_stream_progress(&mut self, event: builder::RxStreamProgress) {
+        fn on_connection_active(&mut self, event: builder::ConnectionActive) {
             let event = event.into_event();
             self.subscriber
-                .on_rx_stream_progress(self.context, &self.meta, &event);
+                .on_connection_active(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_tx_stream_progress(&mut self, event: builder::TxStreamProgress) {
+        fn on_send_rate_limit_updated(&mut self, event: builder::SendRateLimitUpdated) {
             let event = event.into_event();
             self.subscriber
-                .on_tx_stream_progress(self.context, &self.meta, &event);
+                .on_send_rate_limit_updated(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_keep_alive_timer_expired(&mut self, event: builder::KeepAliveTimerExpired) {
+        fn on_congestion_limited(&mut self, event: builder::CongestionLimited) {
             let event = event.into_event();
             self.subscriber
-                .on_keep_alive_timer_expired(self.context, &self.meta, &event);
+                .on_congestion_limited(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_mtu_updated(&mut self, event: builder::MtuUpdated) {
+        fn on_congestion_limit_ended(&mut self, event: builder::CongestionLimitEnded) {
             let event = event.into_event();
             self.subscriber
-                .on_mtu_updated(self.context, &self.meta, &event);
+                .on_congestion_limit_ended(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
         }
         #[inline]
-        fn on_slow_start_exited(&mut self, event: builder::SlowStartExited) {
+        fn on_crypto_retransmitted(&mut self, event: builder::CryptoRetransmitted) {
             let event = event.into_event();
             self.subscriber
-                .on_slow_start_exited(self.context, &self.meta, &event);
+                .on_crypto_retransmitted(self.context, &self.meta, &event);
             self.subscriber
                 .on_connection_event(self.context, &self.meta, &event);
             self.subscriber.on_event(&self.meta, &event);
@@ -5898,10 +8783,14 @@ pub mod testing {
         pub frame_received: u32,
         pub packet_lost: u32,
         pub recovery_metrics: u32,
+        pub rtt_sample: u32,
         pub congestion: u32,
         pub ack_processed: u32,
         pub rx_ack_range_dropped: u32,
         pub ack_range_received: u32,
+        pub duplicate_ack_received: u32,
+        pub ack_sent: u32,
+        pub ack_eliciting_packet_received: u32,
         pub packet_dropped: u32,
         pub key_update: u32,
         pub key_space_discarded: u32,
@@ -5913,6 +8802,8 @@ pub mod testing {
         pub datagram_received: u32,
         pub datagram_dropped: u32,
         pub connection_id_updated: u32,
+        pub local_connection_id_issued: u32,
+        pub local_connection_id_retired: u32,
         pub ecn_state_changed: u32,
         pub connection_migration_denied: u32,
         pub handshake_status_updated: u32,
@@ -5924,6 +8815,16 @@ pub mod testing {
         pub keep_alive_timer_expired: u32,
         pub mtu_updated: u32,
         pub slow_start_exited: u32,
+        pub stream_open_rate_limited: u32,
+        pub stream_starved: u32,
+        pub stream_closed: u32,
+        pub flow_control_window_changed: u32,
+        pub connection_idle: u32,
+        pub connection_active: u32,
+        pub send_rate_limit_updated: u32,
+        pub congestion_limited: u32,
+        pub congestion_limit_ended: u32,
+        pub crypto_retransmitted: u32,
         pub version_information: u32,
         pub endpoint_packet_sent: u32,
         pub endpoint_packet_received: u32,
@@ -5931,10 +8832,13 @@ pub mod testing {
         pub endpoint_datagram_received: u32,
         pub endpoint_datagram_dropped: u32,
         pub endpoint_connection_attempt_failed: u32,
+        pub endpoint_handshake_rate_limited: u32,
+        pub endpoint_memory_pressure: u32,
         pub platform_tx: u32,
         pub platform_tx_error: u32,
         pub platform_rx: u32,
         pub platform_rx_error: u32,
+        pub io_recv_overflow: u32,
         pub platform_feature_configured: u32,
         pub platform_event_loop_wakeup: u32,
         pub platform_event_loop_sleep: u32,
@@ -5972,10 +8876,14 @@ pub mod testing {
                 frame_received: 0,
                 packet_lost: 0,
                 recovery_metrics: 0,
+                rtt_sample: 0,
                 congestion: 0,
                 ack_processed: 0,
                 rx_ack_range_dropped: 0,
                 ack_range_received: 0,
+                duplicate_ack_received: 0,
+                ack_sent: 0,
+                ack_eliciting_packet_received: 0,
                 packet_dropped: 0,
                 key_update: 0,
                 key_space_discarded: 0,
@@ -5987,6 +8895,8 @@ pub mod testing {
                 datagram_received: 0,
                 datagram_dropped: 0,
                 connection_id_updated: 0,
+                local_connection_id_issued: 0,
+                local_connection_id_retired: 0,
                 ecn_state_changed: 0,
                 connection_migration_denied: 0,
                 handshake_status_updated: 0,
@@ -5998,6 +8908,16 @@ pub mod testing {
                 keep_alive_timer_expired: 0,
                 mtu_updated: 0,
                 slow_start_exited: 0,
+                stream_open_rate_limited: 0,
+                stream_starved: 0,
+                stream_closed: 0,
+                flow_control_window_changed: 0,
+                connection_idle: 0,
+                connection_active: 0,
+                send_rate_limit_updated: 0,
+                congestion_limited: 0,
+                congestion_limit_ended: 0,
+                crypto_retransmitted: 0,
                 version_information: 0,
                 endpoint_packet_sent: 0,
                 endpoint_packet_received: 0,
@@ -6005,10 +8925,13 @@ pub mod testing {
                 endpoint_datagram_received: 0,
                 endpoint_datagram_dropped: 0,
                 endpoint_connection_attempt_failed: 0,
+                endpoint_handshake_rate_limited: 0,
+                endpoint_memory_pressure: 0,
                 platform_tx: 0,
                 platform_tx_error: 0,
                 platform_rx: 0,
                 platform_rx_error: 0,
+                io_recv_overflow: 0,
                 platform_feature_configured: 0,
                 platform_event_loop_wakeup: 0,
                 platform_event_loop_sleep: 0,
@@ -6133,6 +9056,17 @@ pub mod testing {
                 self.output.push(format!("{:?} {:?}", meta, event));
             }
         }
+        fn on_rtt_sample(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::RttSample,
+        ) {
+            self.rtt_sample += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
         fn on_congestion(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6178,6 +9112,39 @@ pub mod testing {
                 self.output.push(format!("{:?} {:?}", meta, event));
             }
         }
+        fn on_duplicate_ack_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::DuplicateAckReceived,
+        ) {
+            self.duplicate_ack_received += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_ack_sent(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckSent,
+        ) {
+            self.ack_sent += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_ack_eliciting_packet_received(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::AckElicitingPacketReceived,
+        ) {
+            self.ack_eliciting_packet_received += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
         fn on_packet_dropped(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6299,6 +9266,28 @@ pub mod testing {
                 self.output.push(format!("{:?} {:?}", meta, event));
             }
         }
+        fn on_local_connection_id_issued(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::LocalConnectionIdIssued,
+        ) {
+            self.local_connection_id_issued += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_local_connection_id_retired(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::LocalConnectionIdRetired,
+        ) {
+            self.local_connection_id_retired += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
         fn on_ecn_state_changed(
             &mut self,
             _context: &mut Self::ConnectionContext,
@@ -6420,6 +9409,116 @@ pub mod testing {
                 self.output.push(format!("{:?} {:?}", meta, event));
             }
         }
+        fn on_stream_open_rate_limited(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamOpenRateLimited,
+        ) {
+            self.stream_open_rate_limited += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_stream_starved(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamStarved,
+        ) {
+            self.stream_starved += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_stream_closed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::StreamClosed,
+        ) {
+            self.stream_closed += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_flow_control_window_changed(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::FlowControlWindowChanged,
+        ) {
+            self.flow_control_window_changed += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_connection_idle(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionIdle,
+        ) {
+            self.connection_idle += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_connection_active(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::ConnectionActive,
+        ) {
+            self.connection_active += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_send_rate_limit_updated(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::SendRateLimitUpdated,
+        ) {
+            self.send_rate_limit_updated += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_congestion_limited(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CongestionLimited,
+        ) {
+            self.congestion_limited += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_congestion_limit_ended(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CongestionLimitEnded,
+        ) {
+            self.congestion_limit_ended += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
+        fn on_crypto_retransmitted(
+            &mut self,
+            _context: &mut Self::ConnectionContext,
+            meta: &api::ConnectionMeta,
+            event: &api::CryptoRetransmitted,
+        ) {
+            self.crypto_retransmitted += 1;
+            if self.location.is_some() {
+                self.output.push(format!("{:?} {:?}", meta, event));
+            }
+        }
         fn on_version_information(
             &mut self,
             meta: &api::EndpointMeta,
@@ -6476,6 +9575,22 @@ pub mod testing {
             self.endpoint_connection_attempt_failed += 1;
             self.output.push(format!("{:?} {:?}", meta, event));
         }
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointHandshakeRateLimited,
+        ) {
+            self.endpoint_handshake_rate_limited += 1;
+            self.output.push(format!("{:?} {:?}", meta, event));
+        }
+        fn on_endpoint_memory_pressure(
+            &mut self,
+            meta: &api::EndpointMeta,
+            event: &api::EndpointMemoryPressure,
+        ) {
+            self.endpoint_memory_pressure += 1;
+            self.output.push(format!("{:?} {:?}", meta, event));
+        }
         fn on_platform_tx(&mut self, meta: &api::EndpointMeta, event: &api::PlatformTx) {
             self.platform_tx += 1;
             self.output.push(format!("{:?} {:?}", meta, event));
@@ -6492,6 +9607,10 @@ pub mod testing {
             self.platform_rx_error += 1;
             self.output.push(format!("{:?} {:?}", meta, event));
         }
+        fn on_io_recv_overflow(&mut self, meta: &api::EndpointMeta, event: &api::IoRecvOverflow) {
+            self.io_recv_overflow += 1;
+            self.output.push(format!("{:?} {:?}", meta, event));
+        }
         fn on_platform_feature_configured(
             &mut self,
             meta: &api::EndpointMeta,
@@ -6531,10 +9650,14 @@ pub mod testing {
         pub frame_received: u32,
         pub packet_lost: u32,
         pub recovery_metrics: u32,
+        pub rtt_sample: u32,
         pub congestion: u32,
         pub ack_processed: u32,
         pub rx_ack_range_dropped: u32,
         pub ack_range_received: u32,
+        pub duplicate_ack_received: u32,
+        pub ack_sent: u32,
+        pub ack_eliciting_packet_received: u32,
         pub packet_dropped: u32,
         pub key_update: u32,
         pub key_space_discarded: u32,
@@ -6546,6 +9669,8 @@ pub mod testing {
         pub datagram_received: u32,
         pub datagram_dropped: u32,
         pub connection_id_updated: u32,
+        pub local_connection_id_issued: u32,
+        pub local_connection_id_retired: u32,
         pub ecn_state_changed: u32,
         pub connection_migration_denied: u32,
         pub handshake_status_updated: u32,
@@ -6557,6 +9682,16 @@ pub mod testing {
         pub keep_alive_timer_expired: u32,
         pub mtu_updated: u32,
         pub slow_start_exited: u32,
+        pub stream_open_rate_limited: u32,
+        pub stream_starved: u32,
+        pub stream_closed: u32,
+        pub flow_control_window_changed: u32,
+        pub connection_idle: u32,
+        pub connection_active: u32,
+        pub send_rate_limit_updated: u32,
+        pub congestion_limited: u32,
+        pub congestion_limit_ended: u32,
+        pub crypto_retransmitted: u32,
         pub version_information: u32,
         pub endpoint_packet_sent: u32,
         pub endpoint_packet_received: u32,
@@ -6564,10 +9699,13 @@ pub mod testing {
         pub endpoint_datagram_received: u32,
         pub endpoint_datagram_dropped: u32,
         pub endpoint_connection_attempt_failed: u32,
+        pub endpoint_handshake_rate_limited: u32,
+        pub endpoint_memory_pressure: u32,
         pub platform_tx: u32,
         pub platform_tx_error: u32,
         pub platform_rx: u32,
         pub platform_rx_error: u32,
+        pub io_recv_overflow: u32,
         pub platform_feature_configured: u32,
         pub platform_event_loop_wakeup: u32,
         pub platform_event_loop_sleep: u32,
@@ -6595,10 +9733,14 @@ pub mod testing {
                 frame_received: 0,
                 packet_lost: 0,
                 recovery_metrics: 0,
+                rtt_sample: 0,
                 congestion: 0,
                 ack_processed: 0,
                 rx_ack_range_dropped: 0,
                 ack_range_received: 0,
+                duplicate_ack_received: 0,
+                ack_sent: 0,
+                ack_eliciting_packet_received: 0,
                 packet_dropped: 0,
                 key_update: 0,
                 key_space_discarded: 0,
@@ -6610,6 +9752,8 @@ pub mod testing {
                 datagram_received: 0,
                 datagram_dropped: 0,
                 connection_id_updated: 0,
+                local_connection_id_issued: 0,
+                local_connection_id_retired: 0,
                 ecn_state_changed: 0,
                 connection_migration_denied: 0,
                 handshake_status_updated: 0,
@@ -6621,6 +9765,16 @@ pub mod testing {
                 keep_alive_timer_expired: 0,
                 mtu_updated: 0,
                 slow_start_exited: 0,
+                stream_open_rate_limited: 0,
+                stream_starved: 0,
+                stream_closed: 0,
+                flow_control_window_changed: 0,
+                connection_idle: 0,
+                connection_active: 0,
+                send_rate_limit_updated: 0,
+                congestion_limited: 0,
+                congestion_limit_ended: 0,
+                crypto_retransmitted: 0,
                 version_information: 0,
                 endpoint_packet_sent: 0,
                 endpoint_packet_received: 0,
@@ -6628,10 +9782,13 @@ pub mod testing {
                 endpoint_datagram_received: 0,
                 endpoint_datagram_dropped: 0,
                 endpoint_connection_attempt_failed: 0,
+                endpoint_handshake_rate_limited: 0,
+                endpoint_memory_pressure: 0,
                 platform_tx: 0,
                 platform_tx_error: 0,
                 platform_rx: 0,
                 platform_rx_error: 0,
+                io_recv_overflow: 0,
                 platform_feature_configured: 0,
                 platform_event_loop_wakeup: 0,
                 platform_event_loop_sleep: 0,
@@ -6677,6 +9834,19 @@ pub mod testing {
             let event = event.into_event();
             self.output.push(format!("{:?}", event));
         }
+        fn on_endpoint_handshake_rate_limited(
+            &mut self,
+            event: builder::EndpointHandshakeRateLimited,
+        ) {
+            self.endpoint_handshake_rate_limited += 1;
+            let event = event.into_event();
+            self.output.push(format!("{:?}", event));
+        }
+        fn on_endpoint_memory_pressure(&mut self, event: builder::EndpointMemoryPressure) {
+            self.endpoint_memory_pressure += 1;
+            let event = event.into_event();
+            self.output.push(format!("{:?}", event));
+        }
         fn on_platform_tx(&mut self, event: builder::PlatformTx) {
             self.platform_tx += 1;
             let event = event.into_event();
@@ -6697,6 +9867,11 @@ pub mod testing {
             let event = event.into_event();
             self.output.push(format!("{:?}", event));
         }
+        fn on_io_recv_overflow(&mut self, event: builder::IoRecvOverflow) {
+            self.io_recv_overflow += 1;
+            let event = event.into_event();
+            self.output.push(format!("{:?}", event));
+        }
         fn on_platform_feature_configured(&mut self, event: builder::PlatformFeatureConfigured) {
             self.platform_feature_configured += 1;
             let event = event.into_event();
@@ -6790,6 +9965,13 @@ pub mod testing {
                 self.output.push(format!("{:?}", event));
             }
         }
+        fn on_rtt_sample(&mut self, event: builder::RttSample) {
+            self.rtt_sample += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
         fn on_congestion(&mut self, event: builder::Congestion) {
             self.congestion += 1;
             let event = event.into_event();
@@ -6819,6 +10001,27 @@ pub mod testing {
                 self.output.push(format!("{:?}", event));
             }
         }
+        fn on_duplicate_ack_received(&mut self, event: builder::DuplicateAckReceived) {
+            self.duplicate_ack_received += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_ack_sent(&mut self, event: builder::AckSent) {
+            self.ack_sent += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_ack_eliciting_packet_received(&mut self, event: builder::AckElicitingPacketReceived) {
+            self.ack_eliciting_packet_received += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
         fn on_packet_dropped(&mut self, event: builder::PacketDropped) {
             self.packet_dropped += 1;
             let event = event.into_event();
@@ -6899,6 +10102,20 @@ pub mod testing {
                 self.output.push(format!("{:?}", event));
             }
         }
+        fn on_local_connection_id_issued(&mut self, event: builder::LocalConnectionIdIssued) {
+            self.local_connection_id_issued += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_local_connection_id_retired(&mut self, event: builder::LocalConnectionIdRetired) {
+            self.local_connection_id_retired += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
         fn on_ecn_state_changed(&mut self, event: builder::EcnStateChanged) {
             self.ecn_state_changed += 1;
             let event = event.into_event();
@@ -6976,6 +10193,76 @@ pub mod testing {
                 self.output.push(format!("{:?}", event));
             }
         }
+        fn on_stream_open_rate_limited(&mut self, event: builder::StreamOpenRateLimited) {
+            self.stream_open_rate_limited += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_stream_starved(&mut self, event: builder::StreamStarved) {
+            self.stream_starved += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_stream_closed(&mut self, event: builder::StreamClosed) {
+            self.stream_closed += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_flow_control_window_changed(&mut self, event: builder::FlowControlWindowChanged) {
+            self.flow_control_window_changed += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_connection_idle(&mut self, event: builder::ConnectionIdle) {
+            self.connection_idle += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_connection_active(&mut self, event: builder::ConnectionActive) {
+            self.connection_active += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_send_rate_limit_updated(&mut self, event: builder::SendRateLimitUpdated) {
+            self.send_rate_limit_updated += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_congestion_limited(&mut self, event: builder::CongestionLimited) {
+            self.congestion_limited += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_congestion_limit_ended(&mut self, event: builder::CongestionLimitEnded) {
+            self.congestion_limit_ended += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
+        fn on_crypto_retransmitted(&mut self, event: builder::CryptoRetransmitted) {
+            self.crypto_retransmitted += 1;
+            let event = event.into_event();
+            if self.location.is_some() {
+                self.output.push(format!("{:?}", event));
+            }
+        }
         fn quic_version(&self) -> u32 {
             1
         }
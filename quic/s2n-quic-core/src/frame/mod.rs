@@ -16,6 +16,9 @@ use s2n_codec::{
 pub mod ack_elicitation;
 pub mod congestion_controlled;
 pub mod path_validation;
+pub mod summary;
+
+pub use summary::Summary;
 
 //= https://www.rfc-editor.org/rfc/rfc9000#section-19
 //# As described in Section 12.4, packets contain one or more frames.
@@ -0,0 +1,215 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::event::{self, builder::Frame, IntoEvent};
+use core::ops::AddAssign;
+
+const ACK: u16 = 1 << 0;
+const PING: u16 = 1 << 1;
+const CRYPTO: u16 = 1 << 2;
+const STREAM: u16 = 1 << 3;
+const STREAM_CONTROL: u16 = 1 << 4;
+const FLOW_CONTROL: u16 = 1 << 5;
+const DATAGRAM: u16 = 1 << 6;
+const OTHER: u16 = 1 << 7;
+
+/// A compact summary of the frames written to a packet
+///
+/// The summary is stored with each sent packet so the contents of a packet can be reported if it
+/// is lost, without retaining the frames themselves. Each kind of frame is recorded as a single
+/// bit, along with the number of frames and the range of stream ids with STREAM frames. The
+/// [`FrameSummary`](event::builder::FrameSummary) event is only built from the summary when a
+/// packet is reported.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Summary {
+    kinds: u16,
+    /// The number of frames written, not including PADDING frames
+    frame_count: u16,
+    /// The lowest and highest ids of the streams with STREAM frames
+    ///
+    /// These are only set if the `STREAM` bit is set. Stream ids are saturated at `u32::MAX` to
+    /// keep the summary small.
+    min_stream_id: u32,
+    max_stream_id: u32,
+}
+
+impl Summary {
+    /// Records a frame written to the packet
+    #[inline]
+    pub fn on_frame(&mut self, frame: &Frame) {
+        let kind = match frame {
+            // PADDING doesn't carry any information
+            Frame::Padding { .. } => return,
+            Frame::Ack { .. } => ACK,
+            Frame::Ping { .. } => PING,
+            Frame::Crypto { .. } => CRYPTO,
+            Frame::Stream { id, .. } => {
+                let id = (*id).min(u32::MAX as u64) as u32;
+                self.on_stream_ids(id, id);
+                STREAM
+            }
+            Frame::ResetStream { .. } | Frame::StopSending { .. } => STREAM_CONTROL,
+            Frame::MaxData { .. }
+            | Frame::MaxStreamData { .. }
+            | Frame::MaxStreams { .. }
+            | Frame::DataBlocked { .. }
+            | Frame::StreamDataBlocked { .. }
+            | Frame::StreamsBlocked { .. } => FLOW_CONTROL,
+            Frame::Datagram { .. } => DATAGRAM,
+            _ => OTHER,
+        };
+
+        self.kinds |= kind;
+        self.frame_count = self.frame_count.saturating_add(1);
+    }
+
+    /// Returns `true` if no frames, other than PADDING, were recorded
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.kinds == 0
+    }
+
+    #[inline]
+    fn contains(&self, bits: u16) -> bool {
+        self.kinds & bits != 0
+    }
+
+    /// Extends the range of stream ids with STREAM frames
+    ///
+    /// This must be called before the `STREAM` bit is set for the new frames.
+    #[inline]
+    fn on_stream_ids(&mut self, min: u32, max: u32) {
+        if self.contains(STREAM) {
+            self.min_stream_id = self.min_stream_id.min(min);
+            self.max_stream_id = self.max_stream_id.max(max);
+        } else {
+            self.min_stream_id = min;
+            self.max_stream_id = max;
+        }
+    }
+}
+
+impl AddAssign for Summary {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        if rhs.contains(STREAM) {
+            self.on_stream_ids(rhs.min_stream_id, rhs.max_stream_id);
+        }
+        self.kinds |= rhs.kinds;
+        self.frame_count = self.frame_count.saturating_add(rhs.frame_count);
+    }
+}
+
+impl IntoEvent<event::builder::FrameSummary> for Summary {
+    #[inline]
+    fn into_event(self) -> event::builder::FrameSummary {
+        event::builder::FrameSummary {
+            ack: self.contains(ACK),
+            ping: self.contains(PING),
+            crypto: self.contains(CRYPTO),
+            stream: self.contains(STREAM),
+            stream_control: self.contains(STREAM_CONTROL),
+            flow_control: self.contains(FLOW_CONTROL),
+            datagram: self.contains(DATAGRAM),
+            other: self.contains(OTHER),
+            frame_count: self.frame_count as usize,
+            stream_ids: if self.contains(STREAM) {
+                Some(self.min_stream_id as u64..=self.max_stream_id as u64)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stream(id: u64) -> Frame {
+        Frame::Stream {
+            id,
+            offset: 0,
+            len: 1,
+            is_fin: false,
+        }
+    }
+
+    #[test]
+    fn summary_test() {
+        let mut summary = Summary::default();
+        assert!(summary.is_empty());
+
+        summary.on_frame(&Frame::Padding {});
+        assert!(summary.is_empty());
+
+        summary.on_frame(&Frame::Crypto { offset: 0, len: 1 });
+        summary.on_frame(&stream(8));
+        summary.on_frame(&stream(4));
+        summary.on_frame(&Frame::MaxData { value: 100 });
+
+        let event: event::builder::FrameSummary = summary.into_event();
+        assert!(event.crypto);
+        assert!(event.stream);
+        assert!(event.flow_control);
+        assert!(!event.ack);
+        assert!(!event.ping);
+        assert!(!event.other);
+        assert_eq!(event.frame_count, 4);
+
+        let mut other = Summary::default();
+        other.on_frame(&Frame::Ping {});
+        other.on_frame(&Frame::HandshakeDone {});
+        summary += other;
+
+        let event: event::builder::FrameSummary = summary.into_event();
+        assert!(event.ping);
+        assert!(event.other);
+        assert!(event.stream);
+        assert_eq!(event.frame_count, 6);
+        assert_eq!(event.stream_ids, Some(4..=8));
+    }
+
+    #[test]
+    fn stream_packet_test() {
+        let mut summary = Summary::default();
+        summary.on_frame(&Frame::Ack {
+            ecn_counts: None,
+            largest_acknowledged: 1,
+            ack_range_count: 1,
+        });
+        let event: event::builder::FrameSummary = summary.into_event();
+        assert_eq!(event.stream_ids, None);
+
+        // the range covers all of the streams in the packet
+        summary.on_frame(&stream(12));
+        summary.on_frame(&stream(0));
+        summary.on_frame(&stream(5));
+        summary.on_frame(&Frame::Padding {});
+
+        let event: event::builder::FrameSummary = summary.into_event();
+        assert!(event.ack);
+        assert!(event.stream);
+        assert_eq!(event.frame_count, 4);
+        assert_eq!(event.stream_ids, Some(0..=12));
+
+        // the range includes the streams of coalesced summaries
+        let mut other = Summary::default();
+        other.on_frame(&stream(16));
+        summary += other;
+        let event: event::builder::FrameSummary = summary.into_event();
+        assert_eq!(event.stream_ids, Some(0..=16));
+
+        // stream ids which don't fit in the summary are saturated
+        let mut summary = Summary::default();
+        summary.on_frame(&stream(u64::MAX >> 2));
+        let event: event::builder::FrameSummary = summary.into_event();
+        assert_eq!(event.stream_ids, Some(u32::MAX as u64..=u32::MAX as u64));
+    }
+
+    #[test]
+    fn summary_size_test() {
+        // the summary is stored with every sent packet so it should stay small
+        assert_eq!(core::mem::size_of::<Summary>(), 12);
+    }
+}
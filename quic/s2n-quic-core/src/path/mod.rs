@@ -10,6 +10,7 @@ use core::{
     fmt,
     fmt::{Display, Formatter},
     num::NonZeroU16,
+    time::Duration,
 };
 
 #[cfg(any(test, feature = "generator"))]
@@ -24,7 +25,10 @@ pub const MINIMUM_MTU: u16 = 1200;
 
 // TODO decide on better defaults
 // Safety: 1500 is greater than zero
-pub const DEFAULT_MAX_MTU: MaxMtu = MaxMtu(unsafe { NonZeroU16::new_unchecked(1500) });
+pub const DEFAULT_MAX_MTU: MaxMtu = MaxMtu {
+    value: unsafe { NonZeroU16::new_unchecked(1500) },
+    is_fixed: false,
+};
 
 // Length is the length in octets of this user datagram  including  this
 // header and the data. (This means the minimum value of the length is
@@ -223,8 +227,72 @@ impl Handle for Tuple {
     }
 }
 
+/// Information about the path a connection is currently using
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug)]
+pub struct Info {
+    /// The local address of the path
+    pub local_address: SocketAddress,
+    /// The remote address of the path
+    pub remote_address: SocketAddress,
+    /// Whether the peer's address has been validated on this path
+    ///
+    /// Until the path is validated, the amount of data that can be sent on it is limited to
+    /// three times the amount of data received.
+    pub is_validated: bool,
+    /// The smoothed round-trip time of the path
+    pub smoothed_rtt: Duration,
+    /// The minimum round-trip time observed on the path
+    pub min_rtt: Duration,
+    /// The most recent round-trip time sample of the path
+    pub latest_rtt: Duration,
+}
+
+impl Info {
+    #[doc(hidden)]
+    pub fn new(
+        local_address: SocketAddress,
+        remote_address: SocketAddress,
+        is_validated: bool,
+        smoothed_rtt: Duration,
+        min_rtt: Duration,
+        latest_rtt: Duration,
+    ) -> Self {
+        Self {
+            local_address,
+            remote_address,
+            is_validated,
+            smoothed_rtt,
+            min_rtt,
+            latest_rtt,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
-pub struct MaxMtu(NonZeroU16);
+pub struct MaxMtu {
+    value: NonZeroU16,
+    /// If set, path MTU discovery is disabled and the MTU is pinned to `value`
+    is_fixed: bool,
+}
+
+impl MaxMtu {
+    /// Pins the MTU to the current value, which disables path MTU discovery
+    #[inline]
+    #[must_use]
+    pub const fn fixed(self) -> Self {
+        Self {
+            value: self.value,
+            is_fixed: true,
+        }
+    }
+
+    /// Returns true if the MTU is pinned to the current value
+    #[inline]
+    pub const fn is_fixed(&self) -> bool {
+        self.is_fixed
+    }
+}
 
 impl Default for MaxMtu {
     fn default() -> Self {
@@ -240,23 +308,26 @@ impl TryFrom<u16> for MaxMtu {
             return Err(MaxMtuError(MIN_ALLOWED_MAX_MTU.try_into().unwrap()));
         }
 
-        Ok(MaxMtu(value.try_into().expect(
-            "Value must be greater than zero according to the check above",
-        )))
+        Ok(MaxMtu {
+            value: value
+                .try_into()
+                .expect("Value must be greater than zero according to the check above"),
+            is_fixed: false,
+        })
     }
 }
 
 impl From<MaxMtu> for usize {
     #[inline]
     fn from(value: MaxMtu) -> Self {
-        value.0.get() as usize
+        value.value.get() as usize
     }
 }
 
 impl From<MaxMtu> for u16 {
     #[inline]
     fn from(value: MaxMtu) -> Self {
-        value.0.get()
+        value.value.get()
     }
 }
 
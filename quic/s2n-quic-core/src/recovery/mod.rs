@@ -3,6 +3,7 @@
 
 pub use congestion_controller::CongestionController;
 pub use cubic::CubicCongestionController;
+pub use pacing::RateLimiter;
 pub use rtt_estimator::*;
 pub use sent_packets::*;
 
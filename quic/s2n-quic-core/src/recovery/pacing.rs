@@ -6,7 +6,7 @@ use crate::{
     recovery::{RttEstimator, MAX_BURST_PACKETS},
     time::{Duration, Timestamp},
 };
-use core::ops::Div;
+use core::{convert::TryInto, ops::Div};
 use num_rational::Ratio;
 
 struct PacingGain(Ratio<u32>);
@@ -124,5 +124,81 @@ impl Pacer {
     }
 }
 
+/// Limits the rate at which packets are transmitted to an application-configured number of
+/// bytes per second, independently of the congestion controller
+///
+/// Packets are released in bursts of `MAX_BURST_PACKETS` datagrams, with each burst delayed
+/// by the amount of time it takes to send the burst at the configured rate.
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    // The maximum number of bytes that may be sent per second
+    bytes_per_sec: Option<u64>,
+    // The capacity of the current departure time slot
+    capacity: Counter<u32, Saturating>,
+    // The time the next packet should be transmitted
+    next_packet_departure_time: Option<Timestamp>,
+}
+
+impl RateLimiter {
+    /// Sets the maximum number of bytes that may be sent per second
+    ///
+    /// A value of `None` or `0` disables the limit. Changing the rate keeps the capacity of the
+    /// current slot; the new rate is used for spacing out the following slots.
+    #[inline]
+    pub fn set_rate(&mut self, bytes_per_sec: Option<u64>) {
+        self.bytes_per_sec = bytes_per_sec.filter(|rate| *rate > 0);
+
+        if self.bytes_per_sec.is_none() {
+            // nothing is being limited so there is no state to keep
+            self.capacity = Counter::new(0);
+            self.next_packet_departure_time = None;
+        }
+    }
+
+    /// Returns the maximum number of bytes that may be sent per second, if limited
+    #[inline]
+    pub fn rate(&self) -> Option<u64> {
+        self.bytes_per_sec
+    }
+
+    /// Called when each packet has been written
+    #[inline]
+    pub fn on_packet_sent(&mut self, now: Timestamp, bytes_sent: usize, max_datagram_size: u16) {
+        let bytes_per_sec = if let Some(bytes_per_sec) = self.bytes_per_sec {
+            bytes_per_sec
+        } else {
+            return;
+        };
+
+        if self.capacity == 0 {
+            let burst_size = (MAX_BURST_PACKETS * max_datagram_size) as u32;
+
+            if let Some(next_packet_departure_time) = self.next_packet_departure_time {
+                // the time it takes to send a full burst at the configured rate
+                let interval = Duration::from_nanos(
+                    (burst_size as u128 * 1_000_000_000 / bytes_per_sec as u128)
+                        .try_into()
+                        .unwrap_or(u64::MAX),
+                );
+                self.next_packet_departure_time =
+                    Some((next_packet_departure_time + interval).max(now));
+            } else {
+                self.next_packet_departure_time = Some(now);
+            }
+            self.capacity = Counter::new(burst_size);
+        }
+
+        self.capacity -= bytes_sent as u32;
+    }
+
+    /// Returns the earliest time that a packet may be transmitted.
+    ///
+    /// If the time is in the past or is `None`, the packet should be transmitted immediately.
+    #[inline]
+    pub fn earliest_departure_time(&self) -> Option<Timestamp> {
+        self.next_packet_departure_time
+    }
+}
+
 #[cfg(test)]
 mod tests;
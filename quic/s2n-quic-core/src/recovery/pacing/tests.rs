@@ -5,8 +5,8 @@ use crate::{
     packet::number::PacketNumberSpace,
     path::MINIMUM_MTU,
     recovery::{
-        pacing::{Pacer, INITIAL_INTERVAL, N, SLOW_START_N},
-        RttEstimator,
+        pacing::{Pacer, RateLimiter, INITIAL_INTERVAL, N, SLOW_START_N},
+        RttEstimator, MAX_BURST_PACKETS,
     },
     time::{Clock, NoopClock, Timestamp},
 };
@@ -216,3 +216,88 @@ fn get_interval(
         }
     }
 }
+
+#[test]
+fn rate_limiter() {
+    let mut limiter = RateLimiter::default();
+    let now = NoopClock.get_time();
+
+    // the limiter is disabled by default
+    limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+    assert_eq!(None, limiter.earliest_departure_time());
+
+    // a rate of 0 also disables the limiter
+    limiter.set_rate(Some(0));
+    assert_eq!(None, limiter.rate());
+
+    // allow a single burst to be sent per second
+    let burst_size = (MAX_BURST_PACKETS * MINIMUM_MTU) as u64;
+    limiter.set_rate(Some(burst_size));
+    assert_eq!(Some(burst_size), limiter.rate());
+
+    // the first burst can be sent immediately
+    for _ in 0..MAX_BURST_PACKETS {
+        limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+        assert_eq!(Some(now), limiter.earliest_departure_time());
+    }
+
+    // the next burst is delayed until the previous burst has been sent at the configured rate
+    limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+    assert_eq!(
+        Some(now + Duration::from_secs(1)),
+        limiter.earliest_departure_time()
+    );
+
+    // updating the rate keeps the current slot and spaces out the following slots at the new rate
+    limiter.set_rate(Some(burst_size * 2));
+    assert_eq!(
+        Some(now + Duration::from_secs(1)),
+        limiter.earliest_departure_time()
+    );
+    for _ in 1..MAX_BURST_PACKETS {
+        limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+        assert_eq!(
+            Some(now + Duration::from_secs(1)),
+            limiter.earliest_departure_time()
+        );
+    }
+    limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+    assert_eq!(
+        Some(now + Duration::from_millis(1500)),
+        limiter.earliest_departure_time()
+    );
+
+    // disabling the limiter clears the departure time
+    limiter.set_rate(None);
+    assert_eq!(None, limiter.earliest_departure_time());
+}
+
+#[test]
+fn rate_limiter_repeated_set_rate() {
+    let mut limiter = RateLimiter::default();
+    let now = NoopClock.get_time();
+
+    let burst_size = (MAX_BURST_PACKETS * MINIMUM_MTU) as u64;
+    limiter.set_rate(Some(burst_size));
+
+    // use up the first burst and start the next slot
+    for _ in 0..=MAX_BURST_PACKETS {
+        limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+    }
+    assert_eq!(
+        Some(now + Duration::from_secs(1)),
+        limiter.earliest_departure_time()
+    );
+
+    // setting the rate again doesn't refill the burst
+    for _ in 0..10 {
+        limiter.set_rate(Some(burst_size));
+        limiter.on_packet_sent(now, MINIMUM_MTU as usize, MINIMUM_MTU);
+    }
+
+    // the second slot was used up, so the last packet starts a third slot a second later
+    assert_eq!(
+        Some(now + Duration::from_secs(2)),
+        limiter.earliest_departure_time()
+    );
+}
@@ -43,6 +43,8 @@ pub struct RttEstimator {
     max_ack_delay: Duration,
     /// The time that the first RTT sample was obtained
     first_rtt_sample: Option<Timestamp>,
+    /// The local timer granularity (kGranularity)
+    granularity: Duration,
 }
 
 impl Default for RttEstimator {
@@ -74,9 +76,18 @@ impl RttEstimator {
             rttvar,
             max_ack_delay,
             first_rtt_sample: None,
+            granularity: K_GRANULARITY,
         }
     }
 
+    /// Sets the local timer granularity (kGranularity) used as the lower bound of the variance
+    /// term in the PTO period
+    #[inline]
+    pub fn with_granularity(mut self, granularity: Duration) -> Self {
+        self.granularity = granularity;
+        self
+    }
+
     /// Gets the latest round trip time sample
     #[inline]
     pub fn latest_rtt(&self) -> Duration {
@@ -113,6 +124,12 @@ impl RttEstimator {
         self.max_ack_delay
     }
 
+    /// Gets the local timer granularity (kGranularity)
+    #[inline]
+    pub fn granularity(&self) -> Duration {
+        self.granularity
+    }
+
     //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
     //# The PTO period is the amount of time that a sender ought to wait for
     //# an acknowledgement of a sent packet.
@@ -128,7 +145,7 @@ impl RttEstimator {
         //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
         //# The PTO period MUST be at least kGranularity, to avoid the timer
         //# expiring immediately.
-        pto_period += max(4 * self.rttvar(), K_GRANULARITY);
+        pto_period += max(4 * self.rttvar(), self.granularity);
 
         //= https://www.rfc-editor.org/rfc/rfc9002#section-6.2.1
         //# When the PTO is armed for Initial or Handshake packet number spaces,
@@ -268,7 +285,7 @@ impl RttEstimator {
         //# establishing persistent congestion, including some in response to PTO
         //# expiration, as TCP does with Tail Loss Probes [RFC8985] and an RTO
         //# [RFC5681].
-        (self.smoothed_rtt + max(4 * self.rttvar, K_GRANULARITY) + self.max_ack_delay)
+        (self.smoothed_rtt + max(4 * self.rttvar, self.granularity) + self.max_ack_delay)
             * K_PERSISTENT_CONGESTION_THRESHOLD
     }
 
@@ -678,4 +695,28 @@ mod test {
         let pto_period = rtt_estimator.pto_period(INITIAL_PTO_BACKOFF, space);
         assert!(pto_period >= K_GRANULARITY);
     }
+
+    #[test]
+    fn pto_must_be_at_least_configured_granularity() {
+        let space = PacketNumberSpace::Handshake;
+        let granularity = Duration::from_millis(15);
+        let now = NoopClock.get_time();
+        let mut rtt_estimator = RttEstimator::new(Duration::ZERO).with_granularity(granularity);
+        assert_eq!(rtt_estimator.granularity(), granularity);
+
+        rtt_estimator.update_rtt(
+            Duration::from_millis(0),
+            Duration::from_nanos(1),
+            now,
+            true,
+            space,
+        );
+
+        let pto_period = rtt_estimator.pto_period(INITIAL_PTO_BACKOFF, space);
+        assert!(pto_period >= granularity);
+        assert!(
+            rtt_estimator.persistent_congestion_threshold()
+                >= granularity * super::K_PERSISTENT_CONGESTION_THRESHOLD
+        );
+    }
 }
@@ -2,8 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    frame::ack_elicitation::AckElicitation, inet::ExplicitCongestionNotification, path,
-    time::Timestamp, transmission,
+    frame::{self, ack_elicitation::AckElicitation},
+    inet::ExplicitCongestionNotification,
+    path,
+    time::Timestamp,
+    transmission,
 };
 use core::convert::TryInto;
 
@@ -32,6 +35,8 @@ pub struct SentPacketInfo<PacketInfo> {
     pub ecn: ExplicitCongestionNotification,
     /// Indicates if the packet was part of a probe transmission
     pub transmission_mode: transmission::Mode,
+    /// A summary of the frames contained in the packet
+    pub frames: frame::Summary,
     /// Additional packet metadata dictated by the congestion controller
     pub cc_packet_info: PacketInfo,
 }
@@ -64,9 +69,18 @@ impl<PacketInfo> SentPacketInfo<PacketInfo> {
             path_id,
             ecn,
             transmission_mode,
+            frames: frame::Summary::default(),
             cc_packet_info,
         }
     }
+
+    /// Sets the summary of the frames contained in the packet
+    #[inline]
+    #[must_use]
+    pub fn with_frames(mut self, frames: frame::Summary) -> Self {
+        self.frames = frames;
+        self
+    }
 }
 
 #[cfg(test)]
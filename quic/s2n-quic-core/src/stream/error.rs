@@ -66,6 +66,14 @@ pub enum StreamError {
     NonEmptyOutput {
         source: &'static panic::Location<'static>,
     },
+    /// The amount of data requested by an exact receive exceeds what the stream can buffer
+    ///
+    /// The amount of data buffered on a stream is bounded by its receive window, which means
+    /// the request could never be fulfilled.
+    #[non_exhaustive]
+    ReceiveWindowExceeded {
+        source: &'static panic::Location<'static>,
+    },
 }
 
 #[cfg(feature = "std")]
@@ -98,6 +106,10 @@ impl fmt::Display for StreamError {
                 f,
                 "The stream was provided a non-empty placeholder buffer for receiving data."
             ),
+            Self::ReceiveWindowExceeded { .. } => write!(
+                f,
+                "The requested amount of data exceeds what the stream can buffer"
+            ),
         }
     }
 }
@@ -115,6 +127,7 @@ impl StreamError {
             StreamError::NonWritable { source } => source,
             StreamError::SendingBlocked { source } => source,
             StreamError::NonEmptyOutput { source } => source,
+            StreamError::ReceiveWindowExceeded { source } => source,
         }
     }
 
@@ -181,6 +194,14 @@ impl StreamError {
         let source = panic::Location::caller();
         StreamError::NonEmptyOutput { source }
     }
+
+    #[track_caller]
+    #[inline]
+    #[doc(hidden)]
+    pub fn receive_window_exceeded() -> StreamError {
+        let source = panic::Location::caller();
+        StreamError::ReceiveWindowExceeded { source }
+    }
 }
 
 impl application::error::TryInto for StreamError {
@@ -237,6 +258,7 @@ impl From<StreamError> for std::io::ErrorKind {
             StreamError::NonWritable { .. } => ErrorKind::Other,
             StreamError::SendingBlocked { .. } => ErrorKind::WouldBlock,
             StreamError::NonEmptyOutput { .. } => ErrorKind::InvalidInput,
+            StreamError::ReceiveWindowExceeded { .. } => ErrorKind::InvalidInput,
         }
     }
 }
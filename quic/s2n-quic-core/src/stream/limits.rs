@@ -5,6 +5,7 @@ use crate::{
     transport::parameters::{InitialMaxStreamsBidi, InitialMaxStreamsUni, ValidationError},
     varint::VarInt,
 };
+use s2n_codec::decoder_invariant;
 
 // TODO investigate a good default
 /// The default send buffer size for Streams
@@ -29,6 +30,13 @@ pub struct Limits {
     /// is not communicated to the peer, it is only used for limiting
     /// concurrent streams opened locally by the application.
     pub max_open_local_bidirectional_streams: LocalBidirectional,
+    /// The maximum number of streams the peer may open per second, for each
+    /// stream type. Once this rate is exceeded, additional stream credits are
+    /// withheld from the peer until the current one-second window expires.
+    pub max_remote_open_rate: MaxRemoteOpenRate,
+    /// The amount of time a stream may wait to transmit data it has buffered before it is
+    /// reported as starved. `None` disables the detection.
+    pub starvation_threshold: Option<core::time::Duration>,
 }
 
 impl Default for Limits {
@@ -42,6 +50,8 @@ impl Limits {
         max_send_buffer_size: MaxSendBufferSize::RECOMMENDED,
         max_open_local_unidirectional_streams: LocalUnidirectional::RECOMMENDED,
         max_open_local_bidirectional_streams: LocalBidirectional::RECOMMENDED,
+        max_remote_open_rate: MaxRemoteOpenRate::RECOMMENDED,
+        starvation_threshold: None,
     };
 }
 
@@ -96,6 +106,38 @@ impl TryFrom<u32> for MaxSendBufferSize {
     }
 }
 
+local_limits!(MaxRemoteOpenRate(u32));
+
+impl MaxRemoteOpenRate {
+    /// By default, the rate at which the peer opens streams is not limited
+    pub const RECOMMENDED: Self = Self(u32::MAX);
+
+    #[inline]
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if the rate at which the peer opens streams is limited
+    #[inline]
+    pub fn is_limited(self) -> bool {
+        self.0 != u32::MAX
+    }
+}
+
+impl TryFrom<u32> for MaxRemoteOpenRate {
+    type Error = ValidationError;
+
+    #[inline]
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        // a rate of zero would prevent the peer from ever opening a stream
+        decoder_invariant!(
+            value > 0,
+            "max_remote_open_stream_rate must be greater than zero"
+        );
+        Ok(Self(value))
+    }
+}
+
 varint_local_limits!(LocalUnidirectional(VarInt));
 
 impl LocalUnidirectional {
@@ -101,6 +101,18 @@ impl<'a> Request<'a> {
         self
     }
 
+    /// Sets the low watermark for the rx stream and requires it to be reached
+    ///
+    /// Unlike [`Self::with_low_watermark`], the caller will not be woken before `low` bytes are
+    /// available to be read, unless the stream has finished. If the stream can not buffer `low`
+    /// bytes within its receive window, the request fails with
+    /// [`StreamError::ReceiveWindowExceeded`](stream::StreamError::ReceiveWindowExceeded).
+    pub fn with_exact_low_watermark(&mut self, low: usize) -> &mut Self {
+        self.with_low_watermark(low);
+        self.rx_mut().exact = true;
+        self
+    }
+
     /// Sets the high watermark for the rx stream
     ///
     /// The stream ensures that all the received data will not exceed the watermark amount. This
@@ -213,6 +225,15 @@ pub mod tx {
 
         /// The current status of the stream
         pub status: Status,
+
+        /// The number of bytes of stream data which have been transmitted more than once
+        pub retransmitted_bytes: u64,
+
+        /// The time between the first transmission of stream data and its first acknowledgement
+        pub time_to_first_ack: Option<core::time::Duration>,
+
+        /// The number of bytes of stream data which have been transmitted at least once
+        pub sent_bytes: u64,
     }
 
     impl Default for Response {
@@ -222,6 +243,9 @@ pub mod tx {
                 chunks: Chunks::default(),
                 will_wake: false,
                 status: Status::Open,
+                retransmitted_bytes: 0,
+                time_to_first_ack: None,
+                sent_bytes: 0,
             }
         }
     }
@@ -262,6 +286,12 @@ pub mod rx {
         /// least `low` bytes available to be read. Note that the stream may be woken earlier.
         pub low_watermark: usize,
 
+        /// Requires the low watermark to be reached before the caller is notified
+        ///
+        /// The request fails if the low watermark exceeds the amount of data the stream can
+        /// buffer within its receive window.
+        pub exact: bool,
+
         /// Sets the high watermark for the rx stream
         ///
         /// The stream ensures that all the received data will not exceed the watermark amount. This
@@ -281,6 +311,7 @@ pub mod rx {
             Self {
                 chunks: None,
                 low_watermark: 0,
+                exact: false,
                 high_watermark: core::usize::MAX,
                 stop_sending: None,
                 detached: false,
@@ -303,6 +334,9 @@ pub mod rx {
 
         /// The current status of the stream
         pub status: Status,
+
+        /// Information about the out-of-order data buffered on the stream
+        pub reassembly: Reassembly,
     }
 
     impl Default for Response {
@@ -312,6 +346,7 @@ pub mod rx {
                 chunks: Chunks::default(),
                 will_wake: false,
                 status: Status::Open,
+                reassembly: Reassembly::default(),
             }
         }
     }
@@ -327,6 +362,17 @@ pub mod rx {
             Some(self)
         }
     }
+
+    /// Reassembly buffer usage of a `rx` stream
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub struct Reassembly {
+        /// The number of bytes that were received out of order and are waiting for earlier
+        /// data to arrive before they can be consumed.
+        pub out_of_order_bytes: u64,
+
+        /// The size of the largest range of missing data that precedes buffered bytes.
+        pub largest_gap: u64,
+    }
 }
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -516,6 +562,7 @@ mod tests {
                 rx: Some(rx::Request {
                     chunks: Some(rx_chunks),
                     low_watermark: 5,
+                    exact: false,
                     high_watermark: 10,
                     stop_sending: Some(stop_sending),
                     detached: false,
@@ -1,7 +1,10 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::frame::ack_elicitation::{AckElicitable, AckElicitation};
+use crate::frame::{
+    self,
+    ack_elicitation::{AckElicitable, AckElicitation},
+};
 use core::ops::AddAssign;
 
 pub mod constraint;
@@ -16,6 +19,14 @@ pub struct Outcome {
     pub is_congestion_controlled: bool,
     pub bytes_sent: usize,
     pub bytes_progressed: usize,
+    /// The number of bytes of PADDING frames written
+    pub bytes_padded: usize,
+    /// The number of stream and CRYPTO data bytes written for the first time
+    pub bytes_new_data: usize,
+    /// The number of stream and CRYPTO data bytes written which were already sent previously
+    pub bytes_retransmitted: usize,
+    /// A summary of the frames written
+    pub frames: frame::Summary,
 }
 
 impl AckElicitable for Outcome {
@@ -30,5 +41,9 @@ impl AddAssign for Outcome {
         self.is_congestion_controlled |= rhs.is_congestion_controlled;
         self.bytes_sent += rhs.bytes_sent;
         self.bytes_progressed += rhs.bytes_progressed;
+        self.bytes_padded += rhs.bytes_padded;
+        self.bytes_new_data += rhs.bytes_new_data;
+        self.bytes_retransmitted += rhs.bytes_retransmitted;
+        self.frames += rhs.frames;
     }
 }
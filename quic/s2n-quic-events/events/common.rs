@@ -9,6 +9,9 @@ struct ConnectionMeta {
 
     #[builder(crate::time::Timestamp)]
     timestamp: crate::event::Timestamp,
+
+    /// The application-defined tags attached to the connection
+    tags: crate::connection::Tags,
 }
 
 struct EndpointMeta {
@@ -366,6 +369,35 @@ enum Frame {
     },
 }
 
+/// A summary of the frame types contained in a packet
+struct FrameSummary {
+    /// Set if the packet contained an ACK frame
+    ack: bool,
+    /// Set if the packet contained a PING frame
+    ping: bool,
+    /// Set if the packet contained a CRYPTO frame
+    crypto: bool,
+    /// Set if the packet contained a STREAM frame
+    stream: bool,
+    /// Set if the packet contained a RESET_STREAM or STOP_SENDING frame
+    stream_control: bool,
+    /// Set if the packet contained a frame which updates or reports a flow control limit
+    ///
+    /// These are the MAX_DATA, MAX_STREAM_DATA, MAX_STREAMS, DATA_BLOCKED, STREAM_DATA_BLOCKED and
+    /// STREAMS_BLOCKED frames.
+    flow_control: bool,
+    /// Set if the packet contained a DATAGRAM frame
+    datagram: bool,
+    /// Set if the packet contained any other frame, not including PADDING frames
+    other: bool,
+    /// The number of frames in the packet, not including PADDING frames
+    frame_count: usize,
+    /// The lowest and highest ids of the streams with STREAM frames in the packet
+    ///
+    /// Stream ids above `u32::MAX` are reported as `u32::MAX`.
+    stream_ids: Option<RangeInclusive<u64>>,
+}
+
 impl IntoEvent<builder::Frame> for &crate::frame::Padding {
     fn into_event(self) -> builder::Frame {
         builder::Frame::Padding {}
@@ -832,6 +864,46 @@ enum PathChallengeStatus {
     Abandoned,
 }
 
+/// The reason a local connection ID was retired
+enum LocalConnectionIdRetirementCause {
+    /// The peer retired the connection ID with a RETIRE_CONNECTION_ID frame
+    Peer,
+    /// The connection ID reached the end of its lifetime
+    Expired,
+    /// The connection ID used during the handshake was retired once the handshake was confirmed
+    HandshakeConfirmed,
+}
+
+/// The reason an ACK frame was sent
+enum AckSentReason {
+    /// The number of packets received since the last ACK frame reached the packet tolerance
+    PacketTolerance,
+    /// The `max_ack_delay` timer expired
+    Timer,
+    /// A packet was received out of order or after a gap in packet numbers
+    Reordering,
+    /// A packet was received with an ECN Congestion Experienced marking
+    CongestionExperienced,
+    /// A PATH_CHALLENGE frame was received on the active path
+    PathChallenge,
+    /// A packet which contained a previous ACK frame was declared lost
+    Loss,
+    /// The ACK frame was bundled with other frames that were being transmitted
+    Piggyback,
+}
+
+/// The reason a packet was sent
+enum PacketSentReason {
+    /// The packet was sent as part of normal transmission
+    Normal,
+    /// The packet was sent as a probe after the probe timeout (PTO) expired
+    PtoProbe,
+    /// The packet only carried data which was already sent in a previous packet
+    Retransmit,
+    /// The packet was not ack-eliciting, for example because it only contained an ACK frame
+    AckOnly,
+}
+
 /// The reason the slow start congestion controller state has been exited
 enum SlowStartExitCause {
     /// A packet was determined lost
@@ -857,3 +929,41 @@ enum MtuUpdatedCause {
     /// A blackhole was detected
     Blackhole,
 }
+
+/// The reason PADDING frames were added to a datagram
+enum PaddingReason {
+    /// An ack-eliciting Initial packet was padded so the datagram reaches the minimum size of
+    /// 1200 bytes
+    InitialMinimumSize,
+    /// An ack-eliciting Initial packet was coalesced with later packets and the last of those was
+    /// padded so the datagram reaches the minimum size of 1200 bytes
+    CoalescedMinimumSize,
+    /// The datagram carried a PATH_CHALLENGE or PATH_RESPONSE frame and was padded to validate
+    /// the path MTU
+    PathValidation,
+    /// A packet was padded so it is indistinguishable from a stateless reset, or to fill space at
+    /// the end of the datagram which was too small for another packet
+    MinimumPacketSize,
+}
+
+/// The scope of a flow control window
+enum FlowControlScope {
+    /// The window applies to the data sent on all streams of the connection
+    Connection,
+    /// The window applies to the data sent on a single stream
+    Stream { id: u64 },
+}
+
+/// The direction of data which is limited by a flow control window
+enum FlowControlDirection {
+    /// The window limits the data which the peer is allowed to send
+    ///
+    /// The window is updated once the local endpoint transmits a MAX_DATA or MAX_STREAM_DATA frame
+    /// with a higher limit.
+    Receive,
+    /// The window limits the data which the local endpoint is allowed to send
+    ///
+    /// The window is updated once a MAX_DATA or MAX_STREAM_DATA frame with a higher limit is
+    /// received from the peer.
+    Send,
+}
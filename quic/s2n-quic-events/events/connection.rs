@@ -21,6 +21,7 @@ struct ServerNameInformation<'a> {
 /// Packet was sent by a connection
 struct PacketSent {
     packet_header: PacketHeader,
+    sent_reason: PacketSentReason,
 }
 
 #[event("transport:packet_received")]
@@ -78,6 +79,8 @@ struct PacketLost<'a> {
     path: Path<'a>,
     bytes_lost: u16,
     is_mtu_probe: bool,
+    /// The frames which were contained in the lost packet
+    frames: FrameSummary,
 }
 
 #[event("recovery:metrics_updated")]
@@ -90,12 +93,24 @@ struct RecoveryMetrics<'a> {
     latest_rtt: Duration,
     rtt_variance: Duration,
     max_ack_delay: Duration,
+    /// The local timer granularity used as the lower bound of the PTO period
+    timer_granularity: Duration,
     pto_count: u32,
     congestion_window: u32,
     bytes_in_flight: u32,
     congestion_limited: bool,
 }
 
+#[event("recovery:rtt_sample")]
+/// A new RTT sample was taken for the path
+struct RttSample<'a> {
+    path: Path<'a>,
+    latest_rtt: Duration,
+    min_rtt: Duration,
+    smoothed_rtt: Duration,
+    rtt_variance: Duration,
+}
+
 #[event("recovery:congestion")]
 /// Congestion (ECN or packet loss) has occurred
 struct Congestion<'a> {
@@ -136,6 +151,42 @@ struct AckRangeReceived<'a> {
     packet_header: PacketHeader,
     path: Path<'a>,
     ack_range: RangeInclusive<u64>,
+    /// The largest packet number acknowledged by the ACK frame containing the range
+    largest_acknowledged: u64,
+    /// The ACK delay reported by the peer in the ACK frame containing the range
+    ack_delay: Duration,
+}
+
+#[event("recovery:duplicate_ack_received")]
+/// ACK frame was received which did not acknowledge any new packets
+///
+/// All of the packets in the frame were either already acknowledged, declared lost, or never
+/// sent. This is expected occasionally due to reordering and retransmitted ACK frames, but a
+/// high rate may indicate a misbehaving peer or replayed ACK frames.
+struct DuplicateAckReceived<'a> {
+    packet_header: PacketHeader,
+    path: Path<'a>,
+    /// The largest packet number acknowledged by the ACK frame
+    largest_acknowledged: u64,
+}
+
+#[event("recovery:ack_sent")]
+/// ACK frame was sent
+struct AckSent {
+    packet_header: PacketHeader,
+    path_id: u64,
+    reason: AckSentReason,
+}
+
+#[event("recovery:ack_eliciting_packet_received")]
+/// Ack-eliciting packet was received and processed by the ACK manager
+struct AckElicitingPacketReceived<'a> {
+    packet_header: PacketHeader,
+    path: Path<'a>,
+    /// The reason the packet caused an ACK frame to be sent immediately
+    ///
+    /// `None` indicates the ACK frame was delayed by up to `max_ack_delay`.
+    immediate_ack_reason: Option<AckSentReason>,
 }
 
 #[event("transport:packet_dropped")]
@@ -197,6 +248,17 @@ struct DatagramSent {
     ///
     /// See the [Linux kernel documentation](https://www.kernel.org/doc/html/latest/networking/segmentation-offloads.html#generic-segmentation-offload) for more details.
     gso_offset: usize,
+    /// The number of bytes of PADDING frames in the datagram
+    padding_len: u16,
+    /// The reason the datagram was padded, if it contains any PADDING frames
+    padding_reason: Option<PaddingReason>,
+    /// The number of QUIC packets coalesced into the datagram
+    packet_count: u8,
+    /// The length of the Initial packet in the datagram, if it contains one
+    ///
+    /// Datagrams carrying Initial packets are expanded to at least 1200 bytes, either by padding
+    /// the Initial packet itself or by padding the last packet coalesced after it.
+    initial_packet_len: Option<u16>,
 }
 
 #[event("transport:datagram_received")]
@@ -225,6 +287,26 @@ struct ConnectionIdUpdated<'a> {
     current: ConnectionId<'a>,
 }
 
+#[event("connectivity:local_connection_id_issued")]
+/// A local connection ID was issued to the peer in a NEW_CONNECTION_ID frame
+///
+/// This is only published the first time the connection ID is transmitted, not when a lost
+/// NEW_CONNECTION_ID frame is retransmitted.
+struct LocalConnectionIdIssued<'a> {
+    connection_id: ConnectionId<'a>,
+    sequence_number: u64,
+    /// The sequence number below which the peer was requested to retire connection IDs
+    retire_prior_to: u64,
+}
+
+#[event("connectivity:local_connection_id_retired")]
+/// A local connection ID was retired
+struct LocalConnectionIdRetired<'a> {
+    connection_id: ConnectionId<'a>,
+    sequence_number: u64,
+    cause: LocalConnectionIdRetirementCause,
+}
+
 #[event("recovery:ecn_state_changed")]
 struct EcnStateChanged<'a> {
     path: Path<'a>,
@@ -289,3 +371,92 @@ struct SlowStartExited<'a> {
     cause: SlowStartExitCause,
     congestion_window: u32,
 }
+
+#[event("transport:stream_open_rate_limited")]
+/// The peer opened streams faster than the configured rate and MAX_STREAMS credit was delayed
+struct StreamOpenRateLimited {
+    stream_type: StreamType,
+    /// The number of streams that were withheld from the peer during the rate window
+    deferred_streams: u64,
+}
+
+#[event("transport:stream_starved")]
+/// A stream has waited at least the configured starvation threshold to transmit its data
+///
+/// The event is published once per wait, either when the threshold is reached or when the stream
+/// transmits, whichever happens first.
+struct StreamStarved {
+    id: u64,
+    /// The amount of time the stream waited with data to send
+    duration: Duration,
+}
+
+#[event("transport:stream_closed")]
+/// A stream was closed and its state was released
+struct StreamClosed {
+    id: u64,
+    /// The number of bytes of stream data which were retransmitted
+    retransmitted_bytes: u64,
+}
+
+#[event("transport:flow_control_window_changed")]
+/// A connection or stream flow control window was increased
+struct FlowControlWindowChanged {
+    scope: FlowControlScope,
+    direction: FlowControlDirection,
+    /// The previous maximum offset of data allowed by the window
+    old_window: u64,
+    /// The new maximum offset of data allowed by the window
+    new_window: u64,
+}
+
+#[event("connectivity:connection_idle")]
+/// The connection has no open streams and no data in flight
+///
+/// This is only emitted once the handshake has completed.
+struct ConnectionIdle {}
+
+#[event("connectivity:connection_active")]
+/// The connection has resumed activity after previously becoming idle
+struct ConnectionActive {}
+
+#[event("recovery:send_rate_limit_updated")]
+/// The application updated the send rate limit of the connection
+struct SendRateLimitUpdated {
+    /// The maximum number of bytes sent per second, or `None` if the limit was removed
+    bytes_per_sec: Option<u64>,
+}
+
+#[event("recovery:congestion_limited")]
+/// The connection has become limited by the congestion window
+///
+/// A connection is congestion limited while it has data to send on the active path, but the
+/// congestion window is full. `CongestionLimitEnded` is published once the connection is able
+/// to send again, or no longer has data to send.
+struct CongestionLimited {
+    path_id: u64,
+    /// The congestion window when the connection became congestion limited
+    congestion_window: u32,
+}
+
+#[event("recovery:congestion_limit_ended")]
+/// The connection is no longer limited by the congestion window
+struct CongestionLimitEnded {
+    path_id: u64,
+    /// The amount of time the connection was congestion limited
+    duration: Duration,
+    /// The congestion window when the connection became congestion limited
+    congestion_window: u32,
+}
+
+#[event("recovery:crypto_retransmitted")]
+/// CRYPTO data was retransmitted in an Initial or Handshake packet
+///
+/// This is emitted separately from the retransmission of stream data so delays in connection
+/// establishment caused by loss of handshake packets can be identified.
+struct CryptoRetransmitted {
+    packet_header: PacketHeader,
+    path_id: u64,
+    /// The number of CRYPTO bytes which were retransmitted in the packet
+    bytes: u64,
+}
@@ -66,3 +66,28 @@ struct EndpointDatagramDropped {
 struct EndpointConnectionAttemptFailed {
     error: crate::connection::Error,
 }
+
+#[event("connectivity:endpoint_handshake_rate_limited")]
+#[subject(endpoint)]
+/// The endpoint handshake rate limit was reached
+///
+/// This is emitted once for each one-second window in which the limit is reached. All further
+/// connection attempts in the window are deferred with a Retry packet or refused.
+struct EndpointHandshakeRateLimited {
+    /// The configured maximum number of handshakes per second
+    handshakes_per_sec: usize,
+    /// Whether the attempts are deferred with a Retry packet rather than refused
+    retry: bool,
+}
+
+#[event("connectivity:endpoint_memory_pressure")]
+#[subject(endpoint)]
+/// A connection attempt was refused because the endpoint is under memory pressure
+struct EndpointMemoryPressure {
+    /// The number of bytes buffered by open connections
+    memory_usage: u64,
+    /// The configured endpoint memory limit
+    memory_limit: u64,
+    /// The number of open connections
+    connection_count: usize,
+}
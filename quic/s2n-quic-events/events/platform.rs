@@ -47,6 +47,21 @@ impl From<PlatformRxError> for std::io::Error {
     }
 }
 
+#[event("platform:io_recv_overflow")]
+#[subject(endpoint)]
+/// Emitted when the operating system reports datagrams which were dropped because the socket
+/// receive buffer was full
+///
+/// These drops occur before the datagrams reach the endpoint and are distinct from loss on the
+/// network. Frequent overflows indicate the receive buffer size should be increased or more
+/// sockets should be used to receive.
+struct IoRecvOverflow {
+    /// The number of datagrams dropped since the previous event
+    count: u64,
+    /// The total number of datagrams dropped since the socket was opened
+    total_count: u64,
+}
+
 #[event("platform:feature_configured")]
 #[subject(endpoint)]
 /// Emitted when a platform feature is configured
@@ -18,6 +18,7 @@ struct Output {
     pub connection_publisher_subscriber: TokenStream,
     pub tuple_subscriber: TokenStream,
     pub tracing_subscriber: TokenStream,
+    pub timing_subscriber: TokenStream,
     pub builders: TokenStream,
     pub api: TokenStream,
     pub testing_fields: TokenStream,
@@ -38,6 +39,7 @@ impl ToTokens for Output {
             connection_publisher_subscriber,
             tuple_subscriber,
             tracing_subscriber,
+            timing_subscriber,
             builders,
             api,
             testing_fields,
@@ -99,13 +101,109 @@ impl ToTokens for Output {
                                 self.server.id()
                             }
                         };
-                        tracing::span!(target: "s2n_quic", parent: parent, tracing::Level::DEBUG, "conn", id = meta.id)
+                        tracing::span!(target: "s2n_quic", parent: parent, tracing::Level::DEBUG, "conn", id = meta.id, tags = tracing::field::debug(&meta.tags))
                     }
 
                     #tracing_subscriber
                 }
             }
 
+            #[cfg(feature = "std")]
+            pub mod timing {
+                //! This module contains a subscriber which measures the time spent in each callback
+                //! of another subscriber
+                use super::{api, query, supervisor, Event, Meta};
+                use core::time::Duration;
+                use std::time::Instant;
+
+                /// Records the time spent in each callback of a timed [`Subscriber`]
+                pub trait Recorder: 'static + Send {
+                    /// Called after each callback with the name of the callback and the time spent in it
+                    fn on_callback(&mut self, name: &'static str, elapsed: Duration);
+                }
+
+                impl<F: 'static + Send + FnMut(&'static str, Duration)> Recorder for F {
+                    #[inline]
+                    fn on_callback(&mut self, name: &'static str, elapsed: Duration) {
+                        (self)(name, elapsed)
+                    }
+                }
+
+                /// Wraps a subscriber and measures the time spent in each of its callbacks
+                #[derive(Clone, Debug)]
+                pub struct Subscriber<S, R> {
+                    subscriber: S,
+                    recorder: R,
+                }
+
+                impl<S, R> Subscriber<S, R> {
+                    /// Creates a subscriber which reports the time spent in each callback of `subscriber`
+                    /// to `recorder`
+                    pub fn new(subscriber: S, recorder: R) -> Self {
+                        Self { subscriber, recorder }
+                    }
+
+                    /// Returns a reference to the recorder
+                    pub fn recorder(&self) -> &R {
+                        &self.recorder
+                    }
+                }
+
+                impl<S: super::Subscriber, R: Recorder> super::Subscriber for Subscriber<S, R> {
+                    type ConnectionContext = S::ConnectionContext;
+
+                    #[inline]
+                    fn create_connection_context(&mut self, meta: &api::ConnectionMeta, info: &api::ConnectionInfo) -> Self::ConnectionContext {
+                        let start = Instant::now();
+                        let context = self.subscriber.create_connection_context(meta, info);
+                        self.recorder.on_callback("create_connection_context", start.elapsed());
+                        context
+                    }
+
+                    #[inline]
+                    fn supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &api::ConnectionMeta, context: &supervisor::Context) -> Option<Duration> {
+                        let start = Instant::now();
+                        let timeout = self.subscriber.supervisor_timeout(conn_context, meta, context);
+                        self.recorder.on_callback("supervisor_timeout", start.elapsed());
+                        timeout
+                    }
+
+                    #[inline]
+                    fn on_supervisor_timeout(&mut self, conn_context: &mut Self::ConnectionContext, meta: &api::ConnectionMeta, context: &supervisor::Context) -> supervisor::Outcome {
+                        let start = Instant::now();
+                        let outcome = self.subscriber.on_supervisor_timeout(conn_context, meta, context);
+                        self.recorder.on_callback("on_supervisor_timeout", start.elapsed());
+                        outcome
+                    }
+
+                    #timing_subscriber
+
+                    #[inline]
+                    fn on_event<M: Meta, E: Event>(&mut self, meta: &M, event: &E) {
+                        let start = Instant::now();
+                        self.subscriber.on_event(meta, event);
+                        self.recorder.on_callback("on_event", start.elapsed());
+                    }
+
+                    #[inline]
+                    fn on_connection_event<E: Event>(&mut self, context: &mut Self::ConnectionContext, meta: &api::ConnectionMeta, event: &E) {
+                        let start = Instant::now();
+                        self.subscriber.on_connection_event(context, meta, event);
+                        self.recorder.on_callback("on_connection_event", start.elapsed());
+                    }
+
+                    #[inline]
+                    fn query(context: &Self::ConnectionContext, query: &mut dyn query::Query) -> query::ControlFlow {
+                        S::query(context, query)
+                    }
+
+                    #[inline]
+                    fn query_mut(context: &mut Self::ConnectionContext, query: &mut dyn query::QueryMut) -> query::ControlFlow {
+                        S::query_mut(context, query)
+                    }
+                }
+            }
+
             pub mod builder {
                 use super::*;
 
@@ -140,9 +140,9 @@ impl Struct {
 
             let ident_str = ident.to_string();
             let snake = ident_str.to_snake_case();
-            let function = format!("on_{}", snake);
+            let function_name = format!("on_{}", snake);
             let counter = Ident::new(&snake, Span::call_site());
-            let function = Ident::new(&function, Span::call_site());
+            let function = Ident::new(&function_name, Span::call_site());
 
             let subscriber_doc = format!("Called when the `{}` event is triggered", ident_str);
             let publisher_doc = format!(
@@ -180,6 +180,16 @@ impl Struct {
                         }
                     ));
 
+                    output.timing_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, meta: &api::EndpointMeta, event: &api::#ident) {
+                            let start = Instant::now();
+                            self.subscriber.#function(meta, event);
+                            self.recorder.on_callback(#function_name, start.elapsed());
+                        }
+                    ));
+
                     output.tracing_subscriber.extend(quote!(
                         #[inline]
                         #allow_deprecated
@@ -251,6 +261,16 @@ impl Struct {
                         }
                     ));
 
+                    output.timing_subscriber.extend(quote!(
+                        #[inline]
+                        #allow_deprecated
+                        fn #function(&mut self, context: &mut Self::ConnectionContext, meta: &api::ConnectionMeta, event: &api::#ident) {
+                            let start = Instant::now();
+                            self.subscriber.#function(context, meta, event);
+                            self.recorder.on_callback(#function_name, start.elapsed());
+                        }
+                    ));
+
                     output.tracing_subscriber.extend(quote!(
                         #[inline]
                         #allow_deprecated
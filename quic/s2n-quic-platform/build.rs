@@ -21,6 +21,7 @@ fn main() -> Result<(), Error> {
             supports("gso");
             supports("mtu_disc");
             supports("pktinfo");
+            supports("rxq_ovfl");
             supports("tos");
         }
         "macos" => {
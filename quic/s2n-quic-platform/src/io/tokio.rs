@@ -60,9 +60,11 @@ impl Io {
             send_addr,
             recv_buffer_size,
             send_buffer_size,
+            recv_batch_size,
             max_mtu,
             max_segments,
             reuse_port,
+            event_loop_mode,
         } = self.builder;
 
         endpoint.set_max_mtu(max_mtu);
@@ -245,6 +247,22 @@ impl Io {
             }
         }
 
+        // Set up the RX socket to report the number of datagrams dropped due to a full receive
+        // buffer
+        #[cfg(s2n_quic_platform_rxq_ovfl)]
+        {
+            use std::os::unix::io::AsRawFd;
+            let enabled: libc::c_int = 1;
+
+            libc!(setsockopt(
+                rx_socket.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_RXQ_OVFL,
+                &enabled as *const _ as _,
+                core::mem::size_of_val(&enabled) as _,
+            ))?;
+        }
+
         cfg_if! {
             if #[cfg(any(s2n_quic_platform_socket_msg, s2n_quic_platform_socket_mmsg))] {
                 let mut rx = socket::Queue::<buffer::Buffer>::new(buffer::Buffer::default(), max_segments.into());
@@ -267,6 +285,8 @@ impl Io {
             tx_socket: tx_socket.into(),
             rx,
             tx,
+            recv_batch_size: recv_batch_size.unwrap_or(usize::MAX),
+            event_loop_mode,
             endpoint,
         };
 
@@ -341,6 +361,36 @@ fn bind<A: std::net::ToSocketAddrs>(addr: A, reuse_port: bool) -> io::Result<soc
     Ok(socket)
 }
 
+/// Controls how the endpoint's event loop shares its thread with other tasks
+///
+/// * [`EventLoopMode::Dedicated`] keeps running iterations of the event loop for as long as the
+///   endpoint has work to do, and only returns to the runtime when it waits for the socket or a
+///   timer. This maximizes throughput when the event loop has a worker thread of a multi-threaded
+///   runtime to itself, but a busy endpoint can starve the tasks it shares a thread with. This is
+///   the default.
+/// * [`EventLoopMode::Cooperative`] yields back to the runtime after every iteration so other
+///   tasks on the same thread get a chance to run. This adds the cost of rescheduling the event
+///   loop after each iteration. On a current-thread runtime, the endpoint and the application's
+///   tasks are interleaved in an order which only depends on the order they were woken, which,
+///   combined with a paused clock, makes runs reproducible for tests and debugging.
+///
+/// The mode is never inferred from the runtime and only changes how the event loop is scheduled.
+/// In both modes, the application's connection and stream handles synchronize with the endpoint
+/// with the same locks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventLoopMode {
+    /// The event loop only returns to the runtime when it has no work to do
+    Dedicated,
+    /// The event loop yields back to the runtime after every iteration
+    Cooperative,
+}
+
+impl Default for EventLoopMode {
+    fn default() -> Self {
+        Self::Dedicated
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct Builder {
     handle: Option<Handle>,
@@ -350,9 +400,11 @@ pub struct Builder {
     send_addr: Option<std::net::SocketAddr>,
     recv_buffer_size: Option<usize>,
     send_buffer_size: Option<usize>,
+    recv_batch_size: Option<usize>,
     max_mtu: MaxMtu,
     max_segments: gso::MaxSegments,
     reuse_port: bool,
+    event_loop_mode: EventLoopMode,
 }
 
 impl Builder {
@@ -415,11 +467,31 @@ impl Builder {
     }
 
     /// Sets the size of the operating system’s receive buffer associated with the rx socket
+    ///
+    /// On platforms which support it, datagrams dropped because the receive buffer was full are
+    /// reported with the `on_io_recv_overflow` event.
     pub fn with_recv_buffer_size(mut self, recv_buffer_size: usize) -> io::Result<Self> {
         self.recv_buffer_size = Some(recv_buffer_size);
         Ok(self)
     }
 
+    /// Sets the maximum number of datagrams received from the rx socket at a time
+    ///
+    /// Larger batches reduce the number of syscalls and improve throughput, while smaller batches
+    /// reduce the latency of handling the first datagram in a batch. By default, the socket is
+    /// read until the receive queue is full. The number of datagrams received in each batch is
+    /// reported with the `on_platform_rx` event.
+    pub fn with_recv_batch_size(mut self, recv_batch_size: usize) -> io::Result<Self> {
+        if recv_batch_size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "recv_batch_size must be greater than 0",
+            ));
+        }
+        self.recv_batch_size = Some(recv_batch_size);
+        Ok(self)
+    }
+
     /// Sets the largest maximum transmission unit (MTU) that can be sent on a path
     pub fn with_max_mtu(mut self, max_mtu: u16) -> io::Result<Self> {
         self.max_mtu = max_mtu
@@ -428,6 +500,16 @@ impl Builder {
         Ok(self)
     }
 
+    /// Pins the maximum transmission unit (MTU) to the given value, disabling path MTU discovery
+    ///
+    /// This is useful on paths with a known, fixed MTU (such as tunnels and overlay networks),
+    /// where probing for a larger MTU would only waste packets.
+    pub fn with_fixed_mtu(mut self, mtu: u16) -> io::Result<Self> {
+        self = self.with_max_mtu(mtu)?;
+        self.max_mtu = self.max_mtu.fixed();
+        Ok(self)
+    }
+
     /// Disables Generic Segmentation Offload (GSO)
     ///
     /// By default, GSO will be used unless the platform does not support it or an attempt to use
@@ -449,6 +531,16 @@ impl Builder {
         Ok(self)
     }
 
+    /// Sets how the event loop shares its thread with other tasks
+    ///
+    /// Defaults to [`EventLoopMode::Dedicated`]. Endpoints which share a current-thread runtime
+    /// with the application should use [`EventLoopMode::Cooperative`]. See [`EventLoopMode`] for
+    /// the performance and determinism implications of each mode.
+    pub fn with_event_loop_mode(mut self, mode: EventLoopMode) -> io::Result<Self> {
+        self.event_loop_mode = mode;
+        Ok(self)
+    }
+
     pub fn build(self) -> io::Result<Io> {
         Ok(Io { builder: self })
     }
@@ -461,6 +553,9 @@ struct Instance<E> {
     tx_socket: std::net::UdpSocket,
     rx: socket::Queue<buffer::Buffer>,
     tx: socket::Queue<buffer::Buffer>,
+    /// The maximum number of datagrams received from the rx socket at a time
+    recv_batch_size: usize,
+    event_loop_mode: EventLoopMode,
     endpoint: E,
 }
 
@@ -472,6 +567,8 @@ impl<E: Endpoint<PathHandle = PathHandle>> Instance<E> {
             tx_socket,
             mut rx,
             mut tx,
+            recv_batch_size,
+            event_loop_mode,
             mut endpoint,
         } = self;
 
@@ -549,7 +646,9 @@ impl<E: Endpoint<PathHandle = PathHandle>> Instance<E> {
             }
 
             if let Some(guard) = rx_result {
-                if let Ok(result) = guard?.try_io(|socket| rx.rx(socket, &mut publisher)) {
+                if let Ok(result) =
+                    guard?.try_io(|socket| rx.rx(socket, recv_batch_size, &mut publisher))
+                {
                     result?;
                 }
                 endpoint.receive(&mut rx.rx_queue(), &clock);
@@ -580,6 +679,11 @@ impl<E: Endpoint<PathHandle = PathHandle>> Instance<E> {
                 timeout,
                 processing_duration: timestamp.saturating_duration_since(wakeup_timestamp),
             });
+
+            // give the other tasks on the thread a chance to run
+            if event_loop_mode == EventLoopMode::Cooperative {
+                tokio::task::yield_now().await;
+            }
         }
     }
 }
@@ -771,6 +875,14 @@ mod tests {
     async fn test<A: std::net::ToSocketAddrs>(
         receive_addr: A,
         send_addr: Option<A>,
+    ) -> io::Result<()> {
+        test_with_mode(receive_addr, send_addr, None).await
+    }
+
+    async fn test_with_mode<A: std::net::ToSocketAddrs>(
+        receive_addr: A,
+        send_addr: Option<A>,
+        event_loop_mode: Option<EventLoopMode>,
     ) -> io::Result<()> {
         let rx_socket = bind(receive_addr, false)?;
         let rx_socket: std::net::UdpSocket = rx_socket.into();
@@ -778,6 +890,10 @@ mod tests {
 
         let mut io_builder = Io::builder().with_rx_socket(rx_socket)?;
 
+        if let Some(mode) = event_loop_mode {
+            io_builder = io_builder.with_event_loop_mode(mode)?;
+        }
+
         if let Some(addr) = send_addr {
             let tx_socket = bind(addr, false)?;
             let tx_socket: std::net::UdpSocket = tx_socket.into();
@@ -809,6 +925,21 @@ mod tests {
         test("127.0.0.1:0", Some("127.0.0.1:0")).await
     }
 
+    #[tokio::test(flavor = "current_thread")]
+    async fn current_thread_test() -> io::Result<()> {
+        // the mode isn't inferred from the runtime so the event loop stays dedicated by default
+        assert_eq!(Builder::default().event_loop_mode, EventLoopMode::Dedicated);
+
+        test_with_mode("127.0.0.1:0", None, Some(EventLoopMode::Cooperative)).await
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn multi_thread_test() -> io::Result<()> {
+        // both modes can be used on a multi-threaded runtime
+        test_with_mode("127.0.0.1:0", None, Some(EventLoopMode::Dedicated)).await?;
+        test_with_mode("127.0.0.1:0", None, Some(EventLoopMode::Cooperative)).await
+    }
+
     #[tokio::test]
     async fn ipv6_test() -> io::Result<()> {
         match test(("::1", 0), None).await {
@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::mem::{align_of, size_of};
-use s2n_quic_core::inet::{AncillaryData, ExplicitCongestionNotification};
+use s2n_quic_core::{
+    event,
+    inet::{AncillaryData, ExplicitCongestionNotification},
+};
 
 /// The maximum number of bytes allocated for cmsg data
 ///
-/// This should be enough for UDP_SEGMENT + IP_TOS + IP_PKTINFO. It may need to be increased
-/// to allow for future control messages.
+/// This should be enough for UDP_SEGMENT + IP_TOS + IP_PKTINFO + SO_RXQ_OVFL. It may need to be
+/// increased to allow for future control messages.
 pub const MAX_LEN: usize = 128;
 
 #[test]
@@ -28,6 +31,12 @@ fn max_len_test() {
                 size_of::<libc::in_pktinfo>().max(size_of::<libc::in6_pktinfo>()) as _,
             ) as usize;
         }
+
+        // SO_RXQ_OVFL
+        #[cfg(s2n_quic_platform_rxq_ovfl)]
+        {
+            len += libc::CMSG_LEN(size_of::<u32>() as _) as usize;
+        }
     }
 
     // We use the MAX_LEN to determine if the cmsg has been populated at all so the actual
@@ -167,6 +176,11 @@ pub fn decode(msghdr: &libc::msghdr) -> AncillaryData {
                     result.local_address = local_address.into();
                     result.local_interface = Some(pkt_info.ipi6_ifindex as _);
                 }
+                #[cfg(s2n_quic_platform_rxq_ovfl)]
+                (libc::SOL_SOCKET, libc::SO_RXQ_OVFL, _) => {
+                    // the drop counter is read separately with `decode_rx_overflow`
+                    continue;
+                }
                 #[cfg(s2n_quic_platform_gso)]
                 (libc::SOL_UDP, libc::UDP_SEGMENT, _) => {
                     // ignore GSO settings when reading
@@ -187,6 +201,69 @@ pub fn decode(msghdr: &libc::msghdr) -> AncillaryData {
     result
 }
 
+/// Decodes the `SO_RXQ_OVFL` counter in the given `msghdr`, if present
+///
+/// The value is the number of datagrams the operating system has dropped on the socket because
+/// the receive buffer was full. It is a counter maintained by the operating system which wraps on
+/// overflow.
+#[inline]
+pub fn decode_rx_overflow(msghdr: &libc::msghdr) -> Option<u32> {
+    #[cfg(s2n_quic_platform_rxq_ovfl)]
+    for cmsg in unsafe { Iter::new(msghdr) } {
+        if (cmsg.cmsg_level, cmsg.cmsg_type) == (libc::SOL_SOCKET, libc::SO_RXQ_OVFL) {
+            return Some(unsafe { decode_value::<u32>(cmsg) });
+        }
+    }
+
+    // mark the variable as "used" regardless of platform support
+    let _ = msghdr;
+
+    None
+}
+
+/// Tracks the datagrams dropped by the operating system because the socket receive buffer was
+/// full, as reported with the `SO_RXQ_OVFL` control message
+#[derive(Debug, Default)]
+pub struct RxOverflow {
+    /// The last counter value reported by the operating system
+    last: u32,
+    /// The total number of dropped datagrams
+    total: u64,
+}
+
+impl RxOverflow {
+    /// Publishes an event if the drop counter in the `msghdr` increased since the previous call
+    ///
+    /// The counter is cumulative, so only the last message received in a batch needs to be
+    /// checked.
+    #[inline]
+    pub fn on_rx<Publisher: event::EndpointPublisher>(
+        &mut self,
+        msghdr: &libc::msghdr,
+        publisher: &mut Publisher,
+    ) {
+        let counter = if let Some(counter) = decode_rx_overflow(msghdr) {
+            counter
+        } else {
+            return;
+        };
+
+        let count = counter.wrapping_sub(self.last) as u64;
+        self.last = counter;
+
+        if count == 0 {
+            return;
+        }
+
+        self.total += count;
+
+        publisher.on_io_recv_overflow(event::builder::IoRecvOverflow {
+            count,
+            total_count: self.total,
+        });
+    }
+}
+
 /// Decodes a value of type `T` from the given `cmsghdr`
 /// # Safety
 ///
@@ -275,4 +352,58 @@ mod tests {
             }
         });
     }
+
+    /// Passes a `msghdr` carrying the given `SO_RXQ_OVFL` counter to `rx_overflow`
+    #[cfg(s2n_quic_platform_rxq_ovfl)]
+    fn on_rx(
+        rx_overflow: &mut RxOverflow,
+        counter: u32,
+        publisher: &mut event::testing::Publisher,
+    ) {
+        // back the control buffer with u64s so it's aligned for a cmsghdr
+        let mut cmsg = [0u64; MAX_LEN / 8];
+        let mut msghdr = unsafe { zeroed::<libc::msghdr>() };
+        msghdr.msg_control = cmsg.as_mut_ptr() as _;
+        msghdr.encode_cmsg(libc::SOL_SOCKET, libc::SO_RXQ_OVFL, counter);
+
+        rx_overflow.on_rx(&msghdr, publisher);
+    }
+
+    #[test]
+    #[cfg(s2n_quic_platform_rxq_ovfl)]
+    fn rx_overflow_test() {
+        let mut publisher = event::testing::Publisher::no_snapshot();
+        let mut rx_overflow = RxOverflow::default();
+
+        // nothing is published until the counter changes
+        on_rx(&mut rx_overflow, 0, &mut publisher);
+        assert_eq!(publisher.io_recv_overflow, 0);
+
+        on_rx(&mut rx_overflow, 3, &mut publisher);
+        assert_eq!(publisher.io_recv_overflow, 1);
+        assert_eq!(rx_overflow.total, 3);
+
+        on_rx(&mut rx_overflow, 3, &mut publisher);
+        assert_eq!(publisher.io_recv_overflow, 1);
+
+        // the counter is cumulative so only the difference is added
+        on_rx(&mut rx_overflow, 5, &mut publisher);
+        assert_eq!(publisher.io_recv_overflow, 2);
+        assert_eq!(rx_overflow.total, 5);
+
+        // messages without the counter are ignored
+        let msghdr = unsafe { zeroed::<libc::msghdr>() };
+        rx_overflow.on_rx(&msghdr, &mut publisher);
+        assert_eq!(publisher.io_recv_overflow, 2);
+        assert_eq!(rx_overflow.last, 5);
+
+        // the total keeps counting after the operating system's counter wraps around
+        on_rx(&mut rx_overflow, u32::MAX - 1, &mut publisher);
+        assert_eq!(rx_overflow.total, (u32::MAX - 1) as u64);
+
+        on_rx(&mut rx_overflow, 2, &mut publisher);
+        assert_eq!(publisher.io_recv_overflow, 4);
+        assert_eq!(rx_overflow.last, 2);
+        assert_eq!(rx_overflow.total, u32::MAX as u64 + 3);
+    }
 }
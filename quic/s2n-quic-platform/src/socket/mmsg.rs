@@ -4,6 +4,7 @@
 use crate::{
     buffer::Buffer,
     message::{
+        cmsg,
         mmsg::{self, Message, Ring},
         queue,
     },
@@ -13,7 +14,10 @@ use s2n_quic_core::{event, path::LocalAddress};
 use std::{io, os::unix::io::AsRawFd};
 
 #[derive(Debug, Default)]
-pub struct Queue<B: Buffer>(queue::Queue<Ring<B>>);
+pub struct Queue<B: Buffer> {
+    queue: queue::Queue<Ring<B>>,
+    rx_overflow: cmsg::RxOverflow,
+}
 
 pub use mmsg::Handle;
 
@@ -21,19 +25,22 @@ impl<B: Buffer> Queue<B> {
     pub fn new(buffer: B, max_gso: usize) -> Self {
         let queue = queue::Queue::new(Ring::new(buffer, max_gso));
 
-        Self(queue)
+        Self {
+            queue,
+            rx_overflow: Default::default(),
+        }
     }
 
     pub fn free_len(&self) -> usize {
-        self.0.free_len()
+        self.queue.free_len()
     }
 
     pub fn occupied_len(&self) -> usize {
-        self.0.occupied_len()
+        self.queue.occupied_len()
     }
 
     pub fn set_local_address(&mut self, local_address: LocalAddress) {
-        self.0.set_local_address(local_address)
+        self.queue.set_local_address(local_address)
     }
 
     pub fn tx<Socket: AsRawFd, Publisher: event::EndpointPublisher>(
@@ -41,7 +48,7 @@ impl<B: Buffer> Queue<B> {
         socket: &Socket,
         publisher: &mut Publisher,
     ) -> io::Result<usize> {
-        let mut entries = self.0.occupied_mut();
+        let mut entries = self.queue.occupied_mut();
 
         // Safety: calling a libc function is inherently unsafe as rust cannot
         // make any invariant guarantees. This has to be reviewed by humans instead
@@ -114,13 +121,13 @@ impl<B: Buffer> Queue<B> {
                     errno: libc::EIO as _,
                 });
 
-                if self.0.max_gso() > 1 {
-                    self.0.disable_gso();
+                if self.queue.max_gso() > 1 {
+                    self.queue.disable_gso();
 
                     publisher.on_platform_feature_configured(
                         event::builder::PlatformFeatureConfigured {
                             configuration: event::builder::PlatformFeatureConfiguration::Gso {
-                                max_segments: self.0.max_gso(),
+                                max_segments: self.queue.max_gso(),
                             },
                         },
                     );
@@ -148,12 +155,14 @@ impl<B: Buffer> Queue<B> {
         }
     }
 
+    /// Receives up to `max_batch_size` datagrams from the socket with a single syscall
     pub fn rx<Socket: AsRawFd, Publisher: event::EndpointPublisher>(
         &mut self,
         socket: &Socket,
+        max_batch_size: usize,
         publisher: &mut Publisher,
     ) -> io::Result<usize> {
-        let mut entries = self.0.free_mut();
+        let mut entries = self.queue.free_mut();
 
         if entries.is_empty() {
             return Ok(0);
@@ -172,7 +181,10 @@ impl<B: Buffer> Queue<B> {
         let msgvec = entries.as_mut_ptr() as _;
 
         // > The size of this array is specified in vlen.
-        let vlen = entries.len() as _;
+        //
+        // Limiting the number of messages trades throughput for latency, since the received
+        // datagrams are only processed once the syscall returns.
+        let vlen = entries.len().min(max_batch_size) as _;
 
         // > The flags argument contains flags ORed together.
         //
@@ -206,6 +218,12 @@ impl<B: Buffer> Queue<B> {
         match libc!(recvmmsg(sockfd, msgvec, vlen, flags, timeout)) {
             Ok(status) => {
                 let count = status as usize;
+
+                // the drop counter is cumulative so only the last message needs to be checked
+                if let Some(entry) = entries[..count].last() {
+                    self.rx_overflow.on_rx(&entry.0.msg_hdr, publisher);
+                }
+
                 entries.finish(count);
 
                 publisher.on_platform_rx(event::builder::PlatformRx { count });
@@ -232,10 +250,10 @@ impl<B: Buffer> Queue<B> {
     }
 
     pub fn rx_queue(&mut self) -> queue::OccupiedWipe<Message> {
-        self.0.occupied_wipe_mut()
+        self.queue.occupied_wipe_mut()
     }
 
     pub fn tx_queue(&mut self) -> queue::Free<Message> {
-        self.0.free_mut()
+        self.queue.free_mut()
     }
 }
@@ -4,6 +4,7 @@
 use crate::{
     buffer::Buffer,
     message::{
+        cmsg,
         msg::{self, Message, Ring},
         queue, Message as _,
     },
@@ -13,7 +14,10 @@ use s2n_quic_core::{event, path::LocalAddress};
 use std::{io, os::unix::io::AsRawFd};
 
 #[derive(Debug, Default)]
-pub struct Queue<B: Buffer>(queue::Queue<Ring<B>>);
+pub struct Queue<B: Buffer> {
+    queue: queue::Queue<Ring<B>>,
+    rx_overflow: cmsg::RxOverflow,
+}
 
 pub use msg::Handle;
 
@@ -21,19 +25,22 @@ impl<B: Buffer> Queue<B> {
     pub fn new(buffer: B, max_segments: usize) -> Self {
         let queue = queue::Queue::new(Ring::new(buffer, max_segments));
 
-        Self(queue)
+        Self {
+            queue,
+            rx_overflow: Default::default(),
+        }
     }
 
     pub fn free_len(&self) -> usize {
-        self.0.free_len()
+        self.queue.free_len()
     }
 
     pub fn occupied_len(&self) -> usize {
-        self.0.occupied_len()
+        self.queue.occupied_len()
     }
 
     pub fn set_local_address(&mut self, local_address: LocalAddress) {
-        self.0.set_local_address(local_address)
+        self.queue.set_local_address(local_address)
     }
 
     pub fn tx<Socket: AsRawFd, Publisher: event::EndpointPublisher>(
@@ -42,7 +49,9 @@ impl<B: Buffer> Queue<B> {
         publisher: &mut Publisher,
     ) -> io::Result<usize> {
         let mut count = 0;
-        let mut entries = self.0.occupied_mut();
+        // the number of datagrams which were actually sent, excluding the ignored errors
+        let mut sent = 0;
+        let mut entries = self.queue.occupied_mut();
 
         for entry in entries.iter_mut() {
             // macOS doesn't like when msg_control have valid pointers but the len is 0
@@ -92,8 +101,7 @@ impl<B: Buffer> Queue<B> {
             match result {
                 Ok(_len) => {
                     count += 1;
-
-                    publisher.on_platform_tx(event::builder::PlatformTx { count: 1 });
+                    sent += 1;
                 }
                 Err(err) if count > 0 && err.kind() == io::ErrorKind::WouldBlock => {
                     break;
@@ -113,16 +121,20 @@ impl<B: Buffer> Queue<B> {
                     let len = entries.len();
                     entries.finish(len);
 
+                    if sent > 0 {
+                        publisher.on_platform_tx(event::builder::PlatformTx { count: sent });
+                    }
+
                     publisher
                         .on_platform_tx_error(event::builder::PlatformTxError { errno: libc::EIO });
 
-                    if self.0.max_gso() > 1 {
-                        self.0.disable_gso();
+                    if self.queue.max_gso() > 1 {
+                        self.queue.disable_gso();
 
                         publisher.on_platform_feature_configured(
                             event::builder::PlatformFeatureConfigured {
                                 configuration: event::builder::PlatformFeatureConfiguration::Gso {
-                                    max_segments: self.0.max_gso(),
+                                    max_segments: self.queue.max_gso(),
                                 },
                             },
                         );
@@ -146,18 +158,24 @@ impl<B: Buffer> Queue<B> {
 
         entries.finish(count);
 
+        if sent > 0 {
+            publisher.on_platform_tx(event::builder::PlatformTx { count: sent });
+        }
+
         Ok(count)
     }
 
+    /// Receives up to `max_batch_size` datagrams from the socket
     pub fn rx<Socket: AsRawFd, Publisher: event::EndpointPublisher>(
         &mut self,
         socket: &Socket,
+        max_batch_size: usize,
         publisher: &mut Publisher,
     ) -> io::Result<usize> {
         let mut count = 0;
-        let mut entries = self.0.free_mut();
+        let mut entries = self.queue.free_mut();
 
-        for entry in entries.as_mut() {
+        for entry in entries.iter_mut().take(max_batch_size) {
             // Safety: calling a libc function is inherently unsafe as rust cannot
             // make any invariant guarantees. This has to be reviewed by humans instead
             // so the [docs](https://linux.die.net/man/2/recmsg) are inlined here:
@@ -204,8 +222,6 @@ impl<B: Buffer> Queue<B> {
                     }
 
                     count += 1;
-
-                    publisher.on_platform_rx(event::builder::PlatformRx { count: 1 });
                 }
                 Err(err) if err.kind() == io::ErrorKind::Interrupted => {
                     break;
@@ -214,6 +230,10 @@ impl<B: Buffer> Queue<B> {
                     if count > 0 && err.kind() == io::ErrorKind::WouldBlock {
                         break;
                     } else {
+                        if count > 0 {
+                            publisher.on_platform_rx(event::builder::PlatformRx { count });
+                        }
+
                         entries.finish(count);
 
                         publisher.on_platform_rx_error(event::builder::PlatformRxError {
@@ -226,16 +246,25 @@ impl<B: Buffer> Queue<B> {
             }
         }
 
+        // the drop counter is cumulative so only the last message needs to be checked
+        if let Some(entry) = entries[..count].last() {
+            self.rx_overflow.on_rx(&entry.0, publisher);
+        }
+
+        if count > 0 {
+            publisher.on_platform_rx(event::builder::PlatformRx { count });
+        }
+
         entries.finish(count);
 
         Ok(count)
     }
 
     pub fn rx_queue(&mut self) -> queue::OccupiedWipe<Message> {
-        self.0.occupied_wipe_mut()
+        self.queue.occupied_wipe_mut()
     }
 
     pub fn tx_queue(&mut self) -> queue::Free<Message> {
-        self.0.free_mut()
+        self.queue.free_mut()
     }
 }
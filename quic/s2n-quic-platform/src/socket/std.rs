@@ -97,8 +97,6 @@ impl<B: Buffer> Queue<B> {
                 match socket.send_to(entry.payload_mut(), &remote_address) {
                     Ok(_) => {
                         count += 1;
-
-                        publisher.on_platform_tx(event::builder::PlatformTx { count: 1 });
                     }
                     Err(err) if count > 0 && err.would_block() => {
                         break;
@@ -109,6 +107,10 @@ impl<B: Buffer> Queue<B> {
                     Err(err) => {
                         entries.finish(count);
 
+                        if count > 0 {
+                            publisher.on_platform_tx(event::builder::PlatformTx { count });
+                        }
+
                         publisher.on_platform_rx_error(event::builder::PlatformRxError {
                             errno: errno().0,
                         });
@@ -121,18 +123,26 @@ impl<B: Buffer> Queue<B> {
 
         entries.finish(count);
 
+        if count > 0 {
+            publisher.on_platform_tx(event::builder::PlatformTx { count });
+        }
+
         Ok(count)
     }
 
+    /// Receives up to `max_batch_size` datagrams from the socket
     pub fn rx<S: Socket, Publisher: event::EndpointPublisher>(
         &mut self,
         socket: &S,
+        max_batch_size: usize,
         publisher: &mut Publisher,
     ) -> Result<usize, S::Error> {
         let mut count = 0;
         let mut entries = self.0.free_mut();
+        let max_batch_size = max_batch_size.min(entries.len());
 
-        while let Some(entry) = entries.get_mut(count) {
+        while count < max_batch_size {
+            let entry = &mut entries[count];
             match socket.recv_from(entry.payload_mut()) {
                 Ok((payload_len, Some(remote_address))) => {
                     entry.set_remote_address(&remote_address);
@@ -147,8 +157,6 @@ impl<B: Buffer> Queue<B> {
                     }
 
                     count += 1;
-
-                    publisher.on_platform_rx(event::builder::PlatformRx { count: 1 });
                 }
                 Ok((_payload_len, None)) => {}
                 Err(err) if count > 0 && err.would_block() => {
@@ -160,6 +168,10 @@ impl<B: Buffer> Queue<B> {
                 Err(err) => {
                     entries.finish(count);
 
+                    if count > 0 {
+                        publisher.on_platform_rx(event::builder::PlatformRx { count });
+                    }
+
                     publisher
                         .on_platform_rx_error(event::builder::PlatformRxError { errno: errno().0 });
 
@@ -170,6 +182,10 @@ impl<B: Buffer> Queue<B> {
 
         entries.finish(count);
 
+        if count > 0 {
+            publisher.on_platform_rx(event::builder::PlatformRx { count });
+        }
+
         Ok(count)
     }
 
@@ -283,6 +283,9 @@ impl Session {
             }
         }
 
+        // rustls does not currently expose the negotiated key exchange group or signature
+        // scheme so `on_key_exchange_group` and `on_signature_scheme` are never emitted
+
         Ok(())
     }
 }
@@ -238,6 +238,10 @@ where
                             let application_protocol =
                                 Bytes::copy_from_slice(get_application_protocol(conn)?);
                             self.context.on_application_protocol(application_protocol)?;
+                            self.context
+                                .on_key_exchange_group(get_key_exchange_group(conn))?;
+                            self.context
+                                .on_signature_scheme(get_signature_scheme(conn))?;
                             get_application_params(conn)?
                         };
 
@@ -489,6 +493,60 @@ unsafe fn get_application_protocol<'a>(
         .ok_or(CryptoError::MISSING_EXTENSION)
 }
 
+unsafe fn get_key_exchange_group(connection: *mut s2n_connection) -> tls::NamedGroup {
+    let curve = s2n_connection_get_curve(connection);
+
+    if curve.is_null() {
+        return tls::NamedGroup::Unknown;
+    }
+
+    match get_cstr_slice(curve) {
+        Some(b"secp256r1") => tls::NamedGroup::SECP256R1,
+        Some(b"secp384r1") => tls::NamedGroup::SECP384R1,
+        Some(b"secp521r1") => tls::NamedGroup::SECP521R1,
+        Some(b"x25519") => tls::NamedGroup::X25519,
+        Some(b"x448") => tls::NamedGroup::X448,
+        _ => tls::NamedGroup::Unknown,
+    }
+}
+
+unsafe fn get_signature_scheme(connection: *mut s2n_connection) -> tls::SignatureScheme {
+    use s2n_tls_hash_algorithm::{TLS_HASH_SHA256, TLS_HASH_SHA384, TLS_HASH_SHA512};
+    use s2n_tls_signature_algorithm::{
+        TLS_SIGNATURE_ECDSA, TLS_SIGNATURE_RSA, TLS_SIGNATURE_RSA_PSS_PSS,
+        TLS_SIGNATURE_RSA_PSS_RSAE,
+    };
+
+    let mut signature = 0;
+    let mut digest = 0;
+
+    if s2n_connection_get_selected_signature_algorithm(connection, &mut signature)
+        .into_result()
+        .is_err()
+        || s2n_connection_get_selected_digest_algorithm(connection, &mut digest)
+            .into_result()
+            .is_err()
+    {
+        return tls::SignatureScheme::Unknown;
+    }
+
+    match (signature, digest) {
+        (TLS_SIGNATURE_RSA, TLS_HASH_SHA256) => tls::SignatureScheme::RSA_PKCS1_SHA256,
+        (TLS_SIGNATURE_RSA, TLS_HASH_SHA384) => tls::SignatureScheme::RSA_PKCS1_SHA384,
+        (TLS_SIGNATURE_RSA, TLS_HASH_SHA512) => tls::SignatureScheme::RSA_PKCS1_SHA512,
+        (TLS_SIGNATURE_ECDSA, TLS_HASH_SHA256) => tls::SignatureScheme::ECDSA_SECP256R1_SHA256,
+        (TLS_SIGNATURE_ECDSA, TLS_HASH_SHA384) => tls::SignatureScheme::ECDSA_SECP384R1_SHA384,
+        (TLS_SIGNATURE_ECDSA, TLS_HASH_SHA512) => tls::SignatureScheme::ECDSA_SECP521R1_SHA512,
+        (TLS_SIGNATURE_RSA_PSS_RSAE, TLS_HASH_SHA256) => tls::SignatureScheme::RSA_PSS_RSAE_SHA256,
+        (TLS_SIGNATURE_RSA_PSS_RSAE, TLS_HASH_SHA384) => tls::SignatureScheme::RSA_PSS_RSAE_SHA384,
+        (TLS_SIGNATURE_RSA_PSS_RSAE, TLS_HASH_SHA512) => tls::SignatureScheme::RSA_PSS_RSAE_SHA512,
+        (TLS_SIGNATURE_RSA_PSS_PSS, TLS_HASH_SHA256) => tls::SignatureScheme::RSA_PSS_PSS_SHA256,
+        (TLS_SIGNATURE_RSA_PSS_PSS, TLS_HASH_SHA384) => tls::SignatureScheme::RSA_PSS_PSS_SHA384,
+        (TLS_SIGNATURE_RSA_PSS_PSS, TLS_HASH_SHA512) => tls::SignatureScheme::RSA_PSS_PSS_SHA512,
+        _ => tls::SignatureScheme::Unknown,
+    }
+}
+
 unsafe fn get_transport_parameters<'a>(connection: *mut s2n_connection) -> Option<&'a [u8]> {
     let mut ptr = core::ptr::null();
     let mut len = 0u16;
@@ -3,14 +3,23 @@
 
 use crate::{client, server};
 use core::{
-    sync::atomic::{AtomicBool, AtomicU8, Ordering},
+    sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering},
     task::Poll,
 };
 use s2n_quic_core::{
-    crypto::tls::{
-        self,
-        testing::certificates::{CERT_PEM, KEY_PEM, UNTRUSTED_CERT_PEM, UNTRUSTED_KEY_PEM},
-        Endpoint,
+    crypto::{
+        tls::{
+            self,
+            testing::{
+                certificates::{
+                    CERT_PEM, KEY_PEM, RSA_CERT_PEM, RSA_KEY_PEM, UNTRUSTED_CERT_PEM,
+                    UNTRUSTED_KEY_PEM,
+                },
+                header_protection,
+            },
+            Endpoint,
+        },
+        HeaderProtectionMask,
     },
     transport,
 };
@@ -185,6 +194,49 @@ fn s2n_client_s2n_server_test() {
     run(&mut server_endpoint, &mut client_endpoint, None);
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn s2n_client_s2n_server_signature_scheme_test() {
+    let cases = [
+        (
+            CERT_PEM,
+            KEY_PEM,
+            tls::SignatureScheme::ECDSA_SECP256R1_SHA256,
+        ),
+        (
+            RSA_CERT_PEM,
+            RSA_KEY_PEM,
+            tls::SignatureScheme::RSA_PSS_RSAE_SHA256,
+        ),
+    ];
+
+    for (cert, key, expected) in cases {
+        let mut client_endpoint = client::Builder::default()
+            .with_certificate(cert)
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut server_endpoint = server::Builder::default()
+            .with_certificate(cert, key)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let mut pair = tls::testing::Pair::new(
+            &mut server_endpoint,
+            &mut client_endpoint,
+            "localhost".into(),
+        );
+        while pair.is_handshaking() {
+            pair.poll(None).unwrap();
+        }
+        pair.finish();
+
+        assert_eq!(pair.server.context.signature_scheme, Some(expected));
+        assert_eq!(pair.client.context.signature_scheme, Some(expected));
+    }
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn rustls_client_s2n_server_test() {
@@ -203,6 +255,44 @@ fn s2n_client_rustls_server_test() {
     run(&mut server_endpoint, &mut client_endpoint, None);
 }
 
+#[derive(Default)]
+struct CountingInterceptor {
+    sealing: AtomicUsize,
+    opening: AtomicUsize,
+}
+
+impl header_protection::Interceptor for CountingInterceptor {
+    fn on_sealing_mask(
+        &self,
+        _space: header_protection::KeySpace,
+        _mask: &mut HeaderProtectionMask,
+    ) {
+        self.sealing.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn on_opening_mask(
+        &self,
+        _space: header_protection::KeySpace,
+        _mask: &mut HeaderProtectionMask,
+    ) {
+        self.opening.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn s2n_client_s2n_server_header_protection_interceptor_test() {
+    let counter = Arc::new(CountingInterceptor::default());
+    let mut client_endpoint = s2n_client();
+    let mut server_endpoint = header_protection::Endpoint::new(s2n_server(), counter.clone());
+
+    run(&mut server_endpoint, &mut client_endpoint, None);
+
+    // the handshake and application header keys are checked in both directions
+    assert!(counter.sealing.load(Ordering::Relaxed) > 0);
+    assert!(counter.opening.load(Ordering::Relaxed) > 0);
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn s2n_client_s2n_server_client_auth_test() {
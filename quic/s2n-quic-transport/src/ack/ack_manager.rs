@@ -16,7 +16,7 @@ use s2n_quic_core::{
     counter::{Counter, Saturating},
     event::{
         self,
-        builder::{AckAction, AckProcessed},
+        builder::{AckAction, AckProcessed, AckSentReason},
         IntoEvent as _,
     },
     frame::{ack::EcnCounts, Ack, Ping},
@@ -55,6 +55,15 @@ pub struct AckManager {
     /// Locally configured AckSettings
     pub ack_settings: ack::Settings,
 
+    /// Whether a PING is added to ACK-only packets every `ack_elicitation_interval` transmissions
+    ack_elicitation: bool,
+
+    /// The number of ack-eliciting packets received before an ACK is sent immediately
+    packet_tolerance: u8,
+
+    /// Which frames are written first when both ACKs and data are pending
+    priority: ack::Priority,
+
     /// The largest packet number that we've acked - used for pn decoding
     largest_received_packet_number_acked: PacketNumber,
 
@@ -72,6 +81,15 @@ pub struct AckManager {
 
     /// Explicit Congestion Notification counts from processed packets
     ecn_counts: EcnCounts,
+
+    /// The reason the transmission state was last activated
+    activation_reason: Option<AckSentReason>,
+
+    /// The reason for the most recently transmitted ACK frame, if it hasn't been reported yet
+    sent_reason: Option<AckSentReason>,
+
+    /// Set while the endpoint is under memory pressure
+    is_under_memory_pressure: bool,
 }
 
 impl AckManager {
@@ -80,6 +98,9 @@ impl AckManager {
             ack_delay_timer: Timer::default(),
             ack_eliciting_transmissions: AckElicitingTransmissionSet::default(),
             ack_settings,
+            ack_elicitation: true,
+            packet_tolerance: ack::settings::RECOMMENDED_PACKET_TOLERANCE,
+            priority: ack::Priority::default(),
             ack_ranges: AckRanges::new(ack_settings.ack_ranges_limit as usize),
             largest_received_packet_number_acked: packet_space
                 .new_packet_number(VarInt::from_u8(0)),
@@ -88,9 +109,49 @@ impl AckManager {
             transmissions_since_elicitation: Counter::new(0),
             transmission_state: AckTransmissionState::default(),
             ecn_counts: EcnCounts::default(),
+            activation_reason: None,
+            sent_reason: None,
+            is_under_memory_pressure: false,
         }
     }
 
+    /// Sets whether a PING is added to ACK-only packets every `ack_elicitation_interval`
+    /// transmissions
+    #[must_use]
+    pub fn with_ack_elicitation(mut self, enabled: bool) -> Self {
+        self.ack_elicitation = enabled;
+        self
+    }
+
+    /// Sets the number of ack-eliciting packets received before an ACK is sent immediately
+    #[must_use]
+    pub fn with_packet_tolerance(mut self, packet_tolerance: u8) -> Self {
+        self.packet_tolerance = packet_tolerance;
+        self
+    }
+
+    /// Sets which frames are written first when both ACKs and data are pending
+    #[must_use]
+    pub fn with_priority(mut self, priority: ack::Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns which frames are written first when both ACKs and data are pending
+    #[inline]
+    pub fn priority(&self) -> ack::Priority {
+        self.priority
+    }
+
+    /// Called when the endpoint enters or leaves memory pressure
+    ///
+    /// While under pressure, ACKs are no longer sent after `packet_tolerance` packets and are
+    /// instead delayed until `max_ack_delay` expires. This slows the growth of the peer's
+    /// congestion window, which reduces the amount of data the connection has to buffer.
+    pub fn on_memory_pressure(&mut self, is_under_pressure: bool) {
+        self.is_under_memory_pressure = is_under_pressure;
+    }
+
     /// Called when an outgoing packet is being assembled
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> bool {
         let constraint = context.transmission_constraint();
@@ -138,6 +199,7 @@ impl AckManager {
             // retransmission that is not ack eliciting will not help us recover faster.
             if (context.transmission_constraint().can_transmit()
                 || context.transmission_constraint().can_retransmit())
+                && self.ack_elicitation
                 && self.transmissions_since_elicitation
                     >= self.ack_settings.ack_elicitation_interval
                 && context.write_frame(&Ping).is_some()
@@ -167,6 +229,14 @@ impl AckManager {
                 });
         }
 
+        self.sent_reason = Some(if self.transmission_state.is_active() {
+            self.activation_reason
+                .take()
+                .unwrap_or(AckSentReason::Piggyback)
+        } else {
+            AckSentReason::Piggyback
+        });
+
         // record a transmission
         self.transmission_state
             .on_transmit(!self.ack_ranges.is_empty());
@@ -196,7 +266,7 @@ impl AckManager {
         {
             // transition to active mode when packet is lost
             self.transmission_state.on_update(&self.ack_ranges);
-            self.transmission_state.activate();
+            self.activate(AckSentReason::Loss);
         }
     }
 
@@ -287,8 +357,15 @@ impl AckManager {
             self.largest_received_packet_number_at = Some(now);
         }
 
-        if processed_packet.is_ack_eliciting() {
-            let mut should_activate = false;
+        let is_ack_eliciting = processed_packet.is_ack_eliciting();
+        let mut activation_reason = None;
+
+        if is_ack_eliciting {
+            let mut should_activate = |condition: bool, reason: AckSentReason| {
+                if condition && activation_reason.is_none() {
+                    activation_reason = Some(reason);
+                }
+            };
 
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.1
             //# In order to assist loss detection at the sender, an endpoint SHOULD
@@ -298,38 +375,42 @@ impl AckManager {
             //# *  when the received packet has a packet number less than another
             //#    ack-eliciting packet that has been received, or
 
-            should_activate |= !is_largest;
+            should_activate(!is_largest, AckSentReason::Reordering);
 
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.1
             //# *  when the packet has a packet number larger than the highest-
             //#    numbered ack-eliciting packet that has been received and there are
             //#    missing packets between that packet and this packet.
 
-            should_activate |= !is_ordered;
+            should_activate(!is_ordered, AckSentReason::Reordering);
 
             //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2.1
             //# Similarly, packets marked with the ECN Congestion Experienced (CE)
             //# codepoint in the IP header SHOULD be acknowledged immediately, to
             //# reduce the peer's response time to congestion events.
-            should_activate |= processed_packet.datagram.ecn.congestion_experienced();
-
-            // TODO update to draft link after published
-            // https://github.com/quicwg/base-drafts/pull/3623
-            // An ACK frame SHOULD be generated for at least every 10th ack-eliciting packet
+            should_activate(
+                processed_packet.datagram.ecn.congestion_experienced(),
+                AckSentReason::CongestionExperienced,
+            );
 
             // TODO support delayed ack proposal
             // https://tools.ietf.org/html/draft-iyengar-quic-delayed-ack-00
-            let packet_tolerance = 10;
-
-            should_activate |= self.processed_packets_since_transmission >= packet_tolerance;
+            should_activate(
+                !self.is_under_memory_pressure
+                    && self.processed_packets_since_transmission >= self.packet_tolerance,
+                AckSentReason::PacketTolerance,
+            );
 
             //= https://www.rfc-editor.org/rfc/rfc9000#section-9.3.3
             //# An endpoint that receives a PATH_CHALLENGE on an active path SHOULD
             //# send a non-probing packet in response.
-            should_activate |= processed_packet.path_challenge_on_active_path;
+            should_activate(
+                processed_packet.path_challenge_on_active_path,
+                AckSentReason::PathChallenge,
+            );
 
-            if should_activate {
-                self.transmission_state.activate();
+            if let Some(reason) = activation_reason {
+                self.activate(reason);
             } else if !self.ack_delay_timer.is_armed() {
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-13.2
                 //# Endpoints acknowledge all packets they receive and process.  However,
@@ -344,7 +425,21 @@ impl AckManager {
         // To save on timer churn, check to see if we've already expired since the
         // last time we sent an ACK frame
         if self.ack_delay_timer.poll_expiration(now).is_ready() {
-            self.transmission_state.activate();
+            self.activate(AckSentReason::Timer);
+            activation_reason.get_or_insert(AckSentReason::Timer);
+        }
+
+        if is_ack_eliciting {
+            publisher.on_ack_eliciting_packet_received(
+                event::builder::AckElicitingPacketReceived {
+                    packet_header: event::builder::PacketHeader::new(
+                        packet_number,
+                        publisher.quic_version(),
+                    ),
+                    path,
+                    immediate_ack_reason: activation_reason,
+                },
+            );
         }
     }
 
@@ -354,15 +449,32 @@ impl AckManager {
 
         if self.ack_delay_timer.poll_expiration(timestamp).is_ready() {
             // transition to active transmission when we exceed the ack_delay
-            self.transmission_state.activate();
+            self.activate(AckSentReason::Timer);
         }
     }
 
+    /// Returns the reason for the most recently transmitted ACK frame
+    ///
+    /// The reason is only returned once for each transmission.
+    pub fn take_sent_reason(&mut self) -> Option<AckSentReason> {
+        self.sent_reason.take()
+    }
+
     /// Returns the largest received packet number that has been ACKed at least once
     pub fn largest_received_packet_number_acked(&self) -> PacketNumber {
         self.largest_received_packet_number_acked
     }
 
+    /// Transitions the transmission state to active, recording the reason for the transition
+    fn activate(&mut self, reason: AckSentReason) {
+        let was_active = self.transmission_state.is_active();
+        self.transmission_state.activate();
+
+        if !was_active && self.transmission_state.is_active() {
+            self.activation_reason = Some(reason);
+        }
+    }
+
     /// Computes the ack_delay field for the current state
     fn ack_delay(&self, now: Timestamp) -> VarInt {
         let ack_delay = self
@@ -533,6 +645,274 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ack_ranges_limit() {
+        let settings = ack::Settings {
+            ack_ranges_limit: 2,
+            ..Default::default()
+        };
+        let mut manager = AckManager::new(PacketNumberSpace::ApplicationData, settings);
+        let path = helper_path_server();
+        let path_id = path::Id::test_id();
+        let mut publisher = Publisher::no_snapshot();
+        let datagram = helper_datagram_info(ExplicitCongestionNotification::NotEct);
+
+        // receive packets with gaps in between so each one requires its own range
+        for pn in [1, 3, 5, 7] {
+            let pn = PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(pn));
+            let mut processed_packet = ProcessedPacket::new(pn, &datagram);
+            processed_packet.ack_elicitation = AckElicitation::Eliciting;
+            manager.on_processed_packet(
+                &processed_packet,
+                path_event!(path, path_id),
+                &mut publisher,
+            );
+        }
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut write_context = MockWriteContext::new(
+            s2n_quic_platform::time::now(),
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+
+        assert!(manager.on_transmit(&mut write_context));
+
+        let frame = write_context
+            .frame_buffer
+            .pop_front()
+            .expect("Frame is written");
+
+        if let Frame::Ack(ack) = frame.as_frame() {
+            // only the largest ranges are included in the frame
+            assert_eq!(ack.ack_ranges().len(), 2);
+            assert_eq!(ack.largest_acknowledged(), VarInt::from_u8(7));
+            assert_eq!(
+                ack.ack_ranges().last(),
+                Some(VarInt::from_u8(5)..=VarInt::from_u8(5))
+            );
+        } else {
+            panic!("expected an ACK frame");
+        }
+    }
+
+    #[test]
+    fn ack_sent_reason() {
+        let settings = ack::Settings {
+            max_ack_delay: Duration::from_millis(25),
+            ..Default::default()
+        };
+        let mut manager =
+            AckManager::new(PacketNumberSpace::ApplicationData, settings).with_packet_tolerance(2);
+        let path = helper_path_server();
+        let path_id = path::Id::test_id();
+        let mut publisher = Publisher::no_snapshot();
+        let now = s2n_quic_platform::time::now();
+        let datagram = DatagramInfo {
+            timestamp: now,
+            ..helper_datagram_info(ExplicitCongestionNotification::NotEct)
+        };
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut write_context = MockWriteContext::new(
+            now,
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+
+        let mut on_packet = |manager: &mut AckManager, pn: u8| {
+            let pn = PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(pn));
+            let mut processed_packet = ProcessedPacket::new(pn, &datagram);
+            processed_packet.ack_elicitation = AckElicitation::Eliciting;
+            manager.on_processed_packet(
+                &processed_packet,
+                path_event!(path, path_id),
+                &mut publisher,
+            );
+        };
+
+        // the first packet waits for the ack delay timer
+        on_packet(&mut manager, 1);
+        assert!(!manager.transmission_state.is_active());
+
+        // the second packet reaches the packet tolerance
+        on_packet(&mut manager, 2);
+        assert!(manager.transmission_state.is_active());
+        assert!(manager.on_transmit(&mut write_context));
+        manager.on_transmit_complete(&mut write_context);
+        assert!(matches!(
+            manager.take_sent_reason(),
+            Some(AckSentReason::PacketTolerance)
+        ));
+        assert!(manager.take_sent_reason().is_none());
+
+        // the next packet is acknowledged once the timer expires
+        on_packet(&mut manager, 3);
+        assert!(!manager.transmission_state.is_active());
+        manager.on_timeout(now + settings.max_ack_delay);
+        assert!(manager.transmission_state.is_active());
+        write_context.frame_buffer.clear();
+        assert!(manager.on_transmit(&mut write_context));
+        manager.on_transmit_complete(&mut write_context);
+        assert!(matches!(
+            manager.take_sent_reason(),
+            Some(AckSentReason::Timer)
+        ));
+    }
+
+    #[test]
+    fn immediate_ack_reason() {
+        use event::api::AckSentReason as Reason;
+
+        /// Records the immediate ACK reason of each ack-eliciting packet
+        #[derive(Default)]
+        struct Reasons(Vec<Option<Reason>>);
+
+        impl event::Subscriber for Reasons {
+            type ConnectionContext = ();
+
+            fn create_connection_context(
+                &mut self,
+                _meta: &event::api::ConnectionMeta,
+                _info: &event::api::ConnectionInfo,
+            ) -> Self::ConnectionContext {
+            }
+
+            fn on_ack_eliciting_packet_received(
+                &mut self,
+                _context: &mut Self::ConnectionContext,
+                _meta: &event::api::ConnectionMeta,
+                event: &event::api::AckElicitingPacketReceived,
+            ) {
+                self.0.push(event.immediate_ack_reason.clone());
+            }
+        }
+
+        let settings = ack::Settings::default();
+        let mut manager = AckManager::new(PacketNumberSpace::ApplicationData, settings);
+        let path = helper_path_server();
+        let path_id = path::Id::test_id();
+        let now = NoopClock {}.get_time();
+        let mut reasons = Reasons::default();
+        let mut on_packet = |manager: &mut AckManager,
+                             pn: u8,
+                             ecn: ExplicitCongestionNotification,
+                             timestamp: Timestamp|
+         -> Option<Reason> {
+            let mut context = ();
+            let mut publisher = event::ConnectionPublisherSubscriber::new(
+                event::builder::ConnectionMeta {
+                    endpoint_type: endpoint::Type::Server,
+                    id: 0,
+                    timestamp,
+                    tags: Default::default(),
+                },
+                0,
+                &mut reasons,
+                &mut context,
+            );
+            let pn = PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(pn));
+            let datagram = DatagramInfo {
+                timestamp,
+                ..helper_datagram_info(ecn)
+            };
+            let mut processed_packet = ProcessedPacket::new(pn, &datagram);
+            processed_packet.ack_elicitation = AckElicitation::Eliciting;
+            manager.on_processed_packet(
+                &processed_packet,
+                path_event!(path, path_id),
+                &mut publisher,
+            );
+            reasons.0.pop().expect("an event should be published")
+        };
+        let not_ect = ExplicitCongestionNotification::NotEct;
+
+        // the first packet is delayed by the ack delay timer
+        assert!(on_packet(&mut manager, 1, not_ect, now).is_none());
+
+        // congestion experienced is acknowledged immediately
+        assert!(matches!(
+            on_packet(&mut manager, 2, ExplicitCongestionNotification::Ce, now),
+            Some(Reason::CongestionExperienced { .. })
+        ));
+
+        // a gap in packet numbers is acknowledged immediately
+        assert!(matches!(
+            on_packet(&mut manager, 4, not_ect, now),
+            Some(Reason::Reordering { .. })
+        ));
+
+        // an out of order packet is acknowledged immediately
+        assert!(matches!(
+            on_packet(&mut manager, 3, not_ect, now),
+            Some(Reason::Reordering { .. })
+        ));
+
+        // the ack delay timer expired since the first packet
+        let now = now + settings.max_ack_delay;
+        assert!(matches!(
+            on_packet(&mut manager, 5, not_ect, now),
+            Some(Reason::Timer { .. })
+        ));
+
+        // the following packets are delayed by the ack delay timer again, until as many packets
+        // as the packet tolerance have been received
+        let packet_tolerance = manager.packet_tolerance;
+        for pn in 6..packet_tolerance {
+            assert!(on_packet(&mut manager, pn, not_ect, now).is_none());
+        }
+
+        // reaching the packet tolerance is acknowledged immediately
+        assert!(matches!(
+            on_packet(&mut manager, packet_tolerance, not_ect, now),
+            Some(Reason::PacketTolerance { .. })
+        ));
+    }
+
+    #[test]
+    fn packet_tolerance_under_memory_pressure() {
+        let settings = ack::Settings::default();
+        let mut manager = AckManager::new(PacketNumberSpace::ApplicationData, settings);
+        let path = helper_path_server();
+        let path_id = path::Id::test_id();
+        let mut publisher = Publisher::snapshot();
+        let datagram = helper_datagram_info(ExplicitCongestionNotification::NotEct);
+
+        let mut on_packet = |manager: &mut AckManager, pn: u8| {
+            let pn = PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(pn));
+            let mut processed_packet = ProcessedPacket::new(pn, &datagram);
+            processed_packet.ack_elicitation = AckElicitation::Eliciting;
+            manager.on_processed_packet(
+                &processed_packet,
+                path_event!(path, path_id),
+                &mut publisher,
+            );
+        };
+
+        manager.on_memory_pressure(true);
+        for pn in 0..=manager.packet_tolerance {
+            on_packet(&mut manager, pn);
+        }
+
+        // the ACK is held back until the ack delay timer expires
+        assert!(!manager.transmission_state.is_active());
+        assert!(manager.ack_delay_timer.is_armed());
+
+        manager.on_memory_pressure(false);
+        let pn = manager.packet_tolerance + 1;
+        on_packet(&mut manager, pn);
+
+        assert!(manager.transmission_state.is_active());
+        assert!(matches!(
+            manager.activation_reason,
+            Some(AckSentReason::PacketTolerance)
+        ));
+    }
+
     #[test]
     fn on_transmit_complete_transmission_constrained() {
         let mut manager =
@@ -631,6 +1011,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn on_transmit_complete_ack_elicitation_disabled() {
+        let mut manager =
+            AckManager::new(PacketNumberSpace::ApplicationData, ack::Settings::default())
+                .with_ack_elicitation(false);
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut write_context = MockWriteContext::new(
+            s2n_quic_platform::time::now(),
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+
+        manager.ack_ranges = AckRanges::default();
+        assert!(manager
+            .ack_ranges
+            .insert_packet_number(
+                PacketNumberSpace::ApplicationData.new_packet_number(VarInt::from_u8(1)),
+            )
+            .is_ok());
+        manager.transmission_state = AckTransmissionState::Active { retransmissions: 0 };
+        manager.transmissions_since_elicitation =
+            Counter::new(manager.ack_settings.ack_elicitation_interval);
+
+        manager.on_transmit_complete(&mut write_context);
+
+        assert!(
+            write_context.frame_buffer.is_empty(),
+            "Ping should not be written when ack elicitation is disabled"
+        );
+    }
+
     #[test]
     fn size_of_snapshots() {
         assert_debug_snapshot!("AckManager", size_of::<AckManager>());
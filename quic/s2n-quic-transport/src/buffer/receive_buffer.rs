@@ -181,6 +181,41 @@ impl StreamReceiveBuffer {
         (bytes, chunks)
     }
 
+    /// Returns the number of bytes buffered out of order and the size of the largest gap
+    /// preceding them
+    ///
+    /// Only data that is not yet available for consumption is considered. Allocated space
+    /// after the last received slot is not counted as a gap.
+    #[inline]
+    pub fn reassembly_report(&self) -> (u64, u64) {
+        let mut out_of_order_bytes = 0;
+        let mut largest_gap = 0;
+        let mut current_gap = 0;
+        let mut is_contiguous = true;
+
+        for slot in &self.slots {
+            match slot {
+                SlotState::Received(b) => {
+                    if !is_contiguous {
+                        out_of_order_bytes += b.len() as u64;
+                        largest_gap = largest_gap.max(current_gap);
+                        current_gap = 0;
+                    }
+                }
+                SlotState::Allocated(b) => {
+                    is_contiguous = false;
+                    current_gap += b.len() as u64;
+                }
+                SlotState::Gap(gap_size) => {
+                    is_contiguous = false;
+                    current_gap += *gap_size;
+                }
+            }
+        }
+
+        (out_of_order_bytes, largest_gap)
+    }
+
     /// Allocates a buffer of the configured buffer size.
     /// This currently just allocates from the heap. In the future it could use a custom allocator
     /// or object pool.
@@ -732,3 +732,38 @@ fn pop_watermarked_test() {
         "the receive buffer should be empty after splitting"
     );
 }
+
+#[test]
+fn reassembly_report_test() {
+    let mut buffer = new_receive_buffer();
+
+    assert_eq!(
+        (0, 0),
+        buffer.reassembly_report(),
+        "an empty buffer should not report any out-of-order data"
+    );
+
+    buffer.write_at(4u32.into(), &[4, 5, 6, 7]).unwrap();
+    assert_eq!((4, 4), buffer.reassembly_report());
+
+    buffer.write_at(2u32.into(), &[2]).unwrap();
+    assert_eq!(
+        (5, 2),
+        buffer.reassembly_report(),
+        "the largest gap should be reported"
+    );
+
+    buffer.write_at(0u32.into(), &[0, 1, 2, 3]).unwrap();
+    assert_eq!(
+        (0, 0),
+        buffer.reassembly_report(),
+        "contiguous data should not be reported as out of order"
+    );
+
+    buffer.write_at(10_000u32.into(), &[1]).unwrap();
+    assert_eq!(
+        (1, 10_000 - 8),
+        buffer.reassembly_report(),
+        "gaps spanning multiple slots should be combined"
+    );
+}
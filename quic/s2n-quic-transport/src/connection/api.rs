@@ -12,12 +12,16 @@ use core::{
     fmt,
     sync::atomic::{self, Ordering},
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_core::{
     application,
     application::ServerName,
+    crypto::tls,
+    datagram,
     event::query::{Query, QueryMut},
     inet::SocketAddress,
+    path,
     stream::StreamType,
 };
 
@@ -147,6 +151,21 @@ impl Connection {
             .poll_open_stream(&self.api, stream_type, &mut self.open_token, context)
     }
 
+    /// Opens up to `count` streams of the given type
+    ///
+    /// `Poll::Pending` is only returned if no stream can currently be opened. Otherwise, as many
+    /// streams as the stream limits allow are returned, which may be fewer than `count`.
+    #[inline]
+    pub fn poll_open_streams(
+        &mut self,
+        stream_type: StreamType,
+        count: usize,
+        context: &Context,
+    ) -> Poll<Result<Vec<Stream>, connection::Error>> {
+        self.api
+            .poll_open_streams(&self.api, stream_type, count, &mut self.open_token, context)
+    }
+
     #[inline]
     pub fn poll_request(
         &self,
@@ -175,6 +194,32 @@ impl Connection {
         self.api.application_protocol()
     }
 
+    /// Returns the group selected for the key exchange in the handshake
+    ///
+    /// `None` is returned if the handshake has not completed yet or the TLS provider does not
+    /// report the selected group.
+    #[inline]
+    pub fn key_exchange_group(&self) -> Result<Option<tls::NamedGroup>, connection::Error> {
+        self.api.key_exchange_group()
+    }
+
+    /// Returns the signature scheme selected to authenticate the handshake
+    ///
+    /// `None` is returned if the handshake has not completed yet or the TLS provider does not
+    /// report the selected scheme.
+    #[inline]
+    pub fn signature_scheme(&self) -> Result<Option<tls::SignatureScheme>, connection::Error> {
+        self.api.signature_scheme()
+    }
+
+    /// Returns the DATAGRAM frame limits negotiated with the peer
+    ///
+    /// `None` is returned if the handshake has not completed yet.
+    #[inline]
+    pub fn datagram_config(&self) -> Result<Option<datagram::Config>, connection::Error> {
+        self.api.datagram_config()
+    }
+
     #[inline]
     pub fn id(&self) -> u64 {
         self.api.id()
@@ -189,6 +234,33 @@ impl Connection {
         self.api.keep_alive(enabled)
     }
 
+    /// Limits the rate at which the connection sends data to the given number of bytes per second
+    ///
+    /// `None` removes the limit.
+    #[inline]
+    pub fn set_send_rate_limit(&self, bytes_per_sec: Option<u64>) -> Result<(), connection::Error> {
+        self.api.set_send_rate_limit(bytes_per_sec)
+    }
+
+    /// Gracefully closes the connection once `timeout` has elapsed
+    ///
+    /// Setting a new deadline replaces the previous one.
+    #[inline]
+    pub fn set_deadline(&self, timeout: Duration) -> Result<(), connection::Error> {
+        self.api.set_deadline(timeout)
+    }
+
+    /// Gracefully closes the connection once `cancellation` completes
+    ///
+    /// Setting a new cancellation replaces the previous one.
+    #[inline]
+    pub fn set_cancellation(
+        &self,
+        cancellation: connection::Cancellation,
+    ) -> Result<(), connection::Error> {
+        self.api.set_cancellation(cancellation)
+    }
+
     #[inline]
     pub fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         self.api.local_address()
@@ -199,6 +271,21 @@ impl Connection {
         self.api.remote_address()
     }
 
+    /// Returns information about the path the connection is currently using
+    #[inline]
+    pub fn path_info(&self) -> Result<path::Info, connection::Error> {
+        self.api.path_info()
+    }
+
+    /// Returns the number of bytes which can still be sent on the active path before reaching
+    /// the anti-amplification limit
+    ///
+    /// `None` is returned once the path has been validated.
+    #[inline]
+    pub fn amplification_budget(&self) -> Result<Option<u64>, connection::Error> {
+        self.api.amplification_budget()
+    }
+
     #[inline]
     pub fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api.query_event_context(query)
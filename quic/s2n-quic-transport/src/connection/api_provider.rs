@@ -13,12 +13,16 @@ use bytes::Bytes;
 use core::{
     sync::atomic::AtomicUsize,
     task::{Context, Poll},
+    time::Duration,
 };
 use s2n_quic_core::{
     application,
     application::ServerName,
+    crypto::tls,
+    datagram,
     event::query::{Query, QueryMut},
     inet::SocketAddress,
+    path,
     stream::{ops, StreamId, StreamType},
 };
 
@@ -52,22 +56,50 @@ pub(crate) trait ConnectionApiProvider: Sync + Send {
         context: &Context,
     ) -> Poll<Result<Stream, connection::Error>>;
 
+    fn poll_open_streams(
+        &self,
+        arc_self: &Arc<dyn ConnectionApiProvider>,
+        stream_type: StreamType,
+        count: usize,
+        open_token: &mut connection::OpenToken,
+        context: &Context,
+    ) -> Poll<Result<Vec<Stream>, connection::Error>>;
+
     fn close_connection(&self, code: Option<application::Error>);
 
     fn server_name(&self) -> Result<Option<ServerName>, connection::Error>;
 
     fn application_protocol(&self) -> Result<Bytes, connection::Error>;
 
+    fn key_exchange_group(&self) -> Result<Option<tls::NamedGroup>, connection::Error>;
+
+    fn signature_scheme(&self) -> Result<Option<tls::SignatureScheme>, connection::Error>;
+
+    fn datagram_config(&self) -> Result<Option<datagram::Config>, connection::Error>;
+
     fn id(&self) -> u64;
 
     fn ping(&self) -> Result<(), connection::Error>;
 
     fn keep_alive(&self, enabled: bool) -> Result<(), connection::Error>;
 
+    fn set_send_rate_limit(&self, bytes_per_sec: Option<u64>) -> Result<(), connection::Error>;
+
+    fn set_deadline(&self, timeout: Duration) -> Result<(), connection::Error>;
+
+    fn set_cancellation(
+        &self,
+        cancellation: connection::Cancellation,
+    ) -> Result<(), connection::Error>;
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error>;
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn path_info(&self) -> Result<path::Info, connection::Error>;
+
+    fn amplification_budget(&self) -> Result<Option<u64>, connection::Error>;
+
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error>;
 
     fn query_event_context_mut(&self, query: &mut dyn QueryMut) -> Result<(), connection::Error>;
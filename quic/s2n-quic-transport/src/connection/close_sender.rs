@@ -23,7 +23,7 @@ pub struct CloseSender {
 }
 
 impl CloseSender {
-    pub fn close(&mut self, packet: Bytes, timeout: Duration, now: Timestamp) {
+    pub fn close(&mut self, packet: Bytes, packet_count: u8, timeout: Duration, now: Timestamp) {
         debug_assert!(matches!(self.state, State::Idle));
 
         let mut close_timer = Timer::default();
@@ -31,6 +31,7 @@ impl CloseSender {
 
         self.state = State::Closing {
             packet,
+            packet_count,
             transmission: TransmissionState::Transmitting,
             close_timer,
             limiter: Limiter::default(),
@@ -58,12 +59,14 @@ impl CloseSender {
 
         if let State::Closing {
             packet,
+            packet_count,
             transmission,
             ..
         } = &mut self.state
         {
             Transmission {
                 packet,
+                packet_count: *packet_count,
                 transmission,
                 path,
                 timestamp,
@@ -123,6 +126,7 @@ impl transmission::interest::Provider for CloseSender {
 
 pub struct Transmission<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher> {
     packet: &'a Bytes,
+    packet_count: u8,
     transmission: &'a mut TransmissionState,
     path: &'a mut Path<Config>,
     timestamp: Timestamp,
@@ -184,6 +188,12 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher> tx::Message
             .on_datagram_sent(event::builder::DatagramSent {
                 len: len as u16,
                 gso_offset,
+                // the close packet is encoded once and retransmitted as-is so padding and the
+                // Initial packet length aren't reported for each transmission
+                padding_len: 0,
+                padding_reason: None,
+                packet_count: self.packet_count,
+                initial_packet_len: None,
             });
 
         Ok(len)
@@ -195,6 +205,8 @@ enum State {
     Idle,
     Closing {
         packet: Bytes,
+        /// The number of packets coalesced into `packet`
+        packet_count: u8,
         limiter: Limiter,
         transmission: TransmissionState,
         close_timer: Timer,
@@ -358,7 +370,7 @@ mod tests {
                 }
 
                 path.on_closing();
-                sender.close(PACKET.clone(), *close_time, clock.get_time());
+                sender.close(PACKET.clone(), 1, *close_time, clock.get_time());
 
                 // transmit an initial packet
                 assert!(sender.can_transmit(path.transmission_constraint()));
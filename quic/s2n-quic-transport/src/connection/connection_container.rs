@@ -17,7 +17,7 @@ use crate::{
     },
     stream,
 };
-use alloc::{collections::BTreeMap, sync::Arc};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 use bytes::Bytes;
 use core::{
     cell::Cell,
@@ -26,6 +26,7 @@ use core::{
     pin::Pin,
     sync::atomic::AtomicUsize,
     task::{Context, Poll},
+    time::Duration,
 };
 use intrusive_collections::{
     intrusive_adapter, KeyAdapter, LinkedList, LinkedListLink, RBTree, RBTreeLink,
@@ -33,11 +34,14 @@ use intrusive_collections::{
 use s2n_quic_core::{
     application,
     application::ServerName,
+    crypto::tls,
+    datagram,
     event::{
         query::{Query, QueryMut},
         supervisor,
     },
     inet::SocketAddress,
+    path,
     recovery::K_GRANULARITY,
     time::Timestamp,
     transport,
@@ -93,6 +97,8 @@ struct ConnectionNode<C: connection::Trait, L: connection::Lock<C>> {
     timeout: Cell<Option<Timestamp>>,
     /// The count of outstanding application handles
     application_handle_count: AtomicUsize,
+    /// The number of bytes the connection buffered when its interests were last updated
+    memory_usage: Cell<u64>,
     /// The inner connection type
     _connection: PhantomData<C>,
 }
@@ -113,6 +119,7 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionNode<C, L> {
             waiting_for_timeout_link: RBTreeLink::new(),
             timeout: Cell::new(None),
             application_handle_count: AtomicUsize::new(0),
+            memory_usage: Cell::new(0),
             _connection: PhantomData,
         }
     }
@@ -296,6 +303,36 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         }
     }
 
+    fn poll_open_streams(
+        &self,
+        arc_self: &ConnectionApi,
+        stream_type: stream::StreamType,
+        count: usize,
+        open_token: &mut connection::OpenToken,
+        context: &Context,
+    ) -> Poll<Result<Vec<stream::Stream>, connection::Error>> {
+        let response = self
+            .api_poll_call(|conn| conn.poll_open_streams(stream_type, count, open_token, context));
+
+        match response {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Err(e).into(),
+            Poll::Ready(Ok((first_stream_id, opened))) => {
+                let mut streams = Vec::with_capacity(opened);
+                let mut stream_id = Some(first_stream_id);
+
+                for _ in 0..opened {
+                    let id = stream_id.expect("opened stream ids should be valid");
+                    let connection = Connection::new(arc_self.clone());
+                    streams.push(stream::Stream::new(connection, id));
+                    stream_id = id.next_of_type();
+                }
+
+                Ok(streams).into()
+            }
+        }
+    }
+
     fn close_connection(&self, error: Option<application::Error>) {
         let _: Result<(), connection::Error> = self.api_write_call(|conn| {
             conn.application_close(error);
@@ -311,6 +348,18 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_read_call(|conn| Ok(conn.application_protocol()))
     }
 
+    fn key_exchange_group(&self) -> Result<Option<tls::NamedGroup>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.key_exchange_group()))
+    }
+
+    fn signature_scheme(&self) -> Result<Option<tls::SignatureScheme>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.signature_scheme()))
+    }
+
+    fn datagram_config(&self) -> Result<Option<datagram::Config>, connection::Error> {
+        self.api_read_call(|conn| Ok(conn.datagram_config()))
+    }
+
     fn id(&self) -> u64 {
         self.internal_connection_id.into()
     }
@@ -323,6 +372,21 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_write_call(|conn| conn.keep_alive(enabled))
     }
 
+    fn set_send_rate_limit(&self, bytes_per_sec: Option<u64>) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.set_send_rate_limit(bytes_per_sec))
+    }
+
+    fn set_deadline(&self, timeout: Duration) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.set_deadline(timeout))
+    }
+
+    fn set_cancellation(
+        &self,
+        cancellation: connection::Cancellation,
+    ) -> Result<(), connection::Error> {
+        self.api_write_call(|conn| conn.set_cancellation(cancellation))
+    }
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         self.api_read_call(|conn| conn.local_address())
     }
@@ -331,6 +395,14 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionApiProvider for Con
         self.api_read_call(|conn| conn.remote_address())
     }
 
+    fn path_info(&self) -> Result<path::Info, connection::Error> {
+        self.api_read_call(|conn| conn.path_info())
+    }
+
+    fn amplification_budget(&self) -> Result<Option<u64>, connection::Error> {
+        self.api_read_call(|conn| conn.amplification_budget())
+    }
+
     #[inline]
     fn query_event_context(&self, query: &mut dyn Query) -> Result<(), connection::Error> {
         self.api_read_call(|conn| {
@@ -374,6 +446,10 @@ struct InterestLists<C: connection::Trait, L: connection::Lock<C>> {
     handshake_connections: usize,
     /// Total connection count
     connection_count: usize,
+    /// Total number of bytes buffered by all of the connections
+    memory_usage: u64,
+    /// Set while the connections were notified of endpoint memory pressure
+    is_under_memory_pressure: bool,
 }
 
 impl<C: connection::Trait, L: connection::Lock<C>> InterestLists<C, L> {
@@ -386,6 +462,8 @@ impl<C: connection::Trait, L: connection::Lock<C>> InterestLists<C, L> {
             waiting_for_open: BTreeMap::new(),
             handshake_connections: 0,
             connection_count: 0,
+            memory_usage: 0,
+            is_under_memory_pressure: false,
         }
     }
 
@@ -399,6 +477,10 @@ impl<C: connection::Trait, L: connection::Lock<C>> InterestLists<C, L> {
     ) -> Result<(), L::Error> {
         let id = node.internal_connection_id;
 
+        // Charge the latest memory usage of the connection to the endpoint
+        let prev_memory_usage = node.memory_usage.replace(interests.memory_usage);
+        self.memory_usage = self.memory_usage - prev_memory_usage + interests.memory_usage;
+
         // Note that all comparisons start by checking whether the connection is
         // already part of the given list. This is required in order for the
         // following operation to be safe. Inserting an element in a list while
@@ -584,6 +666,7 @@ impl<C: connection::Trait, L: connection::Lock<C>> InterestLists<C, L> {
         remove_connection_from_list!(waiting_for_timeout, waiting_for_timeout_link);
 
         self.connection_count -= 1;
+        self.memory_usage -= connection.memory_usage.get();
     }
 }
 
@@ -784,7 +867,47 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
         futures_core::Stream::poll_next(Pin::new(&mut self.connector_receiver), cx)
     }
 
-    fn insert_connection(&mut self, connection: C, internal_connection_id: InternalConnectionId) {
+    /// Closes the client connections whose attempts are no longer awaited by the application
+    ///
+    /// `close` is called with each of these connections while they are still handshaking. The
+    /// context is registered with each pending attempt so the endpoint is woken up once the
+    /// application cancels it. Returns the number of connections which were closed.
+    pub(crate) fn close_canceled_attempts<F: FnMut(&mut C)>(
+        &mut self,
+        cx: &mut Context,
+        mut close: F,
+    ) -> usize {
+        debug_assert!(
+            <C::Config as endpoint::Config>::ENDPOINT_TYPE.is_client(),
+            "only clients can open connections"
+        );
+
+        let mut canceled = Vec::new();
+        self.interest_lists.waiting_for_open.retain(|id, sender| {
+            if sender.poll_canceled(cx).is_ready() {
+                canceled.push(*id);
+                false
+            } else {
+                true
+            }
+        });
+
+        for id in canceled.iter() {
+            self.with_connection(*id, &mut close);
+        }
+
+        canceled.len()
+    }
+
+    fn insert_connection(
+        &mut self,
+        mut connection: C,
+        internal_connection_id: InternalConnectionId,
+    ) {
+        if self.interest_lists.is_under_memory_pressure {
+            connection.on_memory_pressure(true);
+        }
+
         let interests = connection.interests();
 
         let connection = L::new(connection);
@@ -805,6 +928,9 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
             self.interest_lists.handshake_connections += 1;
             self.interest_lists.connection_count += 1;
             self.ensure_counter_consistency();
+        } else {
+            // release the charge of the connection, since it was never inserted
+            self.interest_lists.memory_usage -= connection.memory_usage.get();
         }
     }
 
@@ -817,6 +943,79 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
         self.interest_lists.connection_count
     }
 
+    /// Returns the total number of bytes buffered by all of the connections
+    pub fn memory_usage(&self) -> u64 {
+        self.interest_lists.memory_usage
+    }
+
+    /// Returns `true` if the connections were notified of endpoint memory pressure
+    pub fn is_under_memory_pressure(&self) -> bool {
+        self.interest_lists.is_under_memory_pressure
+    }
+
+    /// Notifies all of the connections when the endpoint enters or leaves memory pressure
+    ///
+    /// The endpoint enters memory pressure once the connections buffer at least `memory_limit`
+    /// bytes. The pressure is only released once the usage drops below three quarters of the
+    /// limit, so the connections aren't notified each time the usage crosses the limit.
+    pub fn update_memory_pressure(&mut self, memory_limit: Option<u64>) {
+        let memory_usage = self.memory_usage();
+        let is_under_pressure = memory_limit.map_or(false, |limit| {
+            if self.interest_lists.is_under_memory_pressure {
+                memory_usage >= limit - limit / 4
+            } else {
+                memory_usage >= limit
+            }
+        });
+
+        if self.interest_lists.is_under_memory_pressure == is_under_pressure {
+            return;
+        }
+        self.interest_lists.is_under_memory_pressure = is_under_pressure;
+
+        // The interests can't be updated while iterating over the connection map so collect all
+        // of the connections first. This is expected to be rare, since it only happens on each
+        // transition.
+        let nodes: Vec<_> = self
+            .connection_map
+            .iter()
+            .map(|node| unsafe {
+                // Safety: We know that all of our ConnectionNode's are stored in
+                // reference counted pointers.
+                node.arc_from_ref()
+            })
+            .collect();
+
+        for node in nodes {
+            let interests = match node.inner.write(|conn| {
+                conn.on_memory_pressure(is_under_pressure);
+                conn.interests()
+            }) {
+                Ok(interests) => interests,
+                Err(_) => {
+                    self.remove_poisoned_node(&node);
+                    continue;
+                }
+            };
+
+            if self
+                .interest_lists
+                .update_interests(
+                    &mut self.accept_queue,
+                    &node,
+                    interests,
+                    ConnectionContainerIterationResult::Continue,
+                )
+                .is_err()
+            {
+                self.remove_poisoned_node(&node);
+            }
+        }
+
+        self.ensure_counter_consistency();
+        self.finalize_done_connections();
+    }
+
     /// Looks up the `Connection` with the given ID and executes the provided function
     /// on it.
     ///
@@ -917,6 +1116,13 @@ impl<C: connection::Trait, L: connection::Lock<C>> ConnectionContainer<C, L> {
             let expected = self.count_handshaking_connections();
             assert_eq!(expected, self.interest_lists.handshake_connections);
             assert_eq!(self.len(), self.connection_map.iter().count());
+            assert_eq!(
+                self.memory_usage(),
+                self.connection_map
+                    .iter()
+                    .map(|conn| conn.memory_usage.get())
+                    .sum::<u64>()
+            );
         }
     }
 
@@ -38,6 +38,7 @@ struct TestConnection {
     is_handshaking: bool,
     has_been_accepted: bool,
     is_closed: bool,
+    is_under_memory_pressure: bool,
     interests: ConnectionInterests,
     close_timer: Timer,
 }
@@ -48,6 +49,7 @@ impl Default for TestConnection {
             is_handshaking: true,
             has_been_accepted: false,
             is_closed: false,
+            is_under_memory_pressure: false,
             interests: ConnectionInterests {
                 transmission: true,
                 ..Default::default()
@@ -125,6 +127,10 @@ impl connection::Trait for TestConnection {
         Ok(())
     }
 
+    fn on_memory_pressure(&mut self, is_under_pressure: bool) {
+        self.is_under_memory_pressure = is_under_pressure;
+    }
+
     fn on_wakeup(
         &mut self,
         _timestamp: Timestamp,
@@ -134,6 +140,12 @@ impl connection::Trait for TestConnection {
         Ok(())
     }
 
+    fn take_cancellation(&mut self) -> Option<connection::PendingCancellation> {
+        None
+    }
+
+    fn restore_cancellation(&mut self, _cancellation: connection::PendingCancellation) {}
+
     fn handle_initial_packet(
         &mut self,
         _datagram: &DatagramInfo,
@@ -273,6 +285,16 @@ impl connection::Trait for TestConnection {
         todo!()
     }
 
+    fn poll_open_streams(
+        &mut self,
+        _stream_type: stream::StreamType,
+        _count: usize,
+        _token: &mut connection::OpenToken,
+        _context: &Context,
+    ) -> Poll<Result<(stream::StreamId, usize), connection::Error>> {
+        todo!()
+    }
+
     fn application_close(&mut self, _error: Option<application::Error>) {
         // no-op
     }
@@ -285,6 +307,18 @@ impl connection::Trait for TestConnection {
         todo!()
     }
 
+    fn key_exchange_group(&self) -> Option<tls::NamedGroup> {
+        todo!()
+    }
+
+    fn signature_scheme(&self) -> Option<tls::SignatureScheme> {
+        todo!()
+    }
+
+    fn datagram_config(&self) -> Option<datagram::Config> {
+        todo!()
+    }
+
     fn ping(&mut self) -> Result<(), connection::Error> {
         todo!()
     }
@@ -301,6 +335,39 @@ impl connection::Trait for TestConnection {
         Ok(SocketAddress::default())
     }
 
+    fn set_send_rate_limit(
+        &mut self,
+        _bytes_per_sec: Option<u64>,
+    ) -> Result<(), connection::Error> {
+        Ok(())
+    }
+
+    fn set_deadline(&mut self, _timeout: Duration) -> Result<(), connection::Error> {
+        Ok(())
+    }
+
+    fn set_cancellation(
+        &mut self,
+        _cancellation: connection::Cancellation,
+    ) -> Result<(), connection::Error> {
+        Ok(())
+    }
+
+    fn path_info(&self) -> Result<path::Info, connection::Error> {
+        Ok(path::Info::new(
+            SocketAddress::default(),
+            SocketAddress::default(),
+            true,
+            Duration::ZERO,
+            Duration::ZERO,
+            Duration::ZERO,
+        ))
+    }
+
+    fn amplification_budget(&self) -> Result<Option<u64>, connection::Error> {
+        Ok(None)
+    }
+
     fn error(&self) -> Option<connection::Error> {
         None
     }
@@ -388,7 +455,9 @@ enum Operation {
         transmission: bool,
         new_connection_id: bool,
         timeout: Option<u16>,
+        memory_usage: u16,
     },
+    MemoryPressure(u16),
     CloseApp,
     Receive,
     Timeout(u16),
@@ -435,6 +504,7 @@ fn container_test() {
                     transmission,
                     new_connection_id,
                     timeout,
+                    memory_usage,
                 } => {
                     if connections.is_empty() {
                         continue;
@@ -467,6 +537,7 @@ fn container_test() {
                         i.transmission = *transmission;
                         i.new_connection_id = *new_connection_id;
                         i.timeout = timeout.map(|ms| now + Duration::from_millis(ms as _));
+                        i.memory_usage = *memory_usage as u64;
 
                         // we need to express at least one interest to ensure progress
                         if !(i.transmission || i.new_connection_id || i.timeout.is_some()) {
@@ -480,6 +551,30 @@ fn container_test() {
 
                     assert!(was_called);
                 }
+                Operation::MemoryPressure(limit) => {
+                    let limit = *limit as u64;
+                    let was_under_pressure = container.interest_lists.is_under_memory_pressure;
+                    container.update_memory_pressure(Some(limit));
+
+                    let is_under_pressure = container.interest_lists.is_under_memory_pressure;
+                    if was_under_pressure {
+                        assert_eq!(
+                            is_under_pressure,
+                            container.memory_usage() >= limit - limit / 4
+                        );
+                    } else {
+                        assert_eq!(is_under_pressure, container.memory_usage() >= limit);
+                    }
+
+                    // all of the connections are notified of the transitions
+                    for node in container.connection_map.iter() {
+                        let is_notified = node
+                            .inner
+                            .read(|conn| conn.is_under_memory_pressure)
+                            .unwrap();
+                        assert_eq!(is_notified, is_under_pressure);
+                    }
+                }
                 Operation::CloseApp => {
                     handle = None;
                 }
@@ -570,5 +665,12 @@ fn container_test() {
         }
 
         assert!(connections.next().is_none());
+        // the container charges the latest memory usage reported by each connection
+        let memory_usage: u64 = container
+            .connection_map
+            .iter()
+            .map(|node| node.inner.read(|conn| conn.interests.memory_usage).unwrap())
+            .sum();
+        assert_eq!(container.memory_usage(), memory_usage);
     });
 }
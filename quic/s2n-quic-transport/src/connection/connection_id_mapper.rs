@@ -57,10 +57,10 @@ pub(crate) struct StatelessResetMap {
 }
 
 impl StatelessResetMap {
-    /// Constructs a new `StatelessResetMap`
-    fn new(hash_state: HashState) -> Self {
+    /// Constructs a new `StatelessResetMap` with room for `capacity` tokens
+    fn new(hash_state: HashState, capacity: usize) -> Self {
         Self {
-            map: HashMap::with_hasher(hash_state),
+            map: HashMap::with_capacity_and_hasher(capacity, hash_state),
         }
     }
 
@@ -91,10 +91,10 @@ pub(crate) struct LocalIdMap {
 }
 
 impl LocalIdMap {
-    /// Constructs a new `LocalIdMap`
-    fn new(hash_state: HashState) -> Self {
+    /// Constructs a new `LocalIdMap` with room for `capacity` ids
+    fn new(hash_state: HashState, capacity: usize) -> Self {
         Self {
-            map: HashMap::with_hasher(hash_state),
+            map: HashMap::with_capacity_and_hasher(capacity, hash_state),
         }
     }
 
@@ -139,14 +139,21 @@ pub(crate) struct InitialIdMap {
 }
 
 impl InitialIdMap {
-    /// Constructs a new `InitialIdMap`
+    /// Constructs a new `InitialIdMap` with room for `capacity` ids
     fn new(
         initial_to_internal_hash_state: HashState,
         internal_to_initial_hash_state: HashState,
+        capacity: usize,
     ) -> Self {
         Self {
-            initial_to_internal_id_map: HashMap::with_hasher(initial_to_internal_hash_state),
-            internal_to_initial_id_map: HashMap::with_hasher(internal_to_initial_hash_state),
+            initial_to_internal_id_map: HashMap::with_capacity_and_hasher(
+                capacity,
+                initial_to_internal_hash_state,
+            ),
+            internal_to_initial_id_map: HashMap::with_capacity_and_hasher(
+                capacity,
+                internal_to_initial_hash_state,
+            ),
         }
     }
 
@@ -197,13 +204,14 @@ pub(crate) struct ConnectionIdMapperState {
 }
 
 impl ConnectionIdMapperState {
-    fn new<R: random::Generator>(random_generator: &mut R) -> Self {
+    fn new<R: random::Generator>(random_generator: &mut R, capacity: usize) -> Self {
         Self {
-            local_id_map: LocalIdMap::new(HashState::new(random_generator)),
-            stateless_reset_map: StatelessResetMap::new(HashState::new(random_generator)),
+            local_id_map: LocalIdMap::new(HashState::new(random_generator), capacity),
+            stateless_reset_map: StatelessResetMap::new(HashState::new(random_generator), capacity),
             initial_id_map: InitialIdMap::new(
                 HashState::new(random_generator),
                 HashState::new(random_generator),
+                capacity,
             ),
         }
     }
@@ -222,9 +230,25 @@ impl ConnectionIdMapper {
     pub fn new<R: random::Generator>(
         random_generator: &mut R,
         endpoint_type: endpoint::Type,
+    ) -> Self {
+        Self::with_capacity(random_generator, endpoint_type, 0)
+    }
+
+    /// Creates a new `ConnectionIdMapper` which can map the ids of `capacity` connections
+    /// without reallocating
+    ///
+    /// Each connection may register more than one local id, so this only reserves room for the
+    /// first id of each connection.
+    pub fn with_capacity<R: random::Generator>(
+        random_generator: &mut R,
+        endpoint_type: endpoint::Type,
+        capacity: usize,
     ) -> Self {
         Self {
-            state: Arc::new(Mutex::new(ConnectionIdMapperState::new(random_generator))),
+            state: Arc::new(Mutex::new(ConnectionIdMapperState::new(
+                random_generator,
+                capacity,
+            ))),
             endpoint_type,
         }
     }
@@ -459,4 +483,31 @@ mod tests {
 
         assert_eq!(None, mapper.lookup_internal_connection_id(&local_id));
     }
+
+    #[test]
+    fn with_capacity_test() {
+        let mut random_generator = random::testing::Generator(123);
+        let mut mapper =
+            ConnectionIdMapper::with_capacity(&mut random_generator, endpoint::Type::Server, 100);
+
+        {
+            let state = mapper.state.lock().unwrap();
+            assert!(state.local_id_map.map.capacity() >= 100);
+            assert!(state.stateless_reset_map.map.capacity() >= 100);
+            assert!(state.initial_id_map.initial_to_internal_id_map.capacity() >= 100);
+            assert!(state.initial_id_map.internal_to_initial_id_map.capacity() >= 100);
+        }
+
+        // the reserved capacity doesn't change the behavior of the mapper
+        let internal_id = InternalConnectionIdGenerator::new().generate_id();
+        let local_id = connection::LocalId::try_from_bytes(b"id000001").unwrap();
+        let initial_id = connection::InitialId::try_from(local_id).unwrap();
+        assert!(mapper
+            .try_insert_initial_id(initial_id, internal_id)
+            .is_ok());
+        assert_eq!(
+            Some(internal_id),
+            mapper.lookup_internal_connection_id(&local_id)
+        );
+    }
 }
@@ -34,9 +34,9 @@ use core::{
 use s2n_quic_core::{
     application,
     application::ServerName,
-    connection::{id::Generator as _, InitialId, PeerId},
+    connection::{id::Generator as _, InitialId, PeerId, Tags},
     crypto::{tls, CryptoSuite},
-    datagram::{Receiver, Sender},
+    datagram::{self, Receiver, Sender},
     event::{
         self,
         builder::{DatagramDropReason, MtuUpdatedCause, RxStreamProgress, TxStreamProgress},
@@ -176,6 +176,28 @@ pub struct ConnectionImpl<Config: endpoint::Config> {
     /// A Waker to the connection.
     waker: Waker,
     event_context: EventContext<Config>,
+    /// Set when the connection has no open streams and no data in flight
+    is_idle: bool,
+    /// Set while the endpoint memory limit is reached
+    is_under_memory_pressure: bool,
+    /// The time at which the connection became congestion limited and the congestion window at
+    /// that time, if it is currently limited
+    congestion_limited_since: Option<(Timestamp, u32)>,
+    /// Set when the application updated the send rate limit and the event is yet to be published
+    send_rate_limit_updated: bool,
+    /// Set when the server sent another Retry packet after the client processed one
+    ///
+    /// A handshake timeout is reported as a retry loop when this is set.
+    retry_repeated: bool,
+    /// The timeout of the deadline set by the application, which is yet to be armed
+    ///
+    /// The API calls don't have access to the current time so the deadline timer is armed when the
+    /// connection handles the wakeup.
+    pending_deadline: Option<Duration>,
+    /// The future set by the application which closes the connection once it completes
+    ///
+    /// This is taken and polled by the endpoint once the connection has handled a wakeup.
+    cancellation: Option<connection::PendingCancellation>,
 }
 
 struct EventContext<Config: endpoint::Config> {
@@ -185,6 +207,9 @@ struct EventContext<Config: endpoint::Config> {
     /// The QUIC protocol version which is used for this particular connection
     quic_version: u32,
 
+    /// The application-defined tags included in every event for the connection
+    tags: Tags,
+
     /// Holds the event context associated with the connection
     context: <Config::EventSubscriber as event::Subscriber>::ConnectionContext,
 }
@@ -194,11 +219,22 @@ impl<Config: endpoint::Config> fmt::Debug for EventContext<Config> {
         f.debug_struct("EventContext")
             .field("internal_connection_id", &self.internal_connection_id)
             .field("quic_version", &self.quic_version)
+            .field("tags", &self.tags)
             .finish()
     }
 }
 
 impl<Config: endpoint::Config> EventContext<Config> {
+    #[inline]
+    fn meta(&self, timestamp: Timestamp) -> event::builder::ConnectionMeta {
+        event::builder::ConnectionMeta {
+            endpoint_type: Config::ENDPOINT_TYPE,
+            id: self.internal_connection_id.into(),
+            timestamp,
+            tags: self.tags.clone(),
+        }
+    }
+
     #[inline]
     fn publisher<'a>(
         &'a mut self,
@@ -206,11 +242,7 @@ impl<Config: endpoint::Config> EventContext<Config> {
         subscriber: &'a mut Config::EventSubscriber,
     ) -> event::ConnectionPublisherSubscriber<'a, Config::EventSubscriber> {
         event::ConnectionPublisherSubscriber::new(
-            event::builder::ConnectionMeta {
-                endpoint_type: Config::ENDPOINT_TYPE,
-                id: self.internal_connection_id.into(),
-                timestamp,
-            },
+            self.meta(timestamp),
             self.quic_version,
             subscriber,
             &mut self.context,
@@ -254,6 +286,7 @@ macro_rules! transmission_context {
             outcome: $outcome,
             ecn,
             min_packet_len: None,
+            max_coalesced_packets: $self.limits.max_coalesced_packets(),
             transmission_mode: $transmission_mode,
             publisher: &mut $self.event_context.publisher($timestamp, $subscriber),
             packet_interceptor: $packet_interceptor,
@@ -315,6 +348,14 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             // Move the connection into the active state.
             self.state = ConnectionState::Active;
 
+            // Apply any memory pressure the endpoint reported while the connection was handshaking
+            if self.is_under_memory_pressure {
+                if let Some((space, _)) = space_manager.application_mut() {
+                    space.stream_manager.on_memory_pressure(true);
+                    space.ack_manager.on_memory_pressure(true);
+                }
+            }
+
             // Cancel the max handshake duration timer as the handshake has completed in time
             self.timers.max_handshake_duration_timer.cancel();
 
@@ -379,6 +420,11 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
             })
         }
 
+        self.local_id_registry.publish_events(&mut publisher);
+
+        self.update_idle_status(packet.datagram.timestamp, subscriber);
+        self.update_congestion_limited_status(packet.datagram.timestamp, subscriber);
+
         // check to see if we're flushing and should now close the connection
         if self.poll_flush().is_ready() {
             self.error?;
@@ -387,6 +433,89 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
         Ok(())
     }
 
+    /// Notifies the subscriber when the connection becomes idle or resumes activity
+    ///
+    /// A connection is considered idle once the handshake has completed and it has no open
+    /// streams and no data in flight. Once the connection starts closing, it is neither idle nor
+    /// active so no further transitions are reported.
+    fn update_idle_status(
+        &mut self,
+        timestamp: Timestamp,
+        subscriber: &mut Config::EventSubscriber,
+    ) {
+        if self.state != ConnectionState::Active {
+            return;
+        }
+
+        let is_idle = self.space_manager.application().map_or(false, |space| {
+            space.stream_manager.active_stream_count() == 0
+        }) && self
+            .path_manager
+            .active_path()
+            .congestion_controller
+            .bytes_in_flight()
+            == 0;
+
+        if core::mem::replace(&mut self.is_idle, is_idle) == is_idle {
+            return;
+        }
+
+        let mut publisher = self.event_context.publisher(timestamp, subscriber);
+
+        if is_idle {
+            publisher.on_connection_idle(event::builder::ConnectionIdle {});
+        } else {
+            publisher.on_connection_active(event::builder::ConnectionActive {});
+        }
+    }
+
+    /// Notifies the subscriber when the connection becomes, or is no longer, congestion limited
+    ///
+    /// A connection is considered congestion limited while the congestion window of the active
+    /// path is full and the connection has data waiting to be sent.
+    fn update_congestion_limited_status(
+        &mut self,
+        timestamp: Timestamp,
+        subscriber: &mut Config::EventSubscriber,
+    ) {
+        let path = self.path_manager.active_path();
+
+        let is_congestion_limited = matches!(
+            self.state,
+            ConnectionState::Handshaking | ConnectionState::Active | ConnectionState::Flushing
+        ) && path.congestion_controller.is_congestion_limited()
+            && matches!(
+                self.space_manager.get_transmission_interest(),
+                transmission::Interest::NewData | transmission::Interest::LostData
+            );
+
+        match (self.congestion_limited_since, is_congestion_limited) {
+            (None, true) => {
+                let congestion_window = path.congestion_controller.congestion_window();
+                self.congestion_limited_since = Some((timestamp, congestion_window));
+
+                let path_id = self.path_manager.active_path_id().as_u8() as u64;
+                let mut publisher = self.event_context.publisher(timestamp, subscriber);
+                publisher.on_congestion_limited(event::builder::CongestionLimited {
+                    path_id,
+                    congestion_window,
+                });
+            }
+            (Some((since, congestion_window)), false) => {
+                self.congestion_limited_since = None;
+
+                let path_id = self.path_manager.active_path_id().as_u8() as u64;
+                let mut publisher = self.event_context.publisher(timestamp, subscriber);
+                publisher.on_congestion_limit_ended(event::builder::CongestionLimitEnded {
+                    path_id,
+                    duration: timestamp.saturating_duration_since(since),
+                    congestion_window,
+                });
+            }
+            _ => {}
+        }
+    }
+
     fn on_ack_eliciting_packet_sent(&mut self, timestamp: Timestamp) {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-10.1
         //# An endpoint also restarts its
@@ -423,6 +552,7 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
         packet_interceptor: &'a mut Config::PacketInterceptor,
     ) -> usize {
         let mut count = 0;
+        let max_coalesced_packets = self.limits.max_coalesced_packets();
         let mut pending_paths = self.path_manager.paths_pending_validation();
         while let Some((path_id, path_manager)) = pending_paths.next_path() {
             // It is more efficient to coalesce path validation and other
@@ -449,6 +579,7 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
                         local_id_registry: &mut self.local_id_registry,
                         outcome,
                         min_packet_len: None,
+                        max_coalesced_packets,
                         ecn,
                         transmission_mode,
                         publisher: &mut self.event_context.publisher(timestamp, subscriber),
@@ -471,12 +602,7 @@ impl<Config: endpoint::Config> ConnectionImpl<Config> {
         subscriber: &mut Config::EventSubscriber,
         supervisor_context: &supervisor::Context,
     ) -> Result<(), connection::Error> {
-        let meta = event::builder::ConnectionMeta {
-            endpoint_type: Config::ENDPOINT_TYPE,
-            id: self.event_context.internal_connection_id.into(),
-            timestamp,
-        }
-        .into_event();
+        let meta = self.event_context.meta(timestamp).into_event();
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-21.6
         //# QUIC deployments SHOULD provide mitigations for the Slowloris
@@ -559,11 +685,13 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             context: parameters.event_context,
             internal_connection_id: parameters.internal_connection_id,
             quic_version: parameters.quic_version,
+            tags: parameters.tags,
         };
 
         // The path manager always starts with a single path containing the known peer and local
         // connection ids.
-        let rtt_estimator = RttEstimator::default();
+        let rtt_estimator =
+            RttEstimator::default().with_granularity(parameters.limits.timer_granularity());
         // Assume clients validate the server's address implicitly.
         let peer_validated = Self::Config::ENDPOINT_TYPE.is_server();
 
@@ -614,6 +742,13 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             wakeup_handle,
             waker,
             event_context,
+            is_idle: false,
+            is_under_memory_pressure: false,
+            congestion_limited_since: None,
+            send_rate_limit_updated: false,
+            retry_repeated: false,
+            pending_deadline: None,
+            cancellation: None,
         };
 
         if Config::ENDPOINT_TYPE.is_client() {
@@ -637,11 +772,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             }
         }
 
-        let meta = event::builder::ConnectionMeta {
-            endpoint_type: Config::ENDPOINT_TYPE,
-            id: connection.internal_connection_id().into(),
-            timestamp: parameters.timestamp,
-        };
+        let meta = connection.event_context.meta(parameters.timestamp);
 
         if let Some(duration) = parameters.event_subscriber.supervisor_timeout(
             &mut connection.event_context.context,
@@ -725,7 +856,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 packet_interceptor,
             );
 
-            if let Some(packet) = self.space_manager.on_transmit_close(
+            if let Some((packet, packet_count)) = self.space_manager.on_transmit_close(
                 &early_connection_close,
                 &connection_close,
                 &mut context,
@@ -738,7 +869,8 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                 //# times the current PTO interval as defined in [QUIC-RECOVERY].
                 let timeout = 3 * self.current_pto();
 
-                self.close_sender.close(packet, timeout, timestamp);
+                self.close_sender
+                    .close(packet, packet_count, timeout, timestamp);
             } else if cfg!(debug_assertions) {
                 panic!("missing packet spaces before sending connection close frame");
             }
@@ -891,12 +1023,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                     self.on_ack_eliciting_packet_sent(timestamp);
                 }
 
-                if let Some(edt) = self
-                    .path_manager
-                    .active_path()
-                    .congestion_controller
-                    .earliest_departure_time()
-                {
+                if let Some(edt) = self.path_manager.active_path().earliest_departure_time() {
                     if !edt.has_elapsed(timestamp) {
                         // We can't transmit more until a future time, so arm the pacing
                         // timer to pause transmission until the earliest departure time.
@@ -911,11 +1038,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                     }
                 }
 
-                let meta = event::builder::ConnectionMeta {
-                    endpoint_type: Config::ENDPOINT_TYPE,
-                    id: self.internal_connection_id().into(),
-                    timestamp,
-                };
+                let meta = self.event_context.meta(timestamp);
                 let path_id = self.path_manager.active_path_id().as_u8();
                 let path = self.path_manager.active_path();
                 subscriber.on_recovery_metrics(
@@ -941,6 +1064,8 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
                     })
                 }
 
+                self.local_id_registry.publish_events(&mut publisher);
+
                 // check to see if we are flushing and should close
                 if self.poll_flush().is_ready() {
                     // trigger a wake up so we can close
@@ -966,6 +1091,9 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             }
         }
 
+        self.update_idle_status(timestamp, subscriber);
+        self.update_congestion_limited_status(timestamp, subscriber);
+
         if count == 0 {
             Err(ConnectionOnTransmitError::NoDatagram)
         } else {
@@ -1015,6 +1143,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             timestamp,
             &mut publisher,
         );
+        self.local_id_registry.publish_events(&mut publisher);
 
         if self
             .timers
@@ -1023,17 +1152,35 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             .is_ready()
         {
             debug_assert_eq!(ConnectionState::Handshaking, self.state);
+
+            if self.retry_repeated {
+                return Err(connection::Error::retry_loop());
+            }
+
             return Err(connection::Error::max_handshake_duration_exceeded(
                 self.limits.max_handshake_duration(),
             ));
         }
 
+        if self
+            .timers
+            .deadline_timer
+            .poll_expiration(timestamp)
+            .is_ready()
+        {
+            return Err(connection::Error::deadline_exceeded());
+        }
+
         if self
             .timers
             .peer_idle_timer
             .poll_expiration(timestamp)
             .is_ready()
         {
+            if self.retry_repeated && self.state == ConnectionState::Handshaking {
+                return Err(connection::Error::retry_loop());
+            }
+
             return Err(connection::Error::idle_timer_expired());
         }
 
@@ -1046,6 +1193,9 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             self.on_supervisor_timeout(timestamp, subscriber, supervisor_context)?;
         }
 
+        self.update_idle_status(timestamp, subscriber);
+        self.update_congestion_limited_status(timestamp, subscriber);
+
         // check to see if we're flushing the connection
         if self.poll_flush().is_ready() {
             return self.error;
@@ -1074,6 +1224,15 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(())
     }
 
+    fn on_memory_pressure(&mut self, is_under_pressure: bool) {
+        self.is_under_memory_pressure = is_under_pressure;
+
+        if let Some((space, _)) = self.space_manager.application_mut() {
+            space.stream_manager.on_memory_pressure(is_under_pressure);
+            space.ack_manager.on_memory_pressure(is_under_pressure);
+        }
+    }
+
     /// Handles all external wakeups on the [`Connection`].
     fn on_wakeup(
         &mut self,
@@ -1087,12 +1246,42 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         // check if crypto progress can be made
         self.update_crypto_state(timestamp, subscriber, datagram)?;
 
+        self.update_idle_status(timestamp, subscriber);
+        self.update_congestion_limited_status(timestamp, subscriber);
+
+        // streams which had data queued by the application start waiting to transmit
+        if let Some((space, _)) = self.space_manager.application_mut() {
+            space.stream_manager.start_pending_waits(timestamp);
+        }
+
+        if core::mem::take(&mut self.send_rate_limit_updated) {
+            let bytes_per_sec = self.path_manager.active_path().send_rate_limiter.rate();
+            self.event_context
+                .publisher(timestamp, subscriber)
+                .on_send_rate_limit_updated(event::builder::SendRateLimitUpdated { bytes_per_sec });
+        }
+
+        if let Some(timeout) = self.pending_deadline.take() {
+            self.timers.deadline_timer.set(timestamp + timeout);
+        }
+
         // return an error if the application set one
         self.error?;
 
         Ok(())
     }
 
+    fn take_cancellation(&mut self) -> Option<connection::PendingCancellation> {
+        self.cancellation.take()
+    }
+
+    fn restore_cancellation(&mut self, cancellation: connection::PendingCancellation) {
+        // keep the future the application set while this one was being polled
+        if self.cancellation.is_none() {
+            self.cancellation = Some(cancellation);
+        }
+    }
+
     // Packet handling
     fn on_datagram_received(
         &mut self,
@@ -1456,7 +1645,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         &mut self,
         datagram: &DatagramInfo,
         _path_id: path::Id,
-        _packet: ProtectedVersionNegotiation,
+        packet: ProtectedVersionNegotiation,
         subscriber: &mut Config::EventSubscriber,
         _packet_interceptor: &mut Config::PacketInterceptor,
     ) -> Result<(), ProcessingError> {
@@ -1465,31 +1654,36 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         publisher.on_packet_received(event::builder::PacketReceived {
             packet_header: event::builder::PacketHeader::VersionNegotiation {},
         });
-        //= https://www.rfc-editor.org/rfc/rfc9000#section-6.2
-        //= type=TODO
-        //= feature=Version negotiation handler
-        //= tracking-issue=349
-        //# A client that supports only this version of QUIC MUST abandon the
-        //# current connection attempt if it receives a Version Negotiation
-        //# packet, with the following two exceptions.
+
+        // Only the client is supposed to receive version negotiation packets
+        if Self::Config::ENDPOINT_TYPE.is_server() {
+            return Ok(());
+        }
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-6.2
-        //= type=TODO
-        //= feature=Version negotiation handler
-        //= tracking-issue=349
         //# A client MUST discard any
         //# Version Negotiation packet if it has received and successfully
         //# processed any other packet, including an earlier Version Negotiation
         //# packet.
+        //
+        // An earlier Version Negotiation packet would have closed the connection.
+        if self.path_manager.valid_initial_received() || self.space_manager.retry_cid().is_some() {
+            return Ok(());
+        }
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-6.2
-        //= type=TODO
-        //= feature=Version negotiation handler
-        //= tracking-issue=349
         //# A client MUST discard a Version Negotiation packet that
         //# lists the QUIC version selected by the client.
+        let quic_version = self.event_context.quic_version;
+        if packet.into_iter().any(|version| version == quic_version) {
+            return Ok(());
+        }
 
-        Ok(())
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-6.2
+        //# A client that supports only this version of QUIC MUST abandon the
+        //# current connection attempt if it receives a Version Negotiation
+        //# packet, with the following two exceptions.
+        Err(connection::Error::version_negotiation().into())
     }
 
     /// Is called when a zero rtt packet had been received
@@ -1551,6 +1745,7 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         //# A client MUST accept and process at most one Retry packet for each
         //# connection attempt.
         if self.space_manager.retry_cid().is_some() {
+            self.retry_repeated = true;
             let path = &mut self.path_manager[path_id];
             publisher.on_packet_dropped(event::builder::PacketDropped {
                 reason: event::builder::PacketDropReason::RetryDiscarded {
@@ -1701,6 +1896,10 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             };
         } else {
             interests.timeout = self.next_expiration();
+            interests.memory_usage = self
+                .space_manager
+                .application()
+                .map_or(0, |space| space.stream_manager.buffered_len().as_u64());
         }
 
         interests
@@ -1763,6 +1962,25 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
             .poll_open_local_stream(stream_type, open_token, context)
     }
 
+    fn poll_open_streams(
+        &mut self,
+        stream_type: stream::StreamType,
+        count: usize,
+        open_token: &mut connection::OpenToken,
+        context: &Context,
+    ) -> Poll<Result<(stream::StreamId, usize), connection::Error>> {
+        self.error?;
+
+        let (space, _) = self
+            .space_manager
+            .application_mut()
+            .ok_or_else(connection::Error::unspecified)?;
+
+        space
+            .stream_manager
+            .poll_open_local_streams(stream_type, count, open_token, context)
+    }
+
     fn application_close(&mut self, error: Option<application::Error>) {
         if self.error.is_err() {
             return;
@@ -1788,6 +2006,32 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         self.space_manager.application_protocol.clone()
     }
 
+    fn key_exchange_group(&self) -> Option<tls::NamedGroup> {
+        // Only report the negotiated values once the handshake has been completed
+        if !self.space_manager.is_handshake_complete() {
+            return None;
+        }
+
+        self.space_manager.key_exchange_group
+    }
+
+    fn signature_scheme(&self) -> Option<tls::SignatureScheme> {
+        if !self.space_manager.is_handshake_complete() {
+            return None;
+        }
+
+        self.space_manager.signature_scheme
+    }
+
+    fn datagram_config(&self) -> Option<datagram::Config> {
+        if !self.space_manager.is_handshake_complete() {
+            return None;
+        }
+
+        let space = self.space_manager.application()?;
+        Some(space.datagram_manager.config())
+    }
+
     fn ping(&mut self) -> Result<(), connection::Error> {
         self.error?;
 
@@ -1824,6 +2068,45 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(())
     }
 
+    fn set_send_rate_limit(&mut self, bytes_per_sec: Option<u64>) -> Result<(), connection::Error> {
+        self.error?;
+
+        self.path_manager.set_send_rate_limit(bytes_per_sec);
+
+        // the previous limit may have armed the pacing timer past the point the new limit allows
+        self.timers.pacing_timer.cancel();
+        self.send_rate_limit_updated = true;
+        self.wakeup_handle.wakeup();
+
+        Ok(())
+    }
+
+    fn set_deadline(&mut self, timeout: Duration) -> Result<(), connection::Error> {
+        self.error?;
+
+        self.pending_deadline = Some(timeout);
+        self.wakeup_handle.wakeup();
+
+        Ok(())
+    }
+
+    fn set_cancellation(
+        &mut self,
+        cancellation: connection::Cancellation,
+    ) -> Result<(), connection::Error> {
+        self.error?;
+
+        // the future is woken through the connection's wakeup handle
+        self.cancellation = Some(connection::PendingCancellation::new(
+            cancellation,
+            self.waker.clone(),
+        ));
+        // poll the future for the first time on the next wakeup
+        self.wakeup_handle.wakeup();
+
+        Ok(())
+    }
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error> {
         Ok(*self.path_manager.active_path().handle.local_address())
     }
@@ -1832,6 +2115,24 @@ impl<Config: endpoint::Config> connection::Trait for ConnectionImpl<Config> {
         Ok(*self.path_manager.active_path().handle.remote_address())
     }
 
+    fn path_info(&self) -> Result<path::Info, connection::Error> {
+        let path = self.path_manager.active_path();
+        let rtt = &path.rtt_estimator;
+
+        Ok(path::Info::new(
+            *path.handle.local_address(),
+            *path.handle.remote_address(),
+            path.is_validated(),
+            rtt.smoothed_rtt(),
+            rtt.min_rtt(),
+            rtt.latest_rtt(),
+        ))
+    }
+
+    fn amplification_budget(&self) -> Result<Option<u64>, connection::Error> {
+        Ok(self.path_manager.active_path().amplification_budget())
+    }
+
     fn error(&self) -> Option<connection::Error> {
         self.error.err()
     }
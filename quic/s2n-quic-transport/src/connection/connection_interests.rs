@@ -25,6 +25,9 @@ pub struct ConnectionInterests {
     pub ack: bool,
     /// Is `Some(Timestamp)` if the connection needs to be woken up at the specified time
     pub timeout: Option<Timestamp>,
+    /// The number of bytes the `Connection` currently buffers, which are charged against the
+    /// endpoint memory limit
+    pub memory_usage: u64,
 }
 
 impl ConnectionInterests {
@@ -53,6 +56,7 @@ impl ConnectionInterests {
                 (None, Some(b)) => Some(b),
                 (None, None) => None,
             },
+            memory_usage: self.memory_usage.saturating_add(other.memory_usage),
         }
     }
 }
@@ -89,6 +93,7 @@ mod tests {
             new_connection_id: false,
             ack: false,
             timeout: None,
+            memory_usage: 100,
         };
 
         let b_time = unsafe { Timestamp::from_duration(Duration::from_secs(123)) };
@@ -100,6 +105,7 @@ mod tests {
             new_connection_id: true,
             ack: true,
             timeout: Some(b_time),
+            memory_usage: 20,
         };
 
         let c_time = unsafe { Timestamp::from_duration(Duration::from_secs(456)) };
@@ -111,6 +117,7 @@ mod tests {
             new_connection_id: false,
             ack: false,
             timeout: Some(c_time),
+            memory_usage: 3,
         };
 
         assert_eq!(
@@ -122,6 +129,7 @@ mod tests {
                 new_connection_id: true,
                 ack: true,
                 timeout: Some(b_time),
+                memory_usage: 120,
             },
             a + b
         );
@@ -135,6 +143,7 @@ mod tests {
                 new_connection_id: false,
                 ack: false,
                 timeout: Some(c_time),
+                memory_usage: 103,
             },
             a + c
         );
@@ -148,6 +157,7 @@ mod tests {
                 new_connection_id: true,
                 ack: true,
                 timeout: Some(b_time),
+                memory_usage: 23,
             },
             b + c
         );
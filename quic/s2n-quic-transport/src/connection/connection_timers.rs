@@ -26,6 +26,8 @@ pub struct ConnectionTimers {
     pub pacing_timer: Timer,
     /// The timer for closing the connection if the handshake is still in progress
     pub max_handshake_duration_timer: Timer,
+    /// The timer for closing the connection when the deadline set by the application is reached
+    pub deadline_timer: Timer,
     /// The timer for calling the connection supervisor
     pub supervisor_timer: Timer,
 }
@@ -37,6 +39,7 @@ impl ConnectionTimers {
         self.initial_id_expiration_timer.cancel();
         self.pacing_timer.cancel();
         self.max_handshake_duration_timer.cancel();
+        self.deadline_timer.cancel();
         self.supervisor_timer.cancel();
     }
 }
@@ -49,6 +52,7 @@ impl timer::Provider for ConnectionTimers {
         self.initial_id_expiration_timer.timers(query)?;
         self.pacing_timer.timers(query)?;
         self.max_handshake_duration_timer.timers(query)?;
+        self.deadline_timer.timers(query)?;
         self.supervisor_timer.timers(query)?;
 
         Ok(())
@@ -15,11 +15,16 @@ use crate::{
     stream,
 };
 use bytes::Bytes;
-use core::task::{Context, Poll};
+use core::{
+    task::{Context, Poll},
+    time::Duration,
+};
 use s2n_codec::DecoderBufferMut;
 use s2n_quic_core::{
     application,
     application::ServerName,
+    crypto::tls,
+    datagram,
     event::{self, builder::DatagramDropReason, supervisor, ConnectionPublisher, IntoEvent},
     inet::{DatagramInfo, SocketAddress},
     io::tx,
@@ -99,6 +104,12 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         subscriber: &mut <Self::Config as endpoint::Config>::EventSubscriber,
     ) -> Result<(), connection::Error>;
 
+    /// Is called when the endpoint enters or leaves memory pressure
+    ///
+    /// While the endpoint is under memory pressure, the connection stops extending its receive
+    /// window so the peer can't increase the amount of data buffered by the connection.
+    fn on_memory_pressure(&mut self, is_under_pressure: bool);
+
     /// Handles all external wakeups on the [`Connection`].
     fn on_wakeup(
         &mut self,
@@ -107,6 +118,17 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         datagram: &mut <Self::Config as endpoint::Config>::DatagramEndpoint,
     ) -> Result<(), connection::Error>;
 
+    /// Takes the cancellation future set by the application, if any
+    ///
+    /// The endpoint polls the future after releasing the connection lock and returns it with
+    /// [`Self::restore_cancellation`] if it's still pending.
+    fn take_cancellation(&mut self) -> Option<connection::PendingCancellation>;
+
+    /// Returns a pending future which was taken with [`Self::take_cancellation`]
+    ///
+    /// The future is dropped if the application replaced it while it was being polled.
+    fn restore_cancellation(&mut self, cancellation: connection::PendingCancellation);
+
     // Packet handling
 
     /// Is called when an initial packet had been received
@@ -427,20 +449,49 @@ pub trait ConnectionTrait: 'static + Send + Sized {
         context: &Context,
     ) -> Poll<Result<stream::StreamId, connection::Error>>;
 
+    /// Opens up to `count` streams, returning the ID of the first stream and the number of
+    /// consecutive streams which were opened
+    fn poll_open_streams(
+        &mut self,
+        stream_type: stream::StreamType,
+        count: usize,
+        open_token: &mut connection::OpenToken,
+        context: &Context,
+    ) -> Poll<Result<(stream::StreamId, usize), connection::Error>>;
+
     fn application_close(&mut self, error: Option<application::Error>);
 
     fn server_name(&self) -> Option<ServerName>;
 
     fn application_protocol(&self) -> Bytes;
 
+    fn key_exchange_group(&self) -> Option<tls::NamedGroup>;
+
+    fn signature_scheme(&self) -> Option<tls::SignatureScheme>;
+
+    fn datagram_config(&self) -> Option<datagram::Config>;
+
     fn ping(&mut self) -> Result<(), connection::Error>;
 
     fn keep_alive(&mut self, enabled: bool) -> Result<(), connection::Error>;
 
+    fn set_send_rate_limit(&mut self, bytes_per_sec: Option<u64>) -> Result<(), connection::Error>;
+
+    fn set_deadline(&mut self, timeout: Duration) -> Result<(), connection::Error>;
+
+    fn set_cancellation(
+        &mut self,
+        cancellation: connection::Cancellation,
+    ) -> Result<(), connection::Error>;
+
     fn local_address(&self) -> Result<SocketAddress, connection::Error>;
 
     fn remote_address(&self) -> Result<SocketAddress, connection::Error>;
 
+    fn path_info(&self) -> Result<path::Info, connection::Error>;
+
+    fn amplification_budget(&self) -> Result<Option<u64>, connection::Error>;
+
     fn error(&self) -> Option<connection::Error>;
 
     fn query_event_context(&self, query: &mut dyn event::query::Query);
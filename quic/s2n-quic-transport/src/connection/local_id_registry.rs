@@ -11,7 +11,10 @@ use crate::{
 };
 use core::convert::TryInto;
 use s2n_quic_core::{
-    ack, connection, frame,
+    ack,
+    connection::{self, limits::ACTIVE_CONNECTION_ID_TARGET_DEFAULT},
+    event::{self, builder::LocalConnectionIdRetirementCause, IntoEvent as _},
+    frame,
     packet::number::PacketNumber,
     stateless_reset,
     time::{timer, Duration, Timer, Timestamp},
@@ -22,17 +25,6 @@ use std::sync::{Arc, Mutex};
 /// The amount of ConnectionIds we can register without dynamic memory allocation
 const NR_STATIC_REGISTRABLE_IDS: usize = 5;
 
-//= https://www.rfc-editor.org/rfc/rfc9000#section-5.1.1
-//# An endpoint that initiates migration and requires non-zero-length
-//# connection IDs SHOULD ensure that the pool of connection IDs
-//# available to its peer allows the peer to use a new connection ID on
-//# migration, as the peer will be unable to respond if the pool is
-//# exhausted.
-/// Limit on the number of connection IDs issued to the peer to reduce the amount
-/// of per-path state maintained. Increasing this value allows peers to probe
-/// more paths simultaneously at the expense of additional state to maintain.
-const MAX_ACTIVE_CONNECTION_ID_LIMIT: u64 = 3;
-
 /// Buffer to allow time for a peer to process and retire an expiring connection ID
 /// before the connection ID actually expires.
 ///
@@ -77,8 +69,26 @@ pub struct LocalIdRegistry {
     retire_prior_to: u32,
     /// The maximum number of connection IDs to give to the peer
     active_connection_id_limit: u8,
+    /// The number of connection IDs to maintain at the peer, if the peer's limit allows it
+    active_connection_id_target: u8,
     /// Timer set to track retiring and expired connection IDs
     expiration_timer: Timer,
+    /// Connection IDs which were issued or retired since events were last published
+    pending_events: Vec<LocalIdEvent>,
+}
+
+#[derive(Debug)]
+enum LocalIdEvent {
+    Issued {
+        id: connection::LocalId,
+        sequence_number: u32,
+        retire_prior_to: u32,
+    },
+    Retired {
+        id: connection::LocalId,
+        sequence_number: u32,
+        cause: LocalConnectionIdRetirementCause,
+    },
 }
 
 #[derive(Debug)]
@@ -238,7 +248,10 @@ impl LocalIdRegistry {
             // Initialize to 1 until we know the actual limit
             // from the peer transport parameters
             active_connection_id_limit: 1,
+            // Use the default target until the one from the connection limits is applied
+            active_connection_id_target: ACTIVE_CONNECTION_ID_TARGET_DEFAULT,
             expiration_timer: Timer::default(),
+            pending_events: Vec::new(),
         };
 
         let _ = registry.register_connection_id(
@@ -277,7 +290,15 @@ impl LocalIdRegistry {
         //# such as path validation status, as its peer might interact with it
         //# over as many paths as there are issued connection IDs.
         self.active_connection_id_limit =
-            MAX_ACTIVE_CONNECTION_ID_LIMIT.min(active_connection_id_limit) as u8;
+            (self.active_connection_id_target as u64).min(active_connection_id_limit) as u8;
+    }
+
+    /// Sets the number of connection IDs to maintain at the peer
+    ///
+    /// This must be called before `set_active_connection_id_limit`, which bounds the number of
+    /// connection IDs issued by both the target and the peer's limit.
+    pub fn set_active_connection_id_target(&mut self, active_connection_id_target: u8) {
+        self.active_connection_id_target = active_connection_id_target.max(1);
     }
 
     /// Registers a connection ID mapping at the mapper with an optional expiration
@@ -413,6 +434,15 @@ impl LocalIdRegistry {
             // order packets using the retired connection ID to be received
             let removal_time = timestamp + rtt * RTT_MULTIPLIER;
 
+            // The event was already published if the connection ID was retired locally
+            if !id_info.is_retired() {
+                self.pending_events.push(LocalIdEvent::Retired {
+                    id: id_info.id,
+                    sequence_number: id_info.sequence_number,
+                    cause: LocalConnectionIdRetirementCause::Peer,
+                });
+            }
+
             id_info.status = PendingRemoval(removal_time);
             self.update_timers();
         }
@@ -468,7 +498,12 @@ impl LocalIdRegistry {
                 .filter(|id_info| id_info.is_retire_ready(timestamp))
             {
                 id_info.retire(Some(timestamp));
-                self.retire_prior_to = self.retire_prior_to.max(id_info.sequence_number + 1)
+                self.retire_prior_to = self.retire_prior_to.max(id_info.sequence_number + 1);
+                self.pending_events.push(LocalIdEvent::Retired {
+                    id: id_info.id,
+                    sequence_number: id_info.sequence_number,
+                    cause: LocalConnectionIdRetirementCause::Expired,
+                });
             }
 
             self.unregister_expired_ids(timestamp);
@@ -494,6 +529,14 @@ impl LocalIdRegistry {
                     .try_into()
                     .expect("Length is already checked"),
             }) {
+                if id_info.status == PendingIssuance {
+                    self.pending_events.push(LocalIdEvent::Issued {
+                        id: id_info.id,
+                        sequence_number: id_info.sequence_number,
+                        retire_prior_to: self.retire_prior_to,
+                    });
+                }
+
                 id_info.status = PendingAcknowledgement(packet_number);
             }
         }
@@ -546,11 +589,47 @@ impl LocalIdRegistry {
             self.retire_prior_to = self
                 .retire_prior_to
                 .max(handshake_id_info.sequence_number + 1);
+
+            self.pending_events.push(LocalIdEvent::Retired {
+                id: handshake_id_info.id,
+                sequence_number: handshake_id_info.sequence_number,
+                cause: LocalConnectionIdRetirementCause::HandshakeConfirmed,
+            });
         }
 
         self.update_timers();
     }
 
+    /// Publishes the connection IDs which were issued or retired since the last call
+    pub fn publish_events<Pub: event::ConnectionPublisher>(&mut self, publisher: &mut Pub) {
+        for pending in self.pending_events.drain(..) {
+            match pending {
+                LocalIdEvent::Issued {
+                    id,
+                    sequence_number,
+                    retire_prior_to,
+                } => publisher.on_local_connection_id_issued(
+                    event::builder::LocalConnectionIdIssued {
+                        connection_id: id.into_event(),
+                        sequence_number: sequence_number as u64,
+                        retire_prior_to: retire_prior_to as u64,
+                    },
+                ),
+                LocalIdEvent::Retired {
+                    id,
+                    sequence_number,
+                    cause,
+                } => publisher.on_local_connection_id_retired(
+                    event::builder::LocalConnectionIdRetired {
+                        connection_id: id.into_event(),
+                        sequence_number: sequence_number as u64,
+                        cause,
+                    },
+                ),
+            }
+        }
+    }
+
     /// Updates the expiration timer based on the current registered connection IDs
     fn update_timers(&mut self) {
         if let Some(timestamp) = self.next_status_change_time() {
@@ -4,6 +4,7 @@ use super::*;
 use s2n_quic_core::{
     connection,
     connection::id::MIN_LIFETIME,
+    event::testing::Publisher,
     frame::{Frame, NewConnectionId},
     packet::number::PacketNumberRange,
     random,
@@ -15,9 +16,10 @@ use s2n_quic_core::{
 use crate::{
     connection::{
         connection_id_mapper::*,
+        limits::ACTIVE_CONNECTION_ID_TARGET_DEFAULT,
         local_id_registry::{
             LocalIdInfo, LocalIdRegistrationError, LocalIdRegistry, EXPIRATION_BUFFER,
-            MAX_ACTIVE_CONNECTION_ID_LIMIT, RTT_MULTIPLIER,
+            RTT_MULTIPLIER,
         },
         InternalConnectionIdGenerator,
     },
@@ -392,8 +394,8 @@ fn connection_id_interest() {
 
     reg1.set_active_connection_id_limit(5);
     assert_eq!(
-        MAX_ACTIVE_CONNECTION_ID_LIMIT,
-        reg1.active_connection_id_limit as u64
+        ACTIVE_CONNECTION_ID_TARGET_DEFAULT,
+        reg1.active_connection_id_limit
     );
 
     assert_eq!(
@@ -499,8 +501,41 @@ fn endpoint_may_limit_connection_ids() {
     reg1.set_active_connection_id_limit(100);
 
     assert_eq!(
-        MAX_ACTIVE_CONNECTION_ID_LIMIT,
-        reg1.active_connection_id_limit as u64
+        ACTIVE_CONNECTION_ID_TARGET_DEFAULT,
+        reg1.active_connection_id_limit
+    );
+}
+
+#[test]
+fn active_connection_id_target() {
+    let ext_id_1 = id(b"id01");
+    let (_, mut reg1) = mapper(ext_id_1, None, TEST_TOKEN_1);
+
+    // The peer's limit bounds the target
+    reg1.set_active_connection_id_target(5);
+    reg1.set_active_connection_id_limit(4);
+    assert_eq!(4, reg1.active_connection_id_limit);
+    assert_eq!(
+        connection::id::Interest::New(3),
+        reg1.connection_id_interest()
+    );
+
+    // The target bounds the peer's limit
+    reg1.set_active_connection_id_target(2);
+    reg1.set_active_connection_id_limit(4);
+    assert_eq!(2, reg1.active_connection_id_limit);
+    assert_eq!(
+        connection::id::Interest::New(1),
+        reg1.connection_id_interest()
+    );
+
+    // At least one connection ID is always maintained
+    reg1.set_active_connection_id_target(0);
+    reg1.set_active_connection_id_limit(4);
+    assert_eq!(1, reg1.active_connection_id_limit);
+    assert_eq!(
+        connection::id::Interest::None,
+        reg1.connection_id_interest()
     );
 }
 
@@ -597,6 +632,60 @@ fn on_transmit() {
     );
 }
 
+#[test]
+fn issued_and_retired_events() {
+    let ext_id_1 = id(b"id01");
+    let ext_id_2 = id(b"id02");
+    let ext_id_3 = id(b"id03");
+
+    let now = s2n_quic_platform::time::now() + Duration::from_secs(60);
+    let rtt = Duration::from_millis(100);
+    let mut publisher = Publisher::snapshot();
+
+    let (_, mut reg1) = mapper(ext_id_1, None, TEST_TOKEN_1);
+    reg1.set_active_connection_id_limit(3);
+
+    assert!(reg1
+        .register_connection_id(&ext_id_2, Some(now), TEST_TOKEN_2)
+        .is_ok());
+    assert!(reg1
+        .register_connection_id(&ext_id_3, None, TEST_TOKEN_3)
+        .is_ok());
+
+    // Registering a connection ID doesn't issue it until it is transmitted
+    reg1.publish_events(&mut publisher);
+    assert_eq!(0, publisher.local_connection_id_issued);
+
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+    let mut write_context = MockWriteContext::new(
+        s2n_quic_platform::time::now(),
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+    reg1.on_transmit(&mut write_context);
+    reg1.publish_events(&mut publisher);
+    assert_eq!(2, publisher.local_connection_id_issued);
+
+    // Retransmitting a lost NEW_CONNECTION_ID frame doesn't issue the connection ID again
+    reg1.get_connection_id_info_mut(&ext_id_3).unwrap().status = PendingReissue;
+    reg1.on_transmit(&mut write_context);
+    reg1.publish_events(&mut publisher);
+    assert_eq!(2, publisher.local_connection_id_issued);
+
+    reg1.retire_handshake_connection_id();
+    reg1.on_timeout(now);
+    reg1.publish_events(&mut publisher);
+    assert_eq!(2, publisher.local_connection_id_retired);
+
+    // The handshake connection ID was already retired locally
+    assert!(reg1.on_retire_connection_id(0, &ext_id_3, rtt, now).is_ok());
+    assert!(reg1.on_retire_connection_id(2, &ext_id_1, rtt, now).is_ok());
+    reg1.publish_events(&mut publisher);
+    assert_eq!(3, publisher.local_connection_id_retired);
+}
+
 #[test]
 fn on_transmit_constrained() {
     let ext_id_1 = id(b"id01");
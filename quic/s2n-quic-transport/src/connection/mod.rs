@@ -7,6 +7,11 @@ use crate::{
     endpoint, path::MaxMtu, recovery::congestion_controller, space::PacketSpaceManager,
     wakeup_queue::WakeupHandle,
 };
+use core::{
+    fmt,
+    future::Future,
+    task::{Context, Poll, Waker},
+};
 use s2n_quic_core::{connection, event, event::supervisor, time::Timestamp};
 
 mod api;
@@ -41,9 +46,41 @@ pub use api::Connection;
 pub use connection_impl::ConnectionImpl as Implementation;
 pub use connection_trait::Lock;
 pub use open_token::Pair as OpenToken;
+
 /// re-export core
 pub use s2n_quic_core::connection::*;
 
+/// A future which gracefully closes the connection once it completes
+pub type Cancellation =
+    core::pin::Pin<alloc::boxed::Box<dyn core::future::Future<Output = ()> + Send>>;
+
+/// A [`Cancellation`] set on a connection, along with the waker of the connection
+///
+/// The endpoint takes the future out of the connection and polls it without holding the
+/// connection lock, since the future may call into the connection's API.
+pub(crate) struct PendingCancellation {
+    future: Cancellation,
+    waker: Waker,
+}
+
+impl PendingCancellation {
+    pub fn new(future: Cancellation, waker: Waker) -> Self {
+        Self { future, waker }
+    }
+
+    /// Polls the future, which wakes up the connection once it can make progress
+    pub fn poll(&mut self) -> Poll<()> {
+        let mut context = Context::from_waker(&self.waker);
+        self.future.as_mut().poll(&mut context)
+    }
+}
+
+impl fmt::Debug for PendingCancellation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PendingCancellation").finish()
+    }
+}
+
 /// Parameters which are passed to a Connection.
 /// These are unique per created connection.
 pub struct Parameters<'a, Cfg: endpoint::Config> {
@@ -73,6 +110,8 @@ pub struct Parameters<'a, Cfg: endpoint::Config> {
     pub quic_version: u32,
     /// The limits that were advertised to the peer
     pub limits: connection::Limits,
+    /// The application-defined tags included in every event for the connection
+    pub tags: connection::Tags,
     /// The largest maximum transmission unit (MTU) that can be sent on a path
     pub max_mtu: MaxMtu,
     /// The context that should be passed to all related connection events
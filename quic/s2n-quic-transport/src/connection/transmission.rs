@@ -8,7 +8,7 @@ use crate::{
 use core::time::Duration;
 use s2n_codec::{Encoder, EncoderBuffer};
 use s2n_quic_core::{
-    event::{self, ConnectionPublisher as _},
+    event::{self, builder::PaddingReason, ConnectionPublisher as _},
     frame::ack_elicitation::AckElicitable,
     inet::ExplicitCongestionNotification,
     io::tx,
@@ -27,6 +27,8 @@ pub struct ConnectionTransmissionContext<'a, 'sub, Config: endpoint::Config> {
     pub outcome: &'a mut transmission::Outcome,
     pub ecn: ExplicitCongestionNotification,
     pub min_packet_len: Option<usize>,
+    /// The maximum number of packets coalesced into each datagram
+    pub max_coalesced_packets: usize,
     pub transmission_mode: transmission::Mode,
     pub publisher: &'a mut event::ConnectionPublisherSubscriber<'sub, Config::EventSubscriber>,
     pub packet_interceptor: &'a mut Config::PacketInterceptor,
@@ -42,6 +44,24 @@ impl<'a, 'sub, Config: endpoint::Config> ConnectionTransmissionContext<'a, 'sub,
     }
 }
 
+/// The PADDING written to the packets of a single datagram
+#[derive(Debug, Default)]
+struct DatagramPadding {
+    len: usize,
+    reason: Option<PaddingReason>,
+}
+
+impl DatagramPadding {
+    #[inline]
+    fn on_packet(&mut self, outcome: &transmission::Outcome, reason: PaddingReason) {
+        if outcome.bytes_padded > 0 {
+            self.len += outcome.bytes_padded;
+            // the last packet in the datagram is the one padded to the minimum datagram size
+            self.reason = Some(reason);
+        }
+    }
+}
+
 pub struct ConnectionTransmission<'a, 'sub, Config: endpoint::Config> {
     pub context: ConnectionTransmissionContext<'a, 'sub, Config>,
     pub space_manager: &'a mut PacketSpaceManager<Config>,
@@ -168,18 +188,36 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             // datagram. If there is no ApplicationData packet, the Handshake packet will come at the
             // end, so we check that next. Finally, if there is no ApplicationData or Handshake packet
             // to transmit, the Initial packet itself will be padded.
+            //
+            // Only the spaces which fit in the coalescing limit are written to the datagram, so
+            // the last of those is padded.
+            let max_coalesced_packets = self.context.max_coalesced_packets;
             let mut pn_space_to_pad = {
                 if !has_transmission(space_manager.initial(), transmission_constraint) {
                     // There is no Initial packet, so no padding is needed
                     None
-                } else if has_transmission(space_manager.application(), transmission_constraint) {
-                    Some(PacketNumberSpace::ApplicationData)
-                } else if has_transmission(space_manager.handshake(), transmission_constraint) {
-                    Some(PacketNumberSpace::Handshake)
                 } else {
+                    let coalesced_spaces = [
+                        (
+                            PacketNumberSpace::Handshake,
+                            has_transmission(space_manager.handshake(), transmission_constraint),
+                        ),
+                        (
+                            PacketNumberSpace::ApplicationData,
+                            has_transmission(space_manager.application(), transmission_constraint),
+                        ),
+                    ];
+
+                    let pn_space = coalesced_spaces
+                        .iter()
+                        .filter(|(_, has_packet)| *has_packet)
+                        .map(|(pn_space, _)| *pn_space)
+                        .take(max_coalesced_packets - 1)
+                        .last();
+
                     //= https://www.rfc-editor.org/rfc/rfc9001#section-4.9
                     //# These packets MAY also include PADDING frames.
-                    Some(PacketNumberSpace::Initial)
+                    Some(pn_space.unwrap_or(PacketNumberSpace::Initial))
                 }
             };
 
@@ -191,6 +229,10 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
 
             let is_mtu_probing = self.context.transmission_mode.is_mtu_probing();
 
+            let mut padding = DatagramPadding::default();
+            let mut packet_count = 0;
+            let mut initial_packet_len = None;
+
             let encoder = if let Some((space, handshake_status)) = space_manager
                 .initial_mut()
                 // MTU probes are only sent in the Application Space
@@ -199,6 +241,12 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                 self.context.min_packet_len = pn_space_to_pad
                     .filter(|pn_space| pn_space.is_initial())
                     .map(|_| encoder.capacity());
+                let reason = if self.context.min_packet_len.is_some() {
+                    PaddingReason::InitialMinimumSize
+                } else {
+                    PaddingReason::MinimumPacketSize
+                };
+                let capacity = encoder.capacity();
 
                 match space.on_transmit(
                     &mut self.context,
@@ -207,6 +255,8 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                     encoder,
                 ) {
                     Ok((outcome, encoder)) => {
+                        initial_packet_len = Some((capacity - encoder.capacity()) as u16);
+
                         if Config::ENDPOINT_TYPE.is_server()
                             && !outcome.ack_elicitation().is_ack_eliciting()
                         {
@@ -219,6 +269,8 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                             // The Initial packet was not ack eliciting so there is no need to pad
                             pn_space_to_pad = None;
                         }
+                        padding.on_packet(&outcome, reason);
+                        packet_count += 1;
                         *self.context.outcome += outcome;
                         encoder
                     }
@@ -246,11 +298,16 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             let encoder = if let Some((space, handshake_status)) = space_manager
                 .handshake_mut()
                 // MTU probes are only sent in the Application Space
-                .filter(|_| !is_mtu_probing)
+                .filter(|_| !is_mtu_probing && packet_count < max_coalesced_packets)
             {
                 self.context.min_packet_len = pn_space_to_pad
                     .filter(|pn_space| pn_space.is_handshake())
                     .map(|_| encoder.capacity());
+                let reason = if self.context.min_packet_len.is_some() {
+                    PaddingReason::CoalescedMinimumSize
+                } else {
+                    PaddingReason::MinimumPacketSize
+                };
 
                 let encoder = match space.on_transmit(
                     &mut self.context,
@@ -272,6 +329,8 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                             );
                         }
 
+                        padding.on_packet(&outcome, reason);
+                        packet_count += 1;
                         *self.context.outcome += outcome;
                         encoder
                     }
@@ -319,10 +378,18 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
             // frames are only allowed in the ApplicationData space, which will always be the highest
             // current-available encryption level.
 
-            let encoder = if let Some((space, handshake_status)) = space_manager.application_mut() {
+            let encoder = if let Some((space, handshake_status)) = space_manager
+                .application_mut()
+                .filter(|_| packet_count < max_coalesced_packets)
+            {
                 self.context.min_packet_len = pn_space_to_pad
                     .filter(|pn_space| pn_space.is_application_data())
                     .map(|_| encoder.capacity());
+                let mut reason = if self.context.min_packet_len.is_some() {
+                    PaddingReason::CoalescedMinimumSize
+                } else {
+                    PaddingReason::MinimumPacketSize
+                };
 
                 // Pad the packet when sending path validation frames so that MTU is also validated.
                 let path = &self.context.path_manager[self.context.path_id];
@@ -344,6 +411,7 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                 // like to pad when validating the MTU.
                 if !path.is_validated() && path.has_transmission_interest() {
                     self.context.min_packet_len = Some(encoder.capacity());
+                    reason = PaddingReason::PathValidation;
                 }
 
                 match space.on_transmit(
@@ -353,6 +421,8 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                     encoder,
                 ) {
                     Ok((outcome, encoder)) => {
+                        padding.on_packet(&outcome, reason);
+                        packet_count += 1;
                         *self.context.outcome += outcome;
                         encoder
                     }
@@ -394,6 +464,10 @@ impl<'a, 'sub, Config: endpoint::Config> tx::Message for ConnectionTransmission<
                 .on_datagram_sent(event::builder::DatagramSent {
                     len: datagram_len as u16,
                     gso_offset,
+                    padding_len: padding.len as u16,
+                    padding_reason: padding.reason,
+                    packet_count: packet_count as u8,
+                    initial_packet_len,
                 });
 
             let datagram_len = {
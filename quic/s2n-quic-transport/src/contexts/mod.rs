@@ -54,6 +54,11 @@ pub trait WriteContext {
         Frame: EncoderValue + FrameTrait,
         for<'frame> &'frame Frame: IntoEvent<event::builder::Frame>;
 
+    /// Records the number of stream or CRYPTO data bytes written to the current packet
+    ///
+    /// `is_retransmission` is set if the bytes were already sent in a previous packet.
+    fn on_data_written(&mut self, len: usize, is_retransmission: bool);
+
     /// Returns the ack elicitation of the current packet
     fn ack_elicitation(&self) -> AckElicitation;
 
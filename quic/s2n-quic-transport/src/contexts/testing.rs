@@ -295,6 +295,8 @@ impl<'a> WriteContext for MockWriteContext<'a> {
         self.frame_buffer.write_frame(frame)
     }
 
+    fn on_data_written(&mut self, _len: usize, _is_retransmission: bool) {}
+
     fn ack_elicitation(&self) -> AckElicitation {
         self.frame_buffer.ack_elicitation
     }
@@ -12,7 +12,9 @@ use core::{
     task::{Context, Poll},
 };
 use futures_channel::oneshot;
-use s2n_quic_core::{application::ServerName, inet::SocketAddress, path::RemoteAddress};
+use s2n_quic_core::{
+    application::ServerName, connection::Tags, inet::SocketAddress, path::RemoteAddress,
+};
 
 /// Held by connection Attempt future. Used to receive the actual connection.
 pub(crate) type ConnectionReceiver = oneshot::Receiver<Result<Connection, connection::Error>>;
@@ -25,6 +27,7 @@ pub(crate) type ConnectionSender = oneshot::Sender<Result<Connection, connection
 pub struct Connect {
     pub(crate) remote_address: RemoteAddress,
     pub(crate) server_name: Option<ServerName>,
+    pub(crate) tags: Tags,
 }
 
 impl fmt::Display for Connect {
@@ -49,6 +52,7 @@ impl Connect {
         Self {
             remote_address: addr.into().into(),
             server_name: None,
+            tags: Tags::new(),
         }
     }
 
@@ -60,6 +64,15 @@ impl Connect {
             ..self
         }
     }
+
+    /// Specifies the tags to attach to the connection
+    ///
+    /// Tags are included in the `ConnectionMeta` of every event for the connection. When set,
+    /// these replace any tags configured by the connection limits provider.
+    #[must_use]
+    pub fn with_tags(self, tags: Tags) -> Self {
+        Self { tags, ..self }
+    }
 }
 
 /// Make it easy for applications to create a connection attempt without importing the `Connect` struct
@@ -0,0 +1,99 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tracks the number of handshakes started by the endpoint in fixed one-second windows
+
+use core::time::Duration;
+use s2n_quic_core::time::Timestamp;
+
+/// The length of each window in which handshakes are counted
+const WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The limit has not been reached in the current window
+    Allowed,
+    /// The limit was reached for the first time in the current window
+    Engaged,
+    /// The limit was already reached in the current window
+    Limited,
+}
+
+#[derive(Debug, Default)]
+pub struct Window {
+    start: Option<Timestamp>,
+    count: usize,
+    engaged: bool,
+}
+
+impl Window {
+    /// Checks a connection attempt at `now` against the limit
+    pub fn on_attempt(&mut self, limit: usize, now: Timestamp) -> Status {
+        if self
+            .start
+            .map_or(true, |start| now.saturating_duration_since(start) >= WINDOW)
+        {
+            self.start = Some(now);
+            self.count = 0;
+            self.engaged = false;
+        }
+
+        if self.count < limit {
+            return Status::Allowed;
+        }
+
+        if core::mem::replace(&mut self.engaged, true) {
+            Status::Limited
+        } else {
+            Status::Engaged
+        }
+    }
+
+    /// Counts a handshake which was started in the current window
+    pub fn on_handshake_started(&mut self) {
+        self.count = self.count.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::time::{Clock, NoopClock};
+
+    #[test]
+    fn window_test() {
+        let start = NoopClock.get_time();
+        let mut window = Window::default();
+
+        for _ in 0..3 {
+            assert_eq!(window.on_attempt(3, start), Status::Allowed);
+            window.on_handshake_started();
+        }
+
+        // the event is only reported once in each window
+        assert_eq!(window.on_attempt(3, start), Status::Engaged);
+        assert_eq!(
+            window.on_attempt(3, start + Duration::from_millis(999)),
+            Status::Limited
+        );
+
+        // attempts which don't start a handshake aren't counted
+        let next = start + WINDOW;
+        assert_eq!(window.on_attempt(3, next), Status::Allowed);
+        assert_eq!(window.on_attempt(3, next), Status::Allowed);
+        window.on_handshake_started();
+        window.on_handshake_started();
+        window.on_handshake_started();
+        assert_eq!(window.on_attempt(3, next), Status::Engaged);
+    }
+
+    #[test]
+    fn zero_limit_test() {
+        let start = NoopClock.get_time();
+        let mut window = Window::default();
+
+        assert_eq!(window.on_attempt(0, start), Status::Engaged);
+        assert_eq!(window.on_attempt(0, start), Status::Limited);
+        assert_eq!(window.on_attempt(0, start + WINDOW), Status::Engaged);
+    }
+}
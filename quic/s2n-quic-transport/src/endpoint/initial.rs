@@ -147,11 +147,10 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
 
         let mut transport_parameters = ServerTransportParameters::default();
 
-        let limits = self
-            .config
-            .context()
-            .connection_limits
-            .on_connection(&LimitsInfo::new(&remote_address));
+        let limits_info = LimitsInfo::new(&remote_address);
+        let connection_limits = self.config.context().connection_limits;
+        let limits = connection_limits.on_connection(&limits_info);
+        let tags = connection_limits.on_connection_tags(&limits_info);
 
         transport_parameters.load_limits(&limits);
 
@@ -224,6 +223,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             endpoint_type: Config::ENDPOINT_TYPE,
             id: internal_connection_id.into(),
             timestamp: datagram.timestamp,
+            tags: tags.clone(),
         };
 
         let supervisor_context = supervisor::Context::new(
@@ -248,8 +248,10 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
         let space_manager = PacketSpaceManager::new(
             original_destination_connection_id,
             tls_session,
+            transport_parameters.max_datagram_frame_size.as_u64(),
             initial_key,
             initial_header_key,
+            tls::FragmentLimits::new(endpoint_context.tls),
             datagram.timestamp,
             &mut publisher,
         );
@@ -270,6 +272,7 @@ impl<Config: endpoint::Config> endpoint::Endpoint<Config> {
             congestion_controller,
             timestamp: datagram.timestamp,
             quic_version,
+            tags,
             limits,
             max_mtu,
             event_context,
@@ -49,6 +49,7 @@ pub mod close;
 mod config;
 pub mod connect;
 pub mod handle;
+mod handshake_rate;
 mod initial;
 mod packet_buffer;
 mod retry;
@@ -87,6 +88,8 @@ pub struct Endpoint<Cfg: Config> {
     close_packet_buffer: packet_buffer::Buffer,
     /// The largest maximum transmission unit (MTU) that can be sent on a path
     max_mtu: MaxMtu,
+    /// Counts the handshakes started for the endpoint handshake rate limit
+    handshake_rate: handshake_rate::Window,
 }
 
 impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
@@ -198,7 +201,7 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
         let endpoint_context = self.config.context();
 
         for internal_id in self.dequeued_wakeups.drain(..) {
-            self.connections.with_connection(internal_id, |conn| {
+            let cancellation = self.connections.with_connection(internal_id, |conn| {
                 let timestamp = match now {
                     Some(now) => now,
                     _ => {
@@ -221,8 +224,33 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                         endpoint_context.event_subscriber,
                         endpoint_context.packet_interceptor,
                     );
+                    return None;
                 }
+
+                conn.take_cancellation()
             });
+
+            // The cancellation future is polled without holding the connection lock, since it
+            // may call into the connection's API.
+            if let Some((Some(mut cancellation), _interests)) = cancellation {
+                let is_cancelled = cancellation.poll().is_ready();
+                let timestamp = now.unwrap_or_else(|| clock.get_time());
+
+                self.connections.with_connection(internal_id, |conn| {
+                    if is_cancelled {
+                        conn.close(
+                            connection::Error::cancelled(),
+                            endpoint_context.connection_close_formatter,
+                            close_packet_buffer,
+                            timestamp,
+                            endpoint_context.event_subscriber,
+                            endpoint_context.packet_interceptor,
+                        );
+                    } else {
+                        conn.restore_cancellation(cancellation);
+                    }
+                });
+            }
         }
 
         // try to open connection requests from the application
@@ -233,6 +261,12 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                     Poll::Ready(Some(request)) => {
                         wakeup_count += 1;
 
+                        // the application stopped waiting for the connection before the
+                        // request was processed so there's no need to start the handshake
+                        if request.sender.is_canceled() {
+                            continue;
+                        }
+
                         let time = clock.get_time();
                         if let Err(err) = self.create_client_connection(request, time) {
                             // TODO report that the connection was not successfully created
@@ -246,6 +280,21 @@ impl<Cfg: Config> s2n_quic_core::endpoint::Endpoint for Endpoint<Cfg> {
                     }
                 }
             }
+
+            // close the handshaking connections whose attempts were dropped by the application
+            // so the peer is notified and the connection state is freed
+            let close_packet_buffer = &mut self.close_packet_buffer;
+            let endpoint_context = self.config.context();
+            wakeup_count += self.connections.close_canceled_attempts(cx, |conn| {
+                conn.close(
+                    connection::Error::closed(endpoint::Location::Local),
+                    endpoint_context.connection_close_formatter,
+                    close_packet_buffer,
+                    clock.get_time(),
+                    endpoint_context.event_subscriber,
+                    endpoint_context.packet_interceptor,
+                );
+            });
         }
 
         if wakeup_count > 0 {
@@ -298,22 +347,27 @@ impl<Cfg: Config> Endpoint<Cfg> {
         let (handle, acceptor_sender, connector_receiver, close_handle) =
             handle::Handle::new(max_opening_connections);
 
-        let connection_id_mapper =
-            ConnectionIdMapper::new(config.context().random_generator, Cfg::ENDPOINT_TYPE);
+        let expected_connections = config.context().endpoint_limits.expected_connections();
+        let connection_id_mapper = ConnectionIdMapper::with_capacity(
+            config.context().random_generator,
+            Cfg::ENDPOINT_TYPE,
+            expected_connections,
+        );
 
         let endpoint = Self {
             config,
             connections: ConnectionContainer::new(acceptor_sender, connector_receiver),
             connection_id_generator: InternalConnectionIdGenerator::new(),
             connection_id_mapper,
-            wakeup_queue: WakeupQueue::new(),
+            wakeup_queue: WakeupQueue::with_capacity(expected_connections),
             close_handle,
-            dequeued_wakeups: VecDeque::new(),
+            dequeued_wakeups: VecDeque::with_capacity(expected_connections),
             version_negotiator: version::Negotiator::default(),
             retry_dispatch: retry::Dispatch::default(),
             stateless_reset_dispatch: stateless_reset::Dispatch::default(),
             close_packet_buffer: Default::default(),
             max_mtu: Default::default(),
+            handshake_rate: Default::default(),
         };
 
         (endpoint, handle)
@@ -332,16 +386,17 @@ impl<Cfg: Config> Endpoint<Cfg> {
         }
 
         let remote_address = header.path.remote_address();
+        let memory_usage = self.connections.memory_usage();
 
         let attempt = s2n_quic_core::endpoint::limits::ConnectionAttempt::new(
             self.connections.handshake_connections(),
             self.connections.len(),
             &remote_address,
             timestamp.into_event(),
-        );
+        )
+        .with_memory_usage(memory_usage);
 
         let context = self.config.context();
-        let outcome = context.endpoint_limits.on_connection_attempt(&attempt);
         let mut publisher = event::EndpointPublisherSubscriber::new(
             event::builder::EndpointMeta {
                 endpoint_type: Cfg::ENDPOINT_TYPE,
@@ -351,8 +406,56 @@ impl<Cfg: Config> Endpoint<Cfg> {
             context.event_subscriber,
         );
 
+        // Refuse new connections while the endpoint is under memory pressure. The existing
+        // connections stop extending their receive windows while the endpoint is under memory
+        // pressure so this keeps the total usage of the endpoint from growing any further.
+        if let Some(memory_limit) = context.endpoint_limits.memory_limit() {
+            self.connections.update_memory_pressure(Some(memory_limit));
+
+            if self.connections.is_under_memory_pressure() {
+                publisher.on_endpoint_memory_pressure(event::builder::EndpointMemoryPressure {
+                    memory_usage,
+                    memory_limit,
+                    connection_count: self.connections.len(),
+                });
+                publisher.on_endpoint_datagram_dropped(event::builder::EndpointDatagramDropped {
+                    len: payload_len as u16,
+                    reason: event::builder::DatagramDropReason::RejectedConnectionAttempt,
+                });
+                return None;
+            }
+        }
+
+        // Once the handshake rate limit is reached, the remaining attempts in the window are
+        // handled with the configured outcome without consulting the limiter
+        let mut rate_limited_outcome = None;
+        if let Some(limit) = context.endpoint_limits.handshake_rate_limit() {
+            let status = self
+                .handshake_rate
+                .on_attempt(limit.handshakes_per_sec, timestamp);
+
+            if status == handshake_rate::Status::Engaged {
+                publisher.on_endpoint_handshake_rate_limited(
+                    event::builder::EndpointHandshakeRateLimited {
+                        handshakes_per_sec: limit.handshakes_per_sec,
+                        retry: matches!(limit.outcome, Outcome::Retry { .. }),
+                    },
+                );
+            }
+
+            if status != handshake_rate::Status::Allowed {
+                rate_limited_outcome = Some(limit.outcome);
+            }
+        }
+
+        let outcome = rate_limited_outcome
+            .unwrap_or_else(|| context.endpoint_limits.on_connection_attempt(&attempt));
+
         match outcome {
-            Outcome::Allow { .. } => Some(()),
+            Outcome::Allow { .. } => {
+                self.handshake_rate.on_handshake_started();
+                Some(())
+            }
             Outcome::Retry { .. } => {
                 //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.2
                 //# A server can also use a Retry packet to defer the state and
@@ -920,6 +1023,10 @@ impl<Cfg: Config> Endpoint<Cfg> {
         let close_packet_buffer = &mut self.close_packet_buffer;
         let endpoint_context = self.config.context();
 
+        // apply backpressure to the connections while they buffer too much data
+        self.connections
+            .update_memory_pressure(endpoint_context.endpoint_limits.memory_limit());
+
         self.connections
             .iterate_timeout_list(timestamp, |conn, supervisor_context| {
                 if let Err(error) = conn.on_timeout(
@@ -968,6 +1075,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
                 endpoint::connect::Connect {
                     remote_address,
                     server_name: hostname,
+                    tags,
                 },
             sender,
         } = request;
@@ -1042,10 +1150,25 @@ impl<Cfg: Config> Endpoint<Cfg> {
         //# 0x00000001.
         let quic_version = 0x00000001;
 
+        let limits_info = LimitsInfo::new(&remote_address);
+        let limits = endpoint_context
+            .connection_limits
+            .on_connection(&limits_info);
+
+        // tags set on the connection attempt replace the tags from the connection limits
+        let tags = if tags.is_empty() {
+            endpoint_context
+                .connection_limits
+                .on_connection_tags(&limits_info)
+        } else {
+            tags
+        };
+
         let meta = event::builder::ConnectionMeta {
             endpoint_type: Cfg::ENDPOINT_TYPE,
             id: internal_connection_id.into(),
             timestamp,
+            tags,
         };
         let supervisor_context = supervisor::Context::new(
             self.connections.handshake_connections(),
@@ -1068,9 +1191,6 @@ impl<Cfg: Config> Endpoint<Cfg> {
             initial_source_connection_id: Some(local_connection_id.into()),
             ..Default::default()
         };
-        let limits = endpoint_context
-            .connection_limits
-            .on_connection(&LimitsInfo::new(&remote_address));
         transport_parameters.load_limits(&limits);
 
         transport_parameters.max_datagram_frame_size = endpoint_context
@@ -1107,8 +1227,10 @@ impl<Cfg: Config> Endpoint<Cfg> {
         let space_manager = PacketSpaceManager::new(
             original_destination_connection_id,
             tls_session,
+            transport_parameters.max_datagram_frame_size.as_u64(),
             initial_key,
             initial_header_key,
+            tls::FragmentLimits::new(endpoint_context.tls),
             timestamp,
             &mut publisher,
         );
@@ -1135,6 +1257,7 @@ impl<Cfg: Config> Endpoint<Cfg> {
             timestamp,
             quic_version,
             limits,
+            tags,
             max_mtu: self.max_mtu,
             event_context,
             supervisor_context: &supervisor_context,
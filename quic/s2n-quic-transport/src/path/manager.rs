@@ -151,6 +151,14 @@ impl<Config: endpoint::Config> Manager<Config> {
         &mut self.paths[self.active as usize]
     }
 
+    /// Limits the send rate of all paths to the given number of bytes per second
+    #[inline]
+    pub fn set_send_rate_limit(&mut self, bytes_per_sec: Option<u64>) {
+        for path in self.paths.iter_mut() {
+            path.send_rate_limiter.set_rate(bytes_per_sec);
+        }
+    }
+
     /// Return the Id of the active path
     #[inline]
     pub fn active_path_id(&self) -> Id {
@@ -386,7 +394,8 @@ impl<Config: endpoint::Config> Manager<Config> {
         // estimator for the new path, and they are initialized with initial values,
         // we do not need to reset congestion controller and round-trip time estimator
         // again on confirming the peer's ownership of its new address.
-        let rtt = RttEstimator::new(self.active_path().rtt_estimator.max_ack_delay());
+        let rtt = RttEstimator::new(self.active_path().rtt_estimator.max_ack_delay())
+            .with_granularity(self.active_path().rtt_estimator.granularity());
         let path_info = congestion_controller::PathInfo::new(&remote_address);
         let cc = congestion_controller_endpoint.new_congestion_controller(path_info);
 
@@ -435,6 +444,10 @@ impl<Config: endpoint::Config> Manager<Config> {
 
         let unblocked = path.on_bytes_received(datagram.payload_len);
 
+        // the send rate limit applies to the connection so carry it over to the new path
+        path.send_rate_limiter
+            .set_rate(self.active_path().send_rate_limiter.rate());
+
         let active_path = self.active_path();
         let active_path_id = self.active_path_id();
         publisher.on_path_created(event::builder::PathCreated {
@@ -16,7 +16,7 @@ use core::time::Duration;
 use s2n_quic_core::{
     event::testing::Publisher,
     inet::{DatagramInfo, ExplicitCongestionNotification, SocketAddress},
-    path::{migration, RemoteAddress},
+    path::{migration, MaxMtu, RemoteAddress},
     random::{self, Generator},
     recovery::RttEstimator,
     stateless_reset::token::testing::*,
@@ -731,6 +731,62 @@ fn test_adding_new_path() {
     assert_eq!(manager.paths.len(), 2);
 }
 
+#[test]
+// A new path with a fixed MTU reports the MTU when it is created
+// Setup:
+// - create path manger with one path
+//
+// Trigger:
+// - call on_datagram_received with new remote address and a fixed max MTU
+//
+// Expectation:
+// - assert the MtuUpdated event for the new path contains the fixed MTU
+fn test_adding_new_path_fixed_mtu() {
+    // Setup:
+    let mut publisher = Publisher::snapshot();
+    let first_conn_id = connection::PeerId::try_from_bytes(&[1]).unwrap();
+    let first_addr: SocketAddr = "127.0.0.1:8001".parse().unwrap();
+    let first_addr = RemoteAddress::from(SocketAddress::from(first_addr));
+    let max_mtu = MaxMtu::try_from(1500).unwrap().fixed();
+    let first_path = ServerPath::new(
+        first_addr,
+        first_conn_id,
+        connection::LocalId::TEST_ID,
+        RttEstimator::default(),
+        Default::default(),
+        false,
+        max_mtu,
+    );
+    let mut manager = manager_server(first_path);
+    let new_addr: SocketAddr = "127.0.0.2:8001".parse().unwrap();
+    let new_addr = RemoteAddress::from(SocketAddress::from(new_addr));
+
+    // Trigger:
+    let datagram = DatagramInfo {
+        timestamp: NoopClock {}.get_time(),
+        payload_len: 0,
+        ecn: ExplicitCongestionNotification::default(),
+        destination_connection_id: connection::LocalId::TEST_ID,
+        source_connection_id: None,
+    };
+    let (path_id, _unblocked) = manager
+        .on_datagram_received(
+            &new_addr,
+            &datagram,
+            true,
+            &mut Default::default(),
+            &mut migration::default::Validator::default(),
+            max_mtu,
+            &mut publisher,
+        )
+        .unwrap();
+
+    // Expectation:
+    // the MTU is pinned, so this is the only MtuUpdated event the new path publishes
+    assert_eq!(1, publisher.mtu_updated);
+    assert_eq!(1472, manager[path_id].mtu_controller.mtu());
+}
+
 #[test]
 // do NOT add new path if handshake is not confirmed
 // Setup:
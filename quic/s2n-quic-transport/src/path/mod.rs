@@ -8,7 +8,7 @@ use crate::{
     contexts::WriteContext,
     endpoint,
     endpoint::Type,
-    recovery::{congestion_controller, CongestionController, RttEstimator},
+    recovery::{congestion_controller, CongestionController, RateLimiter, RttEstimator},
     transmission::{self, Mode},
 };
 use s2n_quic_core::{
@@ -60,6 +60,8 @@ pub struct Path<Config: endpoint::Config> {
     pub mtu_controller: mtu::Controller,
     /// Controller for determining the ECN capability of the path
     pub ecn_controller: ecn::Controller,
+    /// Limits the send rate of the path to the rate configured by the application
+    pub send_rate_limiter: RateLimiter,
 
     /// True if the path has been validated by the peer
     peer_validated: bool,
@@ -94,6 +96,7 @@ impl<Config: endpoint::Config> Clone for Path<Config> {
             state: self.state,
             mtu_controller: self.mtu_controller.clone(),
             ecn_controller: self.ecn_controller.clone(),
+            send_rate_limiter: self.send_rate_limiter.clone(),
             peer_validated: self.peer_validated,
             challenge: self.challenge.clone(),
             response_data: self.response_data,
@@ -141,6 +144,7 @@ impl<Config: endpoint::Config> Path<Config> {
             state,
             mtu_controller: mtu::Controller::new(max_mtu, &peer_socket_address),
             ecn_controller: ecn::Controller::default(),
+            send_rate_limiter: RateLimiter::default(),
             peer_validated,
             challenge: Challenge::disabled(),
             response_data: None,
@@ -248,11 +252,23 @@ impl<Config: endpoint::Config> Path<Config> {
     pub fn can_transmit(&self, timestamp: Timestamp) -> bool {
         !self.at_amplification_limit()
             && self
-                .congestion_controller
                 .earliest_departure_time()
                 .map_or(true, |edt| edt.has_elapsed(timestamp))
     }
 
+    /// Returns the earliest time that a packet may be transmitted on this path, taking both
+    /// the congestion controller pacing and the application send rate limit into account
+    #[inline]
+    pub fn earliest_departure_time(&self) -> Option<Timestamp> {
+        match (
+            self.congestion_controller.earliest_departure_time(),
+            self.send_rate_limiter.earliest_departure_time(),
+        ) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
     /// Only PATH_CHALLENGE and PATH_RESPONSE frames should be transmitted here.
     #[inline]
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) {
@@ -503,6 +519,18 @@ impl<Config: endpoint::Config> Path<Config> {
         self.clamp_mtu(mtu, transmission::Mode::Normal) < mtu
     }
 
+    /// Returns the number of bytes which can still be sent before reaching the
+    /// anti-amplification limit
+    ///
+    /// `None` is returned once the path has been validated.
+    #[inline]
+    pub fn amplification_budget(&self) -> Option<u64> {
+        match self.state {
+            State::Validated => None,
+            State::AmplificationLimited { tx_allowance } => Some(u64::from(*tx_allowance)),
+        }
+    }
+
     /// Returns the current PTO period
     #[inline]
     pub fn pto_period(
@@ -977,6 +1005,29 @@ mod tests {
         assert!(path.is_validated());
     }
 
+    #[test]
+    fn amplification_budget_test() {
+        let mut path = testing::helper_path_server();
+        assert_eq!(path.amplification_budget(), Some(0));
+
+        path.on_bytes_received(1200);
+        assert_eq!(path.amplification_budget(), Some(3600));
+
+        path.on_bytes_transmitted(1000);
+        assert_eq!(path.amplification_budget(), Some(2600));
+
+        // the budget saturates at zero
+        path.on_bytes_transmitted(2601);
+        assert_eq!(path.amplification_budget(), Some(0));
+
+        path.on_validated();
+        assert_eq!(path.amplification_budget(), None);
+
+        // Clients are not amplification limited
+        let path = helper_path_client();
+        assert_eq!(path.amplification_budget(), None);
+    }
+
     #[test]
     fn amplification_limited_mtu_test() {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1
@@ -144,10 +144,17 @@ impl Controller {
         let initial_probed_size =
             (ETHERNET_MTU - UDP_HEADER_LEN - min_ip_header_len).min(max_udp_payload);
 
+        // If the MTU is fixed, skip the search entirely and pin the PLPMTU to the max_udp_payload
+        let (state, plpmtu, probed_size) = if max_mtu.is_fixed() {
+            (State::SearchComplete, max_udp_payload, max_udp_payload)
+        } else {
+            (State::Disabled, BASE_PLPMTU, initial_probed_size)
+        };
+
         Self {
-            state: State::Disabled,
-            plpmtu: BASE_PLPMTU,
-            probed_size: initial_probed_size,
+            state,
+            plpmtu,
+            probed_size,
             max_mtu,
             max_udp_payload,
             max_probe_size: max_udp_payload,
@@ -250,6 +257,11 @@ impl Controller {
             return;
         }
 
+        // A fixed MTU is never reduced, so there is no need to detect black holes
+        if self.max_mtu.is_fixed() {
+            return;
+        }
+
         match &self.state {
             State::Disabled => {}
             State::Searching(probe_pn, _) if *probe_pn == packet_number => {
@@ -521,6 +533,46 @@ mod test {
         );
     }
 
+    #[test]
+    fn new_fixed() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let max_mtu = MaxMtu::try_from(1600).unwrap().fixed();
+        let mut controller = Controller::new(max_mtu, &addr.into());
+        let max_udp_payload = 1600 - UDP_HEADER_LEN - IPV4_MIN_HEADER_LEN;
+        assert_eq!(max_udp_payload as usize, controller.mtu());
+        assert_eq!(State::SearchComplete, controller.state);
+        assert!(!controller.pmtu_raise_timer.is_armed());
+
+        // enabling the controller should not start a search
+        controller.enable();
+        assert_eq!(State::SearchComplete, controller.state);
+    }
+
+    #[test]
+    fn on_packet_loss_fixed() {
+        let addr: SocketAddr = "127.0.0.1:443".parse().unwrap();
+        let max_mtu = MaxMtu::try_from(1500).unwrap().fixed();
+        let mut controller = Controller::new(max_mtu, &addr.into());
+        let mtu = controller.mtu();
+        let mut cc = CongestionController::default();
+        let now = now();
+        let mut publisher = Publisher::snapshot();
+
+        for i in 0..=BLACK_HOLE_THRESHOLD as usize {
+            controller.on_packet_loss(
+                pn(i),
+                mtu as u16,
+                now,
+                &mut cc,
+                path::Id::test_id(),
+                &mut publisher,
+            );
+        }
+
+        assert_eq!(mtu, controller.mtu(), "a fixed MTU should not be reduced");
+        assert_eq!(0, cc.on_mtu_update);
+    }
+
     #[test]
     fn new_ipv6() {
         let addr: SocketAddr = "[2001:0db8:85a3:0001:0002:8a2e:0370:7334]:9000"
@@ -21,7 +21,7 @@ use s2n_quic_core::{
     frame::ack::EcnCounts,
     inet::ExplicitCongestionNotification,
     packet::number::{PacketNumber, PacketNumberRange, PacketNumberSpace},
-    recovery::{congestion_controller, CongestionController, RttEstimator, K_GRANULARITY},
+    recovery::{congestion_controller, CongestionController, RttEstimator},
     time::{timer, Timer, Timestamp},
     transport,
 };
@@ -95,6 +95,7 @@ macro_rules! recovery_event {
             latest_rtt: $path.rtt_estimator.latest_rtt(),
             rtt_variance: $path.rtt_estimator.rttvar(),
             max_ack_delay: $path.rtt_estimator.max_ack_delay(),
+            timer_granularity: $path.rtt_estimator.granularity(),
             pto_count: ($path.pto_backoff as f32).log2() as u32,
             congestion_window: $path.congestion_controller.congestion_window(),
             bytes_in_flight: $path.congestion_controller.bytes_in_flight(),
@@ -229,6 +230,11 @@ impl<Config: endpoint::Config> Manager<Config> {
             app_limited,
             &path.rtt_estimator,
         );
+        path.send_rate_limiter.on_packet_sent(
+            time_sent,
+            outcome.bytes_sent,
+            path.mtu_controller.mtu() as u16,
+        );
 
         self.sent_packets.insert(
             packet_number,
@@ -241,7 +247,8 @@ impl<Config: endpoint::Config> Manager<Config> {
                 ecn,
                 transmission_mode,
                 cc_packet_info,
-            ),
+            )
+            .with_frames(outcome.frames),
         );
         path.ecn_controller
             .on_packet_sent(ecn, path_event!(path, path_id), publisher);
@@ -390,6 +397,8 @@ impl<Config: endpoint::Config> Manager<Config> {
             timestamp,
             packet_number,
             ranges,
+            largest_acked_packet_number,
+            ack_delay,
             context,
             publisher,
         )?;
@@ -423,6 +432,17 @@ impl<Config: endpoint::Config> Manager<Config> {
                 context,
                 publisher,
             );
+        } else {
+            let path_id = context.path_id();
+            let path = context.path_mut();
+            publisher.on_duplicate_ack_received(event::builder::DuplicateAckReceived {
+                packet_header: event::builder::PacketHeader::new(
+                    packet_number,
+                    publisher.quic_version(),
+                ),
+                path: path_event!(path, path_id),
+                largest_acknowledged: largest_acked_packet_number.into_event(),
+            });
         }
 
         let path_id = context.path_id().as_u8();
@@ -433,6 +453,7 @@ impl<Config: endpoint::Config> Manager<Config> {
     }
 
     // Process ack_range and return largest_newly_acked and if the packet is ack eliciting.
+    #[allow(clippy::too_many_arguments)]
     fn process_ack_range<Ctx: Context<Config>, Pub: event::ConnectionPublisher>(
         &mut self,
         newly_acked_packets: &mut SmallVec<
@@ -441,6 +462,8 @@ impl<Config: endpoint::Config> Manager<Config> {
         timestamp: Timestamp,
         packet_number: PacketNumber,
         ranges: impl Iterator<Item = PacketNumberRange>,
+        largest_acked_packet_number: PacketNumber,
+        ack_delay: Duration,
         context: &mut Ctx,
         publisher: &mut Pub,
     ) -> Result<(Option<PacketDetails<packet_info_type!()>>, bool), transport::Error> {
@@ -458,6 +481,8 @@ impl<Config: endpoint::Config> Manager<Config> {
                 ),
                 path: path_event!(rx_path, rx_path_id),
                 ack_range: pn_range.into_event(),
+                largest_acknowledged: largest_acked_packet_number.into_event(),
+                ack_delay,
             });
 
             context.validate_packet_ack(timestamp, &pn_range)?;
@@ -499,7 +524,11 @@ impl<Config: endpoint::Config> Manager<Config> {
 
             if let Some((start, end)) = newly_acked_range {
                 // notify components of packets that are newly acked
-                context.on_new_packet_ack(&PacketNumberRange::new(start, end), publisher);
+                context.on_new_packet_ack(
+                    timestamp,
+                    &PacketNumberRange::new(start, end),
+                    publisher,
+                );
             }
         }
 
@@ -548,6 +577,15 @@ impl<Config: endpoint::Config> Manager<Config> {
                 largest_acked_packet_number.space(),
             );
 
+            let path_id = largest_newly_acked_info.path_id;
+            publisher.on_rtt_sample(event::builder::RttSample {
+                path: path_event!(path, path_id),
+                latest_rtt: path.rtt_estimator.latest_rtt(),
+                min_rtt: path.rtt_estimator.min_rtt(),
+                smoothed_rtt: path.rtt_estimator.smoothed_rtt(),
+                rtt_variance: path.rtt_estimator.rttvar(),
+            });
+
             let slow_start = path.congestion_controller.is_slow_start();
             let congestion_window = path.congestion_controller.congestion_window();
             // Update the congestion controller with the latest RTT estimate
@@ -557,7 +595,6 @@ impl<Config: endpoint::Config> Manager<Config> {
                 &path.rtt_estimator,
             );
             if slow_start && !path.congestion_controller.is_slow_start() {
-                let path_id = largest_newly_acked_info.path_id;
                 publisher.on_slow_start_exited(event::builder::SlowStartExited {
                     path: path_event!(path, path_id),
                     cause: SlowStartExitCause::Rtt,
@@ -966,6 +1003,7 @@ impl<Config: endpoint::Config> Manager<Config> {
                 path: path_event!(path, current_path_id),
                 bytes_lost: sent_info.sent_bytes,
                 is_mtu_probe: sent_info.transmission_mode.is_mtu_probing(),
+                frames: sent_info.frames.into_event(),
             });
 
             // Notify the MTU controller of packet loss even if it wasn't a probe since it uses
@@ -1026,7 +1064,7 @@ impl<Config: endpoint::Config> Manager<Config> {
         //# packets as lost too early, this time threshold MUST be set to at
         //# least the local timer granularity, as indicated by the kGranularity
         //# constant.
-        max(time_threshold, K_GRANULARITY)
+        max(time_threshold, rtt_estimator.granularity())
     }
 }
 
@@ -1072,6 +1110,7 @@ pub trait Context<Config: endpoint::Config> {
 
     fn on_new_packet_ack<Pub: event::ConnectionPublisher>(
         &mut self,
+        timestamp: Timestamp,
         packet_number_range: &PacketNumberRange,
         publisher: &mut Pub,
     );
@@ -24,7 +24,7 @@ use s2n_quic_core::{
         congestion_controller::testing::mock::{
             CongestionController as MockCongestionController, Endpoint,
         },
-        DEFAULT_INITIAL_RTT,
+        DEFAULT_INITIAL_RTT, K_GRANULARITY,
     },
     time::{timer::Provider as _, Clock, NoopClock},
     varint::VarInt,
@@ -109,7 +109,7 @@ fn on_packet_sent() {
             ack_elicitation,
             is_congestion_controlled: i % 3 == 0,
             bytes_sent: (2 * i) as usize,
-            bytes_progressed: 0,
+            ..Default::default()
         };
 
         manager.on_packet_sent(
@@ -246,7 +246,7 @@ fn on_packet_sent_across_multiple_paths() {
         ack_elicitation,
         is_congestion_controlled: true,
         bytes_sent: packet_bytes,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     manager.on_packet_sent(
@@ -288,7 +288,7 @@ fn on_packet_sent_across_multiple_paths() {
         ack_elicitation,
         is_congestion_controlled: true,
         bytes_sent: packet_bytes,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // Reset the timer so we can confirm it was set correctly
@@ -353,7 +353,7 @@ fn on_ack_frame() {
                 ack_elicitation: AckElicitation::Eliciting,
                 is_congestion_controlled: true,
                 bytes_sent: packet_bytes,
-                bytes_progressed: 0,
+                ..Default::default()
             },
             time_sent,
             ecn,
@@ -493,7 +493,7 @@ fn on_ack_frame() {
             ack_elicitation: AckElicitation::NonEliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -525,6 +525,64 @@ fn on_ack_frame() {
     assert_eq!(3, context.on_rtt_update_count);
 }
 
+#[test]
+fn on_duplicate_ack_frame() {
+    let space = PacketNumberSpace::ApplicationData;
+    let mut manager = Manager::new(space);
+    let ecn = ExplicitCongestionNotification::default();
+    let mut path_manager = helper_generate_path_manager(Duration::from_millis(10));
+    let mut context = MockContext::new(&mut path_manager);
+    let mut publisher = Publisher::no_snapshot();
+
+    let time_sent = s2n_quic_platform::time::now() + Duration::from_secs(10);
+
+    // Send packets 1 to 3
+    for i in 1..=3 {
+        manager.on_packet_sent(
+            space.new_packet_number(VarInt::from_u8(i)),
+            transmission::Outcome {
+                ack_elicitation: AckElicitation::Eliciting,
+                is_congestion_controlled: true,
+                bytes_sent: 128,
+                ..Default::default()
+            },
+            time_sent,
+            ecn,
+            transmission::Mode::Normal,
+            None,
+            &mut context,
+            &mut publisher,
+        );
+    }
+
+    // The first ACK frame acknowledges new packets
+    let ack_receive_time = time_sent + Duration::from_millis(500);
+    ack_packets(
+        1..=3,
+        ack_receive_time,
+        &mut context,
+        &mut manager,
+        None,
+        &mut publisher,
+    );
+    assert_eq!(context.on_new_packet_ack_count, 1);
+    assert_eq!(publisher.duplicate_ack_received, 0);
+
+    // Processing the same ACK frame again acknowledges nothing new
+    let ack_receive_time = ack_receive_time + Duration::from_millis(10);
+    ack_packets(
+        1..=3,
+        ack_receive_time,
+        &mut context,
+        &mut manager,
+        None,
+        &mut publisher,
+    );
+    assert_eq!(context.on_packet_ack_count, 2);
+    assert_eq!(context.on_new_packet_ack_count, 1);
+    assert_eq!(publisher.duplicate_ack_received, 1);
+}
+
 #[test]
 // pto_backoff reset should happen for the path the packet was sent on
 //
@@ -564,7 +622,7 @@ fn process_new_acked_packets_update_pto_timer() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -581,7 +639,7 @@ fn process_new_acked_packets_update_pto_timer() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -681,7 +739,7 @@ fn process_new_acked_packets_congestion_controller() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -698,7 +756,7 @@ fn process_new_acked_packets_congestion_controller() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -809,7 +867,7 @@ fn process_new_acked_packets_pto_timer() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -826,7 +884,7 @@ fn process_new_acked_packets_pto_timer() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -863,7 +921,7 @@ fn process_new_acked_packets_pto_timer() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -927,7 +985,7 @@ fn process_new_acked_packets_process_ecn() {
                 ack_elicitation: AckElicitation::Eliciting,
                 is_congestion_controlled: true,
                 bytes_sent: packet_bytes,
-                bytes_progressed: 0,
+                ..Default::default()
             },
             time_sent,
             ExplicitCongestionNotification::Ect0,
@@ -1029,7 +1087,7 @@ fn process_new_acked_packets_failed_ecn_validation_does_not_cause_congestion_eve
                 ack_elicitation: AckElicitation::Eliciting,
                 is_congestion_controlled: true,
                 bytes_sent: packet_bytes,
-                bytes_progressed: 0,
+                ..Default::default()
             },
             time_sent,
             ExplicitCongestionNotification::Ect0,
@@ -1087,7 +1145,7 @@ fn no_rtt_update_when_not_acknowledging_the_largest_acknowledged_packet() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -1102,7 +1160,7 @@ fn no_rtt_update_when_not_acknowledging_the_largest_acknowledged_packet() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -1180,7 +1238,7 @@ fn no_rtt_update_when_receiving_packet_on_different_path() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -1195,7 +1253,7 @@ fn no_rtt_update_when_receiving_packet_on_different_path() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         time_sent,
         ecn,
@@ -1298,7 +1356,7 @@ fn rtt_update_when_receiving_ack_from_multiple_paths() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         sent_time,
         ecn,
@@ -1316,7 +1374,7 @@ fn rtt_update_when_receiving_ack_from_multiple_paths() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: packet_bytes,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         sent_time,
         ecn,
@@ -1385,7 +1443,7 @@ fn detect_and_remove_lost_packets() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // Send a packet that was sent too long ago (lost)
@@ -1567,7 +1625,7 @@ fn detect_lost_packets_persistent_congestion_path_aware() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // Send a packet that was sent too long ago (lost)
@@ -1798,7 +1856,7 @@ fn detect_and_remove_lost_packets_nothing_lost() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // Send a packet that is less than the largest acked but not lost
@@ -1851,7 +1909,7 @@ fn detect_and_remove_lost_packets_mtu_probe() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: MINIMUM_MTU as usize + 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // Send an MTU probe packet
@@ -1912,7 +1970,7 @@ fn persistent_congestion() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // t=0: Send packet #1 (app data)
@@ -2075,7 +2133,7 @@ fn persistent_congestion_multiple_periods() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // t=0: Send packet #1 (app data)
@@ -2210,7 +2268,7 @@ fn persistent_congestion_period_does_not_start_until_rtt_sample() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // t=0: Send packet #1 (app data)
@@ -2296,7 +2354,7 @@ fn persistent_congestion_not_ack_eliciting() {
         ack_elicitation: AckElicitation::NonEliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // t=0: Send packet #1 (app data)
@@ -2384,7 +2442,7 @@ fn persistent_congestion_mtu_probe() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 1,
-        bytes_progressed: 0,
+        ..Default::default()
     };
 
     // t=0: Send packet #1 (app data)
@@ -2532,7 +2590,7 @@ fn update_pto_timer() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: 1,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         now,
         ecn,
@@ -2671,7 +2729,7 @@ fn on_timeout() {
             ack_elicitation: AckElicitation::Eliciting,
             is_congestion_controlled: true,
             bytes_sent: 1,
-            bytes_progressed: 0,
+            ..Default::default()
         },
         now - Duration::from_secs(5),
         ecn,
@@ -2984,7 +3042,7 @@ fn probe_packets_count_towards_bytes_in_flight() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 100,
-        bytes_progressed: 0,
+        ..Default::default()
     };
     manager.on_packet_sent(
         space.new_packet_number(VarInt::from_u8(1)),
@@ -3076,7 +3134,7 @@ fn packet_declared_lost_less_than_1_ms_from_loss_threshold() {
         ack_elicitation: AckElicitation::Eliciting,
         is_congestion_controlled: true,
         bytes_sent: 100,
-        bytes_progressed: 0,
+        ..Default::default()
     };
     let random = &mut random::testing::Generator::default();
     manager.on_packet_sent(
@@ -3287,6 +3345,7 @@ impl<'a> recovery::Context<Config> for MockContext<'a> {
 
     fn on_new_packet_ack<Pub: event::ConnectionPublisher>(
         &mut self,
+        _timestamp: Timestamp,
         _packet_number_range: &PacketNumberRange,
         _publisher: &mut Pub,
     ) {
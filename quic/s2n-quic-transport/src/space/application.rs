@@ -8,7 +8,10 @@ use crate::{
     path::{path_event, Path},
     processed_packet::ProcessedPacket,
     recovery,
-    space::{datagram, keep_alive::KeepAlive, HandshakeStatus, PacketSpace, TxPacketNumbers},
+    space::{
+        datagram, keep_alive::KeepAlive, rtt_probe::RttProbe, HandshakeStatus, PacketSpace,
+        TxPacketNumbers,
+    },
     stream::AbstractStreamManager,
     sync::flag,
     transmission,
@@ -61,6 +64,7 @@ pub struct ApplicationSpace<Config: endpoint::Config> {
 
     ping: flag::Ping,
     keep_alive: KeepAlive,
+    rtt_probe: RttProbe,
     processed_packet_numbers: SlidingWindow,
     recovery_manager: recovery::Manager<Config>,
     pub datagram_manager: datagram::Manager<Config>,
@@ -88,10 +92,12 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
         stream_manager: AbstractStreamManager<Config::Stream>,
         ack_manager: AckManager,
         keep_alive: KeepAlive,
+        mut rtt_probe: RttProbe,
         max_mtu: MaxMtu,
         datagram_manager: datagram::Manager<Config>,
     ) -> Self {
         let key_set = KeySet::new(key, Self::key_limits(max_mtu));
+        rtt_probe.start(now);
 
         Self {
             tx_packet_numbers: TxPacketNumbers::new(PacketNumberSpace::ApplicationData, now),
@@ -102,6 +108,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             header_key,
             ping: flag::Ping::default(),
             keep_alive,
+            rtt_probe,
             processed_packet_numbers: SlidingWindow::default(),
             recovery_manager: recovery::Manager::new(PacketNumberSpace::ApplicationData),
             datagram_manager,
@@ -235,6 +242,39 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             self.keep_alive.reset(timestamp);
         }
 
+        if let Some(reason) = self.ack_manager.take_sent_reason() {
+            context.publisher.on_ack_sent(event::builder::AckSent {
+                packet_header: event::builder::PacketHeader::new(
+                    packet_number,
+                    context.publisher.quic_version(),
+                ),
+                path_id: context.path_id.into_event(),
+                reason,
+            });
+        }
+
+        for (id, duration) in self.stream_manager.drain_starved_streams() {
+            context
+                .publisher
+                .on_stream_starved(event::builder::StreamStarved {
+                    id: id.as_varint().as_u64(),
+                    duration,
+                });
+        }
+
+        for update in self.stream_manager.drain_window_updates() {
+            context.publisher.on_flow_control_window_changed(update);
+        }
+
+        for (id, retransmitted_bytes) in self.stream_manager.drain_closed_streams() {
+            context
+                .publisher
+                .on_stream_closed(event::builder::StreamClosed {
+                    id: id.as_varint().as_u64(),
+                    retransmitted_bytes,
+                });
+        }
+
         context
             .publisher
             .on_packet_sent(event::builder::PacketSent {
@@ -242,6 +282,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
                     packet_number,
                     context.publisher.quic_version(),
                 ),
+                sent_reason: super::packet_sent_reason(context.transmission_mode, &outcome),
             });
 
         Ok((outcome, buffer))
@@ -306,6 +347,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
                     packet_number,
                     context.publisher.quic_version(),
                 ),
+                sent_reason: event::builder::PacketSentReason::Normal,
             });
 
         Ok((outcome, buffer))
@@ -382,7 +424,7 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
 
         recovery_manager.on_timeout(timestamp, random_generator, &mut context, publisher);
 
-        self.stream_manager.on_timeout(timestamp);
+        self.stream_manager.on_timeout(timestamp, publisher);
 
         if self.keep_alive.on_timeout(timestamp).is_ready() {
             publisher.on_keep_alive_timer_expired(event::builder::KeepAliveTimerExpired {
@@ -392,6 +434,19 @@ impl<Config: endpoint::Config> ApplicationSpace<Config> {
             // send a ping after timing out
             self.ping();
         }
+
+        if self.rtt_probe.on_timeout(timestamp).is_ready() {
+            // Skip the probe if the congestion window is already full. Acknowledgements for
+            // the outstanding data will produce an RTT sample without adding to the congestion.
+            let is_congestion_limited = path_manager
+                .active_path()
+                .transmission_constraint()
+                .is_congestion_limited();
+
+            if !is_congestion_limited {
+                self.ping();
+            }
+        }
     }
 
     /// Returns `true` if the recovery manager for this packet space requires a probe
@@ -567,6 +622,7 @@ impl<Config: endpoint::Config> timer::Provider for ApplicationSpace<Config> {
         self.key_set.timers(query)?;
         self.stream_manager.timers(query)?;
         self.keep_alive.timers(query)?;
+        self.rtt_probe.timers(query)?;
 
         Ok(())
     }
@@ -642,13 +698,15 @@ impl<'a, Config: endpoint::Config> recovery::Context<Config> for RecoveryContext
 
     fn on_new_packet_ack<Pub: event::ConnectionPublisher>(
         &mut self,
+        timestamp: Timestamp,
         packet_number_range: &PacketNumberRange,
         publisher: &mut Pub,
     ) {
         self.handshake_status
             .on_packet_ack(packet_number_range, publisher);
         self.ping.on_packet_ack(packet_number_range);
-        self.stream_manager.on_packet_ack(packet_number_range);
+        self.stream_manager
+            .on_packet_ack(timestamp, packet_number_range);
         self.local_id_registry.on_packet_ack(packet_number_range);
         self.path_manager.on_packet_ack(packet_number_range);
     }
@@ -954,6 +1012,19 @@ impl<Config: endpoint::Config> PacketSpace<Config> for ApplicationSpace<Config>
             .insert(processed_packet.packet_number)
             .expect("packet number was already checked");
 
+        // publish the send windows which were increased by the peer's frames in the packet
+        for update in self.stream_manager.drain_window_updates() {
+            publisher.on_flow_control_window_changed(update);
+        }
+
+        // publish the streams which were closed by the peer's frames in the packet
+        for (id, retransmitted_bytes) in self.stream_manager.drain_closed_streams() {
+            publisher.on_stream_closed(event::builder::StreamClosed {
+                id: id.as_varint().as_u64(),
+                retransmitted_bytes,
+            });
+        }
+
         Ok(())
     }
 }
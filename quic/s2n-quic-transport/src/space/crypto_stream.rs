@@ -3,10 +3,19 @@
 
 use crate::{
     buffer::StreamReceiveBuffer,
+    path,
     sync::data_sender::{self, DataSender, OutgoingDataFlowController},
     transmission,
 };
-use s2n_quic_core::{ack, frame::crypto::CryptoRef, transport, varint::VarInt};
+use s2n_quic_core::{
+    ack,
+    crypto::tls,
+    event::{self, IntoEvent},
+    frame::crypto::CryptoRef,
+    packet::number::PacketNumber,
+    transport,
+    varint::VarInt,
+};
 
 pub type TxCryptoStream = DataSender<CryptoFlowController, data_sender::writer::Crypto>;
 
@@ -33,7 +42,11 @@ impl OutgoingDataFlowController for CryptoFlowController {
 pub struct CryptoStream {
     pub tx: TxCryptoStream,
     pub rx: StreamReceiveBuffer,
+    /// The limits applied to each CRYPTO frame
+    pub fragment_limits: tls::FragmentLimits,
     is_finished: bool,
+    /// The number of retransmitted bytes which have already been published
+    published_retransmitted_bytes: u64,
 }
 
 const TX_MAX_BUFFER_CAPACITY: u32 = 4096;
@@ -49,7 +62,9 @@ impl CryptoStream {
         Self {
             tx: TxCryptoStream::new(Default::default(), TX_MAX_BUFFER_CAPACITY),
             rx: StreamReceiveBuffer::default(),
+            fragment_limits: Default::default(),
             is_finished: false,
+            published_retransmitted_bytes: 0,
         }
     }
 
@@ -112,6 +127,31 @@ impl CryptoStream {
         Ok(())
     }
 
+    /// Publishes the bytes which were retransmitted since the last call
+    ///
+    /// This should be called after each packet is sent in the crypto stream's space.
+    pub fn on_packet_sent<Pub: event::ConnectionPublisher>(
+        &mut self,
+        packet_number: PacketNumber,
+        path_id: path::Id,
+        publisher: &mut Pub,
+    ) {
+        let retransmitted_bytes = self.tx.retransmitted_bytes();
+        let bytes = retransmitted_bytes - self.published_retransmitted_bytes;
+        self.published_retransmitted_bytes = retransmitted_bytes;
+
+        if bytes > 0 {
+            publisher.on_crypto_retransmitted(event::builder::CryptoRetransmitted {
+                packet_header: event::builder::PacketHeader::new(
+                    packet_number,
+                    publisher.quic_version(),
+                ),
+                path_id: path_id.into_event(),
+                bytes,
+            });
+        }
+    }
+
     /// This method gets called when a packet delivery got acknowledged
     pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) {
         self.tx.on_packet_ack(ack_set);
@@ -12,7 +12,7 @@ use crate::{
 use core::task::Poll;
 use s2n_codec::EncoderValue;
 use s2n_quic_core::{
-    datagram::{Endpoint, Receiver, Sender, WriteError},
+    datagram::{self, Endpoint, Receiver, Sender, WriteError},
     event,
     frame::{self, datagram::DatagramRef},
     varint::VarInt,
@@ -26,6 +26,8 @@ pub struct Manager<Config: endpoint::Config> {
     pub sender: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Sender,
     pub receiver: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Receiver,
     max_datagram_payload: u64,
+    /// The maximum DATAGRAM frame size advertised to the peer
+    max_datagram_frame_size: u64,
 }
 
 impl<Config: endpoint::Config> Manager<Config> {
@@ -33,14 +35,21 @@ impl<Config: endpoint::Config> Manager<Config> {
         sender: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Sender,
         receiver: <<Config as endpoint::Config>::DatagramEndpoint as Endpoint>::Receiver,
         max_datagram_payload: u64,
+        max_datagram_frame_size: u64,
     ) -> Self {
         Self {
             sender,
             receiver,
             max_datagram_payload,
+            max_datagram_frame_size,
         }
     }
 
+    /// Returns the datagram limits negotiated with the peer
+    pub fn config(&self) -> datagram::Config {
+        datagram::Config::new(self.max_datagram_payload, self.max_datagram_frame_size)
+    }
+
     /// A callback that allows users to write datagrams directly to the packet.
     pub fn on_transmit<S: Stream, W: WriteContext>(
         &mut self,
@@ -218,6 +218,17 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
             context.publisher,
         );
 
+        if let Some(reason) = self.ack_manager.take_sent_reason() {
+            context.publisher.on_ack_sent(event::builder::AckSent {
+                packet_header: event::builder::PacketHeader::new(
+                    packet_number,
+                    context.publisher.quic_version(),
+                ),
+                path_id: context.path_id.into_event(),
+                reason,
+            });
+        }
+
         context
             .publisher
             .on_packet_sent(event::builder::PacketSent {
@@ -225,8 +236,12 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
                     packet_number,
                     context.publisher.quic_version(),
                 ),
+                sent_reason: super::packet_sent_reason(context.transmission_mode, &outcome),
             });
 
+        self.crypto_stream
+            .on_packet_sent(packet_number, context.path_id, context.publisher);
+
         Ok((outcome, buffer))
     }
 
@@ -283,6 +298,7 @@ impl<Config: endpoint::Config> InitialSpace<Config> {
                     packet_number,
                     context.publisher.quic_version(),
                 ),
+                sent_reason: event::builder::PacketSentReason::Normal,
             });
 
         Ok((outcome, buffer))
@@ -590,6 +606,7 @@ impl<'a, Config: endpoint::Config> recovery::Context<Config> for RecoveryContext
 
     fn on_new_packet_ack<Pub: event::ConnectionPublisher>(
         &mut self,
+        _timestamp: Timestamp,
         packet_number_range: &PacketNumberRange,
         _publisher: &mut Pub,
     ) {
@@ -39,6 +39,7 @@ mod handshake;
 mod handshake_status;
 mod initial;
 mod keep_alive;
+mod rtt_probe;
 mod session_context;
 mod tx_packet_numbers;
 
@@ -53,6 +54,8 @@ pub(crate) use tx_packet_numbers::TxPacketNumbers;
 struct SessionInfo<Config: endpoint::Config> {
     session: <Config::TLSEndpoint as tls::Endpoint>::Session,
     initial_cid: InitialId,
+    /// The `max_datagram_frame_size` advertised in the local transport parameters
+    local_max_datagram_frame_size: u64,
 }
 
 pub struct PacketSpaceManager<Config: endpoint::Config> {
@@ -74,6 +77,10 @@ pub struct PacketSpaceManager<Config: endpoint::Config> {
     //# another mechanism is used for agreeing on an application protocol,
     //# endpoints MUST use ALPN for this purpose.
     pub application_protocol: Bytes,
+    /// The group selected for the key exchange
+    pub key_exchange_group: Option<tls::NamedGroup>,
+    /// The signature scheme selected to authenticate the handshake
+    pub signature_scheme: Option<tls::SignatureScheme>,
 }
 
 impl<Config: endpoint::Config> fmt::Debug for PacketSpaceManager<Config> {
@@ -136,11 +143,14 @@ macro_rules! packet_space_api {
 }
 
 impl<Config: endpoint::Config> PacketSpaceManager<Config> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new<Pub: event::ConnectionPublisher>(
         initial_cid: InitialId,
         session: <Config::TLSEndpoint as tls::Endpoint>::Session,
+        local_max_datagram_frame_size: u64,
         initial_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::InitialKey,
         header_key: <<Config::TLSEndpoint as tls::Endpoint>::Session as CryptoSuite>::InitialHeaderKey,
+        fragment_limits: tls::FragmentLimits,
         now: Timestamp,
         publisher: &mut Pub,
     ) -> Self {
@@ -150,24 +160,26 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             key_type: event::builder::KeyType::Initial,
             cipher_suite: initial_key.cipher_suite().into_event(),
         });
+
+        let mut initial = Box::new(InitialSpace::new(initial_key, header_key, now, ack_manager));
+        initial.crypto_stream.fragment_limits = fragment_limits;
+
         Self {
             session_info: Some(SessionInfo {
                 session,
                 initial_cid,
+                local_max_datagram_frame_size,
             }),
             retry_cid: None,
-            initial: Some(Box::new(InitialSpace::new(
-                initial_key,
-                header_key,
-                now,
-                ack_manager,
-            ))),
+            initial: Some(initial),
             handshake: None,
             application: None,
             zero_rtt_crypto: None,
             handshake_status: HandshakeStatus::default(),
             server_name: None,
             application_protocol: Bytes::new(),
+            key_exchange_group: None,
+            signature_scheme: None,
         }
     }
 
@@ -209,6 +221,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             let mut context: SessionContext<Config, Pub> = SessionContext {
                 now,
                 initial_cid: &session_info.initial_cid,
+                local_max_datagram_frame_size: session_info.local_max_datagram_frame_size,
                 retry_cid: self.retry_cid.as_deref(),
                 initial: &mut self.initial,
                 handshake: &mut self.handshake,
@@ -220,6 +233,8 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 limits,
                 server_name: &mut self.server_name,
                 application_protocol: &mut self.application_protocol,
+                key_exchange_group: &mut self.key_exchange_group,
+                signature_scheme: &mut self.signature_scheme,
                 waker,
                 publisher,
                 datagram,
@@ -326,7 +341,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
         connection_close: &ConnectionClose,
         context: &mut connection::ConnectionTransmissionContext<Config>,
         packet_buffer: &mut endpoint::PacketBuffer,
-    ) -> Option<Bytes> {
+    ) -> Option<(Bytes, u8)> {
         //= https://www.rfc-editor.org/rfc/rfc9000#section-10.2.3
         //# When sending a CONNECTION_CLOSE frame, the goal is to ensure that the
         //# peer will process the frame.  Generally, this means sending the frame
@@ -334,7 +349,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
         //# packet being discarded.
         let mut can_send_initial = self.initial.is_some();
         let mut can_send_handshake = self.handshake.is_some();
-        let can_send_application = self.application.is_some();
+        let mut can_send_application = self.application.is_some();
 
         //= https://www.rfc-editor.org/rfc/rfc9000#section-10.2.3
         //# After the handshake is confirmed (see
@@ -373,7 +388,23 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
             }
         }
 
-        packet_buffer.write(|buffer| {
+        // If the number of coalesced packets is limited, prefer the packets with the highest
+        // level of protection
+        let mut remaining_packets = context.max_coalesced_packets;
+        for can_send in [
+            &mut can_send_application,
+            &mut can_send_handshake,
+            &mut can_send_initial,
+        ] {
+            if *can_send {
+                *can_send = remaining_packets > 0;
+                remaining_packets = remaining_packets.saturating_sub(1);
+            }
+        }
+
+        let mut packet_count = 0;
+
+        let packet = packet_buffer.write(|buffer| {
             macro_rules! write_packet {
                 ($buffer:expr, $space:ident, $check:expr, $frame:expr) => {
                     if let Some((space, _handshake_status)) = self.$space().filter(|_| $check) {
@@ -382,6 +413,7 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                         match result {
                             Ok((outcome, buffer)) => {
                                 *context.outcome += outcome;
+                                packet_count += 1;
                                 buffer
                             }
                             Err(err) => err.take_buffer(),
@@ -410,7 +442,9 @@ impl<Config: endpoint::Config> PacketSpaceManager<Config> {
                 can_send_application,
                 connection_close
             )
-        })
+        })?;
+
+        Some((packet, packet_count))
     }
 
     pub fn close<Pub: event::ConnectionPublisher>(
@@ -493,6 +527,28 @@ impl<Config: endpoint::Config> transmission::interest::Provider for PacketSpaceM
     }
 }
 
+/// Classifies a transmitted packet for the `PacketSent` event
+///
+/// Packets are only reported as retransmissions if all of the data they carry was already
+/// sent in a previous packet.
+#[inline]
+fn packet_sent_reason(
+    transmission_mode: transmission::Mode,
+    outcome: &transmission::Outcome,
+) -> event::builder::PacketSentReason {
+    use event::builder::PacketSentReason;
+
+    if transmission_mode.is_loss_recovery_probing() {
+        PacketSentReason::PtoProbe
+    } else if !outcome.ack_elicitation.is_ack_eliciting() {
+        PacketSentReason::AckOnly
+    } else if outcome.bytes_retransmitted > 0 && outcome.bytes_new_data == 0 {
+        PacketSentReason::Retransmit
+    } else {
+        PacketSentReason::Normal
+    }
+}
+
 impl<Config: endpoint::Config> connection::finalization::Provider for PacketSpaceManager<Config> {
     fn finalization_status(&self) -> connection::finalization::Status {
         core::iter::empty()
@@ -896,3 +952,51 @@ pub trait PacketSpace<Config: endpoint::Config> {
         Ok(processed_packet)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use event::builder::PacketSentReason;
+    use s2n_quic_core::frame::ack_elicitation::AckElicitation::{Eliciting, NonEliciting};
+    use transmission::Mode::{LossRecoveryProbing, Normal};
+
+    #[test]
+    fn packet_sent_reason_test() {
+        let reason = |mode, bytes_new_data, bytes_retransmitted, ack_elicitation| {
+            let outcome = transmission::Outcome {
+                ack_elicitation,
+                bytes_new_data,
+                bytes_retransmitted,
+                ..Default::default()
+            };
+            packet_sent_reason(mode, &outcome)
+        };
+
+        assert!(matches!(
+            reason(Normal, 100, 0, Eliciting),
+            PacketSentReason::Normal
+        ));
+        assert!(matches!(
+            reason(Normal, 0, 0, NonEliciting),
+            PacketSentReason::AckOnly
+        ));
+        assert!(matches!(
+            reason(Normal, 0, 100, Eliciting),
+            PacketSentReason::Retransmit
+        ));
+        // packets which also carry new data are not retransmissions
+        assert!(matches!(
+            reason(Normal, 1, 100, Eliciting),
+            PacketSentReason::Normal
+        ));
+        // probes are reported as such, even if they retransmit data
+        assert!(matches!(
+            reason(LossRecoveryProbing, 0, 100, Eliciting),
+            PacketSentReason::PtoProbe
+        ));
+        assert!(matches!(
+            reason(LossRecoveryProbing, 0, 0, Eliciting),
+            PacketSentReason::PtoProbe
+        ));
+    }
+}
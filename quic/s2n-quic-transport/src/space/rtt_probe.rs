@@ -0,0 +1,87 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{task::Poll, time::Duration};
+use s2n_quic_core::time::{timer, Timer, Timestamp};
+
+/// Periodically elicits an acknowledgement from the peer to produce RTT samples
+#[derive(Debug, Default)]
+pub struct RttProbe {
+    interval: Option<Duration>,
+    timer: Timer,
+}
+
+impl RttProbe {
+    pub fn new(interval: Option<Duration>) -> Self {
+        debug_assert_ne!(
+            interval,
+            Some(Duration::ZERO),
+            "a zero interval is rejected by the connection limits"
+        );
+
+        Self {
+            interval,
+            timer: Timer::default(),
+        }
+    }
+
+    /// Arms the probe timer, if probing is enabled
+    #[inline]
+    pub fn start(&mut self, now: Timestamp) {
+        if let Some(interval) = self.interval {
+            self.timer.set(now + interval);
+        }
+    }
+
+    #[inline]
+    pub fn on_timeout(&mut self, now: Timestamp) -> Poll<()> {
+        let res = self.timer.poll_expiration(now);
+
+        if res.is_ready() {
+            self.start(now);
+        }
+
+        res
+    }
+}
+
+impl timer::Provider for RttProbe {
+    #[inline]
+    fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
+        self.timer.timers(query)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::time::{Clock, NoopClock};
+
+    #[test]
+    fn probe_interval_test() {
+        let now = NoopClock {}.get_time();
+        let interval = Duration::from_millis(100);
+        let mut probe = RttProbe::new(Some(interval));
+
+        assert!(probe.on_timeout(now).is_pending());
+
+        probe.start(now);
+        assert!(probe.on_timeout(now + interval / 2).is_pending());
+        assert!(probe.on_timeout(now + interval).is_ready());
+
+        // the timer is re-armed after each probe
+        assert!(probe.on_timeout(now + interval + interval / 2).is_pending());
+        assert!(probe.on_timeout(now + interval * 2).is_ready());
+    }
+
+    #[test]
+    fn disabled_probe_test() {
+        let now = NoopClock {}.get_time();
+
+        let mut probe = RttProbe::new(None);
+        probe.start(now);
+        assert!(!probe.timer.is_armed());
+        assert!(probe.on_timeout(now + Duration::from_secs(60)).is_pending());
+    }
+}
@@ -6,8 +6,8 @@ use crate::{
     connection::{self, limits::Limits},
     endpoint, path,
     space::{
-        datagram, keep_alive::KeepAlive, ApplicationSpace, HandshakeSpace, HandshakeStatus,
-        InitialSpace,
+        datagram, keep_alive::KeepAlive, rtt_probe::RttProbe, ApplicationSpace, HandshakeSpace,
+        HandshakeStatus, InitialSpace,
     },
     stream::AbstractStreamManager,
 };
@@ -39,6 +39,8 @@ use s2n_quic_core::{
 pub struct SessionContext<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher> {
     pub now: Timestamp,
     pub initial_cid: &'a InitialId,
+    /// The `max_datagram_frame_size` advertised to the peer
+    pub local_max_datagram_frame_size: u64,
     pub retry_cid: Option<&'a PeerId>,
     pub path_manager: &'a mut path::Manager<Config>,
     pub initial: &'a mut Option<Box<InitialSpace<Config>>>,
@@ -52,6 +54,8 @@ pub struct SessionContext<'a, Config: endpoint::Config, Pub: event::ConnectionPu
     pub limits: &'a mut Limits,
     pub server_name: &'a mut Option<ServerName>,
     pub application_protocol: &'a mut Bytes,
+    pub key_exchange_group: &'a mut Option<tls::NamedGroup>,
+    pub signature_scheme: &'a mut Option<tls::SignatureScheme>,
     pub waker: &'a Waker,
     pub publisher: &'a mut Pub,
     pub datagram: &'a mut Config::DatagramEndpoint,
@@ -315,13 +319,17 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
 
         let ack_manager = AckManager::new(PacketNumberSpace::Handshake, ack::Settings::EARLY);
 
+        // the handshake space uses the same fragmentation as the initial space
+        let fragment_limits = self
+            .initial
+            .as_ref()
+            .map(|space| space.crypto_stream.fragment_limits)
+            .unwrap_or_default();
+
         let cipher_suite = key.cipher_suite().into_event();
-        *self.handshake = Some(Box::new(HandshakeSpace::new(
-            key,
-            header_key,
-            self.now,
-            ack_manager,
-        )));
+        let mut handshake = Box::new(HandshakeSpace::new(key, header_key, self.now, ack_manager));
+        handshake.crypto_stream.fragment_limits = fragment_limits;
+        *self.handshake = Some(handshake);
         self.publisher.on_key_update(event::builder::KeyUpdate {
             key_type: event::builder::KeyType::Handshake,
             cipher_suite,
@@ -379,6 +387,8 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
                 endpoint::Type::Server => self.on_client_params(param_decoder)?,
             };
 
+        self.local_id_registry
+            .set_active_connection_id_target(self.limits.active_connection_id_target());
         self.local_id_registry
             .set_active_connection_id_limit(active_connection_id_limit.as_u64());
 
@@ -392,25 +402,31 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
         let ack_manager = AckManager::new(
             PacketNumberSpace::ApplicationData,
             self.limits.ack_settings(),
-        );
+        )
+        .with_ack_elicitation(self.limits.ack_elicitation())
+        .with_packet_tolerance(self.limits.ack_packet_tolerance())
+        .with_priority(self.limits.ack_priority());
 
         let keep_alive = KeepAlive::new(
             self.limits.max_idle_timeout(),
             self.limits.max_keep_alive_period(),
         );
 
+        let rtt_probe = RttProbe::new(self.limits.rtt_probing_interval());
+
         let conn_info = ConnectionInfo::new(datagram_limits.max_datagram_payload);
         let (datagram_sender, datagram_receiver) = self.datagram.create_connection(&conn_info);
         let datagram_manager = datagram::Manager::new(
             datagram_sender,
             datagram_receiver,
             datagram_limits.max_datagram_payload,
+            self.local_max_datagram_frame_size,
         );
 
         self.path_manager
             .active_path_mut()
             .rtt_estimator
-            .on_max_ack_delay(max_ack_delay);
+            .on_max_ack_delay(self.limits.peer_max_ack_delay(max_ack_delay));
 
         let cipher_suite = key.cipher_suite().into_event();
         let max_mtu = self.path_manager.max_mtu();
@@ -421,6 +437,7 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
             stream_manager,
             ack_manager,
             keep_alive,
+            rtt_probe,
             max_mtu,
             datagram_manager,
         )));
@@ -456,6 +473,21 @@ impl<'a, Config: endpoint::Config, Pub: event::ConnectionPublisher>
         Ok(())
     }
 
+    fn on_key_exchange_group(&mut self, group: tls::NamedGroup) -> Result<(), transport::Error> {
+        *self.key_exchange_group = Some(group);
+
+        Ok(())
+    }
+
+    fn on_signature_scheme(
+        &mut self,
+        scheme: tls::SignatureScheme,
+    ) -> Result<(), transport::Error> {
+        *self.signature_scheme = Some(scheme);
+
+        Ok(())
+    }
+
     fn on_handshake_complete(&mut self) -> Result<(), transport::Error> {
         // After the handshake is complete, the handshake crypto stream should be completely
         // finished
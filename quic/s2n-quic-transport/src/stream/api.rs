@@ -224,6 +224,32 @@ macro_rules! tx_stream_apis {
             self.tx_request()?.reset(error_code).poll(None)?;
             Ok(())
         }
+
+        /// Returns the number of bytes of stream data which have been transmitted more than once
+        ///
+        /// Data is retransmitted after it was declared lost or when it is included in a loss
+        /// recovery probe.
+        pub fn retransmitted_bytes(&mut self) -> Result<u64, StreamError> {
+            let response = self.tx_request()?.send(&mut []).poll(None)?;
+            Ok(response.retransmitted_bytes)
+        }
+
+        /// Returns the number of bytes of stream data which have been transmitted at least once
+        ///
+        /// Retransmissions are not included.
+        pub fn sent_bytes(&mut self) -> Result<u64, StreamError> {
+            let response = self.tx_request()?.send(&mut []).poll(None)?;
+            Ok(response.sent_bytes)
+        }
+
+        /// Returns the time between the first transmission of stream data and the first
+        /// acknowledgement of stream data by the peer
+        ///
+        /// `None` is returned if no stream data has been acknowledged yet.
+        pub fn time_to_first_ack(&mut self) -> Result<Option<core::time::Duration>, StreamError> {
+            let response = self.tx_request()?.send(&mut []).poll(None)?;
+            Ok(response.time_to_first_ack)
+        }
     };
 }
 
@@ -302,6 +328,88 @@ macro_rules! rx_stream_apis {
             self.rx_request()?.stop_sending(error_code).poll(None)?;
             Ok(())
         }
+
+        /// Returns information about the out-of-order data which is currently buffered
+        /// on the `Stream`.
+        ///
+        /// This includes the number of bytes which are waiting for earlier data to arrive
+        /// and the size of the largest gap preceding them.
+        pub fn reassembly(&mut self) -> Result<ops::rx::Reassembly, StreamError> {
+            let response = self.rx_request()?.receive(&mut []).poll(None)?;
+            Ok(response.reassembly)
+        }
+
+        /// Receives exactly `len` bytes of data from the stream.
+        ///
+        /// No data is consumed until either `len` bytes are buffered or the peer has finished
+        /// the stream.
+        ///
+        /// The method will return:
+        /// - `Poll::Ready(Ok((data, is_open)))` once the data was received. If the stream was
+        ///   finished with fewer than `len` bytes remaining, `data` contains the remaining bytes
+        ///   and `is_open` is `false`.
+        /// - `Poll::Ready(Err(stream_error))` if the stream could not be read, because the stream
+        ///   had previously entered an error state.
+        /// - `Poll::Ready(Err(StreamError::ReceiveWindowExceeded))` if `len` exceeds the amount of
+        ///   data the stream buffers before waking the application, which is half of its receive
+        ///   window.
+        /// - `Poll::Pending` if the stream is waiting to receive data from the peer. In this case, the
+        ///   caller should retry receiving after the `Waker` on the provided `Context` is notified.
+        pub fn poll_receive_exact(
+            &mut self,
+            len: usize,
+            cx: &mut Context,
+        ) -> Poll<Result<(Bytes, bool), StreamError>> {
+            let response = self
+                .rx_request()?
+                .receive(&mut [])
+                .with_exact_low_watermark(len)
+                .poll(Some(cx))?;
+
+            // keep waiting unless the peer has already sent all of its data
+            if response.is_pending() && response.status.is_open() {
+                return Poll::Pending;
+            }
+
+            let mut is_open = !response.status.is_finished();
+            let mut remaining = len;
+            let mut data = Bytes::new();
+            let mut buffer = bytes::BytesMut::new();
+
+            while remaining > 0 {
+                let mut chunk = Bytes::new();
+                let response = self
+                    .rx_request()?
+                    .receive(core::slice::from_mut(&mut chunk))
+                    .with_high_watermark(remaining)
+                    .poll(None)?;
+
+                is_open = response.status.is_open() || response.status.is_finishing();
+
+                if response.chunks.consumed == 0 {
+                    break;
+                }
+
+                remaining -= chunk.len();
+
+                // avoid copying if the data was received in a single chunk
+                if data.is_empty() {
+                    data = chunk;
+                } else {
+                    if buffer.is_empty() {
+                        buffer.reserve(len);
+                        buffer.extend_from_slice(&data);
+                    }
+                    buffer.extend_from_slice(&chunk);
+                }
+            }
+
+            if !buffer.is_empty() {
+                data = buffer.freeze();
+            }
+
+            Poll::Ready(Ok((data, is_open)))
+        }
     };
 }
 
@@ -514,6 +622,11 @@ macro_rules! rx_request_apis {
             self
         }
 
+        pub fn with_exact_low_watermark(&mut self, low: usize) -> &mut Self {
+            self.request.with_exact_low_watermark(low);
+            self
+        }
+
         pub fn with_high_watermark(&mut self, high: usize) -> &mut Self {
             self.request.with_high_watermark(high);
             self
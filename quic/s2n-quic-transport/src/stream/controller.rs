@@ -17,12 +17,13 @@ use core::{
 };
 use futures_core::ready;
 use s2n_quic_core::{
-    ack, endpoint,
+    ack, endpoint, event,
     frame::MaxStreams,
     stream::{self, iter::StreamIter, StreamId, StreamType},
     time::{timer, Timestamp},
     transport,
     transport::parameters::InitialFlowControlLimits,
+    varint::VarInt,
 };
 
 pub use remote_initiated::MAX_STREAMS_SYNC_FRACTION;
@@ -68,6 +69,7 @@ impl Controller {
             ),
             remote_bidi_controller: RemoteInitiated::new(
                 initial_local_limits.max_open_remote_bidirectional_streams,
+                stream_limits.max_remote_open_rate,
             ),
             local_uni_controller: LocalInitiated::new(
                 initial_peer_limits.max_open_remote_unidirectional_streams,
@@ -75,6 +77,7 @@ impl Controller {
             ),
             remote_uni_controller: RemoteInitiated::new(
                 initial_local_limits.max_open_remote_unidirectional_streams,
+                stream_limits.max_remote_open_rate,
             ),
         }
     }
@@ -119,6 +122,16 @@ impl Controller {
         Poll::Ready(())
     }
 
+    /// Returns the number of streams of a type which can currently be opened locally without
+    /// exceeding either the local concurrency limits or the peer's stream limits
+    #[inline]
+    pub fn available_local_initiated_stream_capacity(&self, stream_type: StreamType) -> VarInt {
+        match stream_type {
+            StreamType::Bidirectional => self.local_bidi_controller.available_stream_capacity(),
+            StreamType::Unidirectional => self.local_uni_controller.available_stream_capacity(),
+        }
+    }
+
     /// This method is called when the remote peer wishes to open a new stream.
     ///
     /// Opening a Stream also opens all lower Streams of the same type. Therefore
@@ -250,9 +263,17 @@ impl Controller {
     }
 
     /// Called when the connection timer expires
-    pub fn on_timeout(&mut self, now: Timestamp) {
+    pub fn on_timeout<Pub: event::ConnectionPublisher>(
+        &mut self,
+        now: Timestamp,
+        publisher: &mut Pub,
+    ) {
         self.local_bidi_controller.on_timeout(now);
+        self.remote_bidi_controller
+            .on_timeout(StreamType::Bidirectional, now, publisher);
         self.local_uni_controller.on_timeout(now);
+        self.remote_uni_controller
+            .on_timeout(StreamType::Unidirectional, now, publisher);
     }
 
     #[inline]
@@ -271,7 +292,9 @@ impl timer::Provider for Controller {
     #[inline]
     fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
         self.local_bidi_controller.timers(query)?;
+        self.remote_bidi_controller.timers(query)?;
         self.local_uni_controller.timers(query)?;
+        self.remote_uni_controller.timers(query)?;
         Ok(())
     }
 }
@@ -323,16 +346,8 @@ impl StreamDirection {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use s2n_quic_core::varint::VarInt;
 
     impl Controller {
-        pub fn available_local_initiated_stream_capacity(&self, stream_type: StreamType) -> VarInt {
-            match stream_type {
-                StreamType::Bidirectional => self.local_bidi_controller.available_stream_capacity(),
-                StreamType::Unidirectional => self.local_uni_controller.available_stream_capacity(),
-            }
-        }
-
         pub fn remote_initiated_max_streams_latest_value(&self, stream_type: StreamType) -> VarInt {
             match stream_type {
                 StreamType::Bidirectional => self.remote_bidi_controller.latest_limit(),
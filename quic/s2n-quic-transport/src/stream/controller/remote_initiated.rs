@@ -7,8 +7,15 @@ use crate::{
     transmission,
     transmission::WriteContext,
 };
+use core::time::Duration;
 use s2n_quic_core::{
-    ack, frame::MaxStreams, packet::number::PacketNumber, stream::StreamId, transport,
+    ack,
+    event::{self, IntoEvent},
+    frame::MaxStreams,
+    packet::number::PacketNumber,
+    stream::{limits::MaxRemoteOpenRate, StreamId, StreamType},
+    time::{timer, Timer, Timestamp},
+    transport,
     varint::VarInt,
 };
 
@@ -26,6 +33,8 @@ pub const MAX_STREAMS_SYNC_FRACTION: VarInt = VarInt::from_u8(10);
 //# to encode stream IDs larger than 2^62-1.
 // Safety: 2^60 is less than MAX_VARINT_VALUE
 const MAX_STREAMS_MAX_VALUE: VarInt = unsafe { VarInt::new_unchecked(1 << 60) };
+/// The period over which the remote open stream rate is measured
+const OPEN_RATE_WINDOW: Duration = Duration::from_secs(1);
 
 /// The RemoteInitiated controller controls streams initiated by the peer
 #[derive(Debug)]
@@ -40,10 +49,12 @@ pub(super) struct RemoteInitiated {
     max_streams_sync: IncrementalValueSync<VarInt, MaxStreamsToFrameWriter>,
     opened_streams: VarInt,
     closed_streams: VarInt,
+    /// Limits the rate at which stream credits are granted to the peer
+    open_rate_limiter: OpenRateLimiter,
 }
 
 impl RemoteInitiated {
-    pub fn new(max_local_limit: VarInt) -> Self {
+    pub fn new(max_local_limit: VarInt, max_open_rate: MaxRemoteOpenRate) -> Self {
         Self {
             max_local_limit,
             max_streams_sync: IncrementalValueSync::new(
@@ -53,6 +64,7 @@ impl RemoteInitiated {
             ),
             opened_streams: VarInt::from_u8(0),
             closed_streams: VarInt::from_u8(0),
+            open_rate_limiter: OpenRateLimiter::new(max_open_rate),
         }
     }
 
@@ -99,7 +111,14 @@ impl RemoteInitiated {
             .closed_streams
             .saturating_add(self.max_local_limit)
             .min(MAX_STREAMS_MAX_VALUE);
-        self.max_streams_sync.update_latest_value(max_streams);
+
+        if self.open_rate_limiter.is_enabled() {
+            // defer granting the credit until the next transmission so the
+            // current rate can be checked
+            self.open_rate_limiter.pending_max_streams = max_streams;
+        } else {
+            self.max_streams_sync.update_latest_value(max_streams);
+        }
 
         self.check_integrity();
     }
@@ -126,10 +145,32 @@ impl RemoteInitiated {
         stream_id: StreamId,
         context: &mut W,
     ) -> Result<(), OnTransmitError> {
+        if let Some(max_streams) = self
+            .open_rate_limiter
+            .on_transmit(self.max_streams_sync.latest_value(), context.current_time())
+        {
+            self.max_streams_sync.update_latest_value(max_streams);
+        }
+
         self.max_streams_sync.on_transmit(stream_id, context)
     }
 
+    /// Called when the connection timer expires
+    #[inline]
+    pub fn on_timeout<Pub: event::ConnectionPublisher>(
+        &mut self,
+        stream_type: StreamType,
+        now: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        let latest_value = self.max_streams_sync.latest_value();
+        self.open_rate_limiter
+            .on_timeout(stream_type, latest_value, now, publisher);
+    }
+
     pub fn close(&mut self) {
+        self.open_rate_limiter.window.cancel();
+        self.open_rate_limiter.pending_max_streams = VarInt::from_u8(0);
         self.max_streams_sync.stop_sync();
     }
 
@@ -160,10 +201,125 @@ impl transmission::interest::Provider for RemoteInitiated {
         &self,
         query: &mut Q,
     ) -> transmission::interest::Result {
+        if self
+            .open_rate_limiter
+            .has_transmission_interest(self.max_streams_sync.latest_value())
+        {
+            query.on_new_data()?;
+        }
+
         self.max_streams_sync.transmission_interest(query)
     }
 }
 
+impl timer::Provider for RemoteInitiated {
+    #[inline]
+    fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
+        self.open_rate_limiter.window.timers(query)
+    }
+}
+
+/// Limits the number of streams the peer can open within an `OPEN_RATE_WINDOW`
+///
+/// Rather than closing the connection, the limiter applies backpressure by delaying the
+/// MAX_STREAMS credit that would otherwise be granted when the peer closes streams.
+#[derive(Debug)]
+struct OpenRateLimiter {
+    /// The maximum number of stream credits that can be granted per window
+    max_rate: MaxRemoteOpenRate,
+    /// The number of stream credits granted in the current window
+    granted: u32,
+    /// The cumulative stream limit that will be advertised once the rate allows it
+    pending_max_streams: VarInt,
+    /// Set if credits were withheld from the peer in the current window
+    is_limited: bool,
+    /// Expires at the end of the current window
+    window: Timer,
+}
+
+impl OpenRateLimiter {
+    fn new(max_rate: MaxRemoteOpenRate) -> Self {
+        Self {
+            max_rate,
+            granted: 0,
+            pending_max_streams: VarInt::from_u8(0),
+            is_limited: false,
+            window: Timer::default(),
+        }
+    }
+
+    #[inline]
+    fn is_enabled(&self) -> bool {
+        self.max_rate.is_limited()
+    }
+
+    #[inline]
+    fn remaining_credits(&self) -> u32 {
+        if self.window.is_armed() {
+            self.max_rate.as_u32().saturating_sub(self.granted)
+        } else {
+            // a new window will be started on the next transmission
+            self.max_rate.as_u32()
+        }
+    }
+
+    /// Returns the updated cumulative stream limit, if any additional credits may be granted
+    #[inline]
+    fn on_transmit(&mut self, latest_value: VarInt, now: Timestamp) -> Option<VarInt> {
+        if self.pending_max_streams <= latest_value {
+            return None;
+        }
+
+        if !self.window.is_armed() {
+            self.window.set(now + OPEN_RATE_WINDOW);
+            self.granted = 0;
+        }
+
+        let requested = self.pending_max_streams - latest_value;
+        let remaining = VarInt::from_u32(self.remaining_credits());
+        let granted = requested.min(remaining);
+
+        if granted < requested {
+            self.is_limited = true;
+        }
+
+        if granted == VarInt::from_u8(0) {
+            return None;
+        }
+
+        // `granted` is bounded by `remaining`, which fits in a u32
+        self.granted += granted.as_u64() as u32;
+
+        Some(latest_value + granted)
+    }
+
+    #[inline]
+    fn on_timeout<Pub: event::ConnectionPublisher>(
+        &mut self,
+        stream_type: StreamType,
+        latest_value: VarInt,
+        now: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        if self.window.poll_expiration(now).is_pending() {
+            return;
+        }
+
+        if core::mem::take(&mut self.is_limited) {
+            let deferred_streams = self.pending_max_streams.saturating_sub(latest_value);
+            publisher.on_stream_open_rate_limited(event::builder::StreamOpenRateLimited {
+                stream_type: (&stream_type).into_event(),
+                deferred_streams: deferred_streams.as_u64(),
+            });
+        }
+    }
+
+    #[inline]
+    fn has_transmission_interest(&self, latest_value: VarInt) -> bool {
+        self.pending_max_streams > latest_value && self.remaining_credits() > 0
+    }
+}
+
 /// Writes the `MAX_STREAMS` frames based on the stream control window.
 #[derive(Debug, Default)]
 pub(super) struct MaxStreamsToFrameWriter {}
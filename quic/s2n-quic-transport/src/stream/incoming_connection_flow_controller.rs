@@ -46,6 +46,10 @@ struct IncomingConnectionFlowControllerImpl {
     /// The amount of flow control credits which had been acquired and where the
     /// data had already been consumed by the application
     pub(super) consumed_window: VarInt,
+    /// Whether the endpoint is under memory pressure
+    ///
+    /// While this is set, the read window is not extended as data is consumed.
+    pub(super) is_under_memory_pressure: bool,
 }
 
 impl IncomingConnectionFlowControllerImpl {
@@ -59,6 +63,7 @@ impl IncomingConnectionFlowControllerImpl {
             desired_flow_control_window,
             acquired_window: VarInt::from_u32(0),
             consumed_window: VarInt::from_u32(0),
+            is_under_memory_pressure: false,
         }
     }
 
@@ -78,6 +83,21 @@ impl IncomingConnectionFlowControllerImpl {
             "Can not consume more window than previously acquired"
         );
 
+        if !self.is_under_memory_pressure {
+            self.update_read_window();
+        }
+    }
+
+    pub fn set_memory_pressure(&mut self, is_under_pressure: bool) {
+        self.is_under_memory_pressure = is_under_pressure;
+
+        // catch up on all of the data consumed while under pressure
+        if !is_under_pressure {
+            self.update_read_window();
+        }
+    }
+
+    fn update_read_window(&mut self) {
         self.read_window_sync.update_latest_value(
             self.consumed_window
                 .saturating_add(VarInt::from_u32(self.desired_flow_control_window)),
@@ -177,6 +197,28 @@ impl IncomingConnectionFlowController {
         self.inner.borrow().acquired_window
     }
 
+    /// Returns the number of bytes which were received but not yet consumed by the application
+    pub fn buffered_len(&self) -> VarInt {
+        let inner = self.inner.borrow();
+        inner.acquired_window - inner.consumed_window
+    }
+
+    /// Stops extending the read window while the endpoint is under memory pressure
+    ///
+    /// The peer can still use the window which was already advertised, which bounds the amount
+    /// of data the connection buffers. Once the pressure is released, the window is extended to
+    /// account for all of the data consumed in the meantime.
+    pub fn set_memory_pressure(&mut self, is_under_pressure: bool) {
+        self.inner
+            .borrow_mut()
+            .set_memory_pressure(is_under_pressure)
+    }
+
+    /// Returns the highest MAX_DATA window which was transmitted to the peer
+    pub fn advertised_window(&self) -> VarInt {
+        self.inner.borrow().read_window_sync.transmitted_value()
+    }
+
     #[cfg(test)]
     pub fn remaining_window(&self) -> VarInt {
         self.inner.borrow_mut().remaining_window()
@@ -24,7 +24,7 @@ use core::{
 };
 use futures_core::ready;
 use s2n_quic_core::{
-    ack, endpoint,
+    ack, endpoint, event,
     frame::{
         stream::StreamRef, DataBlocked, MaxData, MaxStreamData, MaxStreams, ResetStream,
         StopSending, StreamDataBlocked, StreamsBlocked,
@@ -186,6 +186,11 @@ pub struct StreamManagerState<S> {
     /// Limits for the Stream manager. Since only Stream limits are utilized at
     /// the moment we only store those
     stream_limits: stream::Limits,
+    /// Streams which waited longer than the starvation threshold to transmit, along with the
+    /// duration of the wait
+    starved_streams: Vec<(StreamId, Duration)>,
+    /// Flow control windows which were increased since they were last drained
+    window_updates: Vec<event::builder::FlowControlWindowChanged>,
 }
 
 impl<S: StreamTrait> StreamManagerState<S> {
@@ -239,6 +244,7 @@ impl<S: StreamTrait> StreamManagerState<S> {
             desired_flow_control_window: initial_receive_window.as_u64() as u32,
             initial_send_window,
             max_send_buffer_size: self.stream_limits.max_send_buffer_size.as_u32(),
+            starvation_threshold: self.stream_limits.starvation_threshold,
         }));
     }
 
@@ -453,6 +459,8 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
                 close_reason: None,
                 accept_state: AcceptState::new(local_endpoint_type),
                 stream_limits: connection_limits.stream_limits(),
+                starved_streams: Vec::new(),
+                window_updates: Vec::new(),
             },
         }
     }
@@ -471,6 +479,21 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
             .acquired_window()
     }
 
+    /// The number of bytes received on incoming streams which were not yet consumed by the
+    /// application
+    pub fn buffered_len(&self) -> VarInt {
+        self.inner
+            .incoming_connection_flow_controller
+            .buffered_len()
+    }
+
+    /// Stops extending the connection receive window while the endpoint is under memory pressure
+    pub fn on_memory_pressure(&mut self, is_under_pressure: bool) {
+        self.inner
+            .incoming_connection_flow_controller
+            .set_memory_pressure(is_under_pressure)
+    }
+
     /// Accepts the next incoming stream of a given type
     pub fn poll_accept(
         &mut self,
@@ -580,8 +603,47 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
         Ok(first_unopened_id).into()
     }
 
+    /// Opens up to `count` of the next local initiated streams of a certain type
+    ///
+    /// `Poll::Pending` is only returned if no stream can be opened. Otherwise, as many streams as
+    /// the current stream limits allow are opened, up to `count`. The IDs of the opened streams
+    /// are consecutive for the stream type, so the ID of the first stream is returned along with
+    /// the number of streams which were opened.
+    pub fn poll_open_local_streams(
+        &mut self,
+        stream_type: StreamType,
+        count: usize,
+        open_token: &mut connection::OpenToken,
+        context: &Context,
+    ) -> Poll<Result<(StreamId, usize), connection::Error>> {
+        debug_assert!(count > 0, "at least one stream should be requested");
+
+        let first_stream_id =
+            ready!(self.poll_open_local_stream(stream_type, open_token, context))?;
+        let mut opened = 1;
+
+        while opened < count
+            && self
+                .inner
+                .stream_controller
+                .available_local_initiated_stream_capacity(stream_type)
+                > VarInt::from_u8(0)
+        {
+            // opening a stream can't block when capacity is available
+            if let Poll::Ready(Ok(_)) =
+                self.poll_open_local_stream(stream_type, open_token, context)
+            {
+                opened += 1;
+            } else {
+                break;
+            }
+        }
+
+        Ok((first_stream_id, opened)).into()
+    }
+
     /// This method gets called when a packet delivery got acknowledged
-    pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) {
+    pub fn on_packet_ack<A: ack::Set>(&mut self, timestamp: Timestamp, ack_set: &A) {
         self.inner
             .incoming_connection_flow_controller
             .on_packet_ack(ack_set);
@@ -596,7 +658,7 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
                 // We have to wake inside the lock, since `StreamEvent`s has no capacity
                 // to carry wakers in another iteration
                 let mut events = StreamEvents::new();
-                stream.on_packet_ack(ack_set, &mut events);
+                stream.on_packet_ack(timestamp, ack_set, &mut events);
                 events.wake_all();
             },
         );
@@ -643,8 +705,12 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
     }
 
     /// Called when the connection timer expires
-    pub fn on_timeout(&mut self, now: Timestamp) {
-        self.inner.stream_controller.on_timeout(now);
+    pub fn on_timeout<Pub: event::ConnectionPublisher>(
+        &mut self,
+        now: Timestamp,
+        publisher: &mut Pub,
+    ) {
+        self.inner.stream_controller.on_timeout(now, publisher);
         self.inner
             .outgoing_connection_flow_controller
             .on_timeout(now);
@@ -655,6 +721,45 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
                 StreamContainerIterationResult::Continue
             },
         );
+
+        // streams which are waiting to transmit are reported once they reach the threshold, even
+        // if the scheduler never gets to them
+        if let Some(threshold) = self.inner.stream_limits.starvation_threshold {
+            let starved_streams = &mut self.inner.starved_streams;
+            self.inner.streams.iterate_transmission_list(
+                &mut self.inner.stream_controller,
+                |stream| {
+                    stream.on_timeout(now);
+                    record_starvation(stream, Some(threshold), starved_streams);
+                    StreamContainerIterationResult::Continue
+                },
+            );
+
+            for (id, duration) in self.inner.starved_streams.drain(..) {
+                publisher.on_stream_starved(event::builder::StreamStarved {
+                    id: id.as_varint().as_u64(),
+                    duration,
+                });
+            }
+        }
+    }
+
+    /// Starts the waits of streams which had data queued, or were unblocked, since the last call
+    ///
+    /// This should be called with the current time whenever the connection is woken up, so the
+    /// waits start when the application queues the data rather than when the stream is first
+    /// considered for transmission.
+    pub fn start_pending_waits(&mut self, now: Timestamp) {
+        if self.inner.stream_limits.starvation_threshold.is_none() {
+            return;
+        }
+
+        self.inner
+            .streams
+            .iterate_transmission_list(&mut self.inner.stream_controller, |stream| {
+                stream.start_pending_wait(now);
+                StreamContainerIterationResult::Continue
+            });
     }
 
     /// Closes the [`AbstractStreamManager`] and resets all streams with the
@@ -681,14 +786,30 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
 
     /// Queries the component for any outgoing frames that need to get sent
     pub fn on_transmit<W: WriteContext>(&mut self, context: &mut W) -> Result<(), OnTransmitError> {
+        let receive_window = self
+            .inner
+            .incoming_connection_flow_controller
+            .advertised_window();
         self.inner
             .incoming_connection_flow_controller
             .on_transmit(context)?;
+        record_window_update(
+            &mut self.inner.window_updates,
+            event::builder::FlowControlScope::Connection,
+            event::builder::FlowControlDirection::Receive,
+            receive_window,
+            self.inner
+                .incoming_connection_flow_controller
+                .advertised_window(),
+        );
         self.inner
             .outgoing_connection_flow_controller
             .on_transmit(context)?;
         self.inner.stream_controller.on_transmit(context)?;
 
+        // streams unblocked by frames from the peer start waiting before any of them transmit
+        self.start_pending_waits(context.current_time());
+
         // Due to an error we could not transmit all data.
         // We add streams which could not send data back into the
         // waiting_for_transmission list, so that they will be queried again
@@ -698,6 +819,9 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
         // able to write all the desired data and added themselves as
         // transmit interested again
         let mut transmit_result = Ok(());
+        let starvation_threshold = self.inner.stream_limits.starvation_threshold;
+        let starved_streams = &mut self.inner.starved_streams;
+        let window_updates = &mut self.inner.window_updates;
 
         if context.transmission_constraint().can_retransmit() {
             // ensure components only retransmit in this phase
@@ -708,7 +832,10 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
             self.inner.streams.iterate_retransmission_list(
                 &mut self.inner.stream_controller,
                 |stream: &mut S| {
+                    let receive_window = stream.receive_window();
                     transmit_result = stream.on_transmit(&mut retransmission_context);
+                    record_starvation(stream, starvation_threshold, starved_streams);
+                    record_stream_receive_window_update(stream, receive_window, window_updates);
                     if transmit_result.is_err() {
                         StreamContainerIterationResult::BreakAndInsertAtBack
                     } else {
@@ -725,7 +852,10 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
             self.inner.streams.iterate_transmission_list(
                 &mut self.inner.stream_controller,
                 |stream: &mut S| {
+                    let receive_window = stream.receive_window();
                     transmit_result = stream.on_transmit(context);
+                    record_starvation(stream, starvation_threshold, starved_streams);
+                    record_stream_receive_window_update(stream, receive_window, window_updates);
                     if transmit_result.is_err() {
                         StreamContainerIterationResult::BreakAndInsertAtBack
                     } else {
@@ -741,6 +871,25 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
         transmit_result
     }
 
+    /// Removes and returns the streams which were starved since the last call, along with the
+    /// duration each stream waited to transmit
+    pub fn drain_starved_streams(&mut self) -> impl Iterator<Item = (StreamId, Duration)> + '_ {
+        self.inner.starved_streams.drain(..)
+    }
+
+    /// Removes and returns the streams which were closed since the last call, along with the
+    /// number of bytes each stream retransmitted
+    pub fn drain_closed_streams(&mut self) -> impl Iterator<Item = (StreamId, u64)> + '_ {
+        self.inner.streams.drain_closed_streams()
+    }
+
+    /// Removes and returns the flow control windows which were increased since the last call
+    pub fn drain_window_updates(
+        &mut self,
+    ) -> impl Iterator<Item = event::builder::FlowControlWindowChanged> + '_ {
+        self.inner.window_updates.drain(..)
+    }
+
     /// Calculates the period for sending STREAMS_BLOCKED, STREAM_DATA_BLOCKED and
     /// DATA_BLOCKED frames when blocked, according to the idle timeout and latest RTT estimates
     fn blocked_sync_period(&self, rtt_estimator: &RttEstimator) -> Duration {
@@ -839,9 +988,27 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
     /// a stream
     pub fn on_max_stream_data(&mut self, frame: &MaxStreamData) -> Result<(), transport::Error> {
         let stream_id = StreamId::from_varint(frame.stream_id);
-        self.handle_stream_frame(stream_id, |stream, events| {
-            stream.on_max_stream_data(frame, events)
-        })
+        let mut send_windows = None;
+        let result = self.handle_stream_frame(stream_id, |stream, events| {
+            let send_window = stream.send_window();
+            stream.on_max_stream_data(frame, events)?;
+            send_windows = Some((send_window, stream.send_window()));
+            Ok(())
+        });
+
+        if let Some((old_window, new_window)) = send_windows {
+            record_window_update(
+                &mut self.inner.window_updates,
+                event::builder::FlowControlScope::Stream {
+                    id: stream_id.as_varint().as_u64(),
+                },
+                event::builder::FlowControlDirection::Send,
+                old_window,
+                new_window,
+            );
+        }
+
+        result
     }
 
     /// This is called when a `STOP_SENDING` frame had been received for
@@ -855,9 +1022,22 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
 
     /// This is called when a `MAX_DATA` frame had been received
     pub fn on_max_data(&mut self, frame: MaxData) -> Result<(), transport::Error> {
+        let send_window = self
+            .inner
+            .outgoing_connection_flow_controller
+            .total_window();
         self.inner
             .outgoing_connection_flow_controller
             .on_max_data(frame);
+        record_window_update(
+            &mut self.inner.window_updates,
+            event::builder::FlowControlScope::Connection,
+            event::builder::FlowControlDirection::Send,
+            send_window,
+            self.inner
+                .outgoing_connection_flow_controller
+                .total_window(),
+        );
 
         if self
             .inner
@@ -968,6 +1148,62 @@ impl<S: StreamTrait> AbstractStreamManager<S> {
     pub fn has_pending_streams(&self) -> bool {
         self.inner.streams.has_pending_streams()
     }
+
+    /// Returns the number of streams which have not yet been finalized
+    pub fn active_stream_count(&self) -> usize {
+        self.inner.streams.nr_active_streams()
+    }
+}
+
+/// Records the stream as starved if it just transmitted after waiting at least `threshold`
+#[inline]
+fn record_starvation<S: StreamTrait>(
+    stream: &mut S,
+    threshold: Option<Duration>,
+    starved_streams: &mut Vec<(StreamId, Duration)>,
+) {
+    if let Some(wait) = stream.take_completed_wait() {
+        if threshold.map_or(false, |threshold| wait >= threshold) {
+            starved_streams.push((stream.stream_id(), wait));
+        }
+    }
+}
+
+/// Records the stream's receive window update if the window was increased during a transmission
+#[inline]
+fn record_stream_receive_window_update<S: StreamTrait>(
+    stream: &S,
+    receive_window: VarInt,
+    window_updates: &mut Vec<event::builder::FlowControlWindowChanged>,
+) {
+    record_window_update(
+        window_updates,
+        event::builder::FlowControlScope::Stream {
+            id: stream.stream_id().as_varint().as_u64(),
+        },
+        event::builder::FlowControlDirection::Receive,
+        receive_window,
+        stream.receive_window(),
+    );
+}
+
+/// Records a flow control window update if `new_window` is larger than `old_window`
+#[inline]
+fn record_window_update(
+    window_updates: &mut Vec<event::builder::FlowControlWindowChanged>,
+    scope: event::builder::FlowControlScope,
+    direction: event::builder::FlowControlDirection,
+    old_window: VarInt,
+    new_window: VarInt,
+) {
+    if new_window > old_window {
+        window_updates.push(event::builder::FlowControlWindowChanged {
+            scope,
+            direction,
+            old_window: old_window.as_u64(),
+            new_window: new_window.as_u64(),
+        });
+    }
 }
 
 impl<S: StreamTrait> timer::Provider for AbstractStreamManager<S> {
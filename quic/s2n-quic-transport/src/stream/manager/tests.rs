@@ -34,6 +34,7 @@ use futures_test::task::new_count_waker;
 use s2n_quic_core::{
     ack::Set as AckSet,
     application::Error as ApplicationErrorCode,
+    event::testing::Publisher,
     frame::{
         stream::StreamRef, DataBlocked, Frame, MaxData, MaxStreamData, MaxStreams, ResetStream,
         StopSending, Stream as StreamFrame, StreamDataBlocked, StreamsBlocked,
@@ -71,6 +72,11 @@ struct MockStream {
     on_transmit_try_write_frames: usize,
     on_transmit_count: usize,
     on_transmit_limit: Option<usize>,
+    on_transmit_max_stream_data: Option<VarInt>,
+    receive_window: VarInt,
+    completed_wait: Option<Duration>,
+    start_pending_wait_count: usize,
+    retransmitted_bytes: u64,
     on_data_count: usize,
     on_reset_count: usize,
     on_stream_data_blocked_count: usize,
@@ -147,6 +153,11 @@ impl StreamTrait for MockStream {
             on_transmit_count: 0,
             on_transmit_try_write_frames: 0,
             on_transmit_limit: None,
+            on_transmit_max_stream_data: None,
+            receive_window: VarInt::from_u32(0),
+            completed_wait: None,
+            start_pending_wait_count: 0,
+            retransmitted_bytes: 0,
             lost_data: false,
             set_finalize_on_internal_reset: false,
             next_packet_error: None,
@@ -240,7 +251,12 @@ impl StreamTrait for MockStream {
         Ok(())
     }
 
-    fn on_packet_ack<A: AckSet>(&mut self, _ack_set: &A, events: &mut StreamEvents) {
+    fn on_packet_ack<A: AckSet>(
+        &mut self,
+        _timestamp: Timestamp,
+        _ack_set: &A,
+        events: &mut StreamEvents,
+    ) {
         self.on_packet_ack_count += 1;
         self.store_wakers(events);
     }
@@ -287,9 +303,41 @@ impl StreamTrait for MockStream {
             self.on_transmit_try_write_frames -= 1;
         }
 
+        if let Some(maximum_stream_data) = self.on_transmit_max_stream_data {
+            context
+                .write_frame(&MaxStreamData {
+                    stream_id: self.stream_id().into(),
+                    maximum_stream_data,
+                })
+                .ok_or(OnTransmitError::CouldNotWriteFrame)?;
+            self.on_transmit_max_stream_data = None;
+            self.receive_window = maximum_stream_data;
+        }
+
         Ok(())
     }
 
+    fn start_pending_wait(&mut self, _now: Timestamp) {
+        self.start_pending_wait_count += 1;
+    }
+
+    fn take_completed_wait(&mut self) -> Option<Duration> {
+        self.completed_wait.take()
+    }
+
+    fn retransmitted_bytes(&self) -> u64 {
+        self.retransmitted_bytes
+    }
+
+    fn receive_window(&self) -> VarInt {
+        self.receive_window
+    }
+
+    fn send_window(&self) -> VarInt {
+        self.last_max_stream_data
+            .map_or(VarInt::from_u32(0), |frame| frame.maximum_stream_data)
+    }
+
     fn on_connection_window_available(&mut self) {
         self.on_connection_window_available_count += 1;
         let acquired_window = self
@@ -540,6 +588,35 @@ fn open_returns_error_after_close() {
     ));
 }
 
+#[test]
+fn closed_streams_are_reported_with_retransmitted_bytes() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let stream_2 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    assert_eq!(manager.drain_closed_streams().count(), 0);
+
+    manager.with_asserted_stream(stream_1, |stream| {
+        stream.retransmitted_bytes = 100;
+        stream.interests.retained = false;
+    });
+    assert_eq!(
+        manager.drain_closed_streams().collect::<Vec<_>>(),
+        [(stream_1, 100)]
+    );
+
+    // the streams are only reported once
+    assert_eq!(manager.drain_closed_streams().count(), 0);
+
+    manager.with_asserted_stream(stream_2, |stream| {
+        stream.interests.retained = false;
+    });
+    assert_eq!(
+        manager.drain_closed_streams().collect::<Vec<_>>(),
+        [(stream_2, 0)]
+    );
+}
+
 #[test]
 fn returns_finalization_interest_after_last_stream_is_drained() {
     let mut manager = create_stream_manager(endpoint::Type::Server);
@@ -646,6 +723,100 @@ fn max_data_replenishes_connection_flow_control_window() {
     }
 }
 
+#[test]
+fn max_data_and_max_stream_data_record_window_updates() {
+    use event::builder::{FlowControlDirection, FlowControlScope, FlowControlWindowChanged};
+
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let current_window =
+        manager.with_outgoing_connection_flow_controller(|ctrl| ctrl.total_window());
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+
+    assert!(manager
+        .on_max_data(MaxData {
+            maximum_data: current_window + 100,
+        })
+        .is_ok());
+    // frames which don't increase the window aren't recorded
+    assert!(manager
+        .on_max_data(MaxData {
+            maximum_data: current_window,
+        })
+        .is_ok());
+    assert!(manager
+        .on_max_stream_data(&MaxStreamData {
+            stream_id: stream_1.into(),
+            maximum_stream_data: VarInt::from_u32(1694),
+        })
+        .is_ok());
+
+    let updates: Vec<_> = manager.drain_window_updates().collect();
+    assert_eq!(updates.len(), 2);
+    assert!(matches!(
+        updates[0],
+        FlowControlWindowChanged {
+            scope: FlowControlScope::Connection,
+            direction: FlowControlDirection::Send,
+            old_window,
+            new_window,
+        } if old_window == current_window.as_u64() && new_window == current_window.as_u64() + 100
+    ));
+    assert!(matches!(
+        updates[1],
+        FlowControlWindowChanged {
+            scope: FlowControlScope::Stream { id },
+            direction: FlowControlDirection::Send,
+            old_window: 0,
+            new_window: 1694,
+        } if id == stream_1.as_varint().as_u64()
+    ));
+    assert_eq!(manager.drain_window_updates().count(), 0);
+}
+
+#[test]
+fn max_stream_data_transmission_records_window_updates() {
+    use event::builder::{FlowControlDirection, FlowControlScope, FlowControlWindowChanged};
+
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+    let mut write_context = MockWriteContext::new(
+        s2n_quic_platform::time::now(),
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+
+    // the stream grows its receive window by sending MAX_STREAM_DATA
+    manager.with_asserted_stream(stream_1, |stream| {
+        stream.on_transmit_try_write_frames = 1;
+        stream.on_transmit_max_stream_data = Some(VarInt::from_u32(2000));
+    });
+    assert!(manager.on_transmit(&mut write_context).is_ok());
+
+    let updates: Vec<_> = manager.drain_window_updates().collect();
+    assert_eq!(updates.len(), 1);
+    assert!(matches!(
+        updates[0],
+        FlowControlWindowChanged {
+            scope: FlowControlScope::Stream { id },
+            direction: FlowControlDirection::Receive,
+            old_window: 0,
+            new_window: 2000,
+        } if id == stream_1.as_varint().as_u64()
+    ));
+
+    // sending the same window again isn't recorded
+    manager.with_asserted_stream(stream_1, |stream| {
+        stream.on_transmit_try_write_frames = 1;
+        stream.on_transmit_max_stream_data = Some(VarInt::from_u32(2000));
+    });
+    assert!(manager.on_transmit(&mut write_context).is_ok());
+    assert_eq!(manager.drain_window_updates().count(), 0);
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-4.6
 //= type=test
 //# MAX_STREAMS frames that do not increase the stream limit MUST be ignored.
@@ -795,15 +966,129 @@ fn peer_closing_streams_transmits_max_streams() {
         let packet_number = write_context.packet_number();
         assert!(manager.on_transmit(&mut write_context).is_ok());
 
-        manager.on_packet_ack(&PacketNumberRange::new(packet_number, packet_number));
+        manager.on_packet_ack(
+            s2n_quic_platform::time::now(),
+            &PacketNumberRange::new(packet_number, packet_number),
+        );
+
+        assert_eq!(
+            transmission::Interest::None,
+            manager.get_transmission_interest()
+        );
+    }
+}
+
+#[test]
+fn peer_stream_open_rate_limit_delays_max_streams() {
+    const OPEN_RATE: u32 = 20;
+
+    let limits = ConnectionLimits::default()
+        .with_max_remote_open_stream_rate(OPEN_RATE)
+        .unwrap();
+    let mut publisher = Publisher::snapshot();
+    let mut manager = AbstractStreamManager::<MockStream>::new(
+        &limits,
+        endpoint::Type::Server,
+        create_default_initial_flow_control_limits(),
+        create_default_initial_flow_control_limits(),
+    );
+
+    for stream_type in [StreamType::Bidirectional, StreamType::Unidirectional] {
+        let current_max_streams = manager.with_stream_controller(|ctrl| {
+            ctrl.remote_initiated_max_streams_latest_value(stream_type)
+        });
+
+        // The peer opens up to the current max streams limit
+        for i in 0..*current_max_streams {
+            let stream_id = StreamId::nth(endpoint::Type::Client, stream_type, i).unwrap();
+            assert_eq!(
+                Ok(()),
+                manager.on_data(&stream_data(stream_id, VarInt::from_u32(0), &[], false))
+            );
+        }
+
+        // The peer closes more streams than the rate allows in a single window
+        let streams_to_close = VarInt::from_u32(OPEN_RATE * 2);
+
+        for i in 0..*streams_to_close {
+            let stream_id = StreamId::nth(endpoint::Type::Client, stream_type, i).unwrap();
+            manager.with_asserted_stream(stream_id, |stream| {
+                stream.interests.retained = false;
+            });
+        }
+
+        assert_eq!(
+            transmission::Interest::NewData,
+            manager.get_transmission_interest()
+        );
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        let mut write_context = MockWriteContext::new(
+            s2n_quic_platform::time::now(),
+            &mut frame_buffer,
+            transmission::Constraint::None,
+            transmission::Mode::Normal,
+            endpoint::Type::Server,
+        );
+        assert!(manager.on_transmit(&mut write_context).is_ok());
+
+        // Only the credits allowed by the rate are granted in the first window
+        let expected_frame = Frame::MaxStreams(MaxStreams {
+            stream_type,
+            maximum_streams: current_max_streams + VarInt::from_u32(OPEN_RATE),
+        });
+
+        assert_eq!(
+            expected_frame,
+            write_context.frame_buffer.pop_front().unwrap().as_frame()
+        );
 
         assert_eq!(
             transmission::Interest::None,
             manager.get_transmission_interest()
         );
+
+        let window_expiration = write_context.current_time + Duration::from_secs(1);
+        assert_eq!(Some(window_expiration), manager.next_expiration());
+
+        // The credits which were withheld in the window are reported once it expires
+        manager.on_timeout(window_expiration, &mut publisher);
+        assert_eq!(1, publisher.stream_open_rate_limited);
+        write_context.current_time = window_expiration;
+
+        // The remaining credits are granted once the window expires
+        assert_eq!(
+            transmission::Interest::NewData,
+            manager.get_transmission_interest()
+        );
+        assert!(manager.on_transmit(&mut write_context).is_ok());
+
+        let expected_frame = Frame::MaxStreams(MaxStreams {
+            stream_type,
+            maximum_streams: current_max_streams + streams_to_close,
+        });
+
+        assert_eq!(
+            expected_frame,
+            write_context.frame_buffer.pop_front().unwrap().as_frame()
+        );
+
+        // Wait for the window to expire so the next stream type starts fresh
+        //
+        // All of the credits were granted in this window so nothing is reported
+        manager.on_timeout(window_expiration + Duration::from_secs(1), &mut publisher);
+        assert_eq!(1, publisher.stream_open_rate_limited);
+        publisher.stream_open_rate_limited = 0;
     }
 }
 
+#[test]
+fn zero_stream_open_rate_is_rejected() {
+    assert!(ConnectionLimits::default()
+        .with_max_remote_open_stream_rate(0)
+        .is_err());
+}
+
 //= https://www.rfc-editor.org/rfc/rfc9000#section-4.6
 //= type=test
 //# An endpoint that is unable to open a new stream due to the peer's
@@ -876,7 +1161,10 @@ fn send_streams_blocked_frame_when_blocked_by_peer() {
         let packet_number = write_context.packet_number();
         assert!(manager.on_transmit(&mut write_context).is_ok());
 
-        manager.on_packet_ack(&PacketNumberRange::new(packet_number, packet_number));
+        manager.on_packet_ack(
+            s2n_quic_platform::time::now(),
+            &PacketNumberRange::new(packet_number, packet_number),
+        );
 
         assert_eq!(
             transmission::Interest::None,
@@ -891,7 +1179,10 @@ fn send_streams_blocked_frame_when_blocked_by_peer() {
             manager.next_expiration()
         );
 
-        manager.on_timeout(expected_next_stream_blocked_time);
+        manager.on_timeout(
+            expected_next_stream_blocked_time,
+            &mut Publisher::no_snapshot(),
+        );
 
         // Another STREAM_BLOCKED frame should be sent
         assert_eq!(
@@ -1012,7 +1303,10 @@ where
 
     let rtt_estimator = RttEstimator::new(Duration::from_millis(100));
     manager.on_rtt_update(&rtt_estimator);
-    manager.on_packet_ack(&PacketNumberRange::new(packet_number, packet_number));
+    manager.on_packet_ack(
+        s2n_quic_platform::time::now(),
+        &PacketNumberRange::new(packet_number, packet_number),
+    );
 
     let expected_transmission_backoff = 2;
 
@@ -1104,7 +1398,10 @@ fn send_data_blocked_frame_when_blocked_by_connection_flow_limits() {
     assert!(manager.on_transmit(&mut write_context).is_ok());
     write_context.frame_buffer.clear();
 
-    manager.on_packet_ack(&PacketNumberRange::new(packet_number, packet_number));
+    manager.on_packet_ack(
+        s2n_quic_platform::time::now(),
+        &PacketNumberRange::new(packet_number, packet_number),
+    );
 
     assert_eq!(
         transmission::Interest::None,
@@ -1119,7 +1416,10 @@ fn send_data_blocked_frame_when_blocked_by_connection_flow_limits() {
         manager.next_expiration()
     );
 
-    manager.on_timeout(expected_next_data_blocked_time);
+    manager.on_timeout(
+        expected_next_data_blocked_time,
+        &mut Publisher::no_snapshot(),
+    );
 
     // Another DATA_BLOCKED frame should be sent
     assert_eq!(
@@ -1206,6 +1506,56 @@ fn stream_limit_error_on_peer_open_stream_too_large() {
     }
 }
 
+#[test]
+fn open_local_streams_test() {
+    for stream_type in [StreamType::Bidirectional, StreamType::Unidirectional] {
+        let mut manager = create_stream_manager(endpoint::Type::Server);
+
+        assert!(manager
+            .on_max_streams(&MaxStreams {
+                stream_type,
+                maximum_streams: VarInt::from_u32(100_000),
+            })
+            .is_ok());
+
+        let capacity = manager.with_stream_controller(|ctrl| {
+            ctrl.available_local_initiated_stream_capacity(stream_type)
+        });
+        let capacity = capacity.as_u64() as usize;
+        assert!(capacity > 2);
+
+        let (waker, wake_counter) = new_count_waker();
+        let mut token = connection::OpenToken::new();
+        let context = Context::from_waker(&waker);
+
+        // only the requested number of streams are opened
+        let first_stream_id = StreamId::initial(endpoint::Type::Server, stream_type);
+        assert_eq!(
+            manager.poll_open_local_streams(stream_type, 2, &mut token, &context),
+            Poll::Ready(Ok((first_stream_id, 2)))
+        );
+
+        // fewer streams are opened once the limit is reached
+        let first_stream_id = StreamId::nth(endpoint::Type::Server, stream_type, 2).unwrap();
+        assert_eq!(
+            manager.poll_open_local_streams(stream_type, capacity, &mut token, &context),
+            Poll::Ready(Ok((first_stream_id, capacity - 2)))
+        );
+
+        // no streams can be opened until more capacity is available
+        manager.with_stream_controller(|ctrl| {
+            assert_eq!(
+                ctrl.available_local_initiated_stream_capacity(stream_type),
+                VarInt::from_u8(0)
+            );
+        });
+        assert!(manager
+            .poll_open_local_streams(stream_type, 1, &mut token, &context)
+            .is_pending());
+        assert_eq!(wake_counter, 0);
+    }
+}
+
 #[test]
 fn blocked_on_local_concurrent_stream_limit() {
     for stream_type in [StreamType::Bidirectional, StreamType::Unidirectional] {
@@ -2052,7 +2402,7 @@ fn on_packet_ack_and_loss_is_forwarded_to_interested_streams() {
         *manager.streams_waiting_for_delivery_notifications()
     );
 
-    manager.on_packet_ack(&pn(1));
+    manager.on_packet_ack(s2n_quic_platform::time::now(), &pn(1));
     manager.on_packet_loss(&pn(2));
     manager.on_packet_loss(&pn(3));
 
@@ -2083,8 +2433,8 @@ fn on_packet_ack_and_loss_is_forwarded_to_interested_streams() {
         stream.interests.delivery_notifications = false;
     });
 
-    manager.on_packet_ack(&pn(4));
-    manager.on_packet_ack(&pn(5));
+    manager.on_packet_ack(s2n_quic_platform::time::now(), &pn(4));
+    manager.on_packet_ack(s2n_quic_platform::time::now(), &pn(5));
     manager.on_packet_loss(&pn(6));
 
     for stream_id in &[stream_2, stream_1, stream_4] {
@@ -2242,6 +2592,83 @@ fn add_and_remove_streams_from_retransmission_lists() {
     assert!(manager.streams_waiting_for_retransmission().is_empty());
 }
 
+#[test]
+fn on_transmit_records_starved_streams() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let mut frame_buffer = OutgoingFrameBuffer::new();
+    let threshold = Duration::from_millis(100);
+    manager.inner.stream_limits.starvation_threshold = Some(threshold);
+
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+    let stream_2 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+
+    manager.with_asserted_stream(stream_1, |stream| {
+        stream.on_transmit_try_write_frames = 1;
+        stream.completed_wait = Some(threshold * 2);
+    });
+    manager.with_asserted_stream(stream_2, |stream| {
+        stream.on_transmit_try_write_frames = 1;
+        stream.completed_wait = Some(threshold / 2);
+    });
+
+    let mut write_context = MockWriteContext::new(
+        s2n_quic_platform::time::now(),
+        &mut frame_buffer,
+        transmission::Constraint::None,
+        transmission::Mode::Normal,
+        endpoint::Type::Server,
+    );
+    assert!(manager.on_transmit(&mut write_context).is_ok());
+
+    // only the wait which exceeded the threshold is reported
+    let starved: Vec<_> = manager.drain_starved_streams().collect();
+    assert_eq!(starved, [(stream_1, threshold * 2)]);
+    assert_eq!(manager.drain_starved_streams().count(), 0);
+
+    // the waits are consumed whether or not they were reported
+    manager.with_asserted_stream(stream_2, |stream| {
+        assert_eq!(stream.completed_wait, None);
+    });
+}
+
+#[test]
+fn on_timeout_reports_streams_which_never_transmit() {
+    let mut manager = create_stream_manager(endpoint::Type::Server);
+    let threshold = Duration::from_millis(100);
+    manager.inner.stream_limits.starvation_threshold = Some(threshold);
+
+    let stream_1 = try_open(&mut manager, StreamType::Bidirectional).unwrap();
+
+    // the stream has data to send but the connection never gets to transmit it
+    manager.with_asserted_stream(stream_1, |stream| {
+        stream.on_transmit_try_write_frames = 1;
+    });
+
+    let now = s2n_quic_platform::time::now();
+    manager.start_pending_waits(now);
+    manager.with_asserted_stream(stream_1, |stream| {
+        assert_eq!(stream.start_pending_wait_count, 1);
+        // the wait ends once the starvation timer of the stream expires
+        stream.completed_wait = Some(threshold);
+    });
+
+    let mut publisher = Publisher::no_snapshot();
+    manager.on_timeout(now + threshold, &mut publisher);
+    assert_eq!(publisher.stream_starved, 1);
+    assert_eq!(manager.drain_starved_streams().count(), 0);
+    manager.with_asserted_stream(stream_1, |stream| {
+        assert_eq!(stream.on_timeout_count, 1);
+        assert_eq!(stream.on_transmit_count, 0);
+    });
+
+    // waits are only tracked when starvation detection is enabled
+    manager.inner.stream_limits.starvation_threshold = None;
+    manager.start_pending_waits(now);
+    manager.with_asserted_stream(stream_1, |stream| {
+        assert_eq!(stream.start_pending_wait_count, 1);
+    });
+}
+
 #[test]
 fn on_transmit_queries_streams_for_data() {
     fn assert_stream_write_state(
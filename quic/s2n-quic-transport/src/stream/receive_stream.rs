@@ -716,6 +716,11 @@ impl ReceiveStream {
             .on_transmit(stream_id, context)
     }
 
+    /// Returns the highest `MAX_STREAM_DATA` window which was transmitted to the peer
+    pub fn receive_window(&self) -> VarInt {
+        self.flow_controller.read_window_sync.transmitted_value()
+    }
+
     /// Wakes up the application on progress updates
     ///
     /// If there is not a registered waker and the stream is in a terminal state,
@@ -834,6 +839,12 @@ impl ReceiveStream {
             ReceiveStreamState::Receiving(total_size) => total_size,
         };
 
+        // The stream wakes the waiter once the flow controller watermark is buffered, so an exact
+        // request for more data than that could never be fulfilled
+        if request.exact && request.low_watermark > self.flow_controller.watermark() {
+            return Err(StreamError::receive_window_exceeded());
+        }
+
         let low_watermark = &mut request.low_watermark;
         let high_watermark = &mut request.high_watermark;
         let mut should_wake = false;
@@ -919,6 +930,10 @@ impl ReceiveStream {
         response.bytes.available = available_bytes;
         response.chunks.available = available_chunks;
 
+        let (out_of_order_bytes, largest_gap) = self.receive_buffer.reassembly_report();
+        response.reassembly.out_of_order_bytes = out_of_order_bytes;
+        response.reassembly.largest_gap = largest_gap;
+
         if should_wake {
             if let Some(context) = context {
                 // Store the waker, in order to be able to wakeup the client when
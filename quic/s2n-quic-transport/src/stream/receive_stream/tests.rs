@@ -882,6 +882,72 @@ fn receiving_data_will_lead_to_a_connection_flow_control_window_update() {
     assert!(!test_env.rx_connection_flow_controller.is_inflight());
 }
 
+#[test]
+fn connection_flow_control_window_is_held_back_under_memory_pressure() {
+    let test_env_config = conn_flow_control_test_env_config();
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    let old_window: u64 = test_env
+        .stream
+        .receive_stream
+        .flow_controller
+        .remaining_connection_receive_window()
+        .into();
+
+    test_env
+        .rx_connection_flow_controller
+        .set_memory_pressure(true);
+
+    // Completely fill the flow control window
+    test_env.feed_data(VarInt::from_u32(0), old_window as usize);
+    assert_eq!(
+        VarInt::new(old_window).unwrap(),
+        test_env.rx_connection_flow_controller.buffered_len()
+    );
+
+    // Draining the data releases the memory but doesn't extend the window
+    assert_eq!(old_window as usize, test_env.consume_all_data());
+    assert_eq!(
+        VarInt::from_u32(0),
+        test_env.rx_connection_flow_controller.buffered_len()
+    );
+    assert_eq!(
+        VarInt::new(old_window).unwrap(),
+        test_env
+            .rx_connection_flow_controller
+            .current_receive_window()
+    );
+    assert_eq!(
+        transmission_interests(&[]),
+        test_env
+            .rx_connection_flow_controller
+            .get_transmission_interest()
+    );
+    test_env.assert_write_frames(0);
+
+    // The window catches up with the consumed data once the pressure is released
+    test_env
+        .rx_connection_flow_controller
+        .set_memory_pressure(false);
+    assert_eq!(
+        transmission_interests(&["tx"]),
+        test_env
+            .rx_connection_flow_controller
+            .get_transmission_interest()
+    );
+
+    let expected_window =
+        old_window + u64::from(test_env_config.desired_connection_flow_control_window);
+    test_env.assert_write_frames(1);
+    let mut sent_frame = test_env.sent_frames.pop_front().expect("Frame is written");
+    assert_eq!(
+        Frame::MaxData(MaxData {
+            maximum_data: VarInt::new(expected_window).unwrap(),
+        }),
+        sent_frame.as_frame()
+    );
+}
+
 #[test]
 fn resetting_a_stream_will_free_remaining_connection_flow_control_window() {
     let test_env_config = conn_flow_control_test_env_config();
@@ -1802,7 +1868,9 @@ fn stop_sending_will_trigger_a_stop_sending_frame() {
 
             // Mark the frame as acknowledged
             let mut events = StreamEvents::new();
-            test_env.stream.on_packet_ack(&packet_nr, &mut events);
+            test_env
+                .stream
+                .on_packet_ack(test_env.current_time, &packet_nr, &mut events);
 
             // Nothing new to write; the stream should be finished
             assert_eq!(
@@ -1857,9 +1925,11 @@ fn do_not_retransmit_stop_sending_if_requested_twice() {
             if *ack_packet {
                 // Mark the frame as acknowledged
                 let mut events = StreamEvents::new();
-                test_env
-                    .stream
-                    .on_packet_ack(&sent_frame.packet_nr, &mut events);
+                test_env.stream.on_packet_ack(
+                    test_env.current_time,
+                    &sent_frame.packet_nr,
+                    &mut events,
+                );
 
                 assert_eq!(
                     stream_interests(&[]),
@@ -2350,6 +2420,7 @@ fn receive_multiple_chunks_and_finishing_test() {
                 },
                 will_wake: false,
                 status: ops::Status::Finished,
+                reassembly: ops::rx::Reassembly::default(),
             }),
             ..Default::default()
         }),
@@ -2401,6 +2472,7 @@ fn receive_low_watermark_test() {
                 },
                 will_wake: false,
                 status: ops::Status::Open,
+                reassembly: ops::rx::Reassembly::default(),
             }),
             ..Default::default()
         })),
@@ -2452,6 +2524,7 @@ fn receive_low_watermark_with_data_test() {
                 },
                 will_wake: false,
                 status: ops::Status::Open,
+                reassembly: ops::rx::Reassembly::default(),
             }),
             ..Default::default()
         })),
@@ -2493,6 +2566,7 @@ fn receive_high_watermark_test() {
                 },
                 will_wake: false,
                 status: ops::Status::Open,
+                reassembly: ops::rx::Reassembly::default(),
             }),
             ..Default::default()
         })),
@@ -2517,6 +2591,7 @@ fn receive_high_watermark_test() {
                 },
                 will_wake: false,
                 status: ops::Status::Open,
+                reassembly: ops::rx::Reassembly::default(),
             }),
             ..Default::default()
         })),
@@ -2548,3 +2623,42 @@ fn receiving_into_non_empty_buffers_returns_an_error() {
         "data should not be lost when returning an error"
     );
 }
+
+#[test]
+fn receive_exact_low_watermark_test() {
+    let mut test_env = setup_receive_only_test_env();
+    // requesting more data than the window can buffer should fail
+    let max_len = TestEnvironment::DEFAULT_INITIAL_RECEIVE_WINDOW as usize / 2;
+
+    assert_matches!(
+        test_env.poll_request(
+            ops::Request::default()
+                .receive(&mut [Bytes::new()])
+                .with_exact_low_watermark(max_len + 1)
+        ),
+        Poll::Ready(Err(StreamError::ReceiveWindowExceeded { .. })),
+    );
+
+    assert_eq!(
+        test_env.poll_request(
+            ops::Request::default()
+                .receive(&mut [Bytes::new()])
+                .with_exact_low_watermark(max_len)
+        ),
+        Poll::Pending,
+        "requesting data within the window should wait for the data"
+    );
+
+    test_env.feed_data(VarInt::from_u8(0), max_len - 1);
+    assert_eq!(
+        test_env.wake_counter, 0,
+        "receiving data under the exact low watermark should not wake"
+    );
+
+    test_env.feed_data(VarInt::try_from(max_len - 1).unwrap(), 1);
+    assert_eq!(
+        test_env.wake_counter, 1,
+        "receiving the exact low watermark should wake"
+    );
+    assert_eq!(test_env.consume_all_data(), max_len);
+}
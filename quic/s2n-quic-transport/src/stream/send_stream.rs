@@ -27,7 +27,7 @@ use s2n_quic_core::{
     frame::{MaxStreamData, ResetStream, StopSending, StreamDataBlocked},
     packet::number::PacketNumber,
     stream::{ops, StreamId},
-    time::{timer, Timestamp},
+    time::{timer, Timer, Timestamp},
     transport,
     varint::VarInt,
 };
@@ -274,6 +274,11 @@ impl StreamFlowController {
         self.state
     }
 
+    /// Returns the maximum data offset we are allowed to send, as communicated by the peer
+    pub fn max_stream_data(&self) -> VarInt {
+        self.max_stream_data
+    }
+
     /// Returns the total connection window which has been acquired for this
     /// Stream.
     pub fn acquired_connection_flow_controller_window(&self) -> VarInt {
@@ -457,6 +462,91 @@ pub struct SendStream {
     final_state_observed: bool,
     /// Marks the stream as detached from the application
     detached: bool,
+    /// The time at which stream data was first transmitted
+    first_transmission_time: Option<Timestamp>,
+    /// The time between the first transmission of stream data and the first acknowledgement
+    time_to_first_ack: Option<Duration>,
+    /// Tracks the waits to transmit pending data, if starvation reporting is enabled
+    starvation: Option<Box<Starvation>>,
+}
+
+/// Tracks how long a stream waits to transmit its pending data
+#[derive(Debug)]
+struct Starvation {
+    /// The amount of time the stream may wait to transmit before it is reported as starved
+    threshold: Duration,
+    /// Set when data is queued or the stream is unblocked, until the wait is started
+    ///
+    /// These happen outside of the connection clock, so the wait starts at the next timestamp
+    /// provided to [`SendStream::start_pending_wait`].
+    wait_pending: bool,
+    /// The time since which the stream has been waiting, along with the number of bytes which
+    /// had been sent at that point
+    ///
+    /// The oldest pending data starts at that offset, so the wait ends once it is transmitted.
+    waiting_since: Option<(Timestamp, u64)>,
+    /// Expires once the current wait reaches the threshold
+    timer: Timer,
+    /// The duration of the most recent wait, if it hasn't been reported yet
+    completed_wait: Option<Duration>,
+}
+
+impl Starvation {
+    fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            wait_pending: false,
+            waiting_since: None,
+            timer: Timer::default(),
+            completed_wait: None,
+        }
+    }
+
+    /// Marks the stream as waiting to transmit once it has a timestamp for the start of the wait
+    fn request_wait(&mut self) {
+        if self.waiting_since.is_none() {
+            self.wait_pending = true;
+        }
+    }
+
+    /// Starts waiting to transmit at `now` if the stream has pending data
+    ///
+    /// The stream isn't waiting on the scheduler if it's blocked or has nothing to send, in which
+    /// case any current wait is abandoned.
+    fn start_wait(&mut self, now: Timestamp, has_pending_data: bool, sent_bytes: u64) {
+        if !has_pending_data {
+            self.waiting_since = None;
+            self.timer.cancel();
+            return;
+        }
+
+        if self.waiting_since.is_some() {
+            return;
+        }
+
+        self.waiting_since = Some((now, sent_bytes));
+        self.timer.set(now + self.threshold);
+    }
+
+    /// Ends the current wait and records its duration to be reported
+    fn complete_wait(&mut self, now: Timestamp) {
+        self.timer.cancel();
+
+        if let Some((waiting_since, _)) = self.waiting_since.take() {
+            self.completed_wait = Some(now.saturating_duration_since(waiting_since));
+        }
+    }
+
+    /// Called after the stream was queried for outgoing data
+    fn on_transmit(&mut self, now: Timestamp, has_pending_data: bool, sent_bytes: u64) {
+        if matches!(self.waiting_since, Some((_, offset)) if sent_bytes > offset) {
+            self.complete_wait(now);
+        }
+
+        // the stream waits for the next transmission if any of its data couldn't be sent
+        self.wait_pending = false;
+        self.start_wait(now, has_pending_data, sent_bytes);
+    }
 }
 
 impl SendStream {
@@ -465,6 +555,7 @@ impl SendStream {
         is_closed: bool,
         initial_window: VarInt,
         max_buffer_capacity: u32,
+        starvation_threshold: Option<Duration>,
     ) -> SendStream {
         // If the stream is created in closed state directly move into the
         // terminal state.
@@ -485,6 +576,9 @@ impl SendStream {
             write_waiter: None,
             final_state_observed: is_closed,
             detached: is_closed,
+            first_transmission_time: None,
+            time_to_first_ack: None,
+            starvation: starvation_threshold.map(|threshold| Box::new(Starvation::new(threshold))),
         };
 
         if is_closed {
@@ -513,6 +607,7 @@ impl SendStream {
             self.data_sender
                 .flow_controller_mut()
                 .set_max_stream_data(frame.maximum_stream_data);
+            self.request_wait();
 
             // If the window has been increased we need to unblock waiting writers.
             // However if `finish()` has already been called and the final size
@@ -578,8 +673,19 @@ impl SendStream {
     }
 
     /// This method gets called when a packet delivery got acknowledged
-    pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A, events: &mut StreamEvents) {
-        self.data_sender.on_packet_ack(ack_set);
+    pub fn on_packet_ack<A: ack::Set>(
+        &mut self,
+        timestamp: Timestamp,
+        ack_set: &A,
+        events: &mut StreamEvents,
+    ) {
+        if self.data_sender.on_packet_ack(ack_set) && self.time_to_first_ack.is_none() {
+            if let Some(first_transmission_time) = self.first_transmission_time {
+                self.time_to_first_ack =
+                    Some(timestamp.saturating_duration_since(first_transmission_time));
+            }
+        }
+
         self.data_sender
             .flow_controller_mut()
             .on_packet_ack(ack_set);
@@ -653,12 +759,63 @@ impl SendStream {
         context: &mut W,
     ) -> Result<(), OnTransmitError> {
         self.reset_sync.on_transmit(stream_id, context)?;
-        self.data_sender.on_transmit(stream_id.into(), context)?;
+
+        let capacity = context.remaining_capacity();
+        let result = self.data_sender.on_transmit(stream_id.into(), context);
+        let now = context.current_time();
+
+        if self.first_transmission_time.is_none() && context.remaining_capacity() < capacity {
+            self.first_transmission_time = Some(now);
+        }
+
+        if let Some(starvation) = self.starvation.as_deref_mut() {
+            starvation.on_transmit(
+                now,
+                self.data_sender.has_pending_new_data(),
+                self.data_sender.sent_bytes(),
+            );
+        }
+
+        result?;
+
         self.data_sender
             .flow_controller_mut()
             .on_transmit(stream_id, context)
     }
 
+    /// Marks the stream as waiting to transmit, if starvation reporting is enabled
+    fn request_wait(&mut self) {
+        if let Some(starvation) = self.starvation.as_deref_mut() {
+            starvation.request_wait();
+        }
+    }
+
+    /// Starts waiting at `now` if data was queued or the stream was unblocked since the last call
+    pub fn start_pending_wait(&mut self, now: Timestamp) {
+        if let Some(starvation) = self.starvation.as_deref_mut() {
+            if core::mem::take(&mut starvation.wait_pending) {
+                starvation.start_wait(
+                    now,
+                    self.data_sender.has_pending_new_data(),
+                    self.data_sender.sent_bytes(),
+                );
+            }
+        }
+    }
+
+    /// Returns the duration of the most recent wait to transmit pending data, if the stream
+    /// has transmitted since then and the wait hasn't been returned yet
+    ///
+    /// Waits are only tracked if the stream was created with a starvation threshold.
+    pub fn take_completed_wait(&mut self) -> Option<Duration> {
+        self.starvation.as_deref_mut()?.completed_wait.take()
+    }
+
+    /// Returns the `MAX_STREAM_DATA` window which was received from the peer
+    pub fn send_window(&self) -> VarInt {
+        self.data_sender.flow_controller().max_stream_data()
+    }
+
     /// Updates the period at which `STREAM_DATA_BLOCKED` frames are sent to the peer
     /// if the application is blocked by peer limits.
     pub fn update_blocked_sync_period(&mut self, blocked_sync_period: Duration) {
@@ -669,7 +826,15 @@ impl SendStream {
 
     /// Called when the connection timer expires
     pub fn on_timeout(&mut self, now: Timestamp) {
-        self.data_sender.flow_controller_mut().on_timeout(now)
+        self.data_sender.flow_controller_mut().on_timeout(now);
+
+        // report streams which are still waiting once they reach the threshold, rather than when
+        // they are eventually scheduled
+        if let Some(starvation) = self.starvation.as_deref_mut() {
+            if starvation.timer.poll_expiration(now).is_ready() {
+                starvation.complete_wait(now);
+            }
+        }
     }
 
     /// A reset that is triggered without having received a `RESET` frame.
@@ -705,6 +870,7 @@ impl SendStream {
             self.data_sender
                 .flow_controller_mut()
                 .try_acquire_connection_window();
+            self.request_wait();
         }
     }
 
@@ -749,7 +915,12 @@ impl SendStream {
         request: &mut ops::tx::Request,
         context: Option<&Context>,
     ) -> Result<ops::tx::Response, StreamError> {
-        let mut response = ops::tx::Response::default();
+        let mut response = ops::tx::Response {
+            retransmitted_bytes: self.data_sender.retransmitted_bytes(),
+            time_to_first_ack: self.time_to_first_ack,
+            sent_bytes: self.data_sender.sent_bytes(),
+            ..Default::default()
+        };
 
         if request.detached {
             self.detach();
@@ -834,6 +1005,7 @@ impl SendStream {
 
                 self.data_sender
                     .push(core::mem::replace(chunk, Bytes::new()));
+                self.request_wait();
             }
         } else if !request.finish && !request.flush && context.is_some() {
             // if `chunks` are `None` or `Some(&[])` and we're not ending or flushing the stream,
@@ -1021,6 +1193,9 @@ impl timer::Provider for SendStream {
     #[inline]
     fn timers<Q: timer::Query>(&self, query: &mut Q) -> timer::Result {
         self.data_sender.flow_controller().timers(query)?;
+        if let Some(starvation) = self.starvation.as_deref() {
+            starvation.timer.timers(query)?;
+        }
         Ok(())
     }
 }
@@ -2728,6 +2728,9 @@ fn can_send_multiple_chunks() {
                                     ops::Status::Open
                                 },
                                 will_wake,
+                                retransmitted_bytes: 0,
+                                time_to_first_ack: None,
+                                sent_bytes: 0,
                             }),
                             rx: None,
                         }),
@@ -2847,6 +2850,9 @@ fn can_query_stream_readiness() {
                         },
                         status: ops::Status::Open,
                         will_wake: with_context && expected_buffer_size == 0,
+                        retransmitted_bytes: 0,
+                        time_to_first_ack: None,
+                        sent_bytes: 0,
                     }),
                     rx: None,
                 }),
@@ -2854,3 +2860,184 @@ fn can_query_stream_readiness() {
         }
     }
 }
+
+#[test]
+fn time_to_first_ack_test() {
+    let mut test_env = setup_send_only_test_env();
+
+    let time_to_first_ack = |test_env: &mut TestEnvironment| {
+        test_env
+            .run_request(ops::Request::default().send(&mut []), false)
+            .unwrap()
+            .tx
+            .unwrap()
+            .time_to_first_ack
+    };
+
+    test_env
+        .run_request(
+            ops::Request::default().send(&mut gen_pattern_test_chunks(VarInt::from_u8(0), &[100])),
+            false,
+        )
+        .unwrap();
+    assert_eq!(time_to_first_ack(&mut test_env), None);
+
+    let frame = test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+    assert_eq!(time_to_first_ack(&mut test_env), None);
+
+    let rtt = core::time::Duration::from_millis(100);
+    test_env.current_time += rtt;
+    test_env.ack_packet(frame.packet_nr, ExpectWakeup(None));
+    assert_eq!(time_to_first_ack(&mut test_env), Some(rtt));
+
+    // later acknowledgements don't affect the value
+    test_env
+        .run_request(
+            ops::Request::default()
+                .send(&mut gen_pattern_test_chunks(VarInt::from_u8(100), &[100])),
+            false,
+        )
+        .unwrap();
+    let frame = test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+    test_env.current_time += rtt * 2;
+    test_env.ack_packet(frame.packet_nr, ExpectWakeup(None));
+    assert_eq!(time_to_first_ack(&mut test_env), Some(rtt));
+}
+
+#[test]
+fn sent_bytes_and_wait_test() {
+    let test_env_config = TestEnvironmentConfig {
+        stream_id: StreamId::initial(endpoint::Type::Server, StreamType::Unidirectional),
+        max_packet_size: Some(1000),
+        // the waits are only tracked when starvation is reported
+        starvation_threshold: Some(core::time::Duration::from_secs(1)),
+        ..Default::default()
+    };
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    let sent_bytes = |test_env: &mut TestEnvironment| {
+        test_env
+            .run_request(ops::Request::default().send(&mut []), false)
+            .unwrap()
+            .tx
+            .unwrap()
+            .sent_bytes
+    };
+
+    test_env
+        .run_request(
+            ops::Request::default().send(&mut gen_pattern_test_chunks(VarInt::from_u8(0), &[1500])),
+            false,
+        )
+        .unwrap();
+    assert_eq!(sent_bytes(&mut test_env), 0);
+
+    // the data doesn't fit in a single packet so the stream waits for the next transmission
+    let mut frame = test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+    let first_len = if let Frame::Stream(stream) = frame.as_frame() {
+        stream.data.len() as u64
+    } else {
+        panic!("invalid frame");
+    };
+    assert!(first_len < 1500);
+    assert_eq!(sent_bytes(&mut test_env), first_len);
+    assert_eq!(test_env.stream.take_completed_wait(), None);
+
+    let wait = core::time::Duration::from_millis(50);
+    test_env.current_time += wait;
+    test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+    assert_eq!(sent_bytes(&mut test_env), 1500);
+    assert_eq!(test_env.stream.take_completed_wait(), Some(wait));
+    assert_eq!(test_env.stream.take_completed_wait(), None);
+
+    // the stream doesn't wait once all of the data has been transmitted
+    test_env.current_time += wait;
+    let _ = test_env.transmit();
+    assert_eq!(test_env.stream.take_completed_wait(), None);
+}
+
+#[test]
+fn wait_without_starvation_threshold_test() {
+    use s2n_quic_core::time::timer::Provider as _;
+
+    let test_env_config = TestEnvironmentConfig {
+        stream_id: StreamId::initial(endpoint::Type::Server, StreamType::Unidirectional),
+        max_packet_size: Some(1000),
+        ..Default::default()
+    };
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    test_env
+        .run_request(
+            ops::Request::default().send(&mut gen_pattern_test_chunks(VarInt::from_u8(0), &[1500])),
+            false,
+        )
+        .unwrap();
+    test_env.stream.start_pending_wait(test_env.current_time);
+    test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+
+    // the stream doesn't track waits or arm a timer if starvation isn't reported
+    assert!(test_env.stream.send_stream.starvation.is_none());
+    assert_eq!(test_env.stream.next_expiration(), None);
+
+    test_env.current_time += core::time::Duration::from_millis(50);
+    test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+    assert_eq!(test_env.stream.take_completed_wait(), None);
+}
+
+#[test]
+fn starvation_without_transmission_test() {
+    use s2n_quic_core::time::timer::Provider as _;
+
+    let threshold = core::time::Duration::from_millis(100);
+    let test_env_config = TestEnvironmentConfig {
+        stream_id: StreamId::initial(endpoint::Type::Server, StreamType::Unidirectional),
+        starvation_threshold: Some(threshold),
+        ..Default::default()
+    };
+    let mut test_env = setup_stream_test_env_with_config(test_env_config);
+
+    test_env
+        .run_request(
+            ops::Request::default().send(&mut gen_pattern_test_chunks(VarInt::from_u8(0), &[1500])),
+            false,
+        )
+        .unwrap();
+
+    // the wait starts with the first timestamp after the data was queued
+    assert_eq!(test_env.stream.next_expiration(), None);
+    let queued_at = test_env.current_time;
+    test_env.stream.start_pending_wait(queued_at);
+    assert_eq!(
+        test_env.stream.next_expiration(),
+        Some(queued_at + threshold)
+    );
+
+    // the stream is never scheduled, but is reported once the wait reaches the threshold
+    test_env.current_time += threshold / 2;
+    test_env.stream.on_timeout(test_env.current_time);
+    assert_eq!(test_env.stream.take_completed_wait(), None);
+
+    test_env.current_time += threshold / 2;
+    test_env.stream.on_timeout(test_env.current_time);
+    assert_eq!(test_env.stream.take_completed_wait(), Some(threshold));
+    assert_eq!(test_env.stream.next_expiration(), None);
+
+    // the wait isn't reported a second time once the stream transmits
+    test_env
+        .transmit()
+        .expect("stream data should be transmitted");
+    assert_eq!(test_env.stream.take_completed_wait(), None);
+}
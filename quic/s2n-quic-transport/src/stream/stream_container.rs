@@ -259,6 +259,9 @@ pub struct StreamContainer<S> {
     nr_active_streams: usize,
     /// Additional interest lists in which Streams will be placed dynamically
     interest_lists: InterestLists<S>,
+    /// Streams which were finalized since they were last drained, along with the number of bytes
+    /// each stream retransmitted
+    closed_streams: Vec<(StreamId, u64)>,
 }
 
 impl<S> core::fmt::Debug for StreamContainer<S> {
@@ -336,6 +339,7 @@ impl<S: StreamTrait> StreamContainer<S> {
             stream_map: RBTree::new(StreamTreeAdapter::new()),
             nr_active_streams: 0,
             interest_lists: InterestLists::new(),
+            closed_streams: Vec::new(),
         }
     }
 
@@ -470,10 +474,19 @@ impl<S: StreamTrait> StreamContainer<S> {
                 waiting_for_stream_flow_control_credits_link
             );
 
-            controller.on_close_stream(stream.inner.borrow().stream_id());
+            let inner = stream.inner.borrow();
+            controller.on_close_stream(inner.stream_id());
+            self.closed_streams
+                .push((inner.stream_id(), inner.retransmitted_bytes()));
         }
     }
 
+    /// Removes and returns the streams which were finalized since the last call, along with the
+    /// number of bytes each stream retransmitted
+    pub fn drain_closed_streams(&mut self) -> impl Iterator<Item = (StreamId, u64)> + '_ {
+        self.closed_streams.drain(..)
+    }
+
     /// Iterates over all `Stream`s which are waiting for frame delivery,
     /// and executes the given function on each `Stream`
     ///
@@ -633,6 +646,11 @@ impl<S: StreamTrait> timer::Provider for StreamContainer<S> {
         {
             stream.inner.borrow().timers(query)?;
         }
+
+        // streams waiting to transmit arm a timer for the starvation threshold
+        for stream in self.interest_lists.waiting_for_transmission.iter() {
+            stream.inner.borrow().timers(query)?;
+        }
         Ok(())
     }
 }
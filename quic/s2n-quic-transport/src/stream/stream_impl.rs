@@ -42,6 +42,8 @@ pub struct StreamConfig {
     pub initial_send_window: VarInt,
     /// The maximum buffered amount of data on the sending side
     pub max_send_buffer_size: u32,
+    /// The amount of time the stream may wait to transmit before it is reported as starved
+    pub starvation_threshold: Option<Duration>,
 }
 
 /// A trait which represents an internally used `Stream`
@@ -95,7 +97,12 @@ pub trait StreamTrait: StreamInterestProvider + timer::Provider + core::fmt::Deb
     ) -> Result<(), transport::Error>;
 
     /// This method gets called when a packet delivery got acknowledged
-    fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A, events: &mut StreamEvents);
+    fn on_packet_ack<A: ack::Set>(
+        &mut self,
+        timestamp: Timestamp,
+        ack_set: &A,
+        events: &mut StreamEvents,
+    );
 
     /// This method gets called when a packet loss is reported
     fn on_packet_loss<A: ack::Set>(&mut self, ack_set: &A, events: &mut StreamEvents);
@@ -122,6 +129,24 @@ pub trait StreamTrait: StreamInterestProvider + timer::Provider + core::fmt::Deb
     /// This method is called when a connection window is available
     fn on_connection_window_available(&mut self);
 
+    /// Starts the wait to transmit data which was queued, or unblocked, since the last call
+    fn start_pending_wait(&mut self, now: Timestamp);
+
+    /// Returns how long the stream waited to transmit pending data, if the wait has ended and the
+    /// duration hasn't been returned yet
+    ///
+    /// A wait ends when the stream transmits, or once it reaches the starvation threshold.
+    fn take_completed_wait(&mut self) -> Option<Duration>;
+
+    /// Returns the number of bytes of stream data which were retransmitted
+    fn retransmitted_bytes(&self) -> u64;
+
+    /// Returns the highest `MAX_STREAM_DATA` window which was transmitted to the peer
+    fn receive_window(&self) -> VarInt;
+
+    /// Returns the `MAX_STREAM_DATA` window which was received from the peer
+    fn send_window(&self) -> VarInt;
+
     // These functions are called from the client API
 
     fn poll_request(
@@ -211,6 +236,7 @@ impl StreamTrait for StreamImpl {
                 send_is_closed,
                 config.initial_send_window,
                 config.max_send_buffer_size,
+                config.starvation_threshold,
             ),
         }
     }
@@ -277,9 +303,14 @@ impl StreamTrait for StreamImpl {
     }
 
     #[inline]
-    fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A, events: &mut StreamEvents) {
+    fn on_packet_ack<A: ack::Set>(
+        &mut self,
+        timestamp: Timestamp,
+        ack_set: &A,
+        events: &mut StreamEvents,
+    ) {
         self.receive_stream.on_packet_ack(ack_set);
-        self.send_stream.on_packet_ack(ack_set, events);
+        self.send_stream.on_packet_ack(timestamp, ack_set, events);
     }
 
     #[inline]
@@ -327,6 +358,31 @@ impl StreamTrait for StreamImpl {
         self.send_stream.on_connection_window_available()
     }
 
+    #[inline]
+    fn start_pending_wait(&mut self, now: Timestamp) {
+        self.send_stream.start_pending_wait(now)
+    }
+
+    #[inline]
+    fn take_completed_wait(&mut self) -> Option<Duration> {
+        self.send_stream.take_completed_wait()
+    }
+
+    #[inline]
+    fn retransmitted_bytes(&self) -> u64 {
+        self.send_stream.data_sender.retransmitted_bytes()
+    }
+
+    #[inline]
+    fn receive_window(&self) -> VarInt {
+        self.receive_stream.receive_window()
+    }
+
+    #[inline]
+    fn send_window(&self) -> VarInt {
+        self.send_stream.send_window()
+    }
+
     // These functions are called from the client API
 
     fn poll_request(
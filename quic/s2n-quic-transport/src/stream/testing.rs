@@ -12,7 +12,10 @@ use crate::{
     transmission,
 };
 use bytes::Bytes;
-use core::task::{Context, Poll, Waker};
+use core::{
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 use futures_test::task::{new_count_waker, AwokenCount};
 use s2n_quic_core::{
     application::Error as ApplicationErrorCode,
@@ -385,7 +388,8 @@ impl TestEnvironment {
         self.rx_connection_flow_controller
             .on_packet_ack(&packet_number);
         let mut events = StreamEvents::new();
-        self.stream.on_packet_ack(&packet_number, &mut events);
+        self.stream
+            .on_packet_ack(self.current_time, &packet_number, &mut events);
         events.wake_all();
         let new_wake_count = self.wake_counter.get();
         let was_woken = new_wake_count > old_wake_count;
@@ -487,6 +491,7 @@ pub struct TestEnvironmentConfig {
     pub transmission_constraint: transmission::Constraint,
     pub local_endpoint_type: endpoint::Type,
     pub max_packet_size: Option<usize>,
+    pub starvation_threshold: Option<Duration>,
 }
 
 impl Default for TestEnvironmentConfig {
@@ -515,6 +520,7 @@ impl TestEnvironmentConfig {
             max_send_buffer_size: TestEnvironment::DEFAULT_MAX_SEND_BUFFER_SIZE,
             transmission_constraint: transmission::Constraint::None,
             max_packet_size: None,
+            starvation_threshold: None,
         }
     }
 }
@@ -545,6 +551,7 @@ pub fn setup_stream_test_env_with_config(config: TestEnvironmentConfig) -> TestE
         desired_flow_control_window: config.desired_flow_control_window,
         initial_send_window: VarInt::new(config.initial_send_window).unwrap(),
         max_send_buffer_size: config.max_send_buffer_size as u32,
+        starvation_threshold: config.starvation_threshold,
     });
 
     let (waker, wake_counter) = new_count_waker();
@@ -124,6 +124,10 @@ pub struct DataSender<FlowController, ChunkToFrameWriter> {
     /// Whether the size of the send stream is known and a FIN flag is already
     /// enqueued.
     state: State,
+    /// The number of bytes which have been transmitted more than once
+    retransmitted_bytes: u64,
+    /// The number of bytes which have been transmitted for the first time
+    sent_bytes: u64,
 }
 
 impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
@@ -145,6 +149,8 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
             lost: IntervalSet::new(),
             max_buffer_capacity: VarInt::from_u32(max_buffer_capacity),
             state: State::Sending,
+            retransmitted_bytes: 0,
+            sent_bytes: 0,
         }
     }
 
@@ -197,6 +203,25 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         self.buffer.total_len()
     }
 
+    /// Returns the number of bytes which have been transmitted more than once, either because
+    /// they were declared lost or because they were included in a loss recovery probe
+    pub fn retransmitted_bytes(&self) -> u64 {
+        self.retransmitted_bytes
+    }
+
+    /// Returns the number of bytes which have been transmitted at least once
+    ///
+    /// Retransmissions of the same bytes are not included.
+    pub fn sent_bytes(&self) -> u64 {
+        self.sent_bytes
+    }
+
+    /// Returns true if there is enqueued data which hasn't been transmitted yet and isn't
+    /// blocked by flow control
+    pub fn has_pending_new_data(&self) -> bool {
+        self.transmission_offset < self.buffer.total_len() && !self.flow_controller().is_blocked()
+    }
+
     /// Returns true if the data sender doesn't have any data enqueued for sending
     pub fn is_empty(&self) -> bool {
         self.buffer.is_empty()
@@ -285,7 +310,9 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
     }
 
     /// This method gets called when a packet delivery got acknowledged
-    pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) {
+    ///
+    /// Returns `true` if any data was newly acknowledged
+    pub fn on_packet_ack<A: ack::Set>(&mut self, ack_set: &A) -> bool {
         // If we do not get acknowledgements for any in flight data don't try
         // to release buffer chunks
 
@@ -327,6 +354,8 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         }
 
         self.check_integrity();
+
+        any_acked
     }
 
     fn is_idle(&self) -> bool {
@@ -383,17 +412,22 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         context: &mut W,
     ) -> Result<(), OnTransmitError> {
         let constraint = context.transmission_constraint();
+        let is_single_frame = Writer::is_single_frame(writer_context);
 
         let mut transmitted_lost = false;
         // try to retransmit any lost ranges first
         if constraint.can_retransmit() {
-            transmitted_lost = self.transmissions.transmit_set(
+            if let Some(len) = self.transmissions.transmit_set(
                 &self.buffer,
                 &mut self.lost,
                 &mut self.state,
                 writer_context,
                 context,
-            )?;
+            )? {
+                transmitted_lost = true;
+                self.retransmitted_bytes += len as u64;
+                context.on_data_written(len, true);
+            }
         }
 
         let is_blocked = self.flow_controller().is_blocked();
@@ -403,7 +437,14 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
 
         let starting_transmission_offset = self.transmission_offset;
 
-        if !is_blocked && constraint.can_transmit() && self.transmission_offset < total_len {
+        // only a single frame can be written if a lost range was already retransmitted
+        let can_write_frame = !(is_single_frame && transmitted_lost);
+
+        if can_write_frame
+            && !is_blocked
+            && constraint.can_transmit()
+            && self.transmission_offset < total_len
+        {
             let mut viewer = self.buffer.viewer();
             self.transmission_offset = self
                 .transmissions
@@ -415,6 +456,11 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
                     context,
                 )?
                 .end_exclusive();
+            let len = (self.transmission_offset - starting_transmission_offset).as_u64();
+            self.sent_bytes += len;
+            if len > 0 {
+                context.on_data_written(len as usize, false);
+            }
         }
 
         if Writer::WRITES_FIN && self.state.can_transmit_fin(constraint, is_blocked) {
@@ -435,7 +481,8 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         // much impact on the effectiveness of this feature.
         let retransmit_unacked_data_in_probe = Writer::RETRANSMIT_IN_PROBE
             && context.transmission_mode().is_loss_recovery_probing()
-            && !transmitted_lost;
+            && !transmitted_lost
+            && !(is_single_frame && self.transmission_offset > starting_transmission_offset);
 
         if retransmit_unacked_data_in_probe {
             let mut viewer = self.buffer.viewer();
@@ -448,13 +495,19 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
 
                 let interval_end = interval.end_exclusive().min(starting_transmission_offset);
 
-                self.transmissions.transmit_interval(
+                let transmitted = self.transmissions.transmit_interval(
                     &mut viewer,
                     (interval.start..interval_end).into(),
                     &mut self.state,
                     writer_context,
                     context,
                 )?;
+                self.retransmitted_bytes += transmitted.len() as u64;
+                context.on_data_written(transmitted.len(), true);
+
+                if is_single_frame {
+                    break;
+                }
             }
         }
 
@@ -542,7 +595,7 @@ mod tests {
         transmission::{self, interest::Provider as _},
     };
     use bolero::{check, generator::*};
-    use s2n_quic_core::{endpoint, frame, stream::testing as stream};
+    use s2n_quic_core::{crypto::tls, endpoint, frame, stream::testing as stream};
     use std::collections::HashSet;
 
     #[derive(Clone, Copy, Debug, TypeGenerator)]
@@ -750,6 +803,169 @@ mod tests {
         frame_buffer
     }
 
+    /// Returns the `(packet_nr, offset, len)` of each CRYPTO frame written to the buffer
+    fn crypto_fragments(frame_buffer: &mut OutgoingFrameBuffer) -> Vec<(u64, u64, usize)> {
+        frame_buffer
+            .frames
+            .iter_mut()
+            .map(|frame| {
+                let packet_nr = frame.packet_nr.as_u64();
+                match frame.as_frame() {
+                    frame::Frame::Crypto(frame) => {
+                        (packet_nr, frame.offset.as_u64(), frame.data.len())
+                    }
+                    frame => panic!("unexpected frame {:?}", frame),
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn crypto_max_fragment_len_test() {
+        let limits = tls::FragmentLimits {
+            max_len: Some(30),
+            single_frame: false,
+        };
+        let mut sender: DataSender<_, writer::Crypto> =
+            DataSender::new(TestFlowController::default(), u32::MAX);
+        sender.flow_controller_mut().max_offset = VarInt::MAX;
+        sender.push(Bytes::from_static(&[42; 100]));
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(1200));
+        let mut context = MockWriteContext {
+            current_time: s2n_quic_platform::time::now(),
+            frame_buffer: &mut frame_buffer,
+            transmission_constraint: transmission::Constraint::None,
+            transmission_mode: transmission::Mode::Normal,
+            endpoint: endpoint::Type::Server,
+        };
+
+        while sender.has_transmission_interest() {
+            let _ = sender.on_transmit(limits, &mut context);
+            context.frame_buffer.flush();
+        }
+
+        let fragments = crypto_fragments(context.frame_buffer);
+        let offsets: Vec<_> = fragments
+            .iter()
+            .map(|(_packet_nr, offset, len)| (*offset, *len))
+            .collect();
+        assert_eq!(offsets, [(0, 30), (30, 30), (60, 30), (90, 10)]);
+
+        // every fragment is sent in its own packet
+        let packets: HashSet<_> = fragments.iter().map(|(packet_nr, ..)| *packet_nr).collect();
+        assert_eq!(packets.len(), fragments.len());
+
+        // lost fragments are retransmitted on the same boundaries
+        let lost_packet = context.frame_buffer.frames[1].packet_nr;
+        sender.on_packet_loss(&lost_packet);
+        context.frame_buffer.clear();
+
+        while sender.has_transmission_interest() {
+            let _ = sender.on_transmit(limits, &mut context);
+            context.frame_buffer.flush();
+        }
+
+        let fragments = crypto_fragments(context.frame_buffer);
+        assert_eq!(fragments.len(), 1);
+        assert_eq!((fragments[0].1, fragments[0].2), (30, 30));
+    }
+
+    #[test]
+    fn crypto_single_frame_test() {
+        for single_frame in [false, true] {
+            let limits = tls::FragmentLimits {
+                max_len: None,
+                single_frame,
+            };
+            let mut sender: DataSender<_, writer::Crypto> =
+                DataSender::new(TestFlowController::default(), u32::MAX);
+            sender.flow_controller_mut().max_offset = VarInt::MAX;
+            sender.push(Bytes::from_static(&[42; 100]));
+
+            let mut frame_buffer = OutgoingFrameBuffer::new();
+            frame_buffer.set_max_packet_size(Some(1200));
+            let mut context = MockWriteContext {
+                current_time: s2n_quic_platform::time::now(),
+                frame_buffer: &mut frame_buffer,
+                transmission_constraint: transmission::Constraint::None,
+                transmission_mode: transmission::Mode::Normal,
+                endpoint: endpoint::Type::Server,
+            };
+
+            let _ = sender.on_transmit(limits, &mut context);
+            context.frame_buffer.flush();
+
+            // lose the first flight and queue more data
+            let lost_packet = context.frame_buffer.frames[0].packet_nr;
+            sender.on_packet_loss(&lost_packet);
+            sender.push(Bytes::from_static(&[42; 50]));
+            context.frame_buffer.clear();
+
+            while sender.has_transmission_interest() {
+                let _ = sender.on_transmit(limits, &mut context);
+                context.frame_buffer.flush();
+            }
+
+            let fragments = crypto_fragments(context.frame_buffer);
+            let offsets: Vec<_> = fragments
+                .iter()
+                .map(|(_packet_nr, offset, len)| (*offset, *len))
+                .collect();
+            assert_eq!(offsets, [(0, 100), (100, 50)]);
+
+            let packets: HashSet<_> = fragments.iter().map(|(packet_nr, ..)| *packet_nr).collect();
+            if single_frame {
+                // the retransmission and the new data are sent in separate packets
+                assert_eq!(packets.len(), 2);
+            } else {
+                assert_eq!(packets.len(), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn retransmitted_bytes_test() {
+        let mut sender: DataSender<_, writer::Stream> =
+            DataSender::new(TestFlowController::default(), u32::MAX);
+        sender.flow_controller_mut().max_offset = VarInt::MAX;
+        sender.push(Bytes::from_static(&[42; 100]));
+
+        let mut frame_buffer = OutgoingFrameBuffer::new();
+        frame_buffer.set_max_packet_size(Some(1200));
+        let mut context = MockWriteContext {
+            current_time: s2n_quic_platform::time::now(),
+            frame_buffer: &mut frame_buffer,
+            transmission_constraint: transmission::Constraint::None,
+            transmission_mode: transmission::Mode::Normal,
+            endpoint: endpoint::Type::Server,
+        };
+        let id = VarInt::from_u8(0);
+
+        let _ = sender.on_transmit(id, &mut context);
+        context.frame_buffer.flush();
+        assert_eq!(sender.retransmitted_bytes(), 0);
+
+        let packet = context.frame_buffer.frames[0].packet_nr;
+        sender.on_packet_loss(&packet);
+        assert_eq!(
+            sender.retransmitted_bytes(),
+            0,
+            "bytes are only counted once they are transmitted again"
+        );
+
+        let _ = sender.on_transmit(id, &mut context);
+        context.frame_buffer.flush();
+        assert_eq!(sender.retransmitted_bytes(), 100);
+
+        // data included in a probe is also counted
+        context.transmission_mode = transmission::Mode::LossRecoveryProbing;
+        let _ = sender.on_transmit(id, &mut context);
+        context.frame_buffer.flush();
+        assert_eq!(sender.retransmitted_bytes(), 200);
+    }
+
     #[test]
     fn model() {
         check!()
@@ -53,6 +53,12 @@ pub trait FrameWriter: Default {
     /// transmission::Constraint is `PROBING`
     const RETRANSMIT_IN_PROBE: bool = false;
 
+    /// Returns `true` if at most one frame should be written to each packet
+    #[inline]
+    fn is_single_frame(_writer_context: Self::Context) -> bool {
+        false
+    }
+
     /// Asks the writer to write a frame for the given chunk of data at the offset
     /// provided. The implementation should ensure the view fits by calling `trim_off`.
     ///
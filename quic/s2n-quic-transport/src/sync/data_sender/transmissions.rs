@@ -66,6 +66,8 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         changed
     }
 
+    /// Transmits the intervals in `set`, returning the number of bytes written if any interval
+    /// was transmitted
     #[inline]
     pub fn transmit_set<W: WriteContext>(
         &mut self,
@@ -74,26 +76,30 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
         state: &mut State,
         writer_context: Writer::Context,
         context: &mut W,
-    ) -> Result<bool, OnTransmitError> {
+    ) -> Result<Option<usize>, OnTransmitError> {
         // make sure we've got something to transmit
         if set.is_empty() {
-            return Ok(false);
+            return Ok(None);
         }
 
         let mut viewer = buffer.viewer();
 
-        let mut has_transmitted = false;
+        let mut transmitted_len = None;
         while let Some(mut interval) = set.pop_min() {
             match self.transmit_interval(&mut viewer, interval, state, writer_context, context) {
                 Ok(transmitted) => {
-                    has_transmitted = true;
                     let len = transmitted.len();
+                    *transmitted_len.get_or_insert(0) += len;
                     if len != interval.len() {
                         // only a part of the range was written so push back what wasn't
                         interval.start += len;
                         debug_assert!(interval.is_valid());
                         set.insert_front(interval).unwrap();
-                        return Ok(has_transmitted);
+                        return Ok(transmitted_len);
+                    }
+
+                    if Writer::is_single_frame(writer_context) {
+                        return Ok(transmitted_len);
                     }
                 }
                 Err(err) => {
@@ -104,7 +110,7 @@ impl<FlowController: OutgoingDataFlowController, Writer: FrameWriter>
             }
         }
 
-        Ok(has_transmitted)
+        Ok(transmitted_len)
     }
 
     #[inline]
@@ -3,7 +3,10 @@
 
 use super::*;
 use s2n_codec::EncoderValue;
-use s2n_quic_core::frame::{self, FitError};
+use s2n_quic_core::{
+    crypto::tls,
+    frame::{self, FitError},
+};
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Stream;
@@ -76,7 +79,8 @@ impl FrameWriter for Stream {
 pub struct Crypto;
 
 impl FrameWriter for Crypto {
-    type Context = ();
+    /// The limits applied to each frame
+    type Context = tls::FragmentLimits;
 
     //= https://www.rfc-editor.org/rfc/rfc9000#section-19.6
     //# The stream does not have an explicit end, so CRYPTO frames do not
@@ -96,7 +100,7 @@ impl FrameWriter for Crypto {
         &self,
         offset: VarInt,
         data: &mut View,
-        _writer_context: Self::Context,
+        limits: Self::Context,
         context: &mut W,
     ) -> Result<(), FitError> {
         let remaining_capacity = context.remaining_capacity();
@@ -111,7 +115,11 @@ impl FrameWriter for Crypto {
 
         let frame = frame::Crypto { offset, data };
 
-        let len = frame.try_fit(remaining_capacity)?;
+        let mut len = frame.try_fit(remaining_capacity)?;
+        if let Some(max_len) = limits.max_len {
+            len = len.min(max_len);
+        }
+
         if len == 0 {
             return Err(FitError);
         }
@@ -123,6 +131,11 @@ impl FrameWriter for Crypto {
         Ok(())
     }
 
+    #[inline]
+    fn is_single_frame(limits: Self::Context) -> bool {
+        limits.single_frame
+    }
+
     fn write_fin<W: WriteContext>(
         &self,
         _offset: VarInt,
@@ -20,6 +20,8 @@ use s2n_quic_core::{ack, stream::StreamId};
 pub struct IncrementalValueSync<T, S> {
     latest_value: T,
     value_ackd_up_to: T,
+    /// The highest value which was transmitted to the peer
+    transmitted_value: T,
     threshold: T,
     delivery: DeliveryState<T>,
     writer: S,
@@ -38,6 +40,7 @@ impl<
         let mut sync = IncrementalValueSync {
             latest_value,
             value_ackd_up_to,
+            transmitted_value: value_ackd_up_to,
             delivery: DeliveryState::NotRequested,
             threshold,
             writer: S::default(),
@@ -53,6 +56,13 @@ impl<
         self.latest_value
     }
 
+    /// Returns the highest value which was transmitted to the peer
+    ///
+    /// This is the initial acknowledged value until the first update is transmitted.
+    pub fn transmitted_value(&self) -> T {
+        self.transmitted_value
+    }
+
     /// Returns `true` if the synchronization has been cancelled
     pub fn is_cancelled(&self) -> bool {
         self.delivery.is_cancelled()
@@ -171,6 +181,10 @@ impl<
                 .write_value_as_frame(value, stream_id, context)
                 .ok_or(OnTransmitError::CouldNotWriteFrame)?;
 
+            if value > self.transmitted_value {
+                self.transmitted_value = value;
+            }
+
             // Overwrite the information about the in_flight transmission of the
             // latest value.
             self.delivery = DeliveryState::InFlight(InFlightDelivery {
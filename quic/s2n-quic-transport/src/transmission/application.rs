@@ -132,7 +132,10 @@ impl<'a, S: Stream, Config: endpoint::Config> Normal<'a, S, Config> {
                 self.prioritize_datagrams,
             );
         }
-        let did_send_ack = self.ack_manager.on_transmit(context);
+        // ACKs are written first unless the application prefers data, in which case they are
+        // written at the end of the packet if there is still space.
+        let ack_first = !can_transmit || self.ack_manager.priority().is_ack();
+        let mut did_send_ack = ack_first && self.ack_manager.on_transmit(context);
 
         // Payloads can only transmit and retransmit
         if can_transmit {
@@ -159,6 +162,10 @@ impl<'a, S: Stream, Config: endpoint::Config> Normal<'a, S, Config> {
             let _ = self.ping.on_transmit(context);
         }
 
+        if !ack_first {
+            did_send_ack = self.ack_manager.on_transmit(context);
+        }
+
         if did_send_ack {
             // inform the ack manager the packet is populated
             self.ack_manager.on_transmit_complete(context);
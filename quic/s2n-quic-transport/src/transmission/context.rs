@@ -104,13 +104,16 @@ impl<'a, 'b, 'sub, Config: endpoint::Config> WriteContext for Context<'a, 'b, 's
         self.outcome.ack_elicitation |= frame.ack_elicitation();
         self.outcome.is_congestion_controlled |= frame.is_congestion_controlled();
 
+        let frame = frame.into_event();
+        self.outcome.frames.on_frame(&frame);
+
         self.publisher.on_frame_sent(event::builder::FrameSent {
             packet_header: event::builder::PacketHeader::new(
                 self.packet_number,
                 self.publisher.quic_version(),
             ),
             path_id: self.path_id.into_event(),
-            frame: frame.into_event(),
+            frame,
         });
         self.packet_number
     }
@@ -128,17 +131,29 @@ impl<'a, 'b, 'sub, Config: endpoint::Config> WriteContext for Context<'a, 'b, 's
         self.outcome.ack_elicitation |= frame.ack_elicitation();
         self.outcome.is_congestion_controlled |= frame.is_congestion_controlled();
 
+        let frame = frame.into_event();
+        self.outcome.frames.on_frame(&frame);
+
         self.publisher.on_frame_sent(event::builder::FrameSent {
             packet_header: event::builder::PacketHeader::new(
                 self.packet_number,
                 self.publisher.quic_version(),
             ),
             path_id: self.path_id.into_event(),
-            frame: frame.into_event(),
+            frame,
         });
         Some(self.packet_number)
     }
 
+    #[inline]
+    fn on_data_written(&mut self, len: usize, is_retransmission: bool) {
+        if is_retransmission {
+            self.outcome.bytes_retransmitted += len;
+        } else {
+            self.outcome.bytes_new_data += len;
+        }
+    }
+
     #[inline]
     fn ack_elicitation(&self) -> AckElicitation {
         self.outcome.ack_elicitation
@@ -229,6 +244,11 @@ impl<'a, C: WriteContext> WriteContext for RetransmissionContext<'a, C> {
         self.context.write_frame_forced(frame)
     }
 
+    #[inline]
+    fn on_data_written(&mut self, len: usize, is_retransmission: bool) {
+        self.context.on_data_written(len, is_retransmission)
+    }
+
     #[inline]
     fn ack_elicitation(&self) -> AckElicitation {
         self.context.ack_elicitation()
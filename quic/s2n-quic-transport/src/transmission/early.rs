@@ -36,7 +36,8 @@ impl<'a, Config: endpoint::Config> super::Payload for Payload<'a, Config> {
         if context.transmission_constraint().can_transmit()
             || context.transmission_constraint().can_retransmit()
         {
-            let _ = self.crypto_stream.tx.on_transmit((), context);
+            let fragment_limits = self.crypto_stream.fragment_limits;
+            let _ = self.crypto_stream.tx.on_transmit(fragment_limits, context);
 
             // send PINGs last, since they might not actually be needed if there's an ack-eliciting
             // frame already present in the payload
@@ -111,6 +111,7 @@ impl<'a, 'sub, Config: endpoint::Config, P: Payload> PacketPayloadEncoder
                 // since we still want to send this packet despite Padding being
                 // congestion controlled.
                 context.write_frame_forced(&Padding { length });
+                self.outcome.bytes_padded = length;
             }
 
             {
@@ -24,9 +24,9 @@ struct QueueState<T> {
 }
 
 impl<T: Copy> QueueState<T> {
-    fn new() -> Self {
+    fn with_capacity(capacity: usize) -> Self {
         Self {
-            woken_connections: VecDeque::new(),
+            woken_connections: VecDeque::with_capacity(capacity),
             waker: None,
             wakeup_in_progress: false,
         }
@@ -93,8 +93,13 @@ impl<T: Copy> WakeupQueue<T> {
     ///
     /// If a wakeup is triggered, the given [`Waker`] will be notified.
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Creates a new `WakeupQueue` which can hold `capacity` wakeups without reallocating
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            state: Arc::new(Mutex::new(QueueState::new())),
+            state: Arc::new(Mutex::new(QueueState::with_capacity(capacity))),
         }
     }
 
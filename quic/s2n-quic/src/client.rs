@@ -11,7 +11,11 @@ use core::{
     pin::Pin,
     task::{Context, Poll},
 };
-use s2n_quic_transport::endpoint::{connect, handle::Connector};
+use s2n_quic_transport::{
+    connection::Cancellation,
+    endpoint::{connect, handle::Connector},
+};
+use std::sync::Mutex;
 
 mod builder;
 mod providers;
@@ -86,6 +90,12 @@ impl Client {
 
     /// Establishes a connection to the specified endpoint
     ///
+    /// Dropping the returned [`ConnectionAttempt`] before it completes cancels the attempt. Any
+    /// in-progress handshake is closed, which notifies the peer and frees the connection state.
+    ///
+    /// If the attempt fails, [`connection::Error::connect_error`] categorizes the failure, which
+    /// can be used to decide whether the attempt should be retried.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -107,7 +117,10 @@ impl Client {
     /// ```
     pub fn connect(&self, connect: Connect) -> ConnectionAttempt {
         let attempt = self.connector.connect(connect);
-        ConnectionAttempt(attempt)
+        ConnectionAttempt {
+            attempt,
+            cancellation: Mutex::new(None),
+        }
     }
 
     /// Wait for the client endpoint to finish handling all outstanding connections
@@ -169,16 +182,88 @@ impl Client {
 }
 
 #[must_use = "futures do nothing unless you `.await` or poll them"]
-pub struct ConnectionAttempt(connect::Attempt);
+pub struct ConnectionAttempt {
+    attempt: connect::Attempt,
+    /// The cancellation to attach to the connection once it's established
+    ///
+    /// The future is only accessed through `&mut self` so the mutex is never locked. It only
+    /// keeps the attempt `Sync`.
+    cancellation: Mutex<Option<Cancellation>>,
+}
+
+impl ConnectionAttempt {
+    /// Gracefully closes the connection once the `cancellation` future completes
+    ///
+    /// This ties the connection to an external signal from the moment the attempt is made. If the
+    /// future completes before the handshake does, the attempt is cancelled and fails with
+    /// [`Error::Cancelled`](crate::connection::Error::Cancelled). Otherwise, the future is
+    /// attached to the connection before it's returned, as with
+    /// [`Connection::set_cancellation`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::error::Error;
+    /// use s2n_quic::{client::Connect, Client};
+    /// use std::net::SocketAddr;
+    ///
+    /// # async fn connect(client: Client) -> Result<(), Box<dyn Error>> {
+    /// let (shutdown, on_shutdown) = tokio::sync::oneshot::channel::<()>();
+    ///
+    /// let addr: SocketAddr = "127.0.0.1:443".parse()?;
+    /// let connect = Connect::new(addr).with_server_name("localhost");
+    /// let connection = client
+    ///     .connect(connect)
+    ///     .with_cancellation(async move {
+    ///         let _ = on_shutdown.await;
+    ///     })
+    ///     .await?;
+    /// #
+    /// #    drop(shutdown);
+    /// #    Ok(())
+    /// # }
+    /// ```
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub fn with_cancellation<F>(mut self, cancellation: F) -> Self
+    where
+        F: 'static + Future<Output = ()> + Send,
+    {
+        *self.cancellation_mut() = Some(Box::pin(cancellation));
+        self
+    }
+
+    #[inline]
+    fn cancellation_mut(&mut self) -> &mut Option<Cancellation> {
+        self.cancellation
+            .get_mut()
+            .unwrap_or_else(|err| err.into_inner())
+    }
+}
 
 impl Future for ConnectionAttempt {
     type Output = Result<Connection, connection::Error>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        match Pin::new(&mut self.0).poll(cx) {
-            Poll::Ready(Ok(conn)) => Poll::Ready(Ok(Connection::new(conn))),
+        match Pin::new(&mut self.attempt).poll(cx) {
+            Poll::Ready(Ok(conn)) => {
+                if let Some(cancellation) = self.cancellation_mut().take() {
+                    if let Err(err) = conn.set_cancellation(cancellation) {
+                        return Poll::Ready(Err(err));
+                    }
+                }
+                Poll::Ready(Ok(Connection::new(conn)))
+            }
             Poll::Ready(Err(err)) => Poll::Ready(Err(err)),
-            Poll::Pending => Poll::Pending,
+            Poll::Pending => {
+                // the attempt is cancelled if the future completes before the handshake does
+                if let Some(cancellation) = self.cancellation_mut() {
+                    if cancellation.as_mut().poll(cx).is_ready() {
+                        *self.cancellation_mut() = None;
+                        return Poll::Ready(Err(connection::Error::cancelled()));
+                    }
+                }
+                Poll::Pending
+            }
         }
     }
 }
@@ -1,7 +1,8 @@
 // Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
 // SPDX-License-Identifier: Apache-2.0
 
-use core::fmt;
+use core::{fmt, time::Duration};
+use s2n_quic_core::path;
 use s2n_quic_transport::connection::Connection as Inner;
 
 #[macro_use]
@@ -11,7 +12,7 @@ mod handle;
 
 pub use acceptor::*;
 pub use handle::*;
-pub use s2n_quic_core::connection::Error;
+pub use s2n_quic_core::connection::{ConnectError, Error, Tags};
 
 pub mod error {
     pub use s2n_quic_core::transport::error::Code;
@@ -19,6 +20,41 @@ pub mod error {
 
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
+/// Information about the path a connection is currently using
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug)]
+pub struct PathInfo {
+    /// The local address of the path
+    pub local_address: std::net::SocketAddr,
+    /// The remote address of the path
+    pub remote_address: std::net::SocketAddr,
+    /// Whether the peer's address has been validated on this path
+    ///
+    /// Until the path is validated, the amount of data that can be sent on it is limited to
+    /// three times the amount of data received.
+    pub is_validated: bool,
+    /// The smoothed round-trip time of the path
+    pub smoothed_rtt: Duration,
+    /// The minimum round-trip time observed on the path
+    pub min_rtt: Duration,
+    /// The most recent round-trip time sample of the path
+    pub latest_rtt: Duration,
+}
+
+impl From<path::Info> for PathInfo {
+    #[inline]
+    fn from(info: path::Info) -> Self {
+        Self {
+            local_address: info.local_address.into(),
+            remote_address: info.remote_address.into(),
+            is_validated: info.is_validated,
+            smoothed_rtt: info.smoothed_rtt,
+            min_rtt: info.min_rtt,
+            latest_rtt: info.latest_rtt,
+        }
+    }
+}
+
 pub struct Connection(Inner);
 
 impl fmt::Debug for Connection {
@@ -102,6 +102,74 @@ macro_rules! impl_handle_api {
             Ok(BidirectionalStream::new(stream)).into()
         }
 
+        /// Opens up to `count` [`BidirectionalStream`](`crate::stream::BidirectionalStream`)s
+        ///
+        /// This is equivalent to calling
+        /// [`open_bidirectional_stream`](Self::open_bidirectional_stream) `count` times, but
+        /// opens all of the streams with a single call into the connection.
+        ///
+        /// The method waits until at least one stream can be opened and then opens as many
+        /// streams as the peer's MAX_STREAMS limit and the local concurrency limits allow. As
+        /// such, the returned `Vec` may contain fewer than `count` streams and the remaining
+        /// streams should be requested with another call. An empty `Vec` is returned if `count`
+        /// is zero.
+        ///
+        /// The method will return
+        ///  - `Ok(streams)` if at least one bidirectional stream was opened
+        ///  - `Err(stream_error)` if the streams could not be opened due to an error
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let mut handle: s2n_quic::connection::Handle = todo!();
+        /// #
+        /// let mut remaining = 16;
+        /// while remaining > 0 {
+        ///     let streams = handle.open_bidirectional_streams(remaining).await?;
+        ///     remaining -= streams.len();
+        /// }
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn open_bidirectional_streams(
+            &mut self,
+            count: usize,
+        ) -> $crate::connection::Result<Vec<$crate::stream::BidirectionalStream>> {
+            futures::future::poll_fn(|cx| self.poll_open_bidirectional_streams(count, cx)).await
+        }
+
+        /// Polls opening up to `count` bidirectional streams
+        ///
+        /// The method will return
+        /// - `Poll::Ready(Ok(streams))` if at least one bidirectional stream was opened, or
+        ///   `count` is zero
+        /// - `Poll::Ready(Err(stream_error))` if the streams could not be opened due to an error
+        /// - `Poll::Pending` if no stream can be opened yet
+        #[inline]
+        pub fn poll_open_bidirectional_streams(
+            &mut self,
+            count: usize,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::connection::Result<Vec<$crate::stream::BidirectionalStream>>>
+        {
+            use s2n_quic_core::stream::StreamType;
+            use $crate::stream::BidirectionalStream;
+
+            if count == 0 {
+                return Ok(Vec::new()).into();
+            }
+
+            let streams =
+                futures::ready!(self
+                    .0
+                    .poll_open_streams(StreamType::Bidirectional, count, cx))?;
+
+            Ok(streams.into_iter().map(BidirectionalStream::new).collect()).into()
+        }
+
         /// Opens a [`SendStream`](`crate::stream::SendStream`)
         ///
         /// # Examples
@@ -149,6 +217,33 @@ macro_rules! impl_handle_api {
             self.0.remote_address().map(std::net::SocketAddr::from)
         }
 
+        /// Returns information about the path the connection is currently using
+        ///
+        /// This includes the local and remote addresses, whether the peer's address has been
+        /// validated on the path, and the round-trip time estimates for the path. Until a path is
+        /// validated, the amount of data that can be sent on it is limited by the
+        /// anti-amplification limit, so applications may want to wait for validation before
+        /// sending large amounts of data after a migration.
+        #[inline]
+        pub fn path_info(&self) -> $crate::connection::Result<$crate::connection::PathInfo> {
+            self.0.path_info().map($crate::connection::PathInfo::from)
+        }
+
+        /// Returns the number of bytes which can still be sent before reaching the
+        /// anti-amplification limit
+        ///
+        /// Until the peer's address is validated, a server may only send three times as many
+        /// bytes as it received on the path. `None` is returned once the path has been
+        /// validated, at which point the limit no longer applies. Clients consider the path to
+        /// the server as validated and always return `None`.
+        ///
+        /// This can be used to diagnose handshakes stalling on the limit, for example when the
+        /// certificate chain is too large to fit in the initial budget.
+        #[inline]
+        pub fn amplification_budget(&self) -> $crate::connection::Result<Option<u64>> {
+            self.0.amplification_budget()
+        }
+
         /// Returns the negotiated server name the connection is using.
         #[inline]
         pub fn server_name(&self) -> $crate::connection::Result<Option<$crate::server::Name>> {
@@ -161,6 +256,39 @@ macro_rules! impl_handle_api {
             self.0.application_protocol()
         }
 
+        /// Returns the group that was selected for the key exchange in the handshake
+        ///
+        /// `None` is returned if the handshake has not completed yet or the TLS provider
+        /// does not report the selected group.
+        #[inline]
+        pub fn key_exchange_group(
+            &self,
+        ) -> $crate::connection::Result<Option<$crate::provider::tls::NamedGroup>> {
+            self.0.key_exchange_group()
+        }
+
+        /// Returns the signature scheme that was selected to authenticate the handshake
+        ///
+        /// `None` is returned if the handshake has not completed yet or the TLS provider
+        /// does not report the selected scheme.
+        #[inline]
+        pub fn signature_scheme(
+            &self,
+        ) -> $crate::connection::Result<Option<$crate::provider::tls::SignatureScheme>> {
+            self.0.signature_scheme()
+        }
+
+        /// Returns the DATAGRAM frame limits that were negotiated with the peer
+        ///
+        /// `None` is returned if the handshake has not completed yet. If the peer did not
+        /// advertise support for DATAGRAM frames, `can_send` will return `false`.
+        #[inline]
+        pub fn datagram_config(
+            &self,
+        ) -> $crate::connection::Result<Option<$crate::provider::datagram::Config>> {
+            self.0.datagram_config()
+        }
+
         /// Returns the internal identifier for the [`Connection`](`crate::Connection`)
         ///
         /// Note: This internal identifier is not the same as the connection ID included in packet
@@ -176,6 +304,106 @@ macro_rules! impl_handle_api {
             self.0.ping()
         }
 
+        /// Limits the rate at which the connection sends data to the given number of bytes per
+        /// second
+        ///
+        /// The limit is applied in addition to the congestion controller, so the connection will
+        /// send at the lower of the two rates. Passing `None` removes the limit. The configured
+        /// limit is reported by the [`stats`](crate::provider::event::stats) event provider.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let mut connection: s2n_quic::connection::Connection = todo!();
+        /// #
+        /// // limit the connection to 1MB per second
+        /// connection.set_send_rate_limit(Some(1_000_000))?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn set_send_rate_limit(
+            &mut self,
+            bytes_per_sec: Option<u64>,
+        ) -> $crate::connection::Result<()> {
+            self.0.set_send_rate_limit(bytes_per_sec)
+        }
+
+        /// Gracefully closes the connection once the `deadline` is reached
+        ///
+        /// This bounds the total lifetime of the connection, regardless of its activity, unlike
+        /// the idle timeout. When the deadline is reached, the peer is notified that the connection
+        /// was closed without an error and the connection fails with
+        /// [`Error::DeadlineExceeded`](crate::connection::Error::DeadlineExceeded). Setting a new
+        /// deadline replaces the previous one.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let mut connection: s2n_quic::connection::Connection = todo!();
+        /// #
+        /// use std::time::{Duration, Instant};
+        ///
+        /// // close the connection if it's still open after 60 seconds
+        /// connection.set_deadline(Instant::now() + Duration::from_secs(60))?;
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn set_deadline(
+            &mut self,
+            deadline: std::time::Instant,
+        ) -> $crate::connection::Result<()> {
+            let timeout = deadline.saturating_duration_since(std::time::Instant::now());
+            self.0.set_deadline(timeout)
+        }
+
+        /// Gracefully closes the connection once the `cancellation` future completes
+        ///
+        /// This allows the connection's lifetime to be tied to an external signal, such as a
+        /// cancellation token or a oneshot channel, for both accepted and connected connections.
+        /// When the future completes, the peer is notified that the connection was closed without
+        /// an error and the connection fails with
+        /// [`Error::Cancelled`](crate::connection::Error::Cancelled). The future is polled by the
+        /// endpoint so it should not block. It's polled without holding the connection lock, so it
+        /// may call into the connection's handles. Setting a new cancellation replaces the previous
+        /// one.
+        ///
+        /// Accepted connections should set the cancellation as soon as they are returned from
+        /// `accept`. Client connections can instead pass it in when the connection attempt is made
+        /// with [`ConnectionAttempt::with_cancellation`], which also covers the handshake.
+        ///
+        /// [`ConnectionAttempt::with_cancellation`]: crate::client::ConnectionAttempt::with_cancellation
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::connection::Result<()> {
+        /// #   let mut connection: s2n_quic::connection::Connection = todo!();
+        /// #
+        /// let (shutdown, on_shutdown) = tokio::sync::oneshot::channel::<()>();
+        ///
+        /// // close the connection when the sender is used or dropped
+        /// connection.set_cancellation(async move {
+        ///     let _ = on_shutdown.await;
+        /// })?;
+        /// #
+        /// #   drop(shutdown);
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn set_cancellation<F>(&mut self, cancellation: F) -> $crate::connection::Result<()>
+        where
+            F: 'static + core::future::Future<Output = ()> + Send,
+        {
+            self.0.set_cancellation(Box::pin(cancellation))
+        }
+
         /// Enables or disables the connection to actively keep the connection alive with the peer
         ///
         /// This can be useful for maintaining connections beyond the configured idle timeout. The
@@ -378,6 +606,104 @@ macro_rules! impl_handle_api {
             query.into()
         }
 
+        /// Writes the qlog trace accumulated for the connection to the given `writer`
+        ///
+        /// This requires the endpoint to be configured with a
+        /// [`qlog::Subscriber`](crate::provider::event::qlog::Subscriber). If one is not
+        /// configured, an error with the [`NotFound`](std::io::ErrorKind::NotFound) kind
+        /// is returned.
+        ///
+        /// ```no_run
+        /// use s2n_quic::{provider::event::qlog, Connection, Server};
+        ///
+        /// let mut server = Server::builder()
+        ///     .with_event(qlog::Provider::default()).unwrap()
+        ///     .start().unwrap();
+        /// # let connection: Connection = todo!();
+        ///
+        /// let mut trace = vec![];
+        /// connection.export_qlog(&mut trace).unwrap();
+        /// ```
+        pub fn export_qlog<W: std::io::Write + ?Sized>(
+            &self,
+            writer: &mut W,
+        ) -> std::io::Result<()> {
+            use s2n_quic_core::event::query;
+
+            match self.query_event_context(|trace: &$crate::provider::event::qlog::Trace| {
+                trace.write_to(writer)
+            }) {
+                Ok(result) => result,
+                Err(error @ query::Error::ContextTypeMismatch) => {
+                    Err(std::io::Error::new(std::io::ErrorKind::NotFound, error))
+                }
+                Err(error) => Err(std::io::Error::new(std::io::ErrorKind::Other, error)),
+            }
+        }
+
+        /// Returns a snapshot of the transfer statistics for the connection
+        ///
+        /// This requires the endpoint to be configured with a
+        /// [`stats::Subscriber`](crate::provider::event::stats::Subscriber). If one is not
+        /// configured, a [`ContextTypeMismatch`](s2n_quic_core::event::query::Error::ContextTypeMismatch)
+        /// error is returned.
+        ///
+        /// ```no_run
+        /// use s2n_quic::{provider::event::stats, Connection, Server};
+        ///
+        /// let mut server = Server::builder()
+        ///     .with_event(stats::Provider::default()).unwrap()
+        ///     .start().unwrap();
+        /// # let connection: Connection = todo!();
+        ///
+        /// let stats = connection.stats().unwrap();
+        /// println!("sending {} bytes/s", stats.bytes_sent_per_sec);
+        /// ```
+        pub fn stats(
+            &self,
+        ) -> core::result::Result<
+            $crate::provider::event::stats::Snapshot,
+            s2n_quic_core::event::query::Error,
+        > {
+            self.query_event_context(|stats: &$crate::provider::event::stats::Stats| {
+                stats.snapshot()
+            })
+        }
+
+        /// Resets the cumulative transfer statistics for the connection and returns the
+        /// statistics from before the reset
+        ///
+        /// This allows the statistics to be reported over fixed intervals. Only the counters
+        /// which accumulate over the lifetime of the connection, such as the number of packets
+        /// and bytes sent and received, are reset. See the `Stats::reset` method of the
+        /// [`stats`](crate::provider::event::stats) provider for the full list.
+        ///
+        /// The statistics are read and reset while holding the connection lock, so no events are
+        /// missed between the two.
+        ///
+        /// Like [`Self::stats`], this requires the endpoint to be configured with a
+        /// [`stats::Subscriber`](crate::provider::event::stats::Subscriber).
+        ///
+        /// ```no_run
+        /// use s2n_quic::Connection;
+        /// # let mut connection: Connection = todo!();
+        ///
+        /// let interval = connection.reset_stats().unwrap();
+        /// println!("sent {} bytes in the last interval", interval.bytes_sent);
+        /// ```
+        pub fn reset_stats(
+            &mut self,
+        ) -> core::result::Result<
+            $crate::provider::event::stats::Snapshot,
+            s2n_quic_core::event::query::Error,
+        > {
+            self.query_event_context_mut(|stats: &mut $crate::provider::event::stats::Stats| {
+                let snapshot = stats.snapshot();
+                stats.reset();
+                snapshot
+            })
+        }
+
         /// API for querying the connection's datagram endpoint.
         ///
         ///  Provides mutable access to `Sender` or `Receiver`.
@@ -0,0 +1,594 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Address Validation tokens protected with application-provided encryption
+//!
+//! This provider builds the contents of each Retry token and leaves protecting them to a pair of
+//! `encrypt` and `decrypt` closures. It is a lower-level alternative to implementing the
+//! [`Format`](super::Format) trait, for applications which need to use their own key management,
+//! such as a key shared by a fleet of servers through a key management service.
+//!
+//! Each token binds the remote address and port, the connection IDs chosen by the client, and the
+//! time at which the token was issued. Tokens are only accepted within the configured lifetime
+//! and at most once per endpoint.
+//!
+//! The issue time is read from the system clock rather than the endpoint's monotonic clock, so
+//! tokens remain valid when they are issued and validated by different hosts. Tokens issued by a
+//! host with a clock ahead of the validating host are accepted, as long as the difference is
+//! within the token lifetime.
+//!
+//! The provider does not support tokens delivered in a NEW_TOKEN frame.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use std::error::Error;
+//! use s2n_quic::{provider::address_token::encrypted, Server};
+//! #
+//! # #[derive(Clone)]
+//! # struct Key;
+//! #
+//! # impl Key {
+//! #     fn load() -> Self {
+//! #         Self
+//! #     }
+//! #
+//! #     fn seal(&self, _plaintext: &[u8], _ciphertext: &mut [u8]) -> Option<usize> {
+//! #         unimplemented!()
+//! #     }
+//! #
+//! #     fn open(&self, _ciphertext: &[u8], _plaintext: &mut [u8]) -> Option<usize> {
+//! #         unimplemented!()
+//! #     }
+//! # }
+//! #
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn Error>> {
+//! // a key shared by all of the servers, for example through a key management service
+//! let seal_key = Key::load();
+//! let open_key = seal_key.clone();
+//!
+//! let address_token = encrypted::Provider::new(
+//!     // seal the plaintext into `ciphertext` and return the number of bytes written
+//!     move |plaintext: &[u8], ciphertext: &mut [u8]| seal_key.seal(plaintext, ciphertext),
+//!     // open the `ciphertext` into `plaintext` and return the number of bytes written
+//!     move |ciphertext: &[u8], plaintext: &mut [u8]| open_key.open(ciphertext, plaintext),
+//! );
+//!
+//! let server = Server::builder()
+//!     .with_address_token(address_token)?
+//!     .start()?;
+//! #
+//! #    Ok(())
+//! # }
+//! ```
+
+use core::{mem::size_of, time::Duration};
+use s2n_codec::DecoderBuffer;
+use s2n_quic_core::{connection, event::api::SocketAddress};
+use std::{collections::hash_map::DefaultHasher, time::SystemTime};
+use zerocopy::{AsBytes, FromBytes, Unaligned};
+
+/// The length of the plaintext passed to the `encrypt` closure
+pub const PLAINTEXT_LEN: usize = size_of::<Plaintext>();
+
+/// The maximum length of the ciphertext written by the `encrypt` closure
+///
+/// This leaves room for a nonce, an authentication tag and a key identifier for most ciphers.
+pub const MAX_CIPHERTEXT_LEN: usize = 128;
+
+const DEFAULT_TOKEN_LIFETIME: Duration = Duration::from_secs(2);
+
+const TOKEN_VERSION: u8 = 0x00;
+
+pub struct Provider<Encrypt, Decrypt> {
+    encrypt: Encrypt,
+    decrypt: Decrypt,
+    token_lifetime: Duration,
+}
+
+impl<Encrypt, Decrypt> Provider<Encrypt, Decrypt>
+where
+    Encrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+    Decrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+{
+    /// Creates a provider which protects tokens with the given closures
+    ///
+    /// `encrypt` is called with a plaintext of [`PLAINTEXT_LEN`] bytes and an output buffer of
+    /// [`MAX_CIPHERTEXT_LEN`] bytes. It returns the length of the ciphertext it wrote, or `None`
+    /// if the plaintext could not be encrypted, in which case the Retry packet is not sent.
+    ///
+    /// `decrypt` is called with a ciphertext and an output buffer of [`PLAINTEXT_LEN`] bytes.
+    /// It returns the length of the plaintext it wrote, or `None` if the ciphertext is invalid.
+    ///
+    /// The token is sent to the client, so the ciphertext MUST be authenticated, for example with
+    /// an AEAD. Otherwise clients could modify the token undetected.
+    pub fn new(encrypt: Encrypt, decrypt: Decrypt) -> Self {
+        Self {
+            encrypt,
+            decrypt,
+            token_lifetime: DEFAULT_TOKEN_LIFETIME,
+        }
+    }
+
+    /// Sets the duration for which a token is accepted after it was issued
+    ///
+    /// The lifetime should account for the largest expected round trip time, as well as any
+    /// difference between the clocks of the hosts which issue and validate tokens.
+    pub fn with_token_lifetime(mut self, token_lifetime: Duration) -> Self {
+        self.token_lifetime = token_lifetime;
+        self
+    }
+}
+
+impl<Encrypt, Decrypt> super::Provider for Provider<Encrypt, Decrypt>
+where
+    Encrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+    Decrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+{
+    type Format = Format<Encrypt, Decrypt>;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Format, Self::Error> {
+        Ok(Format {
+            encrypt: self.encrypt,
+            decrypt: self.decrypt,
+            token_lifetime: self.token_lifetime,
+            duplicate_filter: DuplicateFilter::default(),
+        })
+    }
+}
+
+pub struct Format<Encrypt, Decrypt> {
+    encrypt: Encrypt,
+    decrypt: Decrypt,
+    token_lifetime: Duration,
+
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+    //# To protect against such attacks, servers MUST ensure that
+    //# replay of tokens is prevented or limited.
+    duplicate_filter: DuplicateFilter,
+}
+
+impl<Encrypt, Decrypt> Format<Encrypt, Decrypt>
+where
+    Encrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+    Decrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+{
+    fn generate_retry_token_at(
+        &mut self,
+        context: &mut super::Context<'_>,
+        original_destination_connection_id: &connection::InitialId,
+        output_buffer: &mut [u8],
+        now: Duration,
+    ) -> Option<()> {
+        let mut nonce = [0; 16];
+        context.random.public_random_fill(&mut nonce);
+
+        let plaintext = Plaintext::new(
+            context,
+            original_destination_connection_id.as_bytes(),
+            now,
+            nonce,
+        )?;
+
+        let (len, ciphertext) = output_buffer.split_first_mut()?;
+        let ciphertext_len = (self.encrypt)(plaintext.as_bytes(), ciphertext)?;
+
+        if ciphertext_len > MAX_CIPHERTEXT_LEN {
+            return None;
+        }
+
+        *len = ciphertext_len as u8;
+
+        // ensure the remaining bytes are zeroed out
+        for b in ciphertext.iter_mut().skip(ciphertext_len) {
+            *b = 0;
+        }
+
+        Some(())
+    }
+
+    fn validate_token_at(
+        &mut self,
+        context: &mut super::Context<'_>,
+        token: &[u8],
+        now: Duration,
+    ) -> Option<connection::InitialId> {
+        if token.len() != <Self as super::Format>::TOKEN_LEN {
+            return None;
+        }
+
+        let (len, ciphertext) = token.split_first()?;
+        let padding = ciphertext.get(*len as usize..)?;
+        let ciphertext = &ciphertext[..*len as usize];
+
+        if padding.iter().any(|b| *b != 0) {
+            return None;
+        }
+
+        self.duplicate_filter.rotate(now, self.token_lifetime);
+        if self.duplicate_filter.contains(ciphertext) {
+            return None;
+        }
+
+        let mut buffer = [0; PLAINTEXT_LEN];
+        let plaintext_len = (self.decrypt)(ciphertext, &mut buffer)?;
+        let (plaintext, remaining) = DecoderBuffer::new(buffer.get(..plaintext_len)?)
+            .decode::<&Plaintext>()
+            .ok()?;
+        remaining.ensure_empty().ok()?;
+
+        if plaintext.version != TOKEN_VERSION {
+            return None;
+        }
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //# Servers SHOULD ensure that
+        //# tokens sent in Retry packets are only accepted for a short time.
+        let issued_at = Duration::from_millis(u64::from_be_bytes(plaintext.issued_at));
+        let age = if issued_at > now {
+            issued_at - now
+        } else {
+            now - issued_at
+        };
+        if age > self.token_lifetime {
+            return None;
+        }
+
+        let original_destination_connection_id = plaintext.original_destination_connection_id()?;
+
+        // rebuild the token for the current connection and make sure it matches
+        let expected = Plaintext::new(
+            context,
+            original_destination_connection_id.as_bytes(),
+            issued_at,
+            plaintext.nonce,
+        )?;
+
+        if expected.as_bytes() != plaintext.as_bytes() {
+            return None;
+        }
+
+        // Only add the token once it has been validated. This will prevent the filter from
+        // being filled with garbage tokens.
+        self.duplicate_filter.add(ciphertext);
+
+        Some(original_destination_connection_id)
+    }
+}
+
+impl<Encrypt, Decrypt> super::Format for Format<Encrypt, Decrypt>
+where
+    Encrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+    Decrypt: 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+{
+    // the ciphertext is prefixed with its length and padded to a fixed size
+    const TOKEN_LEN: usize = 1 + MAX_CIPHERTEXT_LEN;
+
+    /// The encrypted provider does not support NEW_TOKEN frame tokens
+    fn generate_new_token(
+        &mut self,
+        _context: &mut super::Context<'_>,
+        _source_connection_id: &connection::LocalId,
+        _output_buffer: &mut [u8],
+    ) -> Option<()> {
+        None
+    }
+
+    fn generate_retry_token(
+        &mut self,
+        context: &mut super::Context<'_>,
+        original_destination_connection_id: &connection::InitialId,
+        output_buffer: &mut [u8],
+    ) -> Option<()> {
+        self.generate_retry_token_at(
+            context,
+            original_destination_connection_id,
+            output_buffer,
+            unix_time()?,
+        )
+    }
+
+    fn validate_token(
+        &mut self,
+        context: &mut super::Context<'_>,
+        token: &[u8],
+    ) -> Option<connection::InitialId> {
+        self.validate_token_at(context, token, unix_time()?)
+    }
+}
+
+/// Returns the current time relative to the UNIX epoch
+fn unix_time() -> Option<Duration> {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()
+}
+
+/// The contents of a token, before encryption
+#[derive(Clone, Copy, Debug, FromBytes, AsBytes, Unaligned)]
+#[repr(C)]
+struct Plaintext {
+    version: u8,
+
+    /// The number of milliseconds since the UNIX epoch at which the token was issued
+    issued_at: [u8; 8],
+
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+    //# Tokens
+    //# sent in Retry packets SHOULD include information that allows the
+    //# server to verify that the source IP address and port in client
+    //# packets remain constant.
+    address_family: u8,
+    ip: [u8; 16],
+    port: [u8; 2],
+
+    odcid_len: u8,
+    original_destination_connection_id: [u8; 20],
+
+    peer_cid_len: u8,
+    peer_connection_id: [u8; 20],
+
+    //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+    //# An address validation token MUST be difficult to guess.  Including a
+    //# random value with at least 128 bits of entropy in the token would be
+    //# sufficient, but this depends on the server remembering the value it
+    //# sends to clients.
+    nonce: [u8; 16],
+}
+
+s2n_codec::zerocopy_value_codec!(Plaintext);
+
+impl Plaintext {
+    fn new(
+        context: &super::Context<'_>,
+        original_destination_connection_id: &[u8],
+        issued_at: Duration,
+        nonce: [u8; 16],
+    ) -> Option<Self> {
+        let mut plaintext = Self::new_zeroed();
+        plaintext.version = TOKEN_VERSION;
+        plaintext.issued_at = (issued_at.as_millis() as u64).to_be_bytes();
+        plaintext.nonce = nonce;
+
+        match context.remote_address {
+            SocketAddress::IpV4 { ip, port, .. } => {
+                plaintext.address_family = 4;
+                plaintext.ip[..ip.len()].copy_from_slice(ip);
+                plaintext.port = port.to_be_bytes();
+            }
+            SocketAddress::IpV6 { ip, port, .. } => {
+                plaintext.address_family = 6;
+                plaintext.ip.copy_from_slice(ip);
+                plaintext.port = port.to_be_bytes();
+            }
+            _ => {
+                // we are unable to bind the address so bail
+                return None;
+            }
+        }
+
+        plaintext
+            .original_destination_connection_id
+            .get_mut(..original_destination_connection_id.len())?
+            .copy_from_slice(original_destination_connection_id);
+        plaintext.odcid_len = original_destination_connection_id.len() as u8;
+
+        plaintext
+            .peer_connection_id
+            .get_mut(..context.peer_connection_id.len())?
+            .copy_from_slice(context.peer_connection_id);
+        plaintext.peer_cid_len = context.peer_connection_id.len() as u8;
+
+        Some(plaintext)
+    }
+
+    fn original_destination_connection_id(&self) -> Option<connection::InitialId> {
+        let dcid = self
+            .original_destination_connection_id
+            .get(..self.odcid_len as usize)?;
+        connection::InitialId::try_from_bytes(dcid)
+    }
+}
+
+/// Tracks the tokens accepted within the token lifetime
+///
+/// Tokens are recorded in the current filter, which replaces the previous filter after each
+/// lifetime. An accepted token is remembered for at least one lifetime, after which it has
+/// expired.
+struct DuplicateFilter {
+    current: cuckoofilter::CuckooFilter<DefaultHasher>,
+    previous: cuckoofilter::CuckooFilter<DefaultHasher>,
+    rotates_at: Duration,
+}
+
+impl Default for DuplicateFilter {
+    fn default() -> Self {
+        Self {
+            current: cuckoofilter::CuckooFilter::with_capacity(cuckoofilter::DEFAULT_CAPACITY),
+            previous: cuckoofilter::CuckooFilter::with_capacity(cuckoofilter::DEFAULT_CAPACITY),
+            rotates_at: Duration::ZERO,
+        }
+    }
+}
+
+impl DuplicateFilter {
+    fn rotate(&mut self, now: Duration, token_lifetime: Duration) {
+        if now < self.rotates_at {
+            return;
+        }
+
+        self.previous = core::mem::replace(
+            &mut self.current,
+            cuckoofilter::CuckooFilter::with_capacity(cuckoofilter::DEFAULT_CAPACITY),
+        );
+        self.rotates_at = now + token_lifetime;
+    }
+
+    fn contains(&self, ciphertext: &[u8]) -> bool {
+        self.current.contains(ciphertext) || self.previous.contains(ciphertext)
+    }
+
+    fn add(&mut self, ciphertext: &[u8]) {
+        // Ignore the outcome of adding a token to the filter because we always want to
+        // continue the connection if the filter fails.
+        let _ = self.current.add(ciphertext);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{
+        inet::SocketAddress,
+        random,
+        token::{Context, Format as FormatTrait},
+    };
+    use std::net::SocketAddr;
+
+    const KEY: u8 = 0x5a;
+    const TAG: u8 = 0xa5;
+
+    /// A toy cipher which xors the plaintext with a key and appends a tag
+    fn test_format() -> impl FormatTrait {
+        let provider = Provider::new(
+            |plaintext: &[u8], ciphertext: &mut [u8]| {
+                let ciphertext = ciphertext.get_mut(..plaintext.len() + 1)?;
+                for (c, p) in ciphertext.iter_mut().zip(plaintext) {
+                    *c = p ^ KEY;
+                }
+                ciphertext[plaintext.len()] = TAG;
+                Some(plaintext.len() + 1)
+            },
+            |ciphertext: &[u8], plaintext: &mut [u8]| {
+                let (tag, ciphertext) = ciphertext.split_last()?;
+                if *tag != TAG {
+                    return None;
+                }
+                let plaintext = plaintext.get_mut(..ciphertext.len())?;
+                for (p, c) in plaintext.iter_mut().zip(ciphertext) {
+                    *p = c ^ KEY;
+                }
+                Some(ciphertext.len())
+            },
+        );
+
+        super::super::Provider::start(provider).unwrap()
+    }
+
+    fn test_format_state() -> Format<
+        impl 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+        impl 'static + Send + FnMut(&[u8], &mut [u8]) -> Option<usize>,
+    > {
+        let copy = |input: &[u8], output: &mut [u8]| {
+            output.get_mut(..input.len())?.copy_from_slice(input);
+            Some(input.len())
+        };
+
+        Format {
+            encrypt: copy,
+            decrypt: copy,
+            token_lifetime: DEFAULT_TOKEN_LIFETIME,
+            duplicate_filter: DuplicateFilter::default(),
+        }
+    }
+
+    fn address(addr: &str) -> SocketAddress {
+        let addr: SocketAddr = addr.parse().unwrap();
+        addr.into()
+    }
+
+    #[test]
+    fn retry_token_test() {
+        let mut format = test_format();
+        let conn_id = connection::PeerId::try_from_bytes(&[2, 4, 6, 8, 10]).unwrap();
+        let other_conn_id = connection::PeerId::try_from_bytes(&[1, 3, 5, 7, 9]).unwrap();
+        let odcid = connection::InitialId::try_from_bytes(&[0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+        let addr = address("127.0.0.1:443");
+        let mut random = random::testing::Generator(5);
+
+        let mut token = [0; 1 + MAX_CIPHERTEXT_LEN];
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+        format
+            .generate_retry_token(&mut context, &odcid, &mut token)
+            .unwrap();
+        assert_eq!(token[0] as usize, PLAINTEXT_LEN + 1);
+
+        // the token is bound to the address, port and connection ID of the client
+        for (addr, conn_id) in [
+            (address("127.0.0.2:443"), conn_id),
+            (address("127.0.0.1:444"), conn_id),
+            (address("[::1]:443"), conn_id),
+            (addr, other_conn_id),
+        ] {
+            let mut context = Context::new(&addr, &conn_id, &mut random);
+            assert_eq!(format.validate_token(&mut context, &token), None);
+        }
+
+        // modified tokens are rejected by the cipher or the padding check
+        for i in [0, 1, PLAINTEXT_LEN + 1, MAX_CIPHERTEXT_LEN] {
+            let mut context = Context::new(&addr, &conn_id, &mut random);
+            token[i] = !token[i];
+            assert_eq!(format.validate_token(&mut context, &token), None);
+            token[i] = !token[i];
+        }
+
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+        assert_eq!(format.validate_token(&mut context, &token), Some(odcid));
+
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //= type=test
+        //# To protect against such attacks, servers MUST ensure that
+        //# replay of tokens is prevented or limited.
+        assert_eq!(format.validate_token(&mut context, &token), None);
+    }
+
+    #[test]
+    fn token_lifetime_test() {
+        //= https://www.rfc-editor.org/rfc/rfc9000#section-8.1.4
+        //= type=test
+        //# Servers SHOULD ensure that
+        //# tokens sent in Retry packets are only accepted for a short time.
+        let mut format = test_format_state();
+        let conn_id = connection::PeerId::TEST_ID;
+        let odcid = connection::InitialId::TEST_ID;
+        let addr = address("127.0.0.1:443");
+        let mut random = random::testing::Generator(5);
+        let issued_at = Duration::from_secs(1_000_000);
+
+        let mut token = [0; 1 + MAX_CIPHERTEXT_LEN];
+        let mut context = Context::new(&addr, &conn_id, &mut random);
+        format
+            .generate_retry_token_at(&mut context, &odcid, &mut token, issued_at)
+            .unwrap();
+
+        let now = issued_at + DEFAULT_TOKEN_LIFETIME + Duration::from_millis(1);
+        assert_eq!(format.validate_token_at(&mut context, &token, now), None);
+
+        // tokens issued by hosts with clocks slightly ahead are accepted
+        let now = issued_at - DEFAULT_TOKEN_LIFETIME / 2;
+        assert_eq!(
+            format.validate_token_at(&mut context, &token, now),
+            Some(odcid)
+        );
+    }
+
+    #[test]
+    fn duplicate_filter_test() {
+        let lifetime = DEFAULT_TOKEN_LIFETIME;
+        let mut filter = DuplicateFilter::default();
+        let start = Duration::from_secs(1_000_000);
+
+        filter.rotate(start, lifetime);
+        filter.add(b"token");
+        assert!(filter.contains(b"token"));
+
+        // the token is remembered for the following lifetime
+        filter.rotate(start + lifetime, lifetime);
+        assert!(filter.contains(b"token"));
+
+        filter.rotate(start + lifetime * 2, lifetime);
+        assert!(!filter.contains(b"token"));
+    }
+}
@@ -14,6 +14,7 @@ pub trait Provider: 'static {
 }
 
 pub mod default;
+pub mod encrypted;
 
 pub use default::Provider as Default;
 
@@ -74,6 +74,11 @@ pub mod default {
         pub fn builder() -> Builder {
             Builder::default()
         }
+
+        /// Returns the length of the generated connection Ids
+        pub fn connection_id_len(&self) -> usize {
+            self.len
+        }
     }
 
     /// A builder for [`Format`] providers
@@ -94,6 +99,12 @@ pub mod default {
 
     impl Builder {
         /// Sets the length of the generated connection Id
+        ///
+        /// Longer Ids leave more room for load balancers to encode routing information, while
+        /// shorter Ids reduce the overhead of each short header packet.
+        ///
+        /// The length must be between 4 and 20 bytes. Zero-length Ids are not supported, since the
+        /// endpoint relies on the connection Id to route incoming packets to connections.
         pub fn with_len(mut self, len: usize) -> Result<Self, connection::id::Error> {
             if !(connection::LocalId::MIN_LEN..=connection::id::MAX_LEN).contains(&len) {
                 return Err(connection::id::Error::InvalidLength);
@@ -154,6 +165,7 @@ pub mod default {
 
             for len in connection::LocalId::MIN_LEN..connection::id::MAX_LEN {
                 let mut format = Format::builder().with_len(len).unwrap().build().unwrap();
+                assert_eq!(format.connection_id_len(), len);
 
                 let id = format.generate(&connection_info);
 
@@ -4,7 +4,7 @@
 //! Allows applications to limit peer's ability to open new connections
 
 pub use s2n_quic_core::endpoint::{
-    limits::{ConnectionAttempt, Outcome},
+    limits::{ConnectionAttempt, HandshakeRateLimit, Outcome},
     Limiter,
 };
 use s2n_quic_core::{event::Timestamp, path::THROTTLED_PORTS_LEN};
@@ -180,6 +180,9 @@ pub mod default {
     #[derive(Default)]
     pub struct Builder {
         max_inflight_handshake_limit: Option<usize>,
+        memory_limit: Option<u64>,
+        expected_connections: usize,
+        handshake_rate_limit: Option<HandshakeRateLimit>,
     }
 
     impl Builder {
@@ -189,10 +192,62 @@ pub mod default {
             Ok(self)
         }
 
+        /// Sets the limit on the number of bytes the open connections may buffer
+        ///
+        /// Each connection is charged the data it has received which the application has not
+        /// consumed yet, so idle connections don't use up any of the budget. Once the charges of
+        /// the open connections reach the limit, new connection attempts are dropped and an
+        /// `EndpointMemoryPressure` event is emitted. Existing connections also stop extending
+        /// their receive windows and delay their ACKs, so peers can't grow the usage much past the
+        /// limit. The pressure is released once the usage drops below three quarters of the limit.
+        ///
+        /// Since peers can still use the window which was already advertised, the usage can exceed
+        /// the limit by at most the connection-level receive window (`data_window`) of each open
+        /// connection.
+        pub fn with_memory_limit(mut self, limit: u64) -> Result<Self, Infallible> {
+            self.memory_limit = Some(limit);
+            Ok(self)
+        }
+
+        /// Sets the limit on the number of handshakes the endpoint starts each second
+        ///
+        /// Once `handshakes_per_sec` handshakes have been started within a second, the remaining
+        /// connection attempts in that second are handled with `outcome`, and an
+        /// `EndpointHandshakeRateLimited` event is emitted. Use [`Outcome::retry()`] to require
+        /// peers to validate their address before their handshake is started, or
+        /// [`Outcome::drop()`] to refuse the attempts.
+        ///
+        /// See [`Limiter::handshake_rate_limit`] for more details.
+        pub fn with_handshake_rate_limit(
+            mut self,
+            handshakes_per_sec: usize,
+            outcome: Outcome,
+        ) -> Result<Self, Infallible> {
+            self.handshake_rate_limit = Some(HandshakeRateLimit::new(handshakes_per_sec, outcome));
+            Ok(self)
+        }
+
+        /// Sets the number of connections the endpoint expects to have open at the same time
+        ///
+        /// The endpoint reserves room for this many connections in its internal tables when it is
+        /// started, which avoids rehashing and reallocating them as traffic ramps up. The reserved
+        /// memory is held for the lifetime of the endpoint, even if fewer connections are opened,
+        /// so this should be set close to the expected peak rather than an upper bound. More
+        /// connections than expected can still be opened.
+        ///
+        /// See [`Limiter::expected_connections`] for more details.
+        pub fn with_expected_connections(mut self, count: usize) -> Result<Self, Infallible> {
+            self.expected_connections = count;
+            Ok(self)
+        }
+
         /// Build the limits
         pub fn build(self) -> Result<Limits, Infallible> {
             Ok(Limits {
                 max_inflight_handshake_limit: self.max_inflight_handshake_limit,
+                memory_limit: self.memory_limit,
+                expected_connections: self.expected_connections,
+                handshake_rate_limit: self.handshake_rate_limit,
                 rate_limiter: [BasicRateLimiter::default(); THROTTLED_PORTS_LEN],
             })
         }
@@ -202,6 +257,12 @@ pub mod default {
     pub struct Limits {
         /// Maximum number of handshakes to allow before Retry packets are queued
         max_inflight_handshake_limit: Option<usize>,
+        /// Maximum number of bytes to charge to open connections before attempts are dropped
+        memory_limit: Option<u64>,
+        /// Number of connections to reserve room for when the endpoint is started
+        expected_connections: usize,
+        /// Maximum number of handshakes to start each second
+        handshake_rate_limit: Option<HandshakeRateLimit>,
         rate_limiter: [BasicRateLimiter; THROTTLED_PORTS_LEN],
     }
 
@@ -235,6 +296,18 @@ pub mod default {
 
             Outcome::allow()
         }
+
+        fn memory_limit(&self) -> Option<u64> {
+            self.memory_limit
+        }
+
+        fn expected_connections(&self) -> usize {
+            self.expected_connections
+        }
+
+        fn handshake_rate_limit(&self) -> Option<HandshakeRateLimit> {
+            self.handshake_rate_limit
+        }
     }
 
     /// Default limit values are as non-intrusive as possible
@@ -242,6 +315,9 @@ pub mod default {
         fn default() -> Self {
             Self {
                 max_inflight_handshake_limit: None,
+                memory_limit: None,
+                expected_connections: 0,
+                handshake_rate_limit: None,
                 rate_limiter: [BasicRateLimiter::default(); THROTTLED_PORTS_LEN],
             }
         }
@@ -252,9 +328,21 @@ pub mod default {
         let elp = Limits::builder()
             .with_inflight_handshake_limit(100)
             .unwrap()
+            .with_memory_limit(1 << 30)
+            .unwrap()
+            .with_expected_connections(10_000)
+            .unwrap()
+            .with_handshake_rate_limit(500, Outcome::retry())
+            .unwrap()
             .build()
             .unwrap();
         assert_eq!(elp.max_inflight_handshake_limit, Some(100));
+        assert_eq!(elp.memory_limit(), Some(1 << 30));
+        assert_eq!(elp.expected_connections(), 10_000);
+        assert_eq!(
+            elp.handshake_rate_limit(),
+            Some(HandshakeRateLimit::new(500, Outcome::retry()))
+        );
     }
 
     #[test]
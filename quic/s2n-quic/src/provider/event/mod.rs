@@ -9,7 +9,7 @@ pub use s2n_quic_core::{
     event::{
         api as events,
         api::{ConnectionInfo, ConnectionMeta},
-        query, supervisor, Event, Meta, Subscriber, Timestamp,
+        query, supervisor, timing, Event, Meta, Subscriber, Timestamp,
     },
 };
 
@@ -24,6 +24,15 @@ pub trait Provider {
 /// Provides an implementation to disable all events
 pub mod disabled;
 
+/// Buffers a qlog trace for each connection which can be exported on demand
+pub mod qlog;
+
+/// Records compact event records into a lock-free ring buffer which can be drained on demand
+pub mod ring_buffer;
+
+/// Tracks transfer statistics for each connection and the endpoint which can be queried on demand
+pub mod stats;
+
 /// This module contains event integration with [`tracing`](https://docs.rs/tracing)
 #[cfg(any(feature = "provider-event-tracing", test))]
 pub mod tracing;
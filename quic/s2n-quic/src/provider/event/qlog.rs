@@ -0,0 +1,384 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Buffers a [qlog](https://datatracker.ietf.org/doc/draft-ietf-quic-qlog-main-schema/) trace
+//! for each connection, which can be exported on demand with
+//! [`Connection::export_qlog`](crate::Connection::export_qlog).
+//!
+//! Traces are encoded with the `JSON-SEQ` serialization format. Events which have an equivalent
+//! in the [QUIC event definitions](https://datatracker.ietf.org/doc/draft-ietf-quic-qlog-quic-events/)
+//! are recorded using the schema's names and fields. All other events are recorded in the
+//! `s2n_quic` namespace using the event's `Debug` representation.
+
+use crate::provider::event::{events, ConnectionInfo, ConnectionMeta, Event};
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    io,
+    net::{Ipv4Addr, Ipv6Addr},
+};
+
+/// The default number of events retained for each connection
+const DEFAULT_MAX_EVENTS: usize = 10_000;
+
+/// The record separator which precedes each `JSON-SEQ` record
+const RECORD_SEPARATOR: char = '\u{1e}';
+
+/// The names of the events which are recorded using the QUIC event definitions
+const SCHEMA_EVENTS: &[&str] = &[
+    events::ConnectionStarted::NAME,
+    events::ConnectionClosed::NAME,
+    events::PacketSent::NAME,
+    events::PacketReceived::NAME,
+    events::PacketLost::NAME,
+    events::RecoveryMetrics::NAME,
+    events::DatagramSent::NAME,
+    events::DatagramReceived::NAME,
+];
+
+#[derive(Debug)]
+pub struct Provider {
+    max_events: usize,
+}
+
+impl Default for Provider {
+    fn default() -> Self {
+        Self {
+            max_events: DEFAULT_MAX_EVENTS,
+        }
+    }
+}
+
+impl Provider {
+    /// Sets the maximum number of events retained for each connection
+    ///
+    /// Once the limit is reached, the oldest events are discarded.
+    pub fn with_max_events(mut self, max_events: usize) -> Self {
+        self.max_events = max_events;
+        self
+    }
+}
+
+impl super::Provider for Provider {
+    type Subscriber = Subscriber;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Subscriber, Self::Error> {
+        Ok(Subscriber {
+            max_events: self.max_events,
+        })
+    }
+}
+
+/// Records each connection event into the connection's [`Trace`]
+#[derive(Debug)]
+pub struct Subscriber {
+    max_events: usize,
+}
+
+impl Default for Subscriber {
+    fn default() -> Self {
+        Self {
+            max_events: DEFAULT_MAX_EVENTS,
+        }
+    }
+}
+
+impl super::Subscriber for Subscriber {
+    type ConnectionContext = Trace;
+
+    fn create_connection_context(
+        &mut self,
+        meta: &ConnectionMeta,
+        _info: &ConnectionInfo,
+    ) -> Self::ConnectionContext {
+        let vantage_point = match meta.endpoint_type {
+            events::EndpointType::Server { .. } => "server",
+            events::EndpointType::Client { .. } => "client",
+        };
+
+        Trace {
+            vantage_point,
+            connection_id: meta.id,
+            reference_time: millis(meta.timestamp.duration_since_start()),
+            max_events: self.max_events,
+            events: VecDeque::new(),
+        }
+    }
+
+    fn on_connection_started(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::ConnectionStarted,
+    ) {
+        context.on_event(meta, "connectivity:connection_started", |data| {
+            let path = &event.path;
+            let ip_version = match path.local_addr {
+                events::SocketAddress::IpV6 { .. } => "ipv6",
+                _ => "ipv4",
+            };
+            let _ = write!(data, "\"ip_version\":\"{}\",", ip_version);
+            write_address(data, "src", &path.local_addr);
+            data.push(',');
+            write_address(data, "dst", &path.remote_addr);
+            data.push_str(",\"src_cid\":");
+            write_connection_id(data, &path.local_cid);
+            data.push_str(",\"dst_cid\":");
+            write_connection_id(data, &path.remote_cid);
+        });
+    }
+
+    fn on_connection_closed(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::ConnectionClosed,
+    ) {
+        context.on_event(meta, "connectivity:connection_closed", |data| {
+            data.push_str("\"reason\":\"");
+            escape_json(data, &event.error.to_string());
+            data.push('"');
+        });
+    }
+
+    fn on_packet_sent(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketSent,
+    ) {
+        context.on_event(meta, "transport:packet_sent", |data| {
+            write_packet_header(data, &event.packet_header);
+        });
+    }
+
+    fn on_packet_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketReceived,
+    ) {
+        context.on_event(meta, "transport:packet_received", |data| {
+            write_packet_header(data, &event.packet_header);
+        });
+    }
+
+    fn on_packet_lost(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::PacketLost,
+    ) {
+        context.on_event(meta, "recovery:packet_lost", |data| {
+            write_packet_header(data, &event.packet_header);
+        });
+    }
+
+    fn on_recovery_metrics(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::RecoveryMetrics,
+    ) {
+        context.on_event(meta, "recovery:metrics_updated", |data| {
+            let _ = write!(
+                data,
+                "\"min_rtt\":{:.3},\"smoothed_rtt\":{:.3},\"latest_rtt\":{:.3},\"rtt_variance\":{:.3},\
+                 \"pto_count\":{},\"congestion_window\":{},\"bytes_in_flight\":{}",
+                millis(event.min_rtt),
+                millis(event.smoothed_rtt),
+                millis(event.latest_rtt),
+                millis(event.rtt_variance),
+                event.pto_count,
+                event.congestion_window,
+                event.bytes_in_flight
+            );
+        });
+    }
+
+    fn on_datagram_sent(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::DatagramSent,
+    ) {
+        context.on_event(meta, "transport:datagrams_sent", |data| {
+            let _ = write!(data, "\"count\":1,\"raw\":[{{\"length\":{}}}]", event.len);
+        });
+    }
+
+    fn on_datagram_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::DatagramReceived,
+    ) {
+        context.on_event(meta, "transport:datagrams_received", |data| {
+            let _ = write!(data, "\"count\":1,\"raw\":[{{\"length\":{}}}]", event.len);
+        });
+    }
+
+    fn on_connection_event<E: Event>(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &E,
+    ) {
+        // events with a schema definition are recorded by their own callbacks
+        if SCHEMA_EVENTS.contains(&E::NAME) {
+            return;
+        }
+
+        let name = format!("s2n_quic:{}", E::NAME.replace(':', "_"));
+        context.on_event(meta, &name, |data| {
+            data.push_str("\"debug\":\"");
+            escape_json(data, &format!("{:?}", event));
+            data.push('"');
+        });
+    }
+}
+
+/// The qlog trace accumulated for a single connection
+#[derive(Debug)]
+pub struct Trace {
+    vantage_point: &'static str,
+    connection_id: u64,
+    /// The time at which the connection was created, in milliseconds
+    reference_time: f64,
+    max_events: usize,
+    events: VecDeque<String>,
+}
+
+impl Trace {
+    /// Returns the number of events currently buffered in the trace
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if no events have been buffered in the trace
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Writes the trace to the given `writer`
+    pub fn write_to<W: io::Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        write!(
+            writer,
+            "{}{{\"qlog_version\":\"0.3\",\"qlog_format\":\"JSON-SEQ\",",
+            RECORD_SEPARATOR
+        )?;
+        writeln!(
+            writer,
+            "\"trace\":{{\"common_fields\":{{\"group_id\":\"{}\",\"time_format\":\"relative\",\
+             \"reference_time\":{:.3}}},\"vantage_point\":{{\"type\":\"{}\"}}}}}}",
+            self.connection_id, self.reference_time, self.vantage_point
+        )?;
+
+        for event in &self.events {
+            writeln!(writer, "{}{}", RECORD_SEPARATOR, event)?;
+        }
+
+        writer.flush()
+    }
+
+    /// Records an event with the given `name`, using `data` to write the fields of its data object
+    fn on_event<F: FnOnce(&mut String)>(&mut self, meta: &ConnectionMeta, name: &str, data: F) {
+        if self.max_events == 0 {
+            return;
+        }
+
+        if self.events.len() == self.max_events {
+            self.events.pop_front();
+        }
+
+        let time = millis(meta.timestamp.duration_since_start()) - self.reference_time;
+        let mut record = format!("{{\"time\":{:.3},\"name\":\"{}\",\"data\":{{", time, name);
+        data(&mut record);
+        record.push_str("}}");
+
+        self.events.push_back(record);
+    }
+}
+
+/// Returns the `duration` in fractional milliseconds, which is the unit of time used by qlog
+fn millis(duration: core::time::Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Writes the `header` field for the given packet header
+fn write_packet_header(out: &mut String, header: &events::PacketHeader) {
+    use events::PacketHeader::*;
+
+    let (packet_type, number) = match header {
+        Initial { number, .. } => ("initial", Some(number)),
+        Handshake { number, .. } => ("handshake", Some(number)),
+        ZeroRtt { number, .. } => ("0RTT", Some(number)),
+        OneRtt { number, .. } => ("1RTT", Some(number)),
+        Retry { .. } => ("retry", None),
+        VersionNegotiation { .. } => ("version_negotiation", None),
+        StatelessReset { .. } => ("stateless_reset", None),
+        _ => ("unknown", None),
+    };
+
+    let _ = write!(out, "\"header\":{{\"packet_type\":\"{}\"", packet_type);
+    if let Some(number) = number {
+        let _ = write!(out, ",\"packet_number\":{}", number);
+    }
+    out.push('}');
+}
+
+/// Writes the `<prefix>_ip` and `<prefix>_port` fields for the given address
+fn write_address(out: &mut String, prefix: &str, address: &events::SocketAddress) {
+    let (ip, port) = match address {
+        events::SocketAddress::IpV4 { ip, port, .. } => (Ipv4Addr::from(**ip).to_string(), *port),
+        events::SocketAddress::IpV6 { ip, port, .. } => (Ipv6Addr::from(**ip).to_string(), *port),
+        _ => (String::new(), 0),
+    };
+
+    let _ = write!(
+        out,
+        "\"{prefix}_ip\":\"{}\",\"{prefix}_port\":{}",
+        ip,
+        port,
+        prefix = prefix
+    );
+}
+
+/// Writes the connection ID as a hex-encoded JSON string
+fn write_connection_id(out: &mut String, id: &events::ConnectionId) {
+    out.push('"');
+    for byte in id.bytes {
+        let _ = write!(out, "{:02x}", byte);
+    }
+    out.push('"');
+}
+
+/// Appends `value` to `out` as the contents of a JSON string
+fn escape_json(out: &mut String, value: &str) {
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_json_test() {
+        let mut out = String::new();
+        escape_json(&mut out, "a \"quoted\"\tvalue\\\n\u{1e}");
+        assert_eq!(out, "a \\\"quoted\\\"\\tvalue\\\\\\n\\u001e");
+    }
+}
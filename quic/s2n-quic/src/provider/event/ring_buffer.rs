@@ -0,0 +1,528 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Records events into a preallocated, lock-free ring buffer, which can be drained from another
+//! thread with a [`Reader`].
+//!
+//! Each event is stored as a fixed-size [`Record`], so recording an event never allocates or
+//! formats. The fields of the most frequent events, such as packet numbers and datagram lengths,
+//! are kept in the record's [`Payload`]. This makes the subscriber suitable for connections with
+//! high event rates, where the cost of formatting each event, as the `tracing` subscriber does,
+//! would be prohibitive.
+//!
+//! If the buffer is full when an event is emitted, the event is discarded and counted in
+//! [`Reader::dropped`]. The buffer should be drained often enough, and sized large enough, to
+//! absorb bursts of events.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! use s2n_quic::{provider::event::ring_buffer, Server};
+//!
+//! let events = ring_buffer::Provider::default().with_capacity(1 << 16);
+//! let reader = events.reader();
+//!
+//! let server = Server::builder()
+//!     .with_event(events)?
+//!     .start()?;
+//!
+//! std::thread::spawn(move || loop {
+//!     for record in reader.drain() {
+//!         println!(
+//!             "{:?} {:?} {} {:?}",
+//!             record.timestamp, record.connection_id, record.name, record.payload
+//!         );
+//!     }
+//!     std::thread::sleep(std::time::Duration::from_millis(100));
+//! });
+//! #
+//! #   Ok(())
+//! # }
+//! ```
+
+use crate::provider::event::{events, ConnectionInfo, ConnectionMeta, Event, Meta};
+use core::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+use std::sync::Arc;
+
+/// The default number of records the buffer can hold
+const DEFAULT_CAPACITY: usize = 4096;
+
+/// The maximum number of records the buffer can hold
+pub const MAX_CAPACITY: usize = 1 << 24;
+
+/// A compact record of an emitted event
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Record {
+    /// The name of the event
+    pub name: &'static str,
+    /// The internal id of the connection which emitted the event
+    ///
+    /// This is `None` for endpoint-level events.
+    pub connection_id: Option<u64>,
+    /// The time at which the event was emitted, relative to an unspecified starting point
+    ///
+    /// See [`Timestamp::duration_since_start`](super::Timestamp::duration_since_start).
+    pub timestamp: Duration,
+    /// The data recorded for the event
+    pub payload: Payload,
+}
+
+/// The fields recorded for an event
+///
+/// Only the fields of the most frequent events are recorded, so each [`Record`] has a fixed size.
+/// All other events are recorded with [`Payload::None`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Payload {
+    /// The event doesn't record any fields
+    None,
+    #[non_exhaustive]
+    PacketSent {
+        /// The packet number, if the packet type has one
+        packet_number: Option<u64>,
+    },
+    #[non_exhaustive]
+    PacketReceived {
+        /// The packet number, if the packet type has one
+        packet_number: Option<u64>,
+    },
+    #[non_exhaustive]
+    PacketLost {
+        /// The packet number, if the packet type has one
+        packet_number: Option<u64>,
+        bytes_lost: u16,
+    },
+    #[non_exhaustive]
+    DatagramSent { len: u16 },
+    #[non_exhaustive]
+    DatagramReceived { len: u16 },
+}
+
+impl Default for Payload {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+#[inline]
+fn packet_number(header: &events::PacketHeader) -> Option<u64> {
+    match header {
+        events::PacketHeader::Initial { number, .. }
+        | events::PacketHeader::Handshake { number, .. }
+        | events::PacketHeader::ZeroRtt { number, .. }
+        | events::PacketHeader::OneRtt { number, .. } => Some(*number),
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct Provider {
+    buffer: Arc<Buffer>,
+}
+
+impl Default for Provider {
+    fn default() -> Self {
+        Self {
+            buffer: Arc::new(Buffer::new(DEFAULT_CAPACITY)),
+        }
+    }
+}
+
+impl Provider {
+    /// Sets the number of records the buffer can hold
+    ///
+    /// The capacity is rounded up to the next power of two and limited to [`MAX_CAPACITY`].
+    /// Calling this method replaces the buffer, so any previously returned [`Reader`] will not
+    /// observe events recorded by the subscriber.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.buffer = Arc::new(Buffer::new(capacity));
+        self
+    }
+
+    /// Returns a handle for draining the recorded events
+    ///
+    /// The handle can be cloned and sent to other threads. Each record is only returned to a
+    /// single reader.
+    pub fn reader(&self) -> Reader {
+        Reader {
+            buffer: self.buffer.clone(),
+        }
+    }
+}
+
+impl super::Provider for Provider {
+    type Subscriber = Subscriber;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Subscriber, Self::Error> {
+        Ok(Subscriber {
+            buffer: self.buffer,
+            payload: Payload::None,
+        })
+    }
+}
+
+/// Writes a [`Record`] into the ring buffer for each emitted event
+#[derive(Debug)]
+pub struct Subscriber {
+    buffer: Arc<Buffer>,
+    /// The payload of the event being published
+    ///
+    /// The event-specific callbacks are called before `on_event`, which takes the payload when
+    /// writing the record.
+    payload: Payload,
+}
+
+impl super::Subscriber for Subscriber {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        _meta: &ConnectionMeta,
+        _info: &ConnectionInfo,
+    ) -> Self::ConnectionContext {
+    }
+
+    #[inline]
+    fn on_packet_sent(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::PacketSent,
+    ) {
+        self.payload = Payload::PacketSent {
+            packet_number: packet_number(&event.packet_header),
+        };
+    }
+
+    #[inline]
+    fn on_packet_received(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::PacketReceived,
+    ) {
+        self.payload = Payload::PacketReceived {
+            packet_number: packet_number(&event.packet_header),
+        };
+    }
+
+    #[inline]
+    fn on_packet_lost(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::PacketLost,
+    ) {
+        self.payload = Payload::PacketLost {
+            packet_number: packet_number(&event.packet_header),
+            bytes_lost: event.bytes_lost,
+        };
+    }
+
+    #[inline]
+    fn on_datagram_sent(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::DatagramSent,
+    ) {
+        self.payload = Payload::DatagramSent { len: event.len };
+    }
+
+    #[inline]
+    fn on_datagram_received(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::DatagramReceived,
+    ) {
+        self.payload = Payload::DatagramReceived { len: event.len };
+    }
+
+    #[inline]
+    fn on_event<M: Meta, E: Event>(&mut self, meta: &M, _event: &E) {
+        let connection_id = match meta.subject() {
+            events::Subject::Connection { id, .. } => Some(id),
+            _ => None,
+        };
+
+        self.buffer.push(Record {
+            name: E::NAME,
+            connection_id,
+            timestamp: meta.timestamp().duration_since_start(),
+            payload: core::mem::take(&mut self.payload),
+        });
+    }
+}
+
+/// A handle for draining the events recorded by a [`Subscriber`]
+#[derive(Clone, Debug)]
+pub struct Reader {
+    buffer: Arc<Buffer>,
+}
+
+impl Reader {
+    /// Returns an iterator which removes the currently recorded events from the buffer, from
+    /// oldest to newest
+    ///
+    /// Events recorded while iterating may also be returned.
+    pub fn drain(&self) -> Drain {
+        Drain {
+            buffer: &self.buffer,
+        }
+    }
+
+    /// Returns the number of records which can be held by the buffer
+    pub fn capacity(&self) -> usize {
+        self.buffer.slots.len()
+    }
+
+    /// Returns the total number of events which were discarded because the buffer was full
+    pub fn dropped(&self) -> u64 {
+        self.buffer.dropped.load(Ordering::Relaxed)
+    }
+}
+
+/// An iterator over the records removed from the buffer
+///
+/// Returned by [`Reader::drain`].
+#[derive(Debug)]
+pub struct Drain<'a> {
+    buffer: &'a Buffer,
+}
+
+impl<'a> Iterator for Drain<'a> {
+    type Item = Record;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buffer.pop()
+    }
+}
+
+/// Returns the number of slots allocated for the requested capacity
+#[inline]
+fn slot_count(capacity: usize) -> usize {
+    // the sequence numbers require at least 2 slots to distinguish full and empty slots. The
+    // capacity is limited before rounding up so it can't overflow.
+    capacity.clamp(2, MAX_CAPACITY).next_power_of_two()
+}
+
+/// A bounded multi-producer, multi-consumer queue
+///
+/// Each slot carries a sequence number which indicates whether the slot is ready to be written
+/// or read for a given position, which allows producers and consumers to claim slots without
+/// locking.
+struct Buffer {
+    slots: Box<[Slot]>,
+    mask: usize,
+    /// The position of the next slot to write
+    tail: AtomicUsize,
+    /// The position of the next slot to read
+    head: AtomicUsize,
+    dropped: AtomicU64,
+}
+
+struct Slot {
+    sequence: AtomicUsize,
+    record: UnsafeCell<MaybeUninit<Record>>,
+}
+
+// Safety: access to each slot's record is synchronized with the slot's sequence number
+unsafe impl Sync for Buffer {}
+
+impl core::fmt::Debug for Buffer {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Buffer")
+            .field("capacity", &self.slots.len())
+            .field("dropped", &self.dropped)
+            .finish()
+    }
+}
+
+impl Buffer {
+    fn new(capacity: usize) -> Self {
+        let capacity = slot_count(capacity);
+
+        let slots = (0..capacity)
+            .map(|index| Slot {
+                sequence: AtomicUsize::new(index),
+                record: UnsafeCell::new(MaybeUninit::uninit()),
+            })
+            .collect();
+
+        Self {
+            slots,
+            mask: capacity - 1,
+            tail: AtomicUsize::new(0),
+            head: AtomicUsize::new(0),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    #[inline]
+    fn push(&self, record: Record) {
+        let mut position = self.tail.load(Ordering::Relaxed);
+
+        loop {
+            let slot = &self.slots[position & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            let diff = sequence.wrapping_sub(position) as isize;
+
+            if diff == 0 {
+                // the slot is free for this position so try to claim it
+                match self.tail.compare_exchange_weak(
+                    position,
+                    position.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Safety: the slot was claimed by this producer and won't be read until
+                        // the sequence number is updated
+                        unsafe {
+                            (*slot.record.get()).as_mut_ptr().write(record);
+                        }
+                        slot.sequence
+                            .store(position.wrapping_add(1), Ordering::Release);
+                        return;
+                    }
+                    Err(actual) => position = actual,
+                }
+            } else if diff < 0 {
+                // the slot still holds a record from the previous lap so the buffer is full
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                return;
+            } else {
+                // another producer claimed the slot
+                position = self.tail.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    #[inline]
+    fn pop(&self) -> Option<Record> {
+        let mut position = self.head.load(Ordering::Relaxed);
+
+        loop {
+            let slot = &self.slots[position & self.mask];
+            let sequence = slot.sequence.load(Ordering::Acquire);
+            let diff = sequence.wrapping_sub(position.wrapping_add(1)) as isize;
+
+            if diff == 0 {
+                // the slot holds a record for this position so try to claim it
+                match self.head.compare_exchange_weak(
+                    position,
+                    position.wrapping_add(1),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        // Safety: the record was initialized by the producer before the sequence
+                        // number was updated and the slot was claimed by this consumer
+                        let record = unsafe { (*slot.record.get()).as_ptr().read() };
+                        slot.sequence.store(
+                            position.wrapping_add(self.mask).wrapping_add(1),
+                            Ordering::Release,
+                        );
+                        return Some(record);
+                    }
+                    Err(actual) => position = actual,
+                }
+            } else if diff < 0 {
+                // the slot hasn't been written for this position so the buffer is empty
+                return None;
+            } else {
+                // another consumer claimed the slot
+                position = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: u64) -> Record {
+        Record {
+            name: "test",
+            connection_id: Some(id),
+            timestamp: Duration::from_millis(id),
+            payload: Payload::PacketSent {
+                packet_number: Some(id),
+            },
+        }
+    }
+
+    #[test]
+    fn buffer_test() {
+        let buffer = Buffer::new(3);
+        assert_eq!(buffer.slots.len(), 4);
+        assert_eq!(buffer.pop(), None);
+
+        // wrap around the buffer multiple times
+        for lap in 0..3 {
+            let start = lap * 10;
+            for id in start..start + 6 {
+                buffer.push(record(id));
+            }
+
+            // only the first 4 records fit in the buffer
+            for id in start..start + 4 {
+                assert_eq!(buffer.pop(), Some(record(id)));
+            }
+            assert_eq!(buffer.pop(), None);
+        }
+
+        assert_eq!(buffer.dropped.load(Ordering::Relaxed), 6);
+    }
+
+    #[test]
+    fn capacity_test() {
+        assert_eq!(Provider::default().reader().capacity(), DEFAULT_CAPACITY);
+        assert_eq!(Provider::default().with_capacity(0).reader().capacity(), 2);
+        assert_eq!(Provider::default().with_capacity(5).reader().capacity(), 8);
+
+        // capacities which would overflow when rounded up are limited
+        assert_eq!(slot_count(usize::MAX), MAX_CAPACITY);
+        assert_eq!(slot_count(usize::MAX / 2 + 2), MAX_CAPACITY);
+        assert_eq!(slot_count(MAX_CAPACITY + 1), MAX_CAPACITY);
+    }
+
+    #[test]
+    fn concurrent_test() {
+        const COUNT: u64 = 10_000;
+
+        let provider = Provider::default().with_capacity(64);
+        let reader = provider.reader();
+        let buffer = provider.buffer.clone();
+
+        let producer = std::thread::spawn(move || {
+            for id in 0..COUNT {
+                buffer.push(record(id));
+            }
+        });
+
+        // every record is either received or dropped, so this completes once the producer does
+        let mut received = vec![];
+        while received.len() as u64 + reader.dropped() < COUNT {
+            received.extend(reader.drain().map(|record| record.connection_id.unwrap()));
+        }
+
+        producer.join().unwrap();
+
+        // records are returned in the order they were recorded
+        assert!(received.windows(2).all(|ids| ids[0] < ids[1]));
+        assert_eq!(reader.drain().next(), None);
+    }
+}
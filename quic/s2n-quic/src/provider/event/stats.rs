@@ -0,0 +1,834 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tracks transfer statistics for each connection, which can be queried with
+//! [`Connection::stats`](crate::Connection::stats).
+//!
+//! Each connection accumulates cumulative counters along with smoothed send and receive rates,
+//! which can be reset with [`Connection::reset_stats`](crate::Connection::reset_stats). Statistics
+//! which apply to the entire endpoint can be queried with the handle returned by
+//! [`Provider::endpoint_stats`].
+
+use crate::{
+    connection::Tags,
+    provider::event::{events, supervisor, ConnectionInfo, ConnectionMeta, Event, Timestamp},
+};
+use core::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+use std::sync::Arc;
+
+/// The interval over which each rate sample is taken
+///
+/// The interval is closed out by a supervisor timer, even if no events occur on the connection.
+/// Each interval without any activity is sampled as a rate of 0, so the rates of an idle
+/// connection decay rather than reporting the last active interval.
+pub const RATE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The weight given to each new rate sample
+///
+/// Rates are smoothed with an exponentially weighted moving average:
+///
+/// ```text
+/// rate = RATE_SMOOTHING_FACTOR * sample + (1 - RATE_SMOOTHING_FACTOR) * rate
+/// ```
+pub const RATE_SMOOTHING_FACTOR: f64 = 0.25;
+
+#[derive(Debug, Default)]
+pub struct Provider {
+    endpoint: EndpointStats,
+}
+
+impl Provider {
+    /// Returns a handle for querying the statistics of the endpoint
+    ///
+    /// The handle can be cloned and remains valid after the provider is passed to the endpoint.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), Box<dyn std::error::Error>> {
+    /// use s2n_quic::{provider::event::stats, Server};
+    ///
+    /// let stats = stats::Provider::default();
+    /// let endpoint_stats = stats.endpoint_stats();
+    ///
+    /// let server = Server::builder()
+    ///     .with_event(stats)?
+    ///     .start()?;
+    ///
+    /// let average_batch_size = endpoint_stats.snapshot().average_rx_batch_size;
+    /// #
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn endpoint_stats(&self) -> EndpointStats {
+        self.endpoint.clone()
+    }
+}
+
+impl super::Provider for Provider {
+    type Subscriber = Subscriber;
+    type Error = core::convert::Infallible;
+
+    fn start(self) -> Result<Self::Subscriber, Self::Error> {
+        Ok(Subscriber {
+            endpoint: self.endpoint,
+        })
+    }
+}
+
+/// Records the transfer statistics of each connection and of the endpoint
+#[derive(Debug, Default)]
+pub struct Subscriber {
+    endpoint: EndpointStats,
+}
+
+impl super::Subscriber for Subscriber {
+    type ConnectionContext = Stats;
+
+    fn create_connection_context(
+        &mut self,
+        meta: &ConnectionMeta,
+        _info: &ConnectionInfo,
+    ) -> Self::ConnectionContext {
+        Stats::new(meta.timestamp, meta.tags.clone())
+    }
+
+    fn supervisor_timeout(
+        &mut self,
+        _conn_context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        _context: &supervisor::Context,
+    ) -> Option<Duration> {
+        Some(RATE_INTERVAL)
+    }
+
+    fn on_supervisor_timeout(
+        &mut self,
+        conn_context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _context: &supervisor::Context,
+    ) -> supervisor::Outcome {
+        conn_context.latest_event = meta.timestamp;
+        conn_context.close_intervals(meta.timestamp);
+        supervisor::Outcome::Continue
+    }
+
+    fn on_connection_event<E: Event>(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &E,
+    ) {
+        context.latest_event = meta.timestamp;
+    }
+
+    fn on_connection_started(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::ConnectionStarted,
+    ) {
+        context.local_connection_id_len = event.path.local_cid.bytes.len();
+    }
+
+    fn on_packet_sent(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &events::PacketSent,
+    ) {
+        context.on_packet_sent(meta.timestamp);
+    }
+
+    fn on_packet_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &events::PacketReceived,
+    ) {
+        context.on_packet_received(meta.timestamp);
+    }
+
+    fn on_packet_lost(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::PacketLost,
+    ) {
+        context.on_packet_lost(event.bytes_lost as u64);
+    }
+
+    fn on_datagram_sent(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::DatagramSent,
+    ) {
+        context.on_datagram_sent(meta.timestamp, event.len as u64);
+    }
+
+    fn on_datagram_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        event: &events::DatagramReceived,
+    ) {
+        context.on_datagram_received(meta.timestamp, event.len as u64);
+    }
+
+    fn on_send_rate_limit_updated(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::SendRateLimitUpdated,
+    ) {
+        context.send_rate_limit = event.bytes_per_sec;
+    }
+
+    fn on_transport_parameters_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::TransportParametersReceived,
+    ) {
+        context.peer_max_ack_delay = Some(event.transport_parameters.max_ack_delay);
+    }
+
+    fn on_recovery_metrics(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::RecoveryMetrics,
+    ) {
+        context.effective_max_ack_delay = Some(event.max_ack_delay);
+    }
+
+    fn on_crypto_retransmitted(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        event: &events::CryptoRetransmitted,
+    ) {
+        context.crypto_packets_retransmitted += 1;
+        context.crypto_bytes_retransmitted += event.bytes;
+    }
+
+    fn on_congestion_limited(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &events::CongestionLimited,
+    ) {
+        context.congestion_limited_since = Some(meta.timestamp);
+    }
+
+    fn on_congestion_limit_ended(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &events::CongestionLimitEnded,
+    ) {
+        // measure from the start of the period, or from the last reset if it occurred since
+        if let Some(since) = context.congestion_limited_since.take() {
+            context.congestion_limited_duration += meta.timestamp.saturating_duration_since(since);
+        }
+    }
+
+    fn on_rtt_sample(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        meta: &ConnectionMeta,
+        _event: &events::RttSample,
+    ) {
+        context.on_rtt_sample(meta.timestamp);
+    }
+
+    fn on_duplicate_ack_received(
+        &mut self,
+        context: &mut Self::ConnectionContext,
+        _meta: &ConnectionMeta,
+        _event: &events::DuplicateAckReceived,
+    ) {
+        context.duplicate_acks_received += 1;
+    }
+
+    fn on_platform_rx(&mut self, _meta: &events::EndpointMeta, event: &events::PlatformRx) {
+        self.endpoint.on_platform_rx(event.count as u64);
+    }
+
+    fn on_io_recv_overflow(
+        &mut self,
+        _meta: &events::EndpointMeta,
+        event: &events::IoRecvOverflow,
+    ) {
+        self.endpoint.on_io_recv_overflow(event.count);
+    }
+}
+
+/// A handle to the statistics of an endpoint
+///
+/// Returned by [`Provider::endpoint_stats`].
+#[derive(Clone, Debug, Default)]
+pub struct EndpointStats(Arc<EndpointCounters>);
+
+#[derive(Debug, Default)]
+struct EndpointCounters {
+    rx_batches: AtomicU64,
+    rx_datagrams: AtomicU64,
+    rx_overflow_datagrams: AtomicU64,
+}
+
+impl EndpointStats {
+    /// Returns a copy of the current statistics
+    pub fn snapshot(&self) -> EndpointSnapshot {
+        let rx_batches = self.0.rx_batches.load(Ordering::Relaxed);
+        let rx_datagrams = self.0.rx_datagrams.load(Ordering::Relaxed);
+        let rx_overflow_datagrams = self.0.rx_overflow_datagrams.load(Ordering::Relaxed);
+
+        let average_rx_batch_size = if rx_batches > 0 {
+            rx_datagrams as f64 / rx_batches as f64
+        } else {
+            0.0
+        };
+
+        EndpointSnapshot {
+            rx_batches,
+            rx_datagrams,
+            average_rx_batch_size,
+            rx_overflow_datagrams,
+        }
+    }
+
+    fn on_platform_rx(&self, count: u64) {
+        self.0.rx_batches.fetch_add(1, Ordering::Relaxed);
+        self.0.rx_datagrams.fetch_add(count, Ordering::Relaxed);
+    }
+
+    fn on_io_recv_overflow(&self, count: u64) {
+        self.0
+            .rx_overflow_datagrams
+            .fetch_add(count, Ordering::Relaxed);
+    }
+}
+
+/// A point-in-time copy of the statistics of an endpoint
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct EndpointSnapshot {
+    /// The number of batches of datagrams received from the socket
+    pub rx_batches: u64,
+    /// The total number of datagrams received from the socket
+    pub rx_datagrams: u64,
+    /// The average number of datagrams received in each batch
+    ///
+    /// This can be used to tune the receive batch size of the IO provider.
+    pub average_rx_batch_size: f64,
+    /// The total number of datagrams dropped by the operating system because the socket receive
+    /// buffer was full
+    ///
+    /// This is only reported on platforms which support `SO_RXQ_OVFL`. A growing count indicates
+    /// the receive buffer size should be increased.
+    pub rx_overflow_datagrams: u64,
+}
+
+/// A point-in-time copy of the transfer statistics of a connection
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Snapshot {
+    /// The total number of packets sent
+    pub packets_sent: u64,
+    /// The total number of packets received
+    pub packets_received: u64,
+    /// The total number of UDP payload bytes sent
+    pub bytes_sent: u64,
+    /// The total number of UDP payload bytes received
+    pub bytes_received: u64,
+    /// The total number of packets declared lost
+    pub packets_lost: u64,
+    /// The total number of bytes in packets declared lost
+    pub bytes_lost: u64,
+    /// The smoothed number of packets sent per second
+    pub packets_sent_per_sec: f64,
+    /// The smoothed number of packets received per second
+    pub packets_received_per_sec: f64,
+    /// The smoothed number of bytes sent per second
+    pub bytes_sent_per_sec: f64,
+    /// The smoothed number of bytes received per second
+    pub bytes_received_per_sec: f64,
+    /// The maximum number of bytes per second the application allows the connection to send
+    ///
+    /// See [`Connection::set_send_rate_limit`](crate::Connection::set_send_rate_limit).
+    pub send_rate_limit: Option<u64>,
+    /// The tags attached to the connection, which can be used to label the statistics
+    pub tags: Tags,
+    /// The `max_ack_delay` declared by the peer in its transport parameters
+    pub peer_max_ack_delay: Option<Duration>,
+    /// The `max_ack_delay` of the peer used for loss detection
+    ///
+    /// This differs from `peer_max_ack_delay` when the declared value is clamped with
+    /// [`with_peer_max_ack_delay_limit`](crate::provider::limits::Limits).
+    pub effective_max_ack_delay: Option<Duration>,
+    /// The number of Initial and Handshake packets which retransmitted CRYPTO data
+    ///
+    /// These are counted separately from other retransmissions, since they indicate the handshake
+    /// was delayed by packet loss.
+    pub crypto_packets_retransmitted: u64,
+    /// The number of CRYPTO bytes which were retransmitted in Initial and Handshake packets
+    pub crypto_bytes_retransmitted: u64,
+    /// The length, in bytes, of the local connection ID the connection started with
+    ///
+    /// This can be used to verify the configuration of the
+    /// [`connection_id`](crate::provider::connection_id) provider.
+    pub local_connection_id_len: usize,
+    /// The total amount of time the connection was congestion limited
+    ///
+    /// A connection is congestion limited when the congestion window is full while it has data
+    /// waiting to be sent, which distinguishes connections limited by the network from those
+    /// limited by flow control or the application.
+    ///
+    /// If the connection is currently congestion limited, the period is included up to the latest
+    /// event on the connection.
+    pub congestion_limited_duration: Duration,
+    /// The number of RTT samples taken
+    pub rtt_samples: u64,
+    /// The longest time between two consecutive RTT samples
+    ///
+    /// This indicates how fresh the RTT estimate is. A connection which mostly receives data only
+    /// produces samples when its ACKs are acknowledged; see
+    /// [`Limits::with_ack_elicitation`](crate::provider::limits::Limits::with_ack_elicitation).
+    pub max_rtt_sample_interval: Duration,
+    /// The number of ACK frames received which didn't acknowledge any new packets
+    ///
+    /// A growing count can indicate a peer which sends redundant or replayed ACKs.
+    pub duplicate_acks_received: u64,
+}
+
+/// The transfer statistics accumulated for a single connection
+#[derive(Debug)]
+pub struct Stats {
+    packets_sent: Rate,
+    packets_received: Rate,
+    bytes_sent: Rate,
+    bytes_received: Rate,
+    packets_lost: u64,
+    bytes_lost: u64,
+    send_rate_limit: Option<u64>,
+    tags: Tags,
+    peer_max_ack_delay: Option<Duration>,
+    effective_max_ack_delay: Option<Duration>,
+    crypto_packets_retransmitted: u64,
+    crypto_bytes_retransmitted: u64,
+    local_connection_id_len: usize,
+    congestion_limited_duration: Duration,
+    /// The start of the current congestion limited period, or the last reset if it occurred since
+    congestion_limited_since: Option<Timestamp>,
+    /// The time of the latest event on the connection
+    latest_event: Timestamp,
+    rtt_samples: u64,
+    latest_rtt_sample: Option<Timestamp>,
+    max_rtt_sample_interval: Duration,
+    duplicate_acks_received: u64,
+}
+
+impl Stats {
+    fn new(now: Timestamp, tags: Tags) -> Self {
+        Self {
+            packets_sent: Rate::new(now),
+            packets_received: Rate::new(now),
+            bytes_sent: Rate::new(now),
+            bytes_received: Rate::new(now),
+            packets_lost: 0,
+            bytes_lost: 0,
+            send_rate_limit: None,
+            tags,
+            peer_max_ack_delay: None,
+            effective_max_ack_delay: None,
+            crypto_packets_retransmitted: 0,
+            crypto_bytes_retransmitted: 0,
+            local_connection_id_len: 0,
+            congestion_limited_duration: Duration::ZERO,
+            congestion_limited_since: None,
+            latest_event: now,
+            rtt_samples: 0,
+            latest_rtt_sample: None,
+            max_rtt_sample_interval: Duration::ZERO,
+            duplicate_acks_received: 0,
+        }
+    }
+
+    /// Returns a copy of the current statistics
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            packets_sent: self.packets_sent.total,
+            packets_received: self.packets_received.total,
+            bytes_sent: self.bytes_sent.total,
+            bytes_received: self.bytes_received.total,
+            packets_lost: self.packets_lost,
+            bytes_lost: self.bytes_lost,
+            packets_sent_per_sec: self.packets_sent.smoothed,
+            packets_received_per_sec: self.packets_received.smoothed,
+            bytes_sent_per_sec: self.bytes_sent.smoothed,
+            bytes_received_per_sec: self.bytes_received.smoothed,
+            send_rate_limit: self.send_rate_limit,
+            tags: self.tags.clone(),
+            peer_max_ack_delay: self.peer_max_ack_delay,
+            effective_max_ack_delay: self.effective_max_ack_delay,
+            crypto_packets_retransmitted: self.crypto_packets_retransmitted,
+            crypto_bytes_retransmitted: self.crypto_bytes_retransmitted,
+            local_connection_id_len: self.local_connection_id_len,
+            congestion_limited_duration: self.congestion_limited_duration(),
+            rtt_samples: self.rtt_samples,
+            max_rtt_sample_interval: self.max_rtt_sample_interval,
+            duplicate_acks_received: self.duplicate_acks_received,
+        }
+    }
+
+    /// Resets the cumulative counters to zero
+    ///
+    /// The following statistics are reset:
+    ///
+    /// * `packets_sent`, `packets_received`, `bytes_sent` and `bytes_received`
+    /// * `packets_lost` and `bytes_lost`
+    /// * `crypto_packets_retransmitted` and `crypto_bytes_retransmitted`
+    /// * `congestion_limited_duration`
+    /// * `rtt_samples` and `max_rtt_sample_interval`
+    /// * `duplicate_acks_received`
+    ///
+    /// All other statistics are preserved. The smoothed rates already reflect recent activity, so
+    /// they continue from their current values, and the remaining fields describe the state of the
+    /// connection rather than accumulating over time.
+    pub fn reset(&mut self) {
+        self.packets_sent.total = 0;
+        self.packets_received.total = 0;
+        self.bytes_sent.total = 0;
+        self.bytes_received.total = 0;
+        self.packets_lost = 0;
+        self.bytes_lost = 0;
+        self.crypto_packets_retransmitted = 0;
+        self.crypto_bytes_retransmitted = 0;
+        self.congestion_limited_duration = Duration::ZERO;
+        if self.congestion_limited_since.is_some() {
+            // the current period continues to be counted from the reset
+            self.congestion_limited_since = Some(self.latest_event);
+        }
+        self.rtt_samples = 0;
+        self.max_rtt_sample_interval = Duration::ZERO;
+        self.duplicate_acks_received = 0;
+    }
+
+    /// Returns the time spent congestion limited, including the current period
+    fn congestion_limited_duration(&self) -> Duration {
+        let current = self
+            .congestion_limited_since
+            .map_or(Duration::ZERO, |since| {
+                self.latest_event.saturating_duration_since(since)
+            });
+        self.congestion_limited_duration + current
+    }
+
+    /// Closes out the rate sampling intervals which have elapsed by `now`
+    fn close_intervals(&mut self, now: Timestamp) {
+        self.packets_sent.close_intervals(now);
+        self.packets_received.close_intervals(now);
+        self.bytes_sent.close_intervals(now);
+        self.bytes_received.close_intervals(now);
+    }
+
+    fn on_packet_sent(&mut self, now: Timestamp) {
+        self.packets_sent.record(now, 1);
+    }
+
+    fn on_packet_received(&mut self, now: Timestamp) {
+        self.packets_received.record(now, 1);
+    }
+
+    fn on_packet_lost(&mut self, bytes: u64) {
+        self.packets_lost += 1;
+        self.bytes_lost += bytes;
+    }
+
+    fn on_datagram_sent(&mut self, now: Timestamp, len: u64) {
+        self.bytes_sent.record(now, len);
+    }
+
+    fn on_datagram_received(&mut self, now: Timestamp, len: u64) {
+        self.bytes_received.record(now, len);
+    }
+
+    fn on_rtt_sample(&mut self, now: Timestamp) {
+        if let Some(previous) = self.latest_rtt_sample {
+            let interval = now.saturating_duration_since(previous);
+            self.max_rtt_sample_interval = self.max_rtt_sample_interval.max(interval);
+        }
+        self.latest_rtt_sample = Some(now);
+        self.rtt_samples += 1;
+    }
+}
+
+/// A cumulative counter with an EWMA-smoothed per-second rate
+#[derive(Debug)]
+struct Rate {
+    total: u64,
+    /// The amount recorded in the current sampling interval
+    pending: u64,
+    interval_start: Timestamp,
+    smoothed: f64,
+    has_sample: bool,
+}
+
+impl Rate {
+    fn new(now: Timestamp) -> Self {
+        Self {
+            total: 0,
+            pending: 0,
+            interval_start: now,
+            smoothed: 0.0,
+            has_sample: false,
+        }
+    }
+
+    fn record(&mut self, now: Timestamp, amount: u64) {
+        self.close_intervals(now);
+
+        self.total += amount;
+        self.pending += amount;
+    }
+
+    /// Takes a sample if the current interval has elapsed by `now`
+    ///
+    /// Any additional intervals which elapsed without recording an amount are sampled as 0.
+    fn close_intervals(&mut self, now: Timestamp) {
+        let elapsed = now.saturating_duration_since(self.interval_start);
+
+        if elapsed < RATE_INTERVAL {
+            return;
+        }
+
+        // the pending amount was recorded in the first interval, so only the intervals after it
+        // are idle
+        let idle_intervals = elapsed.as_nanos() / RATE_INTERVAL.as_nanos() - 1;
+        let sampled = elapsed - RATE_INTERVAL * idle_intervals.min(u32::MAX as u128) as u32;
+        let sample = self.pending as f64 / sampled.as_secs_f64();
+
+        self.smoothed = if self.has_sample {
+            RATE_SMOOTHING_FACTOR * sample + (1.0 - RATE_SMOOTHING_FACTOR) * self.smoothed
+        } else {
+            // use the first sample as-is rather than smoothing it with an initial rate of 0
+            sample
+        };
+
+        // each idle interval is sampled as 0
+        let idle_intervals = idle_intervals.min(i32::MAX as u128) as i32;
+        self.smoothed *= (1.0 - RATE_SMOOTHING_FACTOR).powi(idle_intervals);
+
+        self.has_sample = true;
+        self.pending = 0;
+        self.interval_start = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use s2n_quic_core::{
+        event::IntoEvent,
+        time::{Clock, NoopClock},
+    };
+
+    #[test]
+    fn rate_test() {
+        let start = NoopClock {}.get_time();
+        let at = |offset: Duration| -> Timestamp { (start + offset).into_event() };
+        let mut rate = Rate::new(at(Duration::ZERO));
+
+        for i in 0..10 {
+            rate.record(at(Duration::from_millis(i * 100)), 100);
+        }
+
+        // no samples are taken until the interval has elapsed
+        assert_eq!(rate.total, 1000);
+        assert_eq!(rate.smoothed, 0.0);
+
+        // the first sample is used as-is
+        rate.record(at(RATE_INTERVAL), 100);
+        assert_eq!(rate.total, 1100);
+        assert_eq!(rate.smoothed, 1000.0);
+
+        // the following interval only recorded a single amount
+        rate.record(at(RATE_INTERVAL * 2), 0);
+        assert_eq!(
+            rate.smoothed,
+            RATE_SMOOTHING_FACTOR * 100.0 + (1.0 - RATE_SMOOTHING_FACTOR) * 1000.0
+        );
+    }
+
+    #[test]
+    fn idle_rate_test() {
+        let start = NoopClock {}.get_time();
+        let at = |offset: Duration| -> Timestamp { (start + offset).into_event() };
+        let mut rate = Rate::new(at(Duration::ZERO));
+
+        rate.record(at(Duration::ZERO), 1000);
+        rate.close_intervals(at(RATE_INTERVAL));
+        assert_eq!(rate.smoothed, 1000.0);
+
+        // the interval is only closed out once it has elapsed
+        rate.close_intervals(at(RATE_INTERVAL + RATE_INTERVAL / 2));
+        assert_eq!(rate.smoothed, 1000.0);
+
+        // an idle interval is sampled as 0
+        rate.close_intervals(at(RATE_INTERVAL * 2));
+        assert_eq!(rate.smoothed, (1.0 - RATE_SMOOTHING_FACTOR) * 1000.0);
+
+        // each of the intervals elapsed since the last sample is sampled as 0
+        rate.close_intervals(at(RATE_INTERVAL * 5));
+        assert!(
+            (rate.smoothed - (1.0 - RATE_SMOOTHING_FACTOR).powi(4) * 1000.0).abs() < 1e-9,
+            "{}",
+            rate.smoothed
+        );
+
+        // the rate recovers once data is recorded again
+        rate.record(at(RATE_INTERVAL * 5), 1000);
+        rate.close_intervals(at(RATE_INTERVAL * 6));
+        assert!(rate.smoothed > (1.0 - RATE_SMOOTHING_FACTOR).powi(4) * 1000.0);
+        assert_eq!(rate.total, 2000);
+    }
+
+    #[test]
+    fn idle_after_activity_rate_test() {
+        let start = NoopClock {}.get_time();
+        let at = |offset: Duration| -> Timestamp { (start + offset).into_event() };
+        let mut rate = Rate::new(at(Duration::ZERO));
+
+        rate.record(at(Duration::ZERO), 1000);
+        rate.close_intervals(at(RATE_INTERVAL));
+        rate.record(at(RATE_INTERVAL), 1000);
+
+        // the amount is sampled over the interval it was recorded in, and only the following
+        // intervals are decayed
+        rate.close_intervals(at(RATE_INTERVAL * 4));
+        let expected = 1000.0 * (1.0 - RATE_SMOOTHING_FACTOR).powi(2);
+        assert!((rate.smoothed - expected).abs() < 1e-9, "{}", rate.smoothed);
+    }
+
+    #[test]
+    fn reset_test() {
+        let start = NoopClock {}.get_time();
+        let at = |offset: Duration| -> Timestamp { (start + offset).into_event() };
+        let mut stats = Stats::new(at(Duration::ZERO), Tags::new());
+
+        stats.send_rate_limit = Some(1000);
+        stats.on_packet_lost(1200);
+        stats.on_packet_lost(600);
+        stats.crypto_bytes_retransmitted = 100;
+        stats.duplicate_acks_received = 3;
+        for i in 0..=10 {
+            stats.on_packet_sent(at(RATE_INTERVAL / 10 * i));
+            stats.on_datagram_sent(at(RATE_INTERVAL / 10 * i), 100);
+        }
+
+        let before = stats.snapshot();
+        assert_eq!(before.packets_sent, 11);
+        assert_eq!(before.packets_lost, 2);
+        assert_eq!(before.bytes_lost, 1800);
+        assert!(before.bytes_sent_per_sec > 0.0);
+
+        stats.reset();
+
+        let after = stats.snapshot();
+        assert_eq!(after.packets_sent, 0);
+        assert_eq!(after.bytes_sent, 0);
+        assert_eq!(after.packets_lost, 0);
+        assert_eq!(after.bytes_lost, 0);
+        assert_eq!(after.crypto_bytes_retransmitted, 0);
+        assert_eq!(after.duplicate_acks_received, 0);
+
+        // rates and connection state are preserved
+        assert_eq!(after.bytes_sent_per_sec, before.bytes_sent_per_sec);
+        assert_eq!(after.send_rate_limit, Some(1000));
+
+        // counting continues from zero
+        stats.on_packet_sent(at(RATE_INTERVAL * 2));
+        assert_eq!(stats.snapshot().packets_sent, 1);
+    }
+
+    #[test]
+    fn congestion_limited_test() {
+        let start = NoopClock {}.get_time();
+        let at = |offset: Duration| -> Timestamp { (start + offset).into_event() };
+        let mut stats = Stats::new(at(Duration::ZERO), Tags::new());
+
+        stats.latest_event = at(Duration::from_secs(1));
+        stats.congestion_limited_since = Some(stats.latest_event);
+
+        // the current period is included up to the latest event
+        stats.latest_event = at(Duration::from_secs(3));
+        assert_eq!(
+            stats.snapshot().congestion_limited_duration,
+            Duration::from_secs(2)
+        );
+
+        // the current period continues to be counted from the reset
+        stats.reset();
+        assert_eq!(stats.snapshot().congestion_limited_duration, Duration::ZERO);
+
+        stats.latest_event = at(Duration::from_secs(4));
+        assert_eq!(
+            stats.snapshot().congestion_limited_duration,
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn rtt_sample_test() {
+        let start = NoopClock {}.get_time();
+        let at = |offset: Duration| -> Timestamp { (start + offset).into_event() };
+        let mut stats = Stats::new(at(Duration::ZERO), Tags::new());
+
+        // the first sample doesn't have an interval
+        stats.on_rtt_sample(at(Duration::from_secs(1)));
+        assert_eq!(stats.snapshot().rtt_samples, 1);
+        assert_eq!(stats.snapshot().max_rtt_sample_interval, Duration::ZERO);
+
+        stats.on_rtt_sample(at(Duration::from_secs(4)));
+        stats.on_rtt_sample(at(Duration::from_secs(5)));
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.rtt_samples, 3);
+        assert_eq!(snapshot.max_rtt_sample_interval, Duration::from_secs(3));
+
+        // the interval is measured from the last sample before the reset
+        stats.reset();
+        stats.on_rtt_sample(at(Duration::from_secs(7)));
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.rtt_samples, 1);
+        assert_eq!(snapshot.max_rtt_sample_interval, Duration::from_secs(2));
+    }
+
+    #[test]
+    fn endpoint_stats_test() {
+        let stats = EndpointStats::default();
+        assert_eq!(stats.snapshot(), EndpointSnapshot::default());
+
+        // the handle shares the counters with the subscriber
+        let handle = stats.clone();
+        stats.on_platform_rx(1);
+        stats.on_platform_rx(4);
+
+        let snapshot = handle.snapshot();
+        assert_eq!(snapshot.rx_batches, 2);
+        assert_eq!(snapshot.rx_datagrams, 5);
+        assert_eq!(snapshot.average_rx_batch_size, 2.5);
+        assert_eq!(snapshot.rx_overflow_datagrams, 0);
+
+        // drops are summed across events
+        stats.on_io_recv_overflow(3);
+        stats.on_io_recv_overflow(2);
+        assert_eq!(handle.snapshot().rx_overflow_datagrams, 5);
+    }
+}
@@ -8,7 +8,7 @@ use s2n_quic_core::{endpoint::Endpoint, inet::SocketAddress};
 use s2n_quic_platform::io::tokio;
 use std::io;
 
-pub use self::tokio::{Builder, Io as Provider};
+pub use self::tokio::{Builder, EventLoopMode, Io as Provider};
 
 impl super::Provider for Provider {
     type PathHandle = tokio::PathHandle;
@@ -0,0 +1,818 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs the TLS handshake of each connection on a dedicated thread pool
+//!
+//! Certificate verification, signing and key exchange make up most of the CPU time spent on a
+//! connection's handshake. Normally this work happens on the task which drives the endpoint's IO,
+//! so a surge of handshakes delays the processing of packets for all of the endpoint's
+//! connections. Wrapping a TLS provider with [`Provider`] moves the work of the TLS session to an
+//! [`Executor`], which keeps the IO task responsive.
+//!
+//! The endpoint forwards the received handshake data to the session's task and applies the keys
+//! and handshake data produced by the session once the task has finished polling it. This adds
+//! a thread hop to each handshake flight, so offloading is only beneficial when the endpoint is
+//! expected to handle enough concurrent handshakes for the IO task to become CPU bound. The
+//! throughput gain depends on the number of threads in the pool, as well as the cost of the
+//! certificate chain and cipher suites, and should be measured for the specific deployment.
+//!
+//! The `handshake` benchmarks in `s2n-quic-bench` compare inline and offloaded handshakes with 1,
+//! 16 and 64 handshakes in progress at a time. The handshakes are spread across several client
+//! endpoints so that the client side doesn't limit the server. The benchmarks can be run with
+//! `cargo bench -p s2n-quic-bench -- handshake`.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # fn test() -> Result<(), Box<dyn std::error::Error>> {
+//! use s2n_quic::{provider::tls::offload, Server};
+//! # let (cert, key) = ("", "");
+//!
+//! let tls = offload::Provider::new((cert, key)).with_threads(4);
+//!
+//! let server = Server::builder()
+//!     .with_tls(tls)?
+//!     .start()?;
+//! #
+//! #   Ok(())
+//! # }
+//! ```
+
+use bytes::Bytes;
+use core::{
+    fmt,
+    task::{Poll, Waker},
+};
+use s2n_codec::EncoderValue;
+use s2n_quic_core::{
+    application::ServerName,
+    crypto::{
+        tls::{self, NamedGroup, SignatureScheme},
+        CryptoSuite,
+    },
+    transport,
+};
+use std::{
+    collections::VecDeque,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex, MutexGuard},
+    task::Wake,
+};
+
+/// The number of threads in the pool created by [`Provider`], unless configured otherwise
+const DEFAULT_THREADS: usize = 1;
+
+/// A unit of handshake work which is run by an [`Executor`]
+pub type Task = Box<dyn FnOnce() + Send + 'static>;
+
+/// Runs the handshake work of offloaded TLS sessions
+pub trait Executor: 'static + Send + Sync {
+    /// Runs `task` to completion
+    ///
+    /// The task should be run on a thread other than the one driving the endpoint. Tasks
+    /// belonging to the same session are never run concurrently.
+    fn execute(&self, task: Task);
+}
+
+/// A fixed-size pool of threads which run handshake tasks in the order they're submitted
+///
+/// The pool can be cloned to share the same threads across several endpoints. The threads exit
+/// once every clone of the pool, and every session using it, has been dropped.
+#[derive(Clone)]
+pub struct ThreadPool {
+    sender: Arc<Mutex<mpsc::Sender<Task>>>,
+    threads: usize,
+}
+
+impl ThreadPool {
+    /// Spawns a pool with the given number of threads
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero or a thread could not be spawned.
+    pub fn new(threads: usize) -> Self {
+        assert_ne!(threads, 0, "the pool requires at least one thread");
+
+        let (sender, receiver) = mpsc::channel::<Task>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for index in 0..threads {
+            let receiver = receiver.clone();
+            std::thread::Builder::new()
+                .name(format!("s2n-quic-tls-{}", index))
+                .spawn(move || loop {
+                    // release the receiver before running the task so other threads can pick up
+                    // the next one
+                    let task = lock(&receiver).recv();
+                    match task {
+                        Ok(task) => task(),
+                        // the pool was dropped
+                        Err(_) => return,
+                    }
+                })
+                .expect("could not spawn TLS thread");
+        }
+
+        Self {
+            sender: Arc::new(Mutex::new(sender)),
+            threads,
+        }
+    }
+
+    /// Returns the number of threads in the pool
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("threads", &self.threads)
+            .finish()
+    }
+}
+
+impl Executor for ThreadPool {
+    fn execute(&self, task: Task) {
+        // the threads only exit once the sender is dropped so this can't fail
+        let _ = lock(&self.sender).send(task);
+    }
+}
+
+/// Wraps a TLS provider so the handshakes of its endpoints run on an [`Executor`]
+pub struct Provider<T> {
+    inner: T,
+    executor: Option<Arc<dyn Executor>>,
+    threads: usize,
+}
+
+impl<T: super::Provider> Provider<T> {
+    /// Wraps `inner` so its sessions run on a [`ThreadPool`] which is created for each endpoint
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            executor: None,
+            threads: DEFAULT_THREADS,
+        }
+    }
+
+    /// Sets the number of threads in the pool which is created for each endpoint
+    ///
+    /// This is ignored if an executor is configured with [`Self::with_executor`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        assert_ne!(threads, 0, "the pool requires at least one thread");
+        self.threads = threads;
+        self
+    }
+
+    /// Sets the executor which runs the sessions of the endpoints
+    ///
+    /// This can be used to share a [`ThreadPool`] between endpoints, or to run the handshakes on
+    /// an application-provided pool.
+    pub fn with_executor<E: Executor>(mut self, executor: E) -> Self {
+        self.executor = Some(Arc::new(executor));
+        self
+    }
+
+    fn executor(&self) -> Arc<dyn Executor> {
+        self.executor
+            .clone()
+            .unwrap_or_else(|| Arc::new(ThreadPool::new(self.threads)))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Provider<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Provider")
+            .field("inner", &self.inner)
+            .field("threads", &self.threads)
+            .field("custom_executor", &self.executor.is_some())
+            .finish()
+    }
+}
+
+impl<T: super::Provider> super::Provider for Provider<T>
+where
+    <T::Server as tls::Endpoint>::Session: 'static,
+    <T::Client as tls::Endpoint>::Session: 'static,
+{
+    type Server = Endpoint<T::Server>;
+    type Client = Endpoint<T::Client>;
+    type Error = T::Error;
+
+    fn start_server(self) -> Result<Self::Server, Self::Error> {
+        let executor = self.executor();
+        let inner = self.inner.start_server()?;
+        Ok(Endpoint { inner, executor })
+    }
+
+    fn start_client(self) -> Result<Self::Client, Self::Error> {
+        let executor = self.executor();
+        let inner = self.inner.start_client()?;
+        Ok(Endpoint { inner, executor })
+    }
+}
+
+/// A TLS endpoint which runs the sessions of the inner endpoint on an [`Executor`]
+pub struct Endpoint<E> {
+    inner: E,
+    executor: Arc<dyn Executor>,
+}
+
+impl<E: fmt::Debug> fmt::Debug for Endpoint<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Endpoint")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<E: tls::Endpoint> tls::Endpoint for Endpoint<E>
+where
+    E::Session: 'static,
+{
+    type Session = Session<E::Session>;
+
+    fn new_server_session<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+    ) -> Self::Session {
+        let session = self.inner.new_server_session(transport_parameters);
+        Session::new(session, self.executor.clone())
+    }
+
+    fn new_client_session<Params: EncoderValue>(
+        &mut self,
+        transport_parameters: &Params,
+        server_name: ServerName,
+    ) -> Self::Session {
+        let session = self
+            .inner
+            .new_client_session(transport_parameters, server_name);
+        Session::new(session, self.executor.clone())
+    }
+
+    fn max_tag_length(&self) -> usize {
+        self.inner.max_tag_length()
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    #[doc(hidden)]
+    #[inline]
+    fn crypto_fragmentation(&self) -> Option<tls::testing::fragmentation::Mode> {
+        self.inner.crypto_fragmentation()
+    }
+}
+
+/// A TLS session which is polled on an [`Executor`]
+///
+/// Polling the session on the endpoint only exchanges data with the session's task, so it never
+/// runs any of the inner session's cryptographic operations.
+pub struct Session<S: tls::Session> {
+    shared: Arc<Shared<S>>,
+    started: bool,
+}
+
+impl<S: tls::Session> Session<S> {
+    fn new(session: S, executor: Arc<dyn Executor>) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                executor,
+                session: Mutex::new(session),
+                state: Mutex::new(State::default()),
+                inbound: Mutex::new(Inbound::default()),
+                outbound: Mutex::new(Outbound {
+                    outputs: VecDeque::new(),
+                    result: None,
+                    waker: None,
+                }),
+            }),
+            started: false,
+        }
+    }
+}
+
+impl<S: tls::Session> fmt::Debug for Session<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<S: tls::Session> CryptoSuite for Session<S> {
+    type HandshakeKey = S::HandshakeKey;
+    type HandshakeHeaderKey = S::HandshakeHeaderKey;
+    type InitialKey = S::InitialKey;
+    type InitialHeaderKey = S::InitialHeaderKey;
+    type OneRttKey = S::OneRttKey;
+    type OneRttHeaderKey = S::OneRttHeaderKey;
+    type ZeroRttKey = S::ZeroRttKey;
+    type ZeroRttHeaderKey = S::ZeroRttHeaderKey;
+    type RetryKey = S::RetryKey;
+}
+
+impl<S: tls::Session + 'static> tls::Session for Session<S> {
+    fn poll<C: tls::Context<Self>>(
+        &mut self,
+        context: &mut C,
+    ) -> Poll<Result<(), transport::Error>> {
+        // apply everything the session produced since the last poll
+        {
+            let mut outbound = lock(&self.shared.outbound);
+
+            match &outbound.waker {
+                Some(waker) if waker.will_wake(context.waker()) => {}
+                _ => outbound.waker = Some(context.waker().clone()),
+            }
+
+            while let Some(output) = outbound.outputs.pop_front() {
+                if let Some(output) = output.apply(context)? {
+                    // the crypto stream is full so try again on the next poll
+                    outbound.outputs.push_front(output);
+                    break;
+                }
+            }
+
+            if outbound.outputs.is_empty() {
+                if let Some(result) = outbound.result.take() {
+                    return Poll::Ready(result);
+                }
+            }
+        }
+
+        // forward the received data and the state of the crypto streams to the session
+        let mut should_poll = !self.started;
+        {
+            let mut inbound = lock(&self.shared.inbound);
+
+            while let Some(data) = context.receive_initial(None) {
+                inbound.initial.push_back(data);
+                should_poll = true;
+            }
+            while let Some(data) = context.receive_handshake(None) {
+                inbound.handshake.push_back(data);
+                should_poll = true;
+            }
+            while let Some(data) = context.receive_application(None) {
+                inbound.application.push_back(data);
+                should_poll = true;
+            }
+
+            // the session may be waiting for space in one of the crypto streams
+            should_poll |= inbound.update_can_send(
+                context.can_send_initial(),
+                context.can_send_handshake(),
+                context.can_send_application(),
+            );
+        }
+
+        if should_poll {
+            self.started = true;
+            self.shared.schedule();
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<S: tls::Session> Drop for Session<S> {
+    fn drop(&mut self) {
+        // stop polling the session if it's still scheduled
+        lock(&self.shared.state).closed = true;
+    }
+}
+
+/// The state shared between the endpoint and the session's task
+struct Shared<S: tls::Session> {
+    executor: Arc<dyn Executor>,
+    session: Mutex<S>,
+    state: Mutex<State>,
+    inbound: Mutex<Inbound>,
+    outbound: Mutex<Outbound<S>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Set while a task to poll the session is queued or running
+    scheduled: bool,
+    /// Set if the session needs to be polled again after the current task is done
+    repoll: bool,
+    /// Set once the endpoint dropped the session
+    closed: bool,
+}
+
+impl<S: tls::Session + 'static> Shared<S> {
+    fn schedule(self: &Arc<Self>) {
+        {
+            let mut state = lock(&self.state);
+
+            if state.closed {
+                return;
+            }
+
+            if state.scheduled {
+                state.repoll = true;
+                return;
+            }
+
+            state.scheduled = true;
+        }
+
+        let shared = self.clone();
+        self.executor.execute(Box::new(move || shared.run()));
+    }
+
+    fn run(self: Arc<Self>) {
+        let waker = Waker::from(self.clone());
+
+        loop {
+            {
+                let mut state = lock(&self.state);
+                if state.closed {
+                    return;
+                }
+                state.repoll = false;
+            }
+
+            self.poll_session(&waker);
+
+            let mut state = lock(&self.state);
+            if !state.repoll {
+                state.scheduled = false;
+                return;
+            }
+        }
+    }
+
+    fn poll_session(&self, waker: &Waker) {
+        let mut session = lock(&self.session);
+
+        let mut context = RemoteContext {
+            inbound: &self.inbound,
+            waker,
+            outputs: VecDeque::new(),
+            has_handshake_keys: false,
+        };
+
+        // a panicking session would otherwise stall the connection until it times out
+        let poll = panic::catch_unwind(AssertUnwindSafe(|| session.poll(&mut context)))
+            .unwrap_or_else(|_| {
+                Poll::Ready(Err(
+                    transport::Error::INTERNAL_ERROR.with_reason("the TLS session panicked")
+                ))
+            });
+
+        let mut outbound = lock(&self.outbound);
+
+        let mut has_progress = !context.outputs.is_empty();
+        outbound.outputs.extend(context.outputs);
+
+        if let Poll::Ready(result) = poll {
+            outbound.result = Some(result);
+            has_progress = true;
+            // the session is complete so there's no need to poll it again
+            lock(&self.state).closed = true;
+        }
+
+        if has_progress {
+            if let Some(waker) = outbound.waker.as_ref() {
+                waker.wake_by_ref();
+            }
+        }
+    }
+}
+
+impl<S: tls::Session + 'static> Wake for Shared<S> {
+    fn wake(self: Arc<Self>) {
+        self.schedule();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.schedule();
+    }
+}
+
+/// The data received by the endpoint for the session
+#[derive(Debug, Default)]
+struct Inbound {
+    initial: VecDeque<Bytes>,
+    handshake: VecDeque<Bytes>,
+    application: VecDeque<Bytes>,
+    can_send_initial: bool,
+    can_send_handshake: bool,
+    can_send_application: bool,
+}
+
+impl Inbound {
+    /// Updates whether each crypto stream can be written to, returning `true` if any stream can be
+    /// written to which previously could not
+    fn update_can_send(&mut self, initial: bool, handshake: bool, application: bool) -> bool {
+        let unblocked = (initial && !self.can_send_initial)
+            || (handshake && !self.can_send_handshake)
+            || (application && !self.can_send_application);
+
+        self.can_send_initial = initial;
+        self.can_send_handshake = handshake;
+        self.can_send_application = application;
+
+        unblocked
+    }
+}
+
+/// The results produced by the session, waiting to be applied by the endpoint
+struct Outbound<S: CryptoSuite> {
+    outputs: VecDeque<Output<S>>,
+    result: Option<Result<(), transport::Error>>,
+    /// Wakes the connection once there are outputs to apply
+    waker: Option<Waker>,
+}
+
+/// A call made by the session on its context
+enum Output<S: CryptoSuite> {
+    HandshakeKeys(S::HandshakeKey, S::HandshakeHeaderKey),
+    ZeroRttKeys(S::ZeroRttKey, S::ZeroRttHeaderKey, Vec<u8>),
+    OneRttKeys(S::OneRttKey, S::OneRttHeaderKey, Vec<u8>),
+    ServerName(ServerName),
+    ApplicationProtocol(Bytes),
+    KeyExchangeGroup(NamedGroup),
+    SignatureScheme(SignatureScheme),
+    HandshakeComplete,
+    SendInitial(Bytes),
+    SendHandshake(Bytes),
+    SendApplication(Bytes),
+}
+
+impl<S: CryptoSuite> Output<S> {
+    /// Applies the output to the connection's context
+    ///
+    /// The output is returned if the crypto stream it writes to is currently full.
+    fn apply<T: CryptoSuite, C: tls::Context<T>>(
+        self,
+        context: &mut C,
+    ) -> Result<Option<Self>, transport::Error>
+    where
+        T: CryptoSuite<
+            HandshakeKey = S::HandshakeKey,
+            HandshakeHeaderKey = S::HandshakeHeaderKey,
+            OneRttKey = S::OneRttKey,
+            OneRttHeaderKey = S::OneRttHeaderKey,
+            ZeroRttKey = S::ZeroRttKey,
+            ZeroRttHeaderKey = S::ZeroRttHeaderKey,
+        >,
+    {
+        match self {
+            Self::HandshakeKeys(key, header_key) => context.on_handshake_keys(key, header_key)?,
+            Self::ZeroRttKeys(key, header_key, transport_parameters) => context.on_zero_rtt_keys(
+                key,
+                header_key,
+                tls::ApplicationParameters {
+                    transport_parameters: &transport_parameters,
+                },
+            )?,
+            Self::OneRttKeys(key, header_key, transport_parameters) => context.on_one_rtt_keys(
+                key,
+                header_key,
+                tls::ApplicationParameters {
+                    transport_parameters: &transport_parameters,
+                },
+            )?,
+            Self::ServerName(server_name) => context.on_server_name(server_name)?,
+            Self::ApplicationProtocol(protocol) => context.on_application_protocol(protocol)?,
+            Self::KeyExchangeGroup(group) => context.on_key_exchange_group(group)?,
+            Self::SignatureScheme(scheme) => context.on_signature_scheme(scheme)?,
+            Self::HandshakeComplete => context.on_handshake_complete()?,
+            Self::SendInitial(data) if context.can_send_initial() => context.send_initial(data),
+            Self::SendHandshake(data) if context.can_send_handshake() => {
+                context.send_handshake(data)
+            }
+            Self::SendApplication(data) if context.can_send_application() => {
+                context.send_application(data)
+            }
+            output @ (Self::SendInitial(_) | Self::SendHandshake(_) | Self::SendApplication(_)) => {
+                return Ok(Some(output))
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// The context passed to the inner session while it's polled on the executor
+struct RemoteContext<'a, S: CryptoSuite> {
+    inbound: &'a Mutex<Inbound>,
+    waker: &'a Waker,
+    outputs: VecDeque<Output<S>>,
+    /// Set once the session produced the handshake keys
+    ///
+    /// The endpoint creates the handshake crypto stream when the keys are applied, so the
+    /// session can write to it before that has happened.
+    has_handshake_keys: bool,
+}
+
+impl<'a, S: CryptoSuite> RemoteContext<'a, S> {
+    fn receive(
+        queue: impl FnOnce(&mut Inbound) -> &mut VecDeque<Bytes>,
+        inbound: &Mutex<Inbound>,
+        max_len: Option<usize>,
+    ) -> Option<Bytes> {
+        let mut inbound = lock(inbound);
+        let queue = queue(&mut inbound);
+        let mut data = queue.pop_front()?;
+
+        if let Some(max_len) = max_len {
+            if data.len() > max_len {
+                let remaining = data.split_off(max_len);
+                queue.push_front(remaining);
+            }
+        }
+
+        Some(data)
+    }
+}
+
+impl<'a, S: CryptoSuite> tls::Context<S> for RemoteContext<'a, S> {
+    fn on_handshake_keys(
+        &mut self,
+        key: S::HandshakeKey,
+        header_key: S::HandshakeHeaderKey,
+    ) -> Result<(), transport::Error> {
+        self.has_handshake_keys = true;
+        self.outputs
+            .push_back(Output::HandshakeKeys(key, header_key));
+        Ok(())
+    }
+
+    fn on_zero_rtt_keys(
+        &mut self,
+        key: S::ZeroRttKey,
+        header_key: S::ZeroRttHeaderKey,
+        application_parameters: tls::ApplicationParameters,
+    ) -> Result<(), transport::Error> {
+        self.outputs.push_back(Output::ZeroRttKeys(
+            key,
+            header_key,
+            application_parameters.transport_parameters.to_vec(),
+        ));
+        Ok(())
+    }
+
+    fn on_one_rtt_keys(
+        &mut self,
+        key: S::OneRttKey,
+        header_key: S::OneRttHeaderKey,
+        application_parameters: tls::ApplicationParameters,
+    ) -> Result<(), transport::Error> {
+        self.outputs.push_back(Output::OneRttKeys(
+            key,
+            header_key,
+            application_parameters.transport_parameters.to_vec(),
+        ));
+        Ok(())
+    }
+
+    fn on_server_name(&mut self, server_name: ServerName) -> Result<(), transport::Error> {
+        self.outputs.push_back(Output::ServerName(server_name));
+        Ok(())
+    }
+
+    fn on_application_protocol(
+        &mut self,
+        application_protocol: Bytes,
+    ) -> Result<(), transport::Error> {
+        self.outputs
+            .push_back(Output::ApplicationProtocol(application_protocol));
+        Ok(())
+    }
+
+    fn on_key_exchange_group(&mut self, group: NamedGroup) -> Result<(), transport::Error> {
+        self.outputs.push_back(Output::KeyExchangeGroup(group));
+        Ok(())
+    }
+
+    fn on_signature_scheme(&mut self, scheme: SignatureScheme) -> Result<(), transport::Error> {
+        self.outputs.push_back(Output::SignatureScheme(scheme));
+        Ok(())
+    }
+
+    fn on_handshake_complete(&mut self) -> Result<(), transport::Error> {
+        self.outputs.push_back(Output::HandshakeComplete);
+        Ok(())
+    }
+
+    fn receive_initial(&mut self, max_len: Option<usize>) -> Option<Bytes> {
+        Self::receive(|inbound| &mut inbound.initial, self.inbound, max_len)
+    }
+
+    fn receive_handshake(&mut self, max_len: Option<usize>) -> Option<Bytes> {
+        Self::receive(|inbound| &mut inbound.handshake, self.inbound, max_len)
+    }
+
+    fn receive_application(&mut self, max_len: Option<usize>) -> Option<Bytes> {
+        Self::receive(|inbound| &mut inbound.application, self.inbound, max_len)
+    }
+
+    fn can_send_initial(&self) -> bool {
+        lock(self.inbound).can_send_initial
+    }
+
+    fn send_initial(&mut self, transmission: Bytes) {
+        self.outputs.push_back(Output::SendInitial(transmission));
+    }
+
+    fn can_send_handshake(&self) -> bool {
+        self.has_handshake_keys || lock(self.inbound).can_send_handshake
+    }
+
+    fn send_handshake(&mut self, transmission: Bytes) {
+        self.outputs.push_back(Output::SendHandshake(transmission));
+    }
+
+    fn can_send_application(&self) -> bool {
+        lock(self.inbound).can_send_application
+    }
+
+    fn send_application(&mut self, transmission: Bytes) {
+        self.outputs
+            .push_back(Output::SendApplication(transmission));
+    }
+
+    fn waker(&self) -> &Waker {
+        self.waker
+    }
+}
+
+/// Locks the mutex, ignoring any poisoning
+///
+/// None of the state is left inconsistent by a panic since the inner session is polled in
+/// `catch_unwind`.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<T> {
+    mutex.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::mpsc::channel, time::Duration};
+
+    #[test]
+    fn thread_pool_test() {
+        let pool = ThreadPool::new(2);
+        assert_eq!(pool.threads(), 2);
+
+        let (sender, receiver) = channel();
+        for id in 0..10 {
+            let sender = sender.clone();
+            pool.execute(Box::new(move || {
+                let name = std::thread::current().name().map(String::from);
+                sender.send((id, name)).unwrap();
+            }));
+        }
+
+        let mut ids = vec![];
+        for _ in 0..10 {
+            let (id, name) = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+            // the tasks are run on the pool's threads
+            assert!(name.unwrap().starts_with("s2n-quic-tls-"));
+            ids.push(id);
+        }
+        ids.sort_unstable();
+        assert_eq!(ids, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn split_received_data_test() {
+        let inbound = Mutex::new(Inbound::default());
+        lock(&inbound)
+            .initial
+            .push_back(Bytes::from_static(b"hello"));
+
+        let mut receive = |max_len| {
+            RemoteContext::<tls::testing::Session>::receive(
+                |inbound| &mut inbound.initial,
+                &inbound,
+                max_len,
+            )
+        };
+
+        assert_eq!(receive(Some(2)).unwrap(), Bytes::from_static(b"he"));
+        assert_eq!(receive(None).unwrap(), Bytes::from_static(b"llo"));
+        assert!(receive(None).is_none());
+    }
+
+    #[test]
+    fn update_can_send_test() {
+        let mut inbound = Inbound::default();
+
+        assert!(inbound.update_can_send(true, false, false));
+        assert!(!inbound.update_can_send(true, false, false));
+        // only newly writable streams require polling the session again
+        assert!(!inbound.update_can_send(false, false, false));
+        assert!(inbound.update_can_send(false, true, false));
+    }
+}
@@ -153,6 +153,90 @@ macro_rules! impl_receive_stream_api {
             $dispatch_body
         }
 
+        /// Receives exactly `len` bytes of data from the stream.
+        ///
+        /// This is useful for framed protocols, where the length of the next frame is known
+        /// ahead of time. No data is consumed from the stream until either `len` bytes are
+        /// available or the peer has finished the stream.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Ok((data, is_open))` once the data was received, with `is_open` indicating if the
+        ///   stream is still open. If the stream was finished with fewer than `len` bytes remaining,
+        ///   `data` holds the remaining bytes and `is_open` is `false`.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// The amount of data buffered on the stream is bounded by its receive window. If `len`
+        /// exceeds half of the window, the call fails with
+        /// [`stream::Error::ReceiveWindowExceeded`](crate::stream::Error::ReceiveWindowExceeded)
+        /// without consuming any data.
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let mut stream: s2n_quic::stream::ReceiveStream = todo!();
+        /// #
+        /// loop {
+        ///     let (header, is_open) = stream.receive_exact(4).await?;
+        ///
+        ///     if header.len() < 4 {
+        ///         debug_assert!(!is_open);
+        ///         break;
+        ///     }
+        ///
+        ///     let len = u32::from_be_bytes([header[0], header[1], header[2], header[3]]);
+        ///     let (frame, _) = stream.receive_exact(len as usize).await?;
+        ///     println!("received frame: {:?}", frame);
+        /// }
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub async fn receive_exact(
+            &mut self,
+            len: usize,
+        ) -> $crate::stream::Result<(bytes::Bytes, bool)> {
+            ::futures::future::poll_fn(|cx| self.poll_receive_exact(len, cx)).await
+        }
+
+        /// Polls for receiving exactly `len` bytes of data from the stream.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Poll::Pending` if fewer than `len` bytes are buffered and the stream is waiting to
+        ///   receive data from the peer. In this case, the caller should retry receiving after the
+        ///   [`Waker`](core::task::Waker) on the provided [`Context`](core::task::Context) is
+        ///   notified.
+        /// - `Poll::Ready(Ok((data, is_open)))` once the data was received, with `is_open`
+        ///   indicating if the stream is still open. If the stream was finished with fewer than
+        ///   `len` bytes remaining, `data` holds the remaining bytes and `is_open` is `false`.
+        /// - `Poll::Ready(Err(e))` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///   This includes `len` exceeding half of the stream's receive window.
+        #[inline]
+        pub fn poll_receive_exact(
+            &mut self,
+            len: usize,
+            cx: &mut core::task::Context,
+        ) -> core::task::Poll<$crate::stream::Result<(bytes::Bytes, bool)>> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_readable()).into()
+                };
+                ($variant: expr) => {
+                    $variant.poll_receive_exact(len, cx)
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
+
         /// Notifies the peer to stop sending data on the stream.
         ///
         /// This requests the peer to finish the stream as soon as possible
@@ -204,6 +288,46 @@ macro_rules! impl_receive_stream_api {
             $dispatch_body
         }
 
+        /// Returns information about the out-of-order data which is currently buffered on the stream
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Ok(reassembly)` with the number of bytes waiting for earlier data to arrive and the
+        ///   size of the largest gap preceding them.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let mut stream: s2n_quic::stream::ReceiveStream = todo!();
+        /// #
+        /// let reassembly = stream.reassembly()?;
+        /// println!(
+        ///     "buffered: {}, largest gap: {}",
+        ///     reassembly.out_of_order_bytes, reassembly.largest_gap
+        /// );
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn reassembly(&mut self) -> $crate::stream::Result<$crate::stream::Reassembly> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_readable())
+                };
+                ($variant: expr) => {
+                    $variant.reassembly()
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
+
         /// Create a batch request for receiving data
         #[inline]
         pub(crate) fn rx_request(
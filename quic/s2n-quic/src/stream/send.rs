@@ -376,6 +376,128 @@ macro_rules! impl_send_stream_api {
             let $stream = self;
             $dispatch_body
         }
+
+        /// Returns the number of bytes of stream data which have been transmitted more than once
+        ///
+        /// Data is retransmitted after the packet carrying it was declared lost, or when it is
+        /// included in a probe sent after a probe timeout (PTO). This can be used to attribute
+        /// loss to individual streams on a multiplexed connection.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Ok(bytes)` with the number of retransmitted bytes.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let mut stream: s2n_quic::stream::SendStream = todo!();
+        /// #
+        /// println!("retransmitted: {}", stream.retransmitted_bytes()?);
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn retransmitted_bytes(&mut self) -> $crate::stream::Result<u64> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_writable())
+                };
+                ($variant: expr) => {
+                    $variant.retransmitted_bytes()
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
+
+        /// Returns the number of bytes of stream data which have been transmitted at least once
+        ///
+        /// Retransmissions are not included, so the value only grows as the stream makes
+        /// progress. Comparing the value across the streams of a connection shows how the
+        /// connection's capacity was shared between them.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Ok(bytes)` with the number of transmitted bytes.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let mut stream: s2n_quic::stream::SendStream = todo!();
+        /// #
+        /// println!("sent: {}", stream.sent_bytes()?);
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn sent_bytes(&mut self) -> $crate::stream::Result<u64> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_writable())
+                };
+                ($variant: expr) => {
+                    $variant.sent_bytes()
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
+
+        /// Returns the time between the first transmission of stream data and the first
+        /// acknowledgement of stream data by the peer
+        ///
+        /// This measures how quickly the peer confirms receipt of the stream, which is useful for
+        /// tracking the latency of request/response workloads.
+        ///
+        /// # Return value
+        ///
+        /// The function returns:
+        ///
+        /// - `Ok(Some(duration))` once the peer has acknowledged stream data.
+        /// - `Ok(None)` if no stream data has been acknowledged yet.
+        /// - `Err(e)` if the stream encountered a [`stream::Error`](crate::stream::Error).
+        ///
+        /// # Examples
+        ///
+        /// ```rust,no_run
+        /// # async fn test() -> s2n_quic::stream::Result<()> {
+        /// #   let mut stream: s2n_quic::stream::SendStream = todo!();
+        /// #
+        /// if let Some(duration) = stream.time_to_first_ack()? {
+        ///     println!("time to first ack: {:?}", duration);
+        /// }
+        /// #
+        /// #   Ok(())
+        /// # }
+        /// ```
+        #[inline]
+        pub fn time_to_first_ack(
+            &mut self,
+        ) -> $crate::stream::Result<Option<core::time::Duration>> {
+            macro_rules! $dispatch {
+                () => {
+                    Err($crate::stream::Error::non_writable())
+                };
+                ($variant: expr) => {
+                    $variant.time_to_first_ack()
+                };
+            }
+
+            let $stream = self;
+            $dispatch_body
+        }
     };
 }
 
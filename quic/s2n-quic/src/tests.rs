@@ -18,6 +18,17 @@ use s2n_quic_platform::io::testing::primary;
 use setup::*;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
+mod connect_error;
+mod connection;
+mod endpoint_limits;
+mod event_provider;
+mod idle;
+mod recovery;
+mod stats;
+mod stream;
+mod tls;
+mod transmission;
+
 #[test]
 fn client_server_test() {
     test(Model::default(), client_server).unwrap();
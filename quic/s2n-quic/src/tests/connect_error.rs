@@ -0,0 +1,180 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Ensures a connection attempt to an unreachable server is reported as a timeout
+#[test]
+fn connect_error_timeout_test() {
+    let model = Model::default();
+    test(model.clone(), |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        // drop all packets so the server never receives the client's Initial
+        model.set_drop_rate(1.0);
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let error = client.connect(connect).await.unwrap_err();
+
+            let category = error.connect_error();
+            assert_eq!(category, crate::connection::ConnectError::Timeout);
+            assert!(category.is_retryable());
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Rewrites the first datagram received from the server into a Version Negotiation packet which
+/// only lists an unsupported version
+#[derive(Default)]
+struct VersionNegotiationInterceptor {
+    is_rewritten: bool,
+}
+
+impl provider::packet_interceptor::PacketInterceptor for VersionNegotiationInterceptor {
+    fn intercept_rx_datagram<'a>(
+        &mut self,
+        _subject: &s2n_quic_core::event::api::Subject,
+        _datagram: &s2n_quic_core::packet::interceptor::Datagram,
+        payload: s2n_codec::DecoderBufferMut<'a>,
+    ) -> s2n_codec::DecoderBufferMut<'a> {
+        if core::mem::replace(&mut self.is_rewritten, true) {
+            return payload;
+        }
+
+        let bytes = payload.into_less_safe_slice();
+
+        // the server's Initial already carries the connection ids in the same position as a
+        // Version Negotiation packet so only the flags, version and version list are replaced
+        let destination_len = bytes[5] as usize;
+        let source_len = bytes[6 + destination_len] as usize;
+        let versions = 7 + destination_len + source_len;
+
+        bytes[0] = 0x80;
+        bytes[1..5].copy_from_slice(&0u32.to_be_bytes());
+        bytes[versions..versions + 4].copy_from_slice(&0x1a2a_3a4au32.to_be_bytes());
+
+        let (payload, _) = s2n_codec::DecoderBufferMut::new(bytes)
+            .decode_slice(versions + 4)
+            .unwrap();
+        payload
+    }
+}
+
+/// Ensures a client abandons the connection attempt when the server only supports other versions
+#[test]
+fn connect_error_version_negotiation_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_packet_interceptor(VersionNegotiationInterceptor::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let error = client.connect(connect).await.unwrap_err();
+
+            let category = error.connect_error();
+            assert_eq!(
+                category,
+                crate::connection::ConnectError::VersionNegotiation
+            );
+            assert!(!category.is_retryable());
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Replaces every datagram received after the first Retry packet with a copy of that Retry
+#[derive(Default)]
+struct RetryReplayInterceptor {
+    retry: Option<Vec<u8>>,
+}
+
+impl provider::packet_interceptor::PacketInterceptor for RetryReplayInterceptor {
+    fn intercept_rx_datagram<'a>(
+        &mut self,
+        _subject: &s2n_quic_core::event::api::Subject,
+        _datagram: &s2n_quic_core::packet::interceptor::Datagram,
+        payload: s2n_codec::DecoderBufferMut<'a>,
+    ) -> s2n_codec::DecoderBufferMut<'a> {
+        let bytes = payload.into_less_safe_slice();
+
+        if self.retry.is_none() {
+            // a QUIC v1 Retry has the long header, fixed bit and Retry type bits set
+            if bytes.first().map_or(false, |flags| (flags & 0xf0) == 0xf0) {
+                self.retry = Some(bytes.to_vec());
+            }
+            return s2n_codec::DecoderBufferMut::new(bytes);
+        }
+
+        let retry = self.retry.as_ref().unwrap();
+
+        // drop any datagram which is too short to hold the copy
+        let len = if bytes.len() >= retry.len() {
+            bytes[..retry.len()].copy_from_slice(retry);
+            retry.len()
+        } else {
+            0
+        };
+
+        let (payload, _) = s2n_codec::DecoderBufferMut::new(bytes)
+            .decode_slice(len)
+            .unwrap();
+        payload
+    }
+}
+
+/// Ensures a client which only ever receives Retry packets reports a retry loop
+#[test]
+fn connect_error_retry_loop_test() {
+    use provider::endpoint_limits::Outcome;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            // every unvalidated attempt is answered with a Retry packet
+            let limits = provider::endpoint_limits::Default::builder()
+                .with_handshake_rate_limit(0, Outcome::retry())?
+                .build()?;
+
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_endpoint_limits(limits)?
+                .with_event(events())?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_packet_interceptor(RetryReplayInterceptor::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let error = client.connect(connect).await.unwrap_err();
+
+            let category = error.connect_error();
+            assert_eq!(category, crate::connection::ConnectError::RetryLoop);
+            assert!(!category.is_retryable());
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
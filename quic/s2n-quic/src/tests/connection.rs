@@ -0,0 +1,412 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+#[test]
+fn path_info_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            let info = connection.path_info().unwrap();
+            assert_eq!(info.remote_address, connection.remote_addr().unwrap());
+            assert_eq!(info.local_address, connection.local_addr().unwrap());
+            // clients consider the path to the server they connected to as validated
+            assert!(info.is_validated);
+            assert_eq!(connection.amplification_budget().unwrap(), None);
+            assert!(info.smoothed_rtt > Duration::ZERO);
+            assert!(info.min_rtt <= info.smoothed_rtt);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn deadline_test() {
+    use provider::io::testing::time::now;
+
+    const DEADLINE: Duration = Duration::from_secs(5);
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let start = now();
+            connection
+                .set_deadline(std::time::Instant::now() + DEADLINE)
+                .unwrap();
+
+            // the server never opens a stream so this waits until the connection is closed
+            let error = connection.accept_bidirectional_stream().await.unwrap_err();
+            assert!(matches!(
+                error,
+                crate::connection::Error::DeadlineExceeded { .. }
+            ));
+
+            // the deadline is converted from the real clock so allow for some slack
+            let elapsed = now() - start;
+            assert!(elapsed >= DEADLINE - Duration::from_secs(1));
+            assert!(elapsed < DEADLINE + Duration::from_secs(1));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn cancellation_test() {
+    use provider::io::testing::time::now;
+
+    const DELAY: Duration = Duration::from_secs(5);
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let (cancel, on_cancel) = tokio::sync::oneshot::channel::<()>();
+            connection
+                .set_cancellation(async move {
+                    let _ = on_cancel.await;
+                })
+                .unwrap();
+
+            let start = now();
+            primary::spawn(async move {
+                delay(DELAY).await;
+                cancel.send(()).unwrap();
+            });
+
+            // the server never opens a stream so this waits until the connection is closed
+            let error = connection.accept_bidirectional_stream().await.unwrap_err();
+            assert!(matches!(error, crate::connection::Error::Cancelled { .. }));
+            assert!(now() - start >= DELAY);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn connect_cancellation_test() {
+    use provider::io::testing::time::now;
+
+    const DELAY: Duration = Duration::from_secs(5);
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let (cancel, on_cancel) = tokio::sync::oneshot::channel::<()>();
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client
+                .connect(connect)
+                .with_cancellation(async move {
+                    let _ = on_cancel.await;
+                })
+                .await
+                .unwrap();
+
+            let start = now();
+            primary::spawn(async move {
+                delay(DELAY).await;
+                cancel.send(()).unwrap();
+            });
+
+            // the cancellation passed to the attempt is attached to the established connection
+            let error = connection.accept_bidirectional_stream().await.unwrap_err();
+            assert!(matches!(error, crate::connection::Error::Cancelled { .. }));
+            assert!(now() - start >= DELAY);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn connect_cancelled_during_handshake_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            // the cancellation completes before the handshake can
+            let error = client
+                .connect(connect)
+                .with_cancellation(async {})
+                .await
+                .unwrap_err();
+            assert!(matches!(error, crate::connection::Error::Cancelled { .. }));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures the cancellation future may call into the connection while it's being polled
+#[test]
+fn cancellation_api_call_test() {
+    use core::{future::Future, pin::Pin};
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let remote_addr = connection.remote_addr().unwrap();
+
+            let connection_handle = connection.handle();
+            let (cancel, mut on_cancel) = tokio::sync::oneshot::channel::<()>();
+            connection
+                .set_cancellation(futures::future::poll_fn(move |cx| {
+                    // this would deadlock if the future was polled under the connection lock
+                    assert_eq!(connection_handle.remote_addr().unwrap(), remote_addr);
+                    Pin::new(&mut on_cancel).poll(cx).map(|_| ())
+                }))
+                .unwrap();
+
+            primary::spawn(async move {
+                delay(Duration::from_secs(1)).await;
+                cancel.send(()).unwrap();
+            });
+
+            let error = connection.accept_bidirectional_stream().await.unwrap_err();
+            assert!(matches!(error, crate::connection::Error::Cancelled { .. }));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn datagram_config_test() {
+    use provider::datagram::default::Endpoint;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(events())?
+                .with_datagram(Endpoint::builder().build().unwrap())?
+                .start()?)
+        })?;
+
+        let datagram_client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .with_datagram(Endpoint::builder().build().unwrap())?
+            .start()?;
+
+        let plain_client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+
+            let connection = datagram_client.connect(connect.clone()).await.unwrap();
+            let config = connection.datagram_config().unwrap().unwrap();
+            assert!(config.can_send());
+            assert!(config.can_receive());
+
+            // the client doesn't advertise support so the server may not send datagrams to it
+            let connection = plain_client.connect(connect).await.unwrap();
+            let config = connection.datagram_config().unwrap().unwrap();
+            assert!(config.can_send());
+            assert!(!config.can_receive());
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures the tags set at connect/accept are included in every event for the connection
+#[test]
+fn connection_tags_test() {
+    use crate::{
+        connection::Tags,
+        provider::limits::{ConnectionInfo, Limiter, Limits},
+    };
+
+    /// Attaches the same tags to every connection accepted by the server
+    struct Tagged(Tags);
+
+    impl Limiter for Tagged {
+        fn on_connection(&mut self, _info: &ConnectionInfo) -> Limits {
+            Limits::default()
+        }
+
+        fn on_connection_tags(&mut self, _info: &ConnectionInfo) -> Tags {
+            self.0.clone()
+        }
+    }
+
+    let server_tags = Tags::new().with("tenant", "a").unwrap();
+    let client_tags = Tags::new()
+        .with("tenant", "b")
+        .unwrap()
+        .with("region", "us-west-2")
+        .unwrap();
+
+    let server_events = Recorder::default();
+    let client_events = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(server_events.clone())?
+                .with_limits(Tagged(server_tags.clone()))?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((
+                provider::event::stats::Subscriber::default(),
+                client_events.clone(),
+            ))?
+            .start()?;
+
+        let client_tags = client_tags.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr)
+                .with_server_name("localhost")
+                .with_tags(client_tags.clone());
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            let stats = connection.stats().unwrap();
+            assert_eq!(stats.tags, client_tags);
+            assert_eq!(stats.tags.get("region"), Some("us-west-2"));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    for (events, expected) in [(server_events, server_tags), (client_events, client_tags)] {
+        let tags: Vec<_> = events
+            .events()
+            .into_iter()
+            .filter_map(|event| match event {
+                RecordedEvent::ConnectionCreated { tags }
+                | RecordedEvent::ConnectionEvent { tags } => Some(tags),
+                _ => None,
+            })
+            .collect();
+        assert!(tags.len() > 1);
+        for tags in tags {
+            assert_eq!(tags, expected);
+        }
+    }
+}
+
+/// Returns the number of connections which were created on the endpoint
+fn created(recorder: &Recorder) -> usize {
+    recorder.count(|event| matches!(event, RecordedEvent::ConnectionCreated { .. }))
+}
+
+/// Ensures dropping a connection attempt closes the handshaking connection on both endpoints
+#[test]
+fn connect_cancellation_test() {
+    const ATTEMPTS: usize = 10;
+
+    let server_connections = Recorder::default();
+    let client_connections = Recorder::default();
+
+    let model = Model::default();
+    test(model.clone(), |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(server_connections.clone())?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(client_connections.clone())?
+            .start()?;
+
+        let client_connections = client_connections.clone();
+        primary::spawn(async move {
+            for _ in 0..ATTEMPTS {
+                let connect = Connect::new(server_addr).with_server_name("localhost");
+                let attempt = client.connect(connect);
+                // give up after the server has received the first packet but before the
+                // handshake can complete
+                let timeout = delay(model.delay() + model.delay() / 5);
+
+                futures::pin_mut!(attempt);
+                match futures::future::select(attempt, timeout).await {
+                    futures::future::Either::Left(_) => panic!("the handshake should not complete"),
+                    futures::future::Either::Right(_) => {}
+                }
+            }
+
+            assert_eq!(created(&client_connections), ATTEMPTS);
+
+            // wait for the connections to finish closing
+            delay(Duration::from_secs(10)).await;
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    for connections in [server_connections, client_connections] {
+        assert_eq!(created(&connections), ATTEMPTS);
+        assert_eq!(
+            connections.count(|event| matches!(event, RecordedEvent::ConnectionClosed)),
+            ATTEMPTS
+        );
+    }
+}
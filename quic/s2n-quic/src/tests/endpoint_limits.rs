@@ -0,0 +1,129 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+/// Ensures data buffered by the server is charged against the endpoint memory limit and new
+/// connections are refused once the limit is reached
+#[test]
+fn endpoint_memory_limit_test() {
+    // the server keeps all of the received data buffered, which exceeds the limit
+    const LIMIT: u64 = 5_000;
+    const LEN: usize = 10_000;
+
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let limits = provider::endpoint_limits::Default::builder()
+            .with_memory_limit(LIMIT)?
+            .build()?;
+
+        let mut server = Server::builder()
+            .with_io(handle.builder().build()?)?
+            .with_tls(SERVER_CERTS)?
+            .with_endpoint_limits(limits)?
+            .with_event(recorder.clone())?
+            .start()?;
+        let server_addr = server.local_addr()?;
+
+        spawn(async move {
+            while let Some(mut connection) = server.accept().await {
+                spawn(async move {
+                    // accept the stream but never read from it
+                    let _stream = connection.accept_bidirectional_stream().await;
+                    delay(Duration::from_secs(10)).await;
+                });
+            }
+        });
+
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+
+            // an idle connection doesn't buffer anything so it's accepted
+            let mut connection = client.connect(connect.clone()).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from(vec![42; LEN])).await.unwrap();
+            stream.flush().await.unwrap();
+
+            // give the server time to notice the buffered data
+            delay(Duration::from_secs(1)).await;
+
+            assert!(client.connect(connect).await.is_err());
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    let events: Vec<_> = recorder
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            RecordedEvent::EndpointMemoryPressure(event) => Some(event),
+            _ => None,
+        })
+        .collect();
+    assert!(!events.is_empty());
+    for event in events {
+        // the pressure is only released once the usage drops below three quarters of the limit
+        assert!(event.memory_usage >= event.memory_limit - event.memory_limit / 4);
+        assert_eq!(event.connection_count, 1);
+    }
+}
+
+/// Ensures attempts over the handshake rate limit are deferred with a Retry packet and admitted
+/// once the peer has validated its address
+#[test]
+fn endpoint_handshake_rate_limit_test() {
+    use provider::endpoint_limits::Outcome;
+
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            // every unvalidated attempt exceeds a limit of zero
+            let limits = provider::endpoint_limits::Default::builder()
+                .with_handshake_rate_limit(0, Outcome::retry())?
+                .build()?;
+
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_endpoint_limits(limits)?
+                .with_event(recorder.clone())?
+                .start()?)
+        })?;
+
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.receive().await.unwrap().unwrap();
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    let events: Vec<_> = recorder
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            RecordedEvent::EndpointHandshakeRateLimited(event) => Some(event),
+            _ => None,
+        })
+        .collect();
+    assert!(!events.is_empty());
+    for event in events {
+        assert_eq!(event.handshakes_per_sec, 0);
+        assert!(event.retry);
+    }
+}
@@ -0,0 +1,210 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Ensures the qlog trace can be exported from a connection
+#[test]
+fn export_qlog_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::qlog::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            let mut trace = vec![];
+            connection.export_qlog(&mut trace).unwrap();
+            let trace = String::from_utf8(trace).unwrap();
+
+            let mut records = trace.split_terminator('\n').map(|record| {
+                let record = record
+                    .strip_prefix('\u{1e}')
+                    .expect("records should start with a record separator");
+                serde_json::from_str::<serde_json::Value>(record).unwrap()
+            });
+
+            let header = records.next().unwrap();
+            assert_eq!(header["qlog_version"], "0.3");
+            assert_eq!(header["qlog_format"], "JSON-SEQ");
+            assert_eq!(header["trace"]["vantage_point"]["type"], "client");
+            assert_eq!(header["trace"]["common_fields"]["time_format"], "relative");
+            assert!(header["trace"]["common_fields"]["reference_time"].is_number());
+
+            let mut packets_sent = 0;
+            let mut connection_started = 0;
+            for record in records {
+                assert!(record["time"].as_f64().unwrap() >= 0.0);
+                assert!(record["data"].is_object());
+
+                let name = record["name"].as_str().unwrap();
+                let (category, event_type) = name.split_once(':').unwrap();
+                assert!(!category.is_empty() && !event_type.contains(':'));
+
+                match name {
+                    "transport:packet_sent"
+                    | "transport:packet_received"
+                    | "recovery:packet_lost" => {
+                        let header = &record["data"]["header"];
+                        let packet_type = header["packet_type"].as_str().unwrap();
+                        assert!(
+                            ["initial", "handshake", "0RTT", "1RTT"].contains(&packet_type),
+                            "unexpected packet type {}",
+                            packet_type
+                        );
+                        assert!(header["packet_number"].is_u64());
+                        packets_sent += (name == "transport:packet_sent") as usize;
+                    }
+                    "transport:datagrams_sent" | "transport:datagrams_received" => {
+                        assert!(record["data"]["raw"][0]["length"].is_u64());
+                    }
+                    "recovery:metrics_updated" => {
+                        assert!(record["data"]["smoothed_rtt"].is_number());
+                        assert!(record["data"]["congestion_window"].is_u64());
+                    }
+                    "connectivity:connection_started" => {
+                        let ip_version = record["data"]["ip_version"].as_str().unwrap();
+                        assert!(["ipv4", "ipv6"].contains(&ip_version));
+                        assert!(record["data"]["dst_port"].is_u64());
+                        assert!(record["data"]["src_cid"].is_string());
+                        connection_started += 1;
+                    }
+                    _ => {
+                        // events without a schema definition are recorded in a custom namespace
+                        assert_eq!(category, "s2n_quic", "unexpected event {}", name);
+                        assert!(record["data"]["debug"].is_string());
+                    }
+                }
+            }
+
+            assert_eq!(connection_started, 1);
+            assert!(packets_sent > 0);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures the time spent in each subscriber callback is reported
+#[test]
+fn timing_subscriber_test() {
+    use std::sync::{Arc, Mutex};
+
+    let callbacks = Arc::new(Mutex::new(std::collections::HashSet::new()));
+    let recorded = callbacks.clone();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let subscriber = provider::event::timing::Subscriber::new(
+            provider::event::stats::Subscriber::default(),
+            move |name: &'static str, _elapsed: Duration| {
+                callbacks.lock().unwrap().insert(name);
+            },
+        );
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(subscriber)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            // queries are forwarded to the wrapped subscriber
+            let stats = connection.stats().unwrap();
+            assert!(stats.packets_sent > 0);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    let recorded = recorded.lock().unwrap();
+    for name in [
+        "create_connection_context",
+        "on_packet_sent",
+        "on_packet_received",
+        "on_connection_event",
+        "on_event",
+    ] {
+        assert!(recorded.contains(name), "{} was not recorded", name);
+    }
+}
+
+/// Ensures the ring buffer subscriber records the fields of packet and datagram events
+#[test]
+fn ring_buffer_payload_test() {
+    use provider::event::ring_buffer::{self, Payload};
+
+    let events = ring_buffer::Provider::default().with_capacity(1 << 16);
+    let reader = events.reader();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(events)?
+                .start()?)
+        })?;
+
+        client(handle, server_addr)
+    })
+    .unwrap();
+
+    assert_eq!(reader.dropped(), 0);
+
+    let records: Vec<_> = reader.drain().collect();
+    let mut sent_packets = vec![];
+    let mut received_datagrams = 0;
+
+    for record in &records {
+        match record.payload {
+            Payload::PacketSent { packet_number } => {
+                assert_eq!(record.name, "transport:packet_sent");
+                sent_packets.extend(packet_number);
+            }
+            Payload::DatagramReceived { len } => {
+                assert_eq!(record.name, "transport:datagram_received");
+                assert!(len > 0);
+                received_datagrams += 1;
+            }
+            _ => {}
+        }
+    }
+
+    // the server's first packet is its Initial #0
+    assert_eq!(sent_packets.first(), Some(&0));
+    assert!(received_datagrams > 0);
+
+    // events without recorded fields don't pick up the payload of a previous event
+    assert!(records
+        .iter()
+        .filter(|record| record.name == "transport:frame_sent")
+        .all(|record| record.payload == Payload::None));
+}
@@ -0,0 +1,100 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Returns the idle transitions recorded by `recorder`, along with the connection closures
+fn transitions(recorder: &Recorder) -> Vec<&'static str> {
+    recorder
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            RecordedEvent::ConnectionIdle => Some("idle"),
+            RecordedEvent::ConnectionActive => Some("active"),
+            RecordedEvent::ConnectionClosed => Some("closed"),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Ensures connections report transitions between being idle and active
+#[test]
+fn connection_idle_test() {
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(recorder.clone())?
+            .start()?;
+
+        let recorder = recorder.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // wait for the handshake packets to be acknowledged
+            delay(Duration::from_secs(1)).await;
+            assert!(transitions(&recorder).contains(&"idle"));
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            assert!(transitions(&recorder).contains(&"active"));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures closing an idle connection doesn't report it as active
+#[test]
+fn connection_idle_close_test() {
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(recorder.clone())?
+            .start()?;
+
+        let recorder = recorder.clone();
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            // wait for the handshake packets to be acknowledged
+            delay(Duration::from_secs(1)).await;
+            assert!(transitions(&recorder).contains(&"idle"));
+
+            connection.close(123u8.into());
+
+            // wait for the connection to finish closing
+            delay(Duration::from_secs(5)).await;
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    // the connection went straight from idle to closed
+    let transitions = transitions(&recorder);
+    assert!(
+        transitions.ends_with(&["idle", "closed"]),
+        "{:?}",
+        transitions
+    );
+}
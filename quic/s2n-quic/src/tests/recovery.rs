@@ -0,0 +1,172 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Ensures the application send rate limit throttles the connection
+#[test]
+fn send_rate_limit_test() {
+    use provider::io::testing::time::now;
+
+    const RATE: u64 = 50_000;
+    const LEN: usize = 200_000;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            connection.set_send_rate_limit(Some(RATE)).unwrap();
+
+            let start = now();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from(vec![42; LEN])).await.unwrap();
+            stream.finish().unwrap();
+
+            let mut received = 0;
+            while let Some(chunk) = stream.receive().await.unwrap() {
+                received += chunk.len();
+            }
+            assert_eq!(received, LEN);
+
+            // the first burst is sent immediately so allow for one second's worth of data
+            let min_duration = Duration::from_secs((LEN as u64 - RATE) / RATE);
+            assert!(now() - start >= min_duration);
+
+            let stats = connection.stats().unwrap();
+            assert_eq!(stats.send_rate_limit, Some(RATE));
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures the peer's declared max_ack_delay is clamped for loss detection
+#[test]
+fn peer_max_ack_delay_limit_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_limits(
+                    provider::limits::Limits::default()
+                        .with_max_ack_delay(Duration::from_millis(100))
+                        .unwrap(),
+                )?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .with_limits(
+                provider::limits::Limits::default()
+                    .with_peer_max_ack_delay_limit(Duration::from_millis(10))
+                    .unwrap(),
+            )?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            let stats = connection.stats().unwrap();
+            assert_eq!(stats.peer_max_ack_delay, Some(Duration::from_millis(100)));
+            assert_eq!(
+                stats.effective_max_ack_delay,
+                Some(Duration::from_millis(10))
+            );
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Returns the number of RTT samples the client takes while the connection is idle for a second
+fn idle_rtt_samples(rtt_probing: Option<Duration>) -> usize {
+    let recorder = Recorder::default();
+    let idle_samples = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let limits = if let Some(interval) = rtt_probing {
+        provider::limits::Limits::default()
+            .with_rtt_probing(interval)
+            .unwrap()
+    } else {
+        provider::limits::Limits::default()
+    };
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(recorder.clone())?
+            .with_limits(limits)?
+            .start()?;
+
+        let recorder = recorder.clone();
+        let idle_samples = idle_samples.clone();
+        primary::spawn(async move {
+            use std::sync::atomic::Ordering;
+
+            let samples = || recorder.count(|event| matches!(event, RecordedEvent::RttSample));
+
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            // let the handshake settle before measuring the idle period
+            delay(Duration::from_millis(100)).await;
+            let start = samples();
+            delay(Duration::from_secs(1)).await;
+            idle_samples.store(samples() - start, Ordering::Relaxed);
+
+            drop(connection);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    idle_samples.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/// Ensures RTT probing keeps sampling the RTT while the connection is idle
+#[test]
+fn rtt_probing_test() {
+    assert_eq!(idle_rtt_samples(None), 0);
+
+    // a probe is sent every 50ms so roughly 20 samples are expected
+    let samples = idle_rtt_samples(Some(Duration::from_millis(50)));
+    assert!((15..=25).contains(&samples), "samples: {}", samples);
+}
+
+/// Ensures a zero RTT probing interval is rejected
+#[test]
+fn zero_rtt_probing_interval_test() {
+    assert!(provider::limits::Limits::default()
+        .with_rtt_probing(Duration::ZERO)
+        .is_err());
+}
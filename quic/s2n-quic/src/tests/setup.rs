@@ -3,6 +3,7 @@
 
 use crate::{
     client::Connect,
+    connection::Tags,
     provider::{
         event,
         io::testing::{primary, spawn, Handle, Io, Result},
@@ -11,7 +12,10 @@ use crate::{
 };
 use rand::{Rng, RngCore};
 use s2n_quic_core::{crypto::tls::testing::certificates, havoc, stream::testing::Data};
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
 
 pub static SERVER_CERTS: (&str, &str) = (certificates::CERT_PEM, certificates::KEY_PEM);
 
@@ -38,6 +42,164 @@ pub fn events() -> event::tracing::Provider {
     event::tracing::Provider::default()
 }
 
+/// An event which was published to a [`Recorder`]
+#[derive(Clone, Debug)]
+pub enum RecordedEvent {
+    /// A connection was created with the given tags
+    ConnectionCreated {
+        tags: Tags,
+    },
+    /// An event was published on a connection with the given tags
+    ///
+    /// This is recorded for every connection event, after the event itself if it is recorded.
+    ConnectionEvent {
+        tags: Tags,
+    },
+    ConnectionIdle,
+    ConnectionActive,
+    ConnectionClosed,
+    FrameSent(event::events::FrameSent),
+    PacketSent(event::events::PacketSent),
+    DatagramSent(event::events::DatagramSent),
+    RttSample,
+    EndpointMemoryPressure(event::events::EndpointMemoryPressure),
+    EndpointHandshakeRateLimited(event::events::EndpointHandshakeRateLimited),
+}
+
+/// Records the events published to an endpoint so tests can inspect them
+///
+/// Clones share the same recording, so a clone can be passed to the endpoint while the test holds
+/// on to the original.
+#[derive(Clone, Default)]
+pub struct Recorder {
+    events: Arc<Mutex<Vec<RecordedEvent>>>,
+}
+
+impl Recorder {
+    /// Returns the events recorded so far, in the order they were published
+    pub fn events(&self) -> Vec<RecordedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+
+    /// Returns the number of recorded events which match `predicate`
+    pub fn count<F: Fn(&RecordedEvent) -> bool>(&self, predicate: F) -> usize {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| predicate(event))
+            .count()
+    }
+
+    fn record(&self, event: RecordedEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl event::Subscriber for Recorder {
+    type ConnectionContext = ();
+
+    fn create_connection_context(
+        &mut self,
+        meta: &event::ConnectionMeta,
+        _info: &event::ConnectionInfo,
+    ) -> Self::ConnectionContext {
+        self.record(RecordedEvent::ConnectionCreated {
+            tags: meta.tags.clone(),
+        });
+    }
+
+    fn on_connection_idle(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        _event: &event::events::ConnectionIdle,
+    ) {
+        self.record(RecordedEvent::ConnectionIdle);
+    }
+
+    fn on_connection_active(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        _event: &event::events::ConnectionActive,
+    ) {
+        self.record(RecordedEvent::ConnectionActive);
+    }
+
+    fn on_connection_closed(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        _event: &event::events::ConnectionClosed,
+    ) {
+        self.record(RecordedEvent::ConnectionClosed);
+    }
+
+    fn on_frame_sent(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        event: &event::events::FrameSent,
+    ) {
+        self.record(RecordedEvent::FrameSent(event.clone()));
+    }
+
+    fn on_packet_sent(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        event: &event::events::PacketSent,
+    ) {
+        self.record(RecordedEvent::PacketSent(event.clone()));
+    }
+
+    fn on_datagram_sent(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        event: &event::events::DatagramSent,
+    ) {
+        self.record(RecordedEvent::DatagramSent(event.clone()));
+    }
+
+    fn on_rtt_sample(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        _meta: &event::ConnectionMeta,
+        _event: &event::events::RttSample,
+    ) {
+        self.record(RecordedEvent::RttSample);
+    }
+
+    fn on_endpoint_memory_pressure(
+        &mut self,
+        _meta: &event::events::EndpointMeta,
+        event: &event::events::EndpointMemoryPressure,
+    ) {
+        self.record(RecordedEvent::EndpointMemoryPressure(event.clone()));
+    }
+
+    fn on_endpoint_handshake_rate_limited(
+        &mut self,
+        _meta: &event::events::EndpointMeta,
+        event: &event::events::EndpointHandshakeRateLimited,
+    ) {
+        self.record(RecordedEvent::EndpointHandshakeRateLimited(event.clone()));
+    }
+
+    fn on_connection_event<E: event::Event>(
+        &mut self,
+        _context: &mut Self::ConnectionContext,
+        meta: &event::ConnectionMeta,
+        _event: &E,
+    ) {
+        self.record(RecordedEvent::ConnectionEvent {
+            tags: meta.tags.clone(),
+        });
+    }
+}
+
 pub fn server_with<F: FnOnce(Io) -> Result<Server>>(
     handle: &Handle,
     build: F,
@@ -0,0 +1,235 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Ensures transfer statistics can be queried from a connection
+#[test]
+fn stats_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap().unwrap();
+
+            let stats = connection.stats().unwrap();
+            assert!(stats.packets_sent > 0);
+            assert!(stats.packets_received > 0);
+            assert!(stats.bytes_sent >= stats.packets_sent);
+            assert!(stats.bytes_received >= stats.packets_received);
+            // the network doesn't drop any packets
+            assert_eq!(stats.packets_lost, 0);
+            assert_eq!(stats.bytes_lost, 0);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures packets dropped by the network are counted as lost
+#[test]
+fn stats_packet_loss_test() {
+    let model = Model::default();
+    model.set_drop_rate(0.1);
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from(vec![42; 100_000])).await.unwrap();
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+
+            let stats = connection.stats().unwrap();
+            assert!(stats.packets_lost > 0);
+            assert!(stats.packets_lost < stats.packets_sent);
+            assert!(stats.bytes_lost >= stats.packets_lost);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures the transfer rates decay while the connection is idle
+#[test]
+fn stats_idle_rate_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from(vec![42; 100_000])).await.unwrap();
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+
+            // wait for the interval with the transfer to be sampled
+            delay(provider::event::stats::RATE_INTERVAL * 2).await;
+            let active = connection.stats().unwrap();
+            assert!(active.bytes_sent_per_sec > 0.0);
+
+            // each idle interval is sampled as 0, which decays the rate
+            delay(provider::event::stats::RATE_INTERVAL * 10).await;
+            let idle = connection.stats().unwrap();
+            assert!(
+                idle.bytes_sent_per_sec < active.bytes_sent_per_sec / 10.0,
+                "active: {}, idle: {}",
+                active.bytes_sent_per_sec,
+                idle.bytes_sent_per_sec
+            );
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn connection_id_len_test() {
+    use provider::connection_id;
+
+    const LEN: usize = 8;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let connection_id = connection_id::default::Format::builder()
+            .with_len(LEN)?
+            .build()?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_connection_id(connection_id)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            let stats = connection.stats().unwrap();
+            assert_eq!(stats.local_connection_id_len, LEN);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn crypto_retransmission_test() {
+    let model = Model::default();
+    // drop all packets until the client's Initial packet has been retransmitted
+    model.set_drop_rate(1.0);
+
+    test(model.clone(), |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        spawn(async move {
+            delay(Duration::from_millis(1500)).await;
+            model.set_drop_rate(0.0);
+        });
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let connection = client.connect(connect).await.unwrap();
+
+            let stats = connection.stats().unwrap();
+            assert!(stats.crypto_packets_retransmitted > 0);
+            assert!(stats.crypto_bytes_retransmitted > 0);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures the time a bulk transfer spends limited by the congestion window is recorded
+#[test]
+fn congestion_limited_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(provider::event::stats::Provider::default())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            // the handshake isn't limited by the congestion window
+            let stats = connection.stats().unwrap();
+            assert_eq!(stats.congestion_limited_duration, Duration::ZERO);
+
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            // the transfer is larger than the initial congestion window
+            primary::spawn(async move {
+                for _ in 0..100 {
+                    send.send(Bytes::from_static(&[42; 10_000])).await.unwrap();
+                }
+                send.finish().unwrap();
+            });
+
+            // wait for the server to echo back all of the data
+            let mut received = 0;
+            while received < 1_000_000 {
+                received += recv.receive().await.unwrap().unwrap().len();
+            }
+
+            let stats = connection.stats().unwrap();
+            assert!(stats.congestion_limited_duration > Duration::ZERO);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
@@ -0,0 +1,140 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Ensures `receive_exact` waits for the requested amount of data
+#[test]
+fn receive_exact_test() {
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = build_client(handle)?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            primary::spawn(async move {
+                // the echoed data is split across several chunks
+                let (data, is_open) = recv.receive_exact(10).await.unwrap();
+                assert_eq!(&data[..], &[42u8; 10][..]);
+                assert!(is_open);
+
+                // the stream finishes before the requested amount is available
+                let (data, is_open) = recv.receive_exact(10).await.unwrap();
+                assert_eq!(&data[..], &[42u8; 5][..]);
+                assert!(!is_open);
+            });
+
+            for _ in 0..3 {
+                send.send(Bytes::from_static(&[42; 5])).await.unwrap();
+                delay(Duration::from_millis(10)).await;
+            }
+            send.finish().unwrap();
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures `receive_exact` rejects a `len` which the receive window can never buffer
+#[test]
+fn receive_exact_window_exceeded_test() {
+    const WINDOW: u64 = 1000;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_limits(
+                provider::limits::Limits::default()
+                    .with_bidirectional_local_data_window(WINDOW)
+                    .unwrap(),
+            )?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            // the stream only buffers half of its window before waking the application
+            let max_len = WINDOW as usize / 2;
+
+            send.send(Bytes::from(vec![42u8; max_len])).await.unwrap();
+            send.finish().unwrap();
+
+            let error = recv.receive_exact(max_len + 1).await.unwrap_err();
+            assert!(
+                matches!(error, crate::stream::Error::ReceiveWindowExceeded { .. }),
+                "unexpected error {:?}",
+                error
+            );
+
+            // the rejected call did not consume any data
+            let (data, _is_open) = recv.receive_exact(max_len).await.unwrap();
+            assert_eq!(&data[..], &vec![42u8; max_len][..]);
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Ensures bulk stream opening stops at the peer's stream limit
+#[test]
+fn open_bidirectional_streams_test() {
+    const LIMIT: u64 = 4;
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_limits(
+                    provider::limits::Limits::default()
+                        .with_max_open_remote_bidirectional_streams(LIMIT)
+                        .unwrap(),
+                )?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(events())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            assert!(connection
+                .open_bidirectional_streams(0)
+                .await
+                .unwrap()
+                .is_empty());
+
+            let streams = connection.open_bidirectional_streams(10).await.unwrap();
+            assert_eq!(streams.len(), LIMIT as usize);
+
+            let ids: std::collections::HashSet<_> = streams.iter().map(|s| s.id()).collect();
+            assert_eq!(ids.len(), streams.len());
+        });
+
+        Ok(())
+    })
+    .unwrap();
+}
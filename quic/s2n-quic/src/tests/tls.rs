@@ -0,0 +1,213 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::{certificates, fragmentation};
+
+#[test]
+fn offload_tls_test() {
+    use provider::tls::offload;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Runs the tasks immediately so the simulation stays deterministic
+    struct Inline(Arc<AtomicUsize>);
+
+    impl offload::Executor for Inline {
+        fn execute(&self, task: offload::Task) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            task()
+        }
+    }
+
+    let model = Model::default();
+    let server_tasks = Arc::new(AtomicUsize::new(0));
+    let client_tasks = Arc::new(AtomicUsize::new(0));
+
+    test(model, |handle| {
+        let executor = Inline(server_tasks.clone());
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(offload::Provider::new(SERVER_CERTS).with_executor(executor))?
+                .with_event(events())?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(
+                offload::Provider::new(certificates::CERT_PEM)
+                    .with_executor(Inline(client_tasks.clone())),
+            )?
+            .with_event(events())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            assert_eq!(
+                stream.receive().await.unwrap().unwrap(),
+                Bytes::from_static(&[42])
+            );
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    // both handshakes were polled on the executors
+    assert!(server_tasks.load(Ordering::Relaxed) > 0);
+    assert!(client_tasks.load(Ordering::Relaxed) > 0);
+}
+
+/// Runs a handshake with both endpoints fragmenting CRYPTO frames with the given mode and
+/// returns the CRYPTO frames sent by the server
+fn fragmented_handshake(mode: fragmentation::Mode) -> Vec<(String, u64, u16)> {
+    struct Fragmented<P>(P, fragmentation::Mode);
+
+    impl<P: provider::tls::Provider> provider::tls::Provider for Fragmented<P> {
+        type Server = fragmentation::Endpoint<P::Server>;
+        type Client = fragmentation::Endpoint<P::Client>;
+        type Error = P::Error;
+
+        fn start_server(self) -> Result<Self::Server, Self::Error> {
+            Ok(fragmentation::Endpoint::new(self.0.start_server()?, self.1))
+        }
+
+        fn start_client(self) -> Result<Self::Client, Self::Error> {
+            Ok(fragmentation::Endpoint::new(self.0.start_client()?, self.1))
+        }
+    }
+
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(Fragmented(SERVER_CERTS, mode))?
+                .with_event((events(), recorder.clone()))?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(Fragmented(certificates::CERT_PEM, mode))?
+            .with_event(events())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(&[42])).await.unwrap();
+            stream.finish().unwrap();
+            assert_eq!(
+                stream.receive().await.unwrap().unwrap(),
+                Bytes::from_static(&[42])
+            );
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    let frames: Vec<_> = recorder
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            RecordedEvent::FrameSent(event) => match event.frame {
+                provider::event::events::Frame::Crypto { offset, len, .. } => {
+                    // the packet type and number uniquely identify the packet
+                    let packet = format!("{:?}", event.packet_header);
+                    Some((packet, offset, len))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+    assert!(!frames.is_empty());
+    frames
+}
+
+/// Ensures the handshake completes when CRYPTO frames are limited to a fragment size
+#[test]
+fn crypto_fragment_len_test() {
+    let frames = fragmented_handshake(fragmentation::Mode::MaxLen(100));
+
+    // the server flight is larger than a single fragment
+    assert!(frames.len() > 2);
+    for (packet, offset, len) in &frames {
+        assert!(*len <= 100, "{} {} {}", packet, offset, len);
+    }
+}
+
+/// Ensures the handshake completes when each packet carries a single CRYPTO frame
+#[test]
+fn crypto_single_frame_test() {
+    let frames = fragmented_handshake(fragmentation::Mode::SingleFrame);
+
+    let packets: std::collections::HashSet<_> =
+        frames.iter().map(|(packet, ..)| packet.clone()).collect();
+    assert_eq!(packets.len(), frames.len());
+}
+
+/// Ensures the signature scheme selected in the handshake is reported on the connection
+///
+/// Only the s2n-tls provider reports the signature scheme.
+#[test]
+#[cfg(unix)]
+fn signature_scheme_test() {
+    use provider::tls::SignatureScheme;
+
+    let cases = [
+        (
+            certificates::CERT_PEM,
+            certificates::KEY_PEM,
+            SignatureScheme::ECDSA_SECP256R1_SHA256,
+        ),
+        (
+            certificates::RSA_CERT_PEM,
+            certificates::RSA_KEY_PEM,
+            SignatureScheme::RSA_PSS_RSAE_SHA256,
+        ),
+    ];
+
+    for (cert, key, expected) in cases {
+        test(Model::default(), |handle| {
+            let server_addr = server_with(handle, |io| {
+                Ok(Server::builder()
+                    .with_io(io)?
+                    .with_tls((cert, key))?
+                    .with_event(events())?
+                    .start()?)
+            })?;
+
+            let client = Client::builder()
+                .with_io(handle.builder().build().unwrap())?
+                .with_tls(cert)?
+                .with_event(events())?
+                .start()?;
+
+            primary::spawn(async move {
+                let connect = Connect::new(server_addr).with_server_name("localhost");
+                let connection = client.connect(connect).await.unwrap();
+                assert_eq!(connection.signature_scheme().unwrap(), Some(expected));
+            });
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
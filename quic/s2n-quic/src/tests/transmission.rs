@@ -0,0 +1,366 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::Client;
+use s2n_quic_core::crypto::tls::testing::certificates;
+
+/// Transfers data in both directions with the given ACK priority and returns the order of the
+/// frames in the packets which contained both ACK and STREAM frames
+fn ack_priority(priority: provider::limits::AckPriority) -> Vec<Vec<&'static str>> {
+    let model = Model::default();
+    model.set_drop_rate(0.01);
+
+    let limits = || {
+        provider::limits::Limits::default()
+            .with_ack_priority(priority)
+            .unwrap()
+    };
+
+    let recorder = Recorder::default();
+
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(events())?
+                .with_limits(limits())?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event((events(), recorder.clone()))?
+            .with_limits(limits())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let stream = connection.open_bidirectional_stream().await.unwrap();
+            let (mut recv, mut send) = stream.split();
+
+            let mut send_data = s2n_quic_core::stream::testing::Data::new(1_000_000);
+
+            let mut recv_data = send_data;
+            primary::spawn(async move {
+                while let Some(chunk) = recv.receive().await.unwrap() {
+                    recv_data.receive(&[chunk]);
+                }
+                assert!(recv_data.is_finished());
+            });
+
+            while let Some(chunk) = send_data.send_one(usize::MAX) {
+                send.send(chunk).await.unwrap();
+            }
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    let mut packets: Vec<(String, Vec<&'static str>)> = vec![];
+    for event in recorder.events() {
+        use provider::event::events::Frame;
+
+        let event = match event {
+            RecordedEvent::FrameSent(event) => event,
+            _ => continue,
+        };
+        let frame = match event.frame {
+            Frame::Ack { .. } => "ack",
+            Frame::Stream { .. } => "stream",
+            _ => continue,
+        };
+
+        // the packet type and number uniquely identify the packet
+        let packet = format!("{:?}", event.packet_header);
+        match packets.last_mut() {
+            Some((last, frames)) if *last == packet => frames.push(frame),
+            _ => packets.push((packet, vec![frame])),
+        }
+    }
+
+    let mixed: Vec<_> = packets
+        .into_iter()
+        .map(|(_packet, frames)| frames)
+        .filter(|frames| frames.contains(&"ack") && frames.contains(&"stream"))
+        .collect();
+
+    // the client both sends and receives data so some ACKs share a packet with data
+    assert!(!mixed.is_empty());
+
+    mixed
+}
+
+/// Ensures ACK frames are written before data by default
+#[test]
+fn ack_priority_ack_test() {
+    for frames in ack_priority(provider::limits::AckPriority::Ack) {
+        assert_eq!(frames.first(), Some(&"ack"), "{:?}", frames);
+    }
+}
+
+/// Ensures data is still delivered when both endpoints write data before ACK frames, and the
+/// ACK frames are written after the data
+#[test]
+fn ack_priority_data_test() {
+    for frames in ack_priority(provider::limits::AckPriority::Data) {
+        assert_eq!(frames.last(), Some(&"ack"), "{:?}", frames);
+    }
+}
+
+/// Ensures the padding added to reach the minimum Initial datagram size is reported
+#[test]
+fn datagram_padding_test() {
+    use provider::event::events::PaddingReason;
+
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server(handle)?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(recorder.clone())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            client.connect(connect).await.unwrap();
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    let datagrams: Vec<_> = recorder
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            RecordedEvent::DatagramSent(event) => Some(event),
+            _ => None,
+        })
+        .map(|event| {
+            assert_eq!(event.padding_len == 0, event.padding_reason.is_none());
+            let is_initial = matches!(
+                event.padding_reason,
+                Some(PaddingReason::InitialMinimumSize { .. })
+                    | Some(PaddingReason::CoalescedMinimumSize { .. })
+            );
+            (
+                event.len,
+                event.padding_len,
+                is_initial,
+                event.initial_packet_len,
+            )
+        })
+        .collect();
+
+    // the client's first datagram only contains an Initial packet with the ClientHello
+    let (len, padding_len, is_initial, initial_packet_len) = datagrams[0];
+    assert!(is_initial);
+    assert!(padding_len > 0);
+    assert!(len >= 1200);
+    assert_eq!(initial_packet_len, Some(len));
+
+    for (len, _padding_len, is_initial, initial_packet_len) in datagrams.iter() {
+        if *is_initial {
+            assert!(*len >= 1200);
+        }
+
+        // every client datagram carrying an Initial packet is expanded to the minimum size
+        if let Some(initial_packet_len) = initial_packet_len {
+            assert!(initial_packet_len <= len);
+            assert!(*len >= 1200);
+        }
+    }
+}
+
+fn coalesced_packets(max_coalesced_packets: Option<u8>) -> Vec<u8> {
+    let recorder = Recorder::default();
+
+    let limits = if let Some(max) = max_coalesced_packets {
+        provider::limits::Limits::default()
+            .with_max_coalesced_packets(max)
+            .unwrap()
+    } else {
+        provider::limits::Limits::default()
+    };
+
+    let model = Model::default();
+    test(model, |handle| {
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(recorder.clone())?
+                .with_limits(limits)?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(recorder.clone())?
+            .with_limits(limits)?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from_static(b"hello")).await.unwrap();
+            stream.finish().unwrap();
+            stream.receive().await.unwrap();
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    recorder
+        .events()
+        .into_iter()
+        .filter_map(|event| match event {
+            RecordedEvent::DatagramSent(event) => Some(event.packet_count),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Ensures packets are coalesced by default and the number of coalesced packets can be limited
+#[test]
+fn max_coalesced_packets_test() {
+    let datagrams = coalesced_packets(None);
+    assert!(datagrams.iter().all(|count| (1..=3).contains(count)));
+    // the server coalesces its Handshake packet with the Initial packet carrying the ServerHello
+    assert!(datagrams.iter().any(|count| *count > 1));
+
+    let datagrams = coalesced_packets(Some(1));
+    assert!(!datagrams.is_empty());
+    assert!(datagrams.iter().all(|count| *count == 1));
+}
+
+/// A data range written to a packet, keyed by the stream or CRYPTO space it belongs to
+type DataRange = (String, u64, u64);
+
+/// Ensures probes sent after a PTO are distinguished from other packets and packets carrying
+/// new data are never reported as retransmissions
+#[test]
+fn packet_sent_reason_test() {
+    use provider::event::events::{Frame, PacketHeader, PacketSentReason};
+    use std::collections::HashMap;
+
+    let recorder = Recorder::default();
+
+    let model = Model::default();
+    test(model, |handle| {
+        // drop the client's packets to force it to send probes and retransmissions
+        let server_addr = server_with(handle, |io| {
+            Ok(Server::builder()
+                .with_io(io)?
+                .with_tls(SERVER_CERTS)?
+                .with_event(events())?
+                .with_packet_interceptor(
+                    Loss::builder(Random::with_seed(123))
+                        .with_rx_loss(0..20)
+                        .with_rx_pass(1..5)
+                        .build(),
+                )?
+                .start()?)
+        })?;
+
+        let client = Client::builder()
+            .with_io(handle.builder().build().unwrap())?
+            .with_tls(certificates::CERT_PEM)?
+            .with_event(recorder.clone())?
+            .start()?;
+
+        primary::spawn(async move {
+            let connect = Connect::new(server_addr).with_server_name("localhost");
+            let mut connection = client.connect(connect).await.unwrap();
+
+            let mut stream = connection.open_bidirectional_stream().await.unwrap();
+            stream.send(Bytes::from(vec![42; 10_000])).await.unwrap();
+            stream.finish().unwrap();
+            while stream.receive().await.unwrap().is_some() {}
+        });
+
+        Ok(())
+    })
+    .unwrap();
+
+    // the data ranges written to each packet, keyed by the stream or CRYPTO space they belong to
+    let mut packets = vec![];
+    let mut pending = vec![];
+    for event in recorder.events() {
+        match event {
+            RecordedEvent::FrameSent(event) => {
+                let range = match event.frame {
+                    Frame::Stream {
+                        id, offset, len, ..
+                    } => (format!("stream {}", id), offset, offset + len as u64),
+                    Frame::Crypto { offset, len, .. } => {
+                        let space = match event.packet_header {
+                            PacketHeader::Initial { .. } => "initial",
+                            PacketHeader::Handshake { .. } => "handshake",
+                            _ => "application",
+                        };
+                        (format!("crypto {}", space), offset, offset + len as u64)
+                    }
+                    _ => continue,
+                };
+                pending.push(range);
+            }
+            RecordedEvent::PacketSent(event) => {
+                let ranges = core::mem::take(&mut pending);
+                packets.push((event.packet_header, event.sent_reason, ranges));
+            }
+            _ => {}
+        }
+    }
+
+    // the first packet is the client's Initial, which carries the ClientHello
+    let (header, reason, ranges) = &packets[0];
+    assert!(matches!(header, PacketHeader::Initial { number: 0, .. }));
+    assert!(matches!(reason, PacketSentReason::Normal { .. }));
+    assert!(!ranges.is_empty());
+
+    let mut sent: HashMap<&str, u64> = HashMap::new();
+    let mut probes = 0;
+    let mut retransmissions = 0;
+
+    for (header, reason, ranges) in packets.iter() {
+        // check if any of the ranges extend past the data sent in previous packets
+        let mut has_new_data = false;
+        for (key, _start, end) in ranges {
+            let max_end = sent.entry(key.as_str()).or_default();
+            if end > max_end {
+                has_new_data = true;
+                *max_end = *end;
+            }
+        }
+
+        match reason {
+            PacketSentReason::PtoProbe { .. } => probes += 1,
+            PacketSentReason::Retransmit { .. } => {
+                assert!(!has_new_data, "{:?} carried new data", header);
+                assert!(!ranges.is_empty(), "{:?} didn't carry any data", header);
+                retransmissions += 1;
+            }
+            PacketSentReason::AckOnly { .. } => {
+                assert!(ranges.is_empty(), "{:?} carried data", header);
+            }
+            _ => {}
+        }
+    }
+
+    assert!(probes > 0);
+    assert!(retransmissions > 0);
+}